@@ -1002,6 +1002,181 @@ pub mod pool_upgrade_command {
     }
 }
 
+pub mod ledgers_freeze_command {
+    use super::*;
+
+    command!(CommandMetadata::build("ledgers-freeze", "Send LEDGERS_FREEZE request to freeze deprecated ledgers (default ledgers are not allowed to be frozen).")
+                .add_required_param("ledgers_ids", "List of ledger ids to freeze, separated by comma.")
+                .add_optional_param("sign","Sign the request (True by default)")
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_example("ledger ledgers-freeze ledgers_ids=1010,1011")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let submitter_did = ensure_active_did(&ctx)?;
+
+        let ledgers_ids = get_str_array_param("ledgers_ids", params).map_err(error_err!())?
+            .into_iter()
+            .map(|id| id.trim().parse::<u64>()
+                .map_err(|_| println_err!("Invalid format of ledger id: \"{}\"", id)))
+            .collect::<Result<Vec<u64>, ()>>()?;
+
+        let ledgers_ids = serde_json::to_string(&ledgers_ids)
+            .map_err(|_| println_err!("Invalid format of ledgers ids"))?;
+
+        let request = Ledger::build_ledgers_freeze_request(&submitter_did, &ledgers_ids)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let (_, response): (String, Response<serde_json::Value>) =
+            send_write_request!(ctx, params, &request, wallet_handle, &wallet_name, &submitter_did);
+
+        let res = handle_transaction_response(response)
+            .map(|result| print_transaction_response(result,
+                                                     "Ledgers Freeze request has been sent to Ledger.",
+                                                     None,
+                                                     &[("ledgers_ids", "Ledgers Ids")],
+                                                     true));
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod get_frozen_ledgers_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-frozen-ledgers", "Send GET_FROZEN_LEDGERS request to get the list of frozen ledgers.")
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_example("ledger get-frozen-ledgers")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let submitter_did = get_active_did(&ctx);
+
+        let request = Ledger::build_get_frozen_ledgers_request(submitter_did.as_ref().map(String::as_str))
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let (_, response) = send_read_request!(&ctx, params, &request, submitter_did.as_ref().map(String::as_str));
+
+        let result = handle_transaction_response(response)?;
+
+        let ledgers =
+            match result["data"].as_object() {
+                Some(data) =>
+                    data.iter()
+                        .map(|(id, ledger)|
+                            json!({
+                                "id": id,
+                                "ledger": ledger["ledger"],
+                                "state": ledger["state"],
+                                "seq_no": ledger["seq_no"],
+                            }))
+                        .collect::<Vec<serde_json::Value>>(),
+                None if result["data"].is_null() => Vec::new(),
+                None => return Err(println_err!("Wrong data has been received"))
+            };
+
+        print_list_table(&ledgers,
+                         &[("id", "Ledger Id"),
+                             ("ledger", "Ledger Root Hash"),
+                             ("state", "State Root Hash"),
+                             ("seq_no", "Last Seq No")],
+                         "There are no frozen ledgers");
+
+        trace!("execute << ");
+        Ok(())
+    }
+}
+
+pub mod upgrade_status_command {
+    use super::*;
+
+    command!(CommandMetadata::build("upgrade-status", "Get the status of the pool upgrade from the verified CONFIG ledger transactions.")
+                .add_example("ledger upgrade-status")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let pool_handle = ensure_connected_pool_handle(&ctx)?;
+        let submitter_did = get_active_did(&ctx);
+
+        let mut txns: Vec<serde_json::Value> = Vec::new();
+        let mut from = 1;
+
+        loop {
+            let page = Ledger::get_txn_range(pool_handle, submitter_did.as_ref().map(String::as_str), Some("CONFIG"), from, None)
+                .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+            let page: serde_json::Value = serde_json::from_str(&page)
+                .map_err(|_| println_err!("Wrong data has been received"))?;
+
+            if let Some(page_txns) = page["txns"].as_array() {
+                txns.extend(page_txns.iter().cloned());
+            }
+
+            match page["next"].as_i64() {
+                Some(next) => from = next as i32,
+                None => break
+            }
+        }
+
+        let txns = serde_json::to_string(&txns)
+            .map_err(|_| println_err!("Invalid format of transactions"))?;
+
+        let status = Ledger::parse_upgrade_status(&txns)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let status: serde_json::Value = serde_json::from_str(&status)
+            .map_err(|_| println_err!("Wrong data has been received"))?;
+
+        if status["pool_upgrade"].is_null() {
+            println_succ!("There is no pool upgrade on the Ledger");
+        } else {
+            println_succ!("Pool upgrade:");
+            print_table(&status["pool_upgrade"],
+                        &[("name", "Name"),
+                            ("action", "Action"),
+                            ("version", "Version"),
+                            ("seq_no", "Seq No"),
+                            ("txn_time", "Transaction Time")]);
+        }
+
+        let nodes = status["nodes"].as_object()
+            .map(|nodes|
+                nodes.iter()
+                    .map(|(node, upgrade)|
+                        json!({
+                            "node": node,
+                            "action": upgrade["action"],
+                            "version": upgrade["version"],
+                            "seq_no": upgrade["seq_no"],
+                            "txn_time": upgrade["txn_time"],
+                        }))
+                    .collect::<Vec<serde_json::Value>>())
+            .unwrap_or_default();
+
+        print_list_table(&nodes,
+                         &[("node", "Node"),
+                             ("action", "Action"),
+                             ("version", "Version"),
+                             ("seq_no", "Seq No"),
+                             ("txn_time", "Transaction Time")],
+                         "There are no node upgrade reports on the Ledger");
+
+        trace!("execute << ");
+        Ok(())
+    }
+}
+
 pub mod custom_command {
     use super::*;
 
@@ -3867,6 +4042,55 @@ pub mod tests {
         }
     }
 
+    mod ledgers_freeze {
+        use super::*;
+
+        #[test]
+        pub fn ledgers_freeze_works_without_sending() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = ledgers_freeze_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledgers_ids", "1010,1011".to_string());
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let transaction = get_transaction(&ctx).unwrap();
+            let transaction: serde_json::Value = serde_json::from_str(&transaction).unwrap();
+            assert_eq!(json!([1010, 1011]), transaction["operation"]["ledgers_ids"]);
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn ledgers_freeze_works_for_invalid_ledger_id() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = ledgers_freeze_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledgers_ids", "1010,abc".to_string());
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            assert!(get_transaction(&ctx).is_none());
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn get_frozen_ledgers_works_without_sending() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = get_frozen_ledgers_command::new();
+                let mut params = CommandParams::new();
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            assert!(get_transaction(&ctx).is_some());
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
     mod custom {
         use super::*;
 
@@ -82,6 +82,22 @@ impl Ledger {
                                            reinstall, force, package).wait()
     }
 
+    pub fn build_ledgers_freeze_request(submitter_did: &str, ledgers_ids: &str) -> Result<String, IndyError> {
+        ledger::build_ledgers_freeze_request(submitter_did, ledgers_ids).wait()
+    }
+
+    pub fn build_get_frozen_ledgers_request(submitter_did: Option<&str>) -> Result<String, IndyError> {
+        ledger::build_get_frozen_ledgers_request(submitter_did).wait()
+    }
+
+    pub fn get_txn_range(pool_handle: PoolHandle, submitter_did: Option<&str>, ledger_type: Option<&str>, from: i32, to: Option<i32>) -> Result<String, IndyError> {
+        ledger::get_txn_range(pool_handle, submitter_did, ledger_type, from, to).wait()
+    }
+
+    pub fn parse_upgrade_status(txns_json: &str) -> Result<String, IndyError> {
+        ledger::parse_upgrade_status(txns_json).wait()
+    }
+
     pub fn build_auth_rule_request(submitter_did: &str,
                                    txn_type: &str,
                                    action: &str,
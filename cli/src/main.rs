@@ -170,6 +170,9 @@ fn build_executor() -> CommandExecutor {
         .add_command(ledger::pool_config_command::new())
         .add_command(ledger::pool_restart_command::new())
         .add_command(ledger::pool_upgrade_command::new())
+        .add_command(ledger::ledgers_freeze_command::new())
+        .add_command(ledger::get_frozen_ledgers_command::new())
+        .add_command(ledger::upgrade_status_command::new())
         .add_command(ledger::custom_command::new())
         .add_command(ledger::get_payment_sources_command::new())
         .add_command(ledger::payment_command::new())
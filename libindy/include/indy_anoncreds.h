@@ -89,6 +89,22 @@ extern "C" {
                                                                            const char*   revoc_reg_delta_json)
                                                       );
     
+    extern indy_error_t indy_issuer_create_credential_with_rollover(indy_handle_t command_handle,
+                                                                    indy_handle_t wallet_handle,
+                                                                    const char *  cred_offer_json,
+                                                                    const char *  cred_req_json,
+                                                                    const char *  cred_values_json,
+                                                                    const char *  rev_reg_id,
+                                                                    indy_handle_t blob_storage_reader_handle,
+                                                                    indy_handle_t tails_writer_handle,
+                                                                    const char *  rollover_config_json,
+
+                                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                                         indy_error_t  err,
+                                                                                         const char*   cred_json,
+                                                                                         const char*   issuance_json)
+                                                                    );
+    
     extern indy_error_t indy_issuer_revoke_credential(indy_handle_t command_handle,
                                                       indy_handle_t wallet_handle,
                                                       indy_handle_t blob_storage_reader_handle,
@@ -100,6 +116,56 @@ extern "C" {
                                                                            const char*   revoc_reg_delta_json)
                                                       );
 
+    extern indy_error_t indy_issuer_build_revoc_reg_entry_request(indy_handle_t command_handle,
+                                                                  indy_handle_t wallet_handle,
+                                                                  indy_handle_t blob_storage_reader_handle,
+                                                                  const char *  submitter_did,
+                                                                  const char *  rev_reg_id,
+                                                                  const char *  revoked_json,
+                                                                  const char *  unrevoked_json,
+
+                                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                                       indy_error_t  err,
+                                                                                       const char*   rev_reg_entry_request_json,
+                                                                                       const char*   revoc_reg_delta_json)
+                                                                  );
+
+    extern indy_error_t indy_issuer_set_issued_credential_tags(indy_handle_t command_handle,
+                                                               indy_handle_t wallet_handle,
+                                                               const char *  rev_reg_id,
+                                                               const char *  cred_revoc_id,
+                                                               const char *  tags_json,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err)
+                                                               );
+
+    extern indy_error_t indy_issuer_search_issued_credentials(indy_handle_t command_handle,
+                                                              indy_handle_t wallet_handle,
+                                                              const char *  query_json,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   indy_handle_t search_handle,
+                                                                                   indy_u32_t    total_count)
+                                                              );
+
+    extern indy_error_t indy_issuer_fetch_issued_credentials(indy_handle_t command_handle,
+                                                             indy_handle_t search_handle,
+                                                             indy_u32_t    count,
+
+                                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   issued_credentials_json)
+                                                             );
+
+    extern indy_error_t indy_issuer_close_issued_credentials_search(indy_handle_t command_handle,
+                                                                    indy_handle_t search_handle,
+
+                                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                                         indy_error_t  err)
+                                                                    );
+
 /*    extern indy_error_t indy_issuer_recover_credential(indy_handle_t command_handle,
                                                        indy_handle_t wallet_handle,
                                                        indy_handle_t blob_storage_reader_handle,
@@ -274,6 +340,19 @@ extern "C" {
                                                                         indy_bool_t   valid )
                                                    );
 
+    extern indy_error_t indy_verifier_verify_proof_verbose(indy_handle_t command_handle,
+                                                           const char *  proof_request_json,
+                                                           const char *  proof_json,
+                                                           const char *  schemas_json,
+                                                           const char *  credential_defs_jsons,
+                                                           const char *  rev_reg_defs_json,
+                                                           const char *  rev_regs_json,
+
+                                                           void           (*cb)(indy_handle_t command_handle_,
+                                                                                indy_error_t  err,
+                                                                                const char*   report_json)
+                                                           );
+
 
     extern indy_error_t indy_create_revocation_state(indy_handle_t command_handle,
                                                      indy_handle_t blob_storage_reader_handle,
@@ -301,6 +380,20 @@ extern "C" {
                                                                           const char*   updated_rev_state_json)
                                                      );
 
+    extern indy_error_t indy_prover_create_revocation_states(indy_handle_t command_handle,
+                                                             indy_handle_t wallet_handle,
+                                                             indy_handle_t pool_handle,
+                                                             const char *  submitter_did,
+                                                             indy_handle_t blob_storage_reader_handle,
+                                                             const char *  proof_request_json,
+                                                             const char *  requested_credentials_json,
+
+                                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   rev_states_json,
+                                                                                  const char*   requested_credentials_json)
+                                                             );
+
 
     extern indy_error_t indy_generate_nonce(indy_handle_t command_handle,
                                             void           (*cb)(indy_handle_t command_handle_,
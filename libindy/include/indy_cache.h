@@ -123,6 +123,26 @@ extern "C" {
                                                   void          (*cb)(indy_handle_t command_handle_,
                                                                       indy_error_t  err)
                                                  );
+
+    /// Purge revocation state cache.
+    ///
+    /// Revocation states are cached in the wallet by indy_prover_create_revocation_states.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///  }
+    extern indy_error_t indy_purge_rev_state_cache(indy_handle_t command_handle,
+                                                   indy_handle_t wallet_handle,
+                                                   const char *  options_json,
+                                                   void          (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err)
+                                                  );
 #ifdef __cplusplus
 }
 #endif
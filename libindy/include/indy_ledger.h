@@ -41,6 +41,42 @@ extern "C" {
                                                                           indy_error_t  err,
                                                                           const char*   request_result_json)
                                                      );
+
+    /// Signs and submits request message to validator pool with options overriding the pool defaults for this request.
+    ///
+    /// Adds submitter information to passed request json, signs it with submitter
+    /// sign key (see wallet_sign), and sends signed request message
+    /// to validator pool (see submit_request_with_options).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// request_json: Request data json.
+    /// options_json: Request options json (see indy_submit_request_with_options).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    /// Crypto*
+
+    extern indy_error_t indy_sign_and_submit_request_with_options(indy_handle_t command_handle,
+                                                                  indy_handle_t pool_handle,
+                                                                  indy_handle_t wallet_handle,
+                                                                  const char *  submitter_did,
+                                                                  const char *  request_json,
+                                                                  const char *  options_json,
+
+                                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                                       indy_error_t  err,
+                                                                                       const char*   request_result_json)
+                                                                  );
     
     /// Publishes request message to validator pool (no signing, unlike sign_and_submit_request).
     ///
@@ -68,6 +104,99 @@ extern "C" {
                                                                  const char*   request_result_json)
                                            );
 
+    /// Publishes request message to validator pool with options overriding the pool defaults for this request.
+    ///
+    /// The request is sent to the validator pool as is. It's assumed that it's already prepared.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// request_json: Request data json.
+    /// options_json: Request options json.
+    /// {
+    ///     "read_consensus": string (optional) - consensus policy for read requests (pool default is used if not set):
+    ///         "fast" - first reply with verified state proof or f+1 matching replies
+    ///         "state_proof" - only reply with verified state proof is accepted
+    ///         "consensus" - f+1 matching replies are required
+    ///         "all" - request is sent to all nodes and libindy waits for all of them to reply or time out.
+    ///             If all nodes replied the same, the reply is returned as is. Otherwise, the diff is returned:
+    ///             {
+    ///                 "op": "REPLY_DIFF",
+    ///                 "replies": [{"nodes": [<node alias>], "reply": <reply json>}], // grouped by equal replies
+    ///                 "timeout_nodes": [<node alias>],
+    ///             }
+    ///     "require_state_proof": bool (optional, false by default) - accept only replies with verified state proof,
+    ///         same as "state_proof" read consensus. Can't be combined with another "read_consensus"
+    ///     "timeout": int (optional) - time to wait for the node reply or REQACK (in sec), pool "timeout" if not set
+    ///     "extended_timeout": int (optional) - time to wait for the node reply after REQACK (in sec),
+    ///         pool "extended_timeout" if not set
+    ///     "preferred_nodes": [<node alias>] (optional) - nodes asked first by the requests sent to one node at a time
+    ///     "excluded_nodes": [<node alias>] (optional) - nodes the request is never sent to
+    ///     "retries": int (optional, 0 by default) - how many times the write request is resent to the pool
+    ///         if consensus wasn't reached because of timeouts
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_submit_request_with_options(indy_handle_t command_handle,
+                                                         indy_handle_t pool_handle,
+                                                         const char *  request_json,
+                                                         const char *  options_json,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   request_result_json)
+                                                         );
+
+    /// Publishes write request message to validator pool and reports whether it was written.
+    ///
+    /// The request is sent to the validator pool as is. It's assumed that it's already prepared and signed.
+    /// If the consensus wasn't reached because of timeouts the same request is resent to the pool.
+    /// Resending is safe: the pool orders the request with the same identifier and reqId only once
+    /// and replies with the already written transaction to the repeated one.
+    /// Concurrent submissions of the same request to the same pool are joined and get the same outcome.
+    /// If the consensus still wasn't reached, NYM, SCHEMA and CRED_DEF requests are checked by reading
+    /// the written object (GET_*) and its transaction (GET_TXN) which must have the identifier and reqId of the request.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// request_json: Request data json.
+    /// options_json: Request options json (see indy_submit_request_with_options).
+    ///     "retries" is 1 by default for write requests.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Write outcome json:
+    /// {
+    ///     "status": string - one of:
+    ///         "committed" - the transaction is written to the ledger
+    ///         "rejected" - the request was rejected by the pool (REQNACK or REJECT)
+    ///         "not_written" - the pool didn't reach consensus on the request and the follow-up read found the object written by other transaction
+    ///         "unknown" - the pool didn't reach consensus on the request and the follow-up read doesn't prove the outcome (e.g. the object isn't found yet), it may be written later
+    ///     "reply": <reply json> or null - pool reply the status is based on (GET_* or GET_TXN reply for the checked request)
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_submit_write_request(indy_handle_t command_handle,
+                                                  indy_handle_t pool_handle,
+                                                  const char *  request_json,
+                                                  const char *  options_json,
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   write_outcome_json)
+                                                  );
+
     /// Send action to particular nodes of validator pool.
     ///
     /// The list of requests can be send:
@@ -104,6 +233,40 @@ extern "C" {
                                                                 const char*   request_result_json)
                                            );
 
+    /// Send action to validator pool with options overriding the pool defaults for this request.
+    ///
+    /// The list of requests can be send:
+    ///     POOL_RESTART
+    ///     GET_VALIDATOR_INFO
+    ///
+    /// The request is sent to the nodes as is. It's assumed that it's already prepared.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// request_json: Request data json.
+    /// options_json: Request options json (see indy_submit_request_with_options).
+    ///     The action is sent to "preferred_nodes" only (all nodes if not set) except "excluded_nodes".
+    ///     "timeout" is time to wait respond from nodes, pool "extended_timeout" if not set.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_submit_action_with_options(indy_handle_t command_handle,
+                                                        indy_handle_t pool_handle,
+                                                        const char *  request_json,
+                                                        const char *  options_json,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             const char*   request_result_json)
+                                                        );
+
     /// Signs request message.
     ///
     /// Adds submitter information to passed request json, signs it with submitter
@@ -169,6 +332,8 @@ extern "C" {
 
     /// Builds a request to get a DDO.
     ///
+    /// Note: the reply isn't verified by state proof, use indy_resolve_did to get verified DID document.
+    ///
     /// #Params
     /// command_handle: command handle to map callback to caller context.
     /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
@@ -190,6 +355,140 @@ extern "C" {
                                                                         const char*   request_result_json)
                                                   );
     
+    /// Resolves `did:sov` DID into W3C DID document.
+    ///
+    /// The document is built from the NYM of the DID (verkey) and its raw `endpoint` attribute
+    /// fetched by GET_NYM and GET_ATTR requests. By default only the replies with verified state proof
+    /// are accepted, so the document is trustworthy even if it is received from a single node.
+    /// Abbreviated verkey is expanded to the full one.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// did: DID to resolve, either unqualified or qualified with `sov` method.
+    /// options_json: (Optional) Request options json (see indy_submit_request_with_options).
+    ///     If neither "read_consensus" nor "require_state_proof" is set, "require_state_proof" is true.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// DID document json:
+    /// {
+    ///     "@context": "https://www.w3.org/ns/did/v1",
+    ///     "id": "did:sov:<did>",
+    ///     "verificationMethod": [{
+    ///         "id": "did:sov:<did>#key-1",
+    ///         "type": "Ed25519VerificationKey2018",
+    ///         "controller": "did:sov:<did>",
+    ///         "publicKeyBase58": <full verkey>
+    ///     }], // empty if DID has no verkey
+    ///     "authentication": ["did:sov:<did>#key-1"],
+    ///     "service": [{
+    ///         "id": "did:sov:<did>#endpoint",
+    ///         "type": "endpoint",
+    ///         "serviceEndpoint": <endpoint>,
+    ///         "routingKeys": [<verkey>] // omitted if empty
+    ///     }] // omitted if DID has no endpoint
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    
+    extern indy_error_t indy_resolve_did(indy_handle_t command_handle,
+                                         indy_handle_t pool_handle,
+                                         const char *  did,
+                                         const char *  options_json,
+
+                                         void           (*cb)(indy_handle_t command_handle_,
+                                                              indy_error_t  err,
+                                                              const char*   did_doc_json)
+                                         );
+
+    /// Reads many schemas, credential definitions and revocation registry definitions from the ledger in one call.
+    ///
+    /// GET_SCHEMA, GET_CRED_DEF and GET_REVOC_REG_DEF requests for all the ids are sent to the pool at once
+    /// and processed concurrently over the pool connections. By default only the replies with verified state proof
+    /// are accepted. Failure to read one object doesn't fail the whole batch, the error is reported for the id instead.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// ids_json: ids of the objects to read:
+    /// {
+    ///     "schemas": [<schema_id>], // (optional)
+    ///     "cred_defs": [<cred_def_id>], // (optional)
+    ///     "rev_reg_defs": [<rev_reg_def_id>] // (optional)
+    /// }
+    /// options_json: (Optional) Request options json (see indy_submit_request_with_options) applied to every request.
+    ///     If neither "read_consensus" nor "require_state_proof" is set, "require_state_proof" is true.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Ledger objects json:
+    /// {
+    ///     "schemas": {
+    ///         <schema_id>: {"object": <schema json as indy_parse_get_schema_response returns>}
+    ///                      or {"error": {"code": int - error code, "message": string}}
+    ///     },
+    ///     "cred_defs": {
+    ///         <cred_def_id>: {"object": <cred def json>} or {"error": {...}}
+    ///     },
+    ///     "rev_reg_defs": {
+    ///         <rev_reg_def_id>: {"object": <rev reg def json>} or {"error": {...}}
+    ///     }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_get_ledger_objects(indy_handle_t command_handle,
+                                                indy_handle_t pool_handle,
+                                                const char *  submitter_did,
+                                                const char *  ids_json,
+                                                const char *  options_json,
+
+                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err,
+                                                                     const char*   objects_json)
+                                                );
+
+    /// Verifies a saved ledger reply offline against a pinned set of pool nodes BLS keys.
+    ///
+    /// The reply must contain the state proof (or the audit path for GET_TXN) with the multi-signature
+    /// of the pool nodes as it was received from the ledger. The multi-signature is checked against
+    /// the passed keys, so no connection to the pool is required.
+    /// Note: freshness of the reply isn't checked, use returned timestamp to decide on it.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// reply_json: saved reply of a read request.
+    /// bls_keys_json: BLS keys of pool validator nodes (see indy_get_pool_bls_keys):
+    ///     {
+    ///         <node alias>: <base58 BLS key> or null
+    ///     }
+    ///     All validator nodes must be listed to calculate the number of required signatures properly.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Verification result json:
+    /// {
+    ///     "verified": bool - whether the reply data is proved by the multi-signature
+    ///     "timestamp": int or null - unix time (in seconds) the multi-signature was made at, if verified
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    
+    extern indy_error_t indy_verify_reply_offline(indy_handle_t command_handle,
+                                                  const char *  reply_json,
+                                                  const char *  bls_keys_json,
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   verification_json)
+                                                  );
+
     /// Builds a NYM request. Request to create a new NYM record for a specific user.
     ///
     /// #Params
@@ -576,7 +875,8 @@ extern "C" {
     /// ledger_type: (Optional) type of the ledger the requested transaction belongs to:
     ///     DOMAIN - used default,
     ///     POOL,
-    ///     CONFIG
+    ///     CONFIG,
+    ///     AUDIT
     ///     any number
     /// seq_no: seq_no of transaction in ledger.
     /// cb: Callback that takes command result as parameter.
@@ -597,6 +897,135 @@ extern "C" {
                                                                         const char*   request_json)
                                                    );
 
+    /// Fetches a range of transactions from the ledger by GET_TXN requests and verifies them.
+    ///
+    /// Every transaction is checked against the root of the ledger by its audit path.
+    /// All transactions of the page are verified against the same root, which must be signed by a valid
+    /// BLS multi-signature of the pool nodes (their BLS keys are taken from the local pool ledger).
+    /// Replies without the multi-signature are rejected as unverified.
+    /// If the ledger grows while the page is fetched, the page is fetched again against the new root.
+    /// At most 100 transactions are returned at once, use `next` to fetch the rest of the range.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// ledger_type: (Optional) type of the ledger the transactions belong to:
+    ///     DOMAIN - used default,
+    ///     POOL,
+    ///     CONFIG,
+    ///     AUDIT
+    ///     any number
+    /// from: seq_no of the first transaction of the range (starting from 1).
+    /// to: seq_no of the last transaction of the range (inclusive).
+    ///     Pass -1 to fetch transactions up to the end of the ledger.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Page of the range as json:
+    /// {
+    ///     "txns": [<GET_TXN reply data>], // transactions with their audit paths in order of seq_no
+    ///     "ledger_size": int or null - size of the ledger the transactions were verified against,
+    ///     "root_hash": string or null - root hash of the ledger the transactions were verified against,
+    ///     "next": int or null - seq_no to pass as `from` to fetch the next page,
+    ///             null if the range is fetched or the end of the ledger is reached.
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_get_txn_range(indy_handle_t command_handle,
+                                           indy_handle_t pool_handle,
+                                           const char *  submitter_did,
+                                           const char *  ledger_type,
+                                           indy_i32_t    from,
+                                           indy_i32_t    to,
+
+                                           void           (*cb)(indy_handle_t command_handle_,
+                                                                indy_error_t  err,
+                                                                const char*   txns_json)
+                                           );
+
+    /// Subscribes to transactions newly committed to the ledger.
+    ///
+    /// The ledger is periodically caught up with the pool: the size and the root of the ledger are
+    /// requested from all nodes by LEDGER_STATUS and must be agreed by f+1 nodes, then new transactions
+    /// are requested from one node by CATCHUP_REQ. The subscription keeps only the right edge of the ledger
+    /// merkle tree: it is seeded from GET_TXN reply of the transaction preceding the first one to report
+    /// (verified by BLS multi-signature of the pool), and new transactions are verified by extending it
+    /// to the agreed root, so the ledger history before the first transaction isn't caught up.
+    /// Transactions are reported by `txn_cb` in order of seq_no.
+    /// Errors of the sync are reported by `txn_cb` too, the sync is retried on the next interval.
+    /// The subscription is cancelled automatically when the pool is closed.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    ///                 It is also passed to every call of `txn_cb`.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// ledger_type: (Optional) type of the ledger to subscribe to:
+    ///     DOMAIN - used default,
+    ///     CONFIG,
+    ///     AUDIT
+    ///     any number
+    ///     POOL ledger is caught up by the pool itself and isn't supported.
+    /// from: seq_no of the first transaction to report (starting from 1).
+    ///     Pass -1 to report only transactions committed after the subscription.
+    /// options_json: (Optional) options of the subscription:
+    /// {
+    ///     "interval": int (optional) - interval between syncs with the ledger (in sec), 10 by default.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    /// txn_cb: Callback that is called for every newly committed transaction.
+    ///
+    /// #Returns
+    /// Subscription handle (to be passed to `indy_unsubscribe_ledger`)
+    /// txn_cb is called with the transaction as it is stored on the ledger:
+    /// {
+    ///     "txn": {...},
+    ///     "txnMetadata": {"seqNo": int, ...},
+    ///     ...
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_subscribe_ledger(indy_handle_t command_handle,
+                                              indy_handle_t pool_handle,
+                                              const char *  ledger_type,
+                                              indy_i32_t    from,
+                                              const char *  options_json,
+
+                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                   indy_error_t  err,
+                                                                   indy_handle_t subscription_handle),
+
+                                              void           (*txn_cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   txn_json)
+                                              );
+
+    /// Cancels the subscription to newly committed transactions of the ledger.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// subscription_handle: subscription handle (returned by indy_subscribe_ledger).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_unsubscribe_ledger(indy_handle_t command_handle,
+                                                indy_handle_t subscription_handle,
+
+                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err)
+                                                );
+
     /// Builds a POOL_CONFIG request. Request to change Pool's configuration.
     ///
     /// #Params
@@ -695,6 +1124,107 @@ extern "C" {
                                                                              const char*   request_json)
                                                         );
 
+    /// Builds a LEDGERS_FREEZE request. Request to freeze list of ledgers (sent by Trustee).
+    ///
+    /// Frozen ledgers can't be written or caught up anymore, so it is the way to retire
+    /// the ledgers of removed plugins.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+    ///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+    /// ledgers_ids: non-empty list of ids of the ledgers to freeze as json array of integers.
+    ///              Base ledgers (0 - POOL, 1 - DOMAIN, 2 - CONFIG, 3 - AUDIT) can't be frozen.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    
+    extern indy_error_t indy_build_ledgers_freeze_request(indy_handle_t command_handle,
+                                                          const char *  submitter_did,
+                                                          const char *  ledgers_ids,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   request_json)
+                                                          );
+
+    /// Builds a GET_FROZEN_LEDGERS request. Request to get list of frozen ledgers.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    /// The reply data contains the frozen ledgers by their ids:
+    /// {
+    ///     <ledger_id>: {
+    ///         "ledger": string - root hash of the ledger at the moment of freezing
+    ///         "state": string - root hash of the ledger state at the moment of freezing
+    ///         "seq_no": int - size of the ledger at the moment of freezing
+    ///     }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    
+    extern indy_error_t indy_build_get_frozen_ledgers_request(indy_handle_t command_handle,
+                                                              const char *  submitter_did,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   request_json)
+                                                              );
+
+    /// Parses the status of the pool upgrade from the transactions of the config ledger.
+    ///
+    /// The latest POOL_UPGRADE transaction is treated as the current pool upgrade, and the latest
+    /// NODE_UPGRADE transaction of every node as its current upgrade state.
+    /// The transactions can be fetched with `indy_get_txn_range` for CONFIG ledger.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// txns_json: json array of config ledger transactions (as in GET_TXN reply data).
+    ///            Transactions of other types are ignored.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Upgrade status json:
+    /// {
+    ///     "pool_upgrade": {
+    ///         "name": string - name of the upgrade
+    ///         "version": string - version the pool is upgraded to
+    ///         "action": string - either `start` or `cancel`
+    ///         "schedule": {<node did>: <upgrade time>} or null
+    ///         "seq_no": int or null - seq_no of the transaction
+    ///         "txn_time": int or null - time the transaction was written at
+    ///     } or null if there are no upgrades,
+    ///     "nodes": {
+    ///         <node did>: {
+    ///             "action": string - one of `in_progress`, `complete`, `fail`
+    ///             "version": string - version the node is upgraded to
+    ///             "seq_no": int or null - seq_no of the transaction
+    ///             "txn_time": int or null - time the transaction was written at
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    
+    extern indy_error_t indy_parse_upgrade_status(indy_handle_t command_handle,
+                                                  const char *  txns_json,
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   upgrade_status_json)
+                                                  );
+
     /// Builds a REVOC_REG_DEF request. Request to add the definition of revocation registry
     /// to an exists credential definition.
     ///
@@ -951,6 +1481,128 @@ extern "C" {
                                                                                      unsigned long long      timestamp)
                                                                );
 
+    /// Builds a Rich Schema object request. Request to add one of the JSON-LD based Rich Schema objects:
+    /// context, rich schema, encoding, mapping, credential definition or presentation definition.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+    ///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+    /// rich_schema_object_json: Rich Schema object json
+    /// {
+    ///     id: string - identifier of the object (DID-like string),
+    ///     content: string - JSON-LD document of the object serialized to string,
+    ///     rsName: string - name of the object,
+    ///     rsVersion: string - version of the object,
+    ///     rsType: string - type of the object, one of:
+    ///         "ctx" - JSON-LD context (JSON_LD_CONTEXT transaction),
+    ///         "sch" - rich schema (RICH_SCHEMA transaction),
+    ///         "enc" - encoding (RICH_SCHEMA_ENCODING transaction),
+    ///         "map" - mapping (RICH_SCHEMA_MAPPING transaction),
+    ///         "cdf" - credential definition (RICH_SCHEMA_CRED_DEF transaction),
+    ///         "pdf" - presentation definition (RICH_SCHEMA_PRES_DEF transaction),
+    ///     ver: (Optional) string - version of the object json, "1" by default.
+    /// }
+    /// Content of the context must contain `@context`.
+    /// Content of the rich schema must contain `@type` and `@id` equal to the object id.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_rich_schema_object_request(indy_handle_t command_handle,
+                                                              const char *  submitter_did,
+                                                              const char *  rich_schema_object_json,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   request_json)
+                                                             );
+
+    /// Builds a GET_RICH_SCHEMA_OBJECT_BY_ID request. Request to get Rich Schema object of any type by its id.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// id: identifier of the Rich Schema object.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_get_rich_schema_object_by_id_request(indy_handle_t command_handle,
+                                                                        const char *  submitter_did,
+                                                                        const char *  id,
+
+                                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                                             indy_error_t  err,
+                                                                                             const char*   request_json)
+                                                                       );
+
+    /// Builds a GET_RICH_SCHEMA_OBJECT_BY_METADATA request. Request to get Rich Schema object by its type, name and version.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// rs_type: type of the Rich Schema object, one of: "ctx", "sch", "enc", "map", "cdf", "pdf".
+    /// rs_name: name of the Rich Schema object.
+    /// rs_version: version of the Rich Schema object.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_get_rich_schema_object_by_metadata_request(indy_handle_t command_handle,
+                                                                              const char *  submitter_did,
+                                                                              const char *  rs_type,
+                                                                              const char *  rs_name,
+                                                                              const char *  rs_version,
+
+                                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                                   indy_error_t  err,
+                                                                                                   const char*   request_json)
+                                                                             );
+
+    /// Parse a GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA response to get Rich Schema object.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_rich_schema_object_response: response of GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Rich Schema object id and Rich Schema object json.
+    /// {
+    ///     id: string - identifier of the object,
+    ///     content: string - JSON-LD document of the object serialized to string,
+    ///     rsName: string - name of the object,
+    ///     rsVersion: string - version of the object,
+    ///     rsType: string - type of the object,
+    ///     ver: string - version of the object json
+    /// }
+    ///
+    /// #Errors
+    /// LedgerNotFound - the object is not written on the ledger.
+    /// Common*
+
+    extern indy_error_t indy_parse_get_rich_schema_object_response(indy_handle_t command_handle,
+                                                                   const char *  get_rich_schema_object_response,
+
+                                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   rich_schema_object_id,
+                                                                                        const char*   rich_schema_object_json)
+                                                                  );
+
 
     /// Parse transaction response to fetch metadata.
     /// The important use case for this method is validation of Node's response freshens.
@@ -1354,6 +2006,220 @@ extern "C" {
                                                                           const char*   out_request_json)
                                                      );
 
+    /// Prepares an endorsement envelope for a request which needs signatures of several parties.
+    ///
+    /// The envelope contains the request and the constraint of the auth rule the request must satisfy
+    /// together with the sets of signers (roles and counts) any of which satisfies the constraint.
+    /// The envelope can be passed between the parties, each of them adds own signature by `indy_endorsement_add_signature`.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// request_json: request to be signed (signatures already present in the request are kept).
+    /// get_auth_rule_response_json: response of GET_AUTH_RULE request for the action performed by the request.
+    ///     The response must contain exactly one rule and its auth_type must match the type of the request.
+    /// owner_did: (Optional) DID of the owner of the ledger object affected by the request.
+    ///     Signature of this DID satisfies role constraints which require the owner (need_to_be_owner).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Endorsement envelope json:
+    /// {
+    ///     "request": json - the request,
+    ///     "constraint": json - the constraint of the auth rule,
+    ///     "owner": string or null - DID of the owner,
+    ///     "required_signers": [[<role constraint>]] - sets of role constraints any of which satisfies the constraint,
+    ///     "signers": {
+    ///         <did>: {"verkey": string} - signers added by `indy_endorsement_add_signature`
+    ///     }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_prepare_endorsement(indy_handle_t command_handle,
+                                                 const char *  request_json,
+                                                 const char *  get_auth_rule_response_json,
+                                                 const char *  owner_did,
+
+                                                 void           (*cb)(indy_handle_t command_handle_,
+                                                                      indy_error_t  err,
+                                                                      const char*   endorsement_json)
+                                                 );
+
+    /// Signs the request of an endorsement envelope by the DID from the wallet and records the signer.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// endorsement_json: endorsement envelope (created by `indy_prepare_endorsement`).
+    /// signer_did: DID of the signer stored in the wallet.
+    ///     The role of the signer isn't recorded, it's taken from the ledger when the status is evaluated.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Endorsement envelope json with the signature and the signer added.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+
+    extern indy_error_t indy_endorsement_add_signature(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  endorsement_json,
+                                                       const char *  signer_did,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   endorsement_json)
+                                                       );
+
+    /// Checks signatures of the request of an endorsement envelope against the keys of the recorded signers.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// endorsement_json: endorsement envelope (created by `indy_prepare_endorsement`).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Signatures check json:
+    /// {
+    ///     "valid": [string] - DIDs of the signers whose signatures match the request,
+    ///     "invalid": [string] - DIDs of the signers whose signatures don't match the request
+    ///                           (most probably the request was changed after signing),
+    ///     "unknown": [string] - DIDs which have signed the request without `indy_endorsement_add_signature`,
+    ///     "missing": [string] - DIDs of the recorded signers whose signatures were removed from the request
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_endorsement_check_signatures(indy_handle_t command_handle,
+                                                          const char *  endorsement_json,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   signatures_json)
+                                                          );
+
+    /// Evaluates whether the request of an endorsement envelope is ready to be submitted.
+    ///
+    /// Only valid signatures of the signers recorded by `indy_endorsement_add_signature` count towards the constraint.
+    /// Roles of the signers are taken from their NYMs, so a signer without NYM in GET_NYM responses
+    /// or whose NYM verkey differs from the signing one doesn't count.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// endorsement_json: endorsement envelope (created by `indy_prepare_endorsement`).
+    /// get_nym_responses_json: list of GET_NYM responses for the signers received from the ledger:
+    ///     [<response json>] (each response may be json object or string)
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Status json:
+    /// {
+    ///     "ready": bool - the constraint is met and there are no invalid signatures,
+    ///     "unmet": [{
+    ///         "constraint": json - role constraint (or the whole constraint if it's forbidden) which isn't met,
+    ///         "signatures": int - number of matching valid signatures collected for it
+    ///     }],
+    ///     "signatures": json - signatures check (see `indy_endorsement_check_signatures`),
+    ///     "unresolved": [string] - DIDs of the valid signers whose roles aren't resolved from GET_NYM responses
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_endorsement_status(indy_handle_t command_handle,
+                                                const char *  endorsement_json,
+                                                const char *  get_nym_responses_json,
+
+                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err,
+                                                                     const char*   status_json)
+                                                );
+
+    /// Submits the request of an endorsement envelope to validator pool if it is ready to be submitted.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// endorsement_json: endorsement envelope (created by `indy_prepare_endorsement`).
+    /// get_nym_responses_json: list of GET_NYM responses for the signers (see `indy_endorsement_status`).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_submit_endorsement(indy_handle_t command_handle,
+                                                indy_handle_t pool_handle,
+                                                const char *  endorsement_json,
+                                                const char *  get_nym_responses_json,
+
+                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err,
+                                                                     const char*   request_result_json)
+                                                );
+
+    /// Evaluates locally whether the request will be accepted by the ledger according to the auth rules.
+    ///
+    /// Allows to reject the request which doesn't have enough signatures before sending it to the pool.
+    /// The rules applicable to the request are selected by the transaction type of the request, the action
+    /// and the field, the old and the new value of the rule (`*` matches any value, empty string matches the absent field).
+    /// The ledger chooses ADD or EDIT rule depending on its state, so the caller specifies the action the request performs.
+    /// The request is considered accepted only if all the applicable rules are satisfied.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// request_json: request to be evaluated.
+    /// auth_action: action the request performs on the ledger: ADD to create the object or EDIT to modify the existing one.
+    /// old_value: (Optional) current value of the field on the ledger for EDIT action.
+    /// get_auth_rule_response_json: response of GET_AUTH_RULE request (either for the specific rule or for all rules).
+    /// signers_json: signers of the request:
+    /// [
+    ///     {
+    ///         "role": string or null - ledger role of the signer (TRUSTEE, STEWARD, ENDORSER, NETWORK_MONITOR or role code),
+    ///         "owner": bool - (optional, false by default) whether the signer is the owner of the ledger object
+    ///     }
+    /// ]
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Evaluation json:
+    /// {
+    ///     "accepted": bool - all applicable rules are satisfied,
+    ///     "rules": [{
+    ///         "auth_action": string - ADD or EDIT,
+    ///         "field": string,
+    ///         "old_value": string or null,
+    ///         "new_value": string or null,
+    ///         "satisfied": bool - the constraint of the rule is met,
+    ///         "unmet": [{"constraint": json, "signatures": int}] - the parts of the constraint which are not met,
+    ///         "fees": [string] - fee aliases from the metadata of the role constraints the rule is satisfied by
+    ///     }]
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_evaluate_auth_rule(indy_handle_t command_handle,
+                                                const char *  request_json,
+                                                const char *  auth_action,
+                                                const char *  old_value,
+                                                const char *  get_auth_rule_response_json,
+                                                const char *  signers_json,
+
+                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err,
+                                                                     const char*   evaluation_json)
+                                                );
+
 #ifdef __cplusplus
 }
 #endif
@@ -1,4 +1,4 @@
-use indy_api_types::{ErrorCode, IndyHandle, CommandHandle, WalletHandle, SearchHandle, INVALID_SEARCH_HANDLE};
+use indy_api_types::{ErrorCode, IndyHandle, CommandHandle, WalletHandle, PoolHandle, SearchHandle, INVALID_SEARCH_HANDLE};
 use indy_api_types::errors::prelude::*;
 use crate::commands::{Command, CommandExecutor};
 use crate::commands::anoncreds::AnoncredsCommand;
@@ -7,12 +7,13 @@ use crate::commands::anoncreds::prover::ProverCommand;
 use crate::commands::anoncreds::verifier::VerifierCommand;
 use crate::domain::anoncreds::schema::{Schema, AttributeNames, Schemas};
 use crate::domain::crypto::did::DidValue;
+use indy_api_types::domain::wallet::Tags;
 use crate::domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionConfig, CredentialDefinitionId, CredentialDefinitions};
 use crate::domain::anoncreds::credential_offer::CredentialOffer;
 use crate::domain::anoncreds::credential_request::{CredentialRequest, CredentialRequestMetadata};
 use crate::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
 use crate::domain::anoncreds::credential::{Credential, CredentialValues};
-use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryConfig, RevocationRegistryDefinition, RevocationRegistryId, RevocationRegistryDefinitions};
+use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryConfig, RevocationRegistryDefinition, RevocationRegistryId, RevocationRegistryDefinitions, RevocationRegistryRolloverConfig};
 use crate::domain::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
 use crate::domain::anoncreds::proof::Proof;
 use crate::domain::anoncreds::proof_request::{ProofRequest, ProofRequestExtraQuery};
@@ -644,6 +645,110 @@ pub extern fn indy_issuer_create_credential(command_handle: CommandHandle,
     res
 }
 
+/// Issue Credential for the given Cred Request as `indy_issuer_create_credential` does
+/// and roll the revocation registry over once it fills up.
+///
+/// When issuing the credential makes the utilization of the registry cross the threshold the next registry is created
+/// and stored into the wallet (its tails file is written with tails_writer_handle) before the credential is issued.
+/// It's linked to the current one along with the issuance, so if the call fails no credential is issued
+/// and the retry returns the requests to publish the next registry again.
+/// Subsequent calls with the id of the original registry transparently issue credentials from the next registry
+/// once the current one is full.
+///
+/// The created registry must be published to the ledger by the caller:
+/// the REVOC_REG_DEF and REVOC_REG_ENTRY requests are returned in issuance_json.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_offer_json: a cred offer created by indy_issuer_create_credential_offer
+/// cred_req_json: a credential request created by indy_prover_create_credential_req
+/// cred_values_json: a credential containing attribute values for each of requested attribute names (see `indy_issuer_create_credential`).
+/// rev_reg_id: id of revocation registry stored in the wallet (the registry the chain of rolled over registries starts from)
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
+/// tails_writer_handle: handle of blob storage to store tails of the next registry
+/// rollover_config_json: rollover configuration json.
+///     {
+///         "threshold": float, - (optional, 0.9 by default) share of the used registry indices (0, 1] triggering the rollover
+///         "max_cred_num": int, - (optional, the same as the current registry by default) maximum number of credentials the next registry can process
+///         "tag": string, - (optional) tag of the next registry. By default the tag of the current registry with the incremented `-<number>` suffix
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// cred_json: Credential json containing signed credential values (see `indy_issuer_create_credential`)
+/// issuance_json: Issuance details
+///     {
+///         "rev_reg_id": string, - id of the revocation registry the credential has been issued from
+///         "cred_rev_id": string, - local id for revocation info (Can be used for revocation of this credential)
+///         "rev_reg_delta": <revoc_reg_delta_json>, - Revocation registry delta json with a newly issued credential
+///         "rollover": { - (optional) the registry created by this call
+///             "rev_reg_id": string, - id of the next revocation registry
+///             "rev_reg_def_request": <request_json>, - REVOC_REG_DEF request to publish the next registry
+///             "rev_reg_entry_request": <request_json>, - REVOC_REG_ENTRY request with the initial state of the next registry
+///         }
+///     }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_create_credential_with_rollover(command_handle: CommandHandle,
+                                                          wallet_handle: WalletHandle,
+                                                          cred_offer_json: *const c_char,
+                                                          cred_req_json: *const c_char,
+                                                          cred_values_json: *const c_char,
+                                                          rev_reg_id: *const c_char,
+                                                          blob_storage_reader_handle: IndyHandle,
+                                                          tails_writer_handle: IndyHandle,
+                                                          rollover_config_json: *const c_char,
+                                                          cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                               cred_json: *const c_char,
+                                                                               issuance_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_create_credential_with_rollover: >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, \
+    blob_storage_reader_handle: {:?}, tails_writer_handle: {:?}, rollover_config_json: {:?}",
+           wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle, tails_writer_handle, rollover_config_json);
+
+    check_useful_validatable_json!(cred_offer_json, ErrorCode::CommonInvalidParam3, CredentialOffer);
+    check_useful_validatable_json!(cred_req_json, ErrorCode::CommonInvalidParam4, CredentialRequest);
+    check_useful_validatable_json!(cred_values_json, ErrorCode::CommonInvalidParam5, CredentialValues);
+    check_useful_validatable_string!(rev_reg_id, ErrorCode::CommonInvalidParam6, RevocationRegistryId);
+    check_useful_validatable_json!(rollover_config_json, ErrorCode::CommonInvalidParam9, RevocationRegistryRolloverConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam10);
+
+    trace!("indy_issuer_create_credential_with_rollover: entities >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, \
+    blob_storage_reader_handle: {:?}, tails_writer_handle: {:?}, rollover_config_json: {:?}",
+           wallet_handle, cred_offer_json, secret!(&cred_req_json), secret!(&cred_values_json), rev_reg_id, blob_storage_reader_handle, tails_writer_handle, rollover_config_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CreateCredentialWithRollover(
+                    wallet_handle,
+                    cred_offer_json,
+                    cred_req_json,
+                    cred_values_json,
+                    rev_reg_id,
+                    blob_storage_reader_handle,
+                    tails_writer_handle,
+                    rollover_config_json,
+                    Box::new(move |result| {
+                        let (err, cred_json, issuance_json) = prepare_result_2!(result, String::new(), String::new());
+                        trace!("indy_issuer_create_credential_with_rollover: cred_json: {:?}, issuance_json: {:?}", secret!(cred_json.as_str()), issuance_json);
+                        let cred_json = ctypes::string_to_cstring(cred_json);
+                        let issuance_json = ctypes::string_to_cstring(issuance_json);
+                        cb(command_handle, err, cred_json.as_ptr(), issuance_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_create_credential_with_rollover: <<< res: {:?}", res);
+
+    res
+}
+
 /// Revoke a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
 ///
 /// The corresponding credential definition and revocation registry must be already
@@ -711,6 +816,315 @@ pub extern fn indy_issuer_revoke_credential(command_handle: CommandHandle,
     res
 }
 
+/// Revoke and unrevoke a set of credentials identified by their cred_revoc_ids (returned by indy_issuer_create_credential)
+/// and build REVOC_REG_ENTRY transaction publishing all the changes at once.
+///
+/// The corresponding credential definition and revocation registry must be already
+/// created an stored into the wallet.
+///
+/// The revocation registry stored in the wallet is updated only if all the indices are processed successfully.
+/// The merged revocation registry delta is returned alongside the request so it can be kept by the Issuer.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// blob_storage_reader_cfg_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// rev_reg_id: id of revocation registry stored in wallet
+/// revoked_json: array of local ids of the credentials to revoke
+///     [number, ...]
+/// unrevoked_json: array of local ids of the credentials to unrevoke
+///     [number, ...]
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// rev_reg_entry_request_json: Request result as json (REVOC_REG_ENTRY transaction ready to be signed).
+/// revoc_reg_delta_json: Revocation registry delta json with all the changes applied
+/// {
+///     value: {
+///         prevAccum: string - previous accumulator value.
+///         accum: string - current accumulator value.
+///         issued: array<number> an array of unrevoked indices.
+///         revoked: array<number> an array of revoked indices.
+///     },
+///     ver: string - version revocation registry delta json
+/// }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_build_revoc_reg_entry_request(command_handle: CommandHandle,
+                                                        wallet_handle: WalletHandle,
+                                                        blob_storage_reader_cfg_handle: IndyHandle,
+                                                        submitter_did: *const c_char,
+                                                        rev_reg_id: *const c_char,
+                                                        revoked_json: *const c_char,
+                                                        unrevoked_json: *const c_char,
+                                                        cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                             rev_reg_entry_request_json: *const c_char,
+                                                                             revoc_reg_delta_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_build_revoc_reg_entry_request: >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, submitter_did: {:?}, rev_reg_id: {:?}, revoked_json: {:?}, unrevoked_json: {:?}",
+           wallet_handle, blob_storage_reader_cfg_handle, submitter_did, rev_reg_id, revoked_json, unrevoked_json);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_validatable_string!(rev_reg_id, ErrorCode::CommonInvalidParam5, RevocationRegistryId);
+    check_useful_json!(revoked_json, ErrorCode::CommonInvalidParam6, Vec<u32>);
+    check_useful_json!(unrevoked_json, ErrorCode::CommonInvalidParam7, Vec<u32>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_issuer_build_revoc_reg_entry_request: entities >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, submitter_did: {:?}, rev_reg_id: {:?}, revoked_json: {:?}, unrevoked_json: {:?}",
+           wallet_handle, blob_storage_reader_cfg_handle, submitter_did, rev_reg_id, secret!(&revoked_json), secret!(&unrevoked_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::BuildRevocRegEntryRequest(
+                    wallet_handle,
+                    blob_storage_reader_cfg_handle,
+                    submitter_did,
+                    rev_reg_id,
+                    revoked_json,
+                    unrevoked_json,
+                    Box::new(move |result| {
+                        let (err, request_json, revoc_reg_delta_json) = prepare_result_2!(result, String::new(), String::new());
+                        trace!("indy_issuer_build_revoc_reg_entry_request: request_json: {:?}, revoc_reg_delta_json: {:?}", request_json, revoc_reg_delta_json);
+                        let request_json = ctypes::string_to_cstring(request_json);
+                        let revoc_reg_delta_json = ctypes::string_to_cstring(revoc_reg_delta_json);
+                        cb(command_handle, err, request_json.as_ptr(), revoc_reg_delta_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_build_revoc_reg_entry_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Set tags of the credential issued from the revocation registry.
+///
+/// Every credential created by indy_issuer_create_credential with a revocation registry is recorded in the wallet
+/// with the following tags:
+/// {
+///     "cred_def_id": string - id of the credential definition,
+///     "rev_reg_id": string - id of the revocation registry,
+///     "cred_rev_id": string - local id of the credential in the revocation registry,
+///     "~issuance_time": string - time of the issuance (seconds since Unix Epoch), unencrypted tag,
+///     "~revoked": "true" | "false" - whether the credential is revoked by the Issuer, unencrypted tag,
+/// }
+/// This call replaces the tags set by the Issuer (for example, the identifier of the holder) keeping the ones listed above.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// rev_reg_id: id of revocation registry stored in wallet
+/// cred_revoc_id: local id for revocation info (returned by indy_issuer_create_credential)
+/// tags_json: tags to set to the issued credential record
+///     {
+///         "tagName1": <str>,
+///         ...
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_set_issued_credential_tags(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     rev_reg_id: *const c_char,
+                                                     cred_revoc_id: *const c_char,
+                                                     tags_json: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_issuer_set_issued_credential_tags: >>> wallet_handle: {:?}, rev_reg_id: {:?}, cred_revoc_id: {:?}, tags_json: {:?}",
+           wallet_handle, rev_reg_id, cred_revoc_id, tags_json);
+
+    check_useful_validatable_string!(rev_reg_id, ErrorCode::CommonInvalidParam3, RevocationRegistryId);
+    check_useful_c_str!(cred_revoc_id, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(tags_json, ErrorCode::CommonInvalidParam5, Tags);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_issuer_set_issued_credential_tags: entities >>> wallet_handle: {:?}, rev_reg_id: {:?}, cred_revoc_id: {:?}, tags_json: {:?}",
+           wallet_handle, rev_reg_id, secret!(cred_revoc_id.as_str()), tags_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::SetIssuedCredentialTags(
+                    wallet_handle,
+                    rev_reg_id,
+                    cred_revoc_id,
+                    tags_json,
+                    Box::new(move |result| {
+                        let err = prepare_result!(result);
+                        trace!("indy_issuer_set_issued_credential_tags:");
+                        cb(command_handle, err)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_set_issued_credential_tags: <<< res: {:?}", res);
+
+    res
+}
+
+/// Search for the credentials issued from the revocation registries of the Issuer.
+///
+/// Instead of immediately returning of fetched credentials
+/// this call returns search_handle that can be used later
+/// to fetch records by small batches (with indy_issuer_fetch_issued_credentials).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// query_json: (Optional) Wql query filter for the issued credentials by their tags (see `indy_issuer_set_issued_credential_tags`)
+///     {
+///         "rev_reg_id": <rev_reg_id>,
+///         "~revoked": "false",
+///         "~issuance_time": {"$gt": <seconds since Unix Epoch>},
+///         "holder": <tag value set by the Issuer>,
+///     }
+///     where query: indy-sdk/docs/design/011-wallet-query-language/README.md
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// search_handle: Search handle that can be used later to fetch records by small batches (with indy_issuer_fetch_issued_credentials)
+/// total_count: Total count of records
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_search_issued_credentials(command_handle: CommandHandle,
+                                                    wallet_handle: WalletHandle,
+                                                    query_json: *const c_char,
+                                                    cb: Option<extern fn(
+                                                        command_handle_: CommandHandle, err: ErrorCode,
+                                                        search_handle: SearchHandle,
+                                                        total_count: usize)>) -> ErrorCode {
+    trace!("indy_issuer_search_issued_credentials: >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    check_useful_opt_c_str!(query_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_search_issued_credentials: entities >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::SearchIssuedCredentials(
+                    wallet_handle,
+                    query_json,
+                    Box::new(move |result| {
+                        let (err, handle, total_count) = prepare_result_2!(result, INVALID_SEARCH_HANDLE, 0);
+                        cb(command_handle, err, handle, total_count)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_search_issued_credentials: <<< res: {:?}", res);
+
+    res
+}
+
+/// Fetch next issued credentials for search.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// search_handle: Search handle (created by indy_issuer_search_issued_credentials)
+/// count: Count of issued credentials to fetch
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// issued_credentials_json: list of the issued credentials
+///     [{
+///         "cred_def_id": string,
+///         "rev_reg_id": string,
+///         "cred_rev_id": string,
+///         "issuance_time": number - seconds since Unix Epoch,
+///         "revoked": bool,
+///         "tags": {tag name: tag value} - tags set by the Issuer
+///     }]
+/// NOTE: The list of length less than the requested count means the search iterator is completed.
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_fetch_issued_credentials(command_handle: CommandHandle,
+                                                   search_handle: SearchHandle,
+                                                   count: usize,
+                                                   cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                        issued_credentials_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_fetch_issued_credentials: >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_fetch_issued_credentials: entities >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::FetchIssuedCredentials(
+                    search_handle,
+                    count,
+                    boxed_callback_string!("indy_issuer_fetch_issued_credentials", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_fetch_issued_credentials: <<< res: {:?}", res);
+
+    res
+}
+
+/// Close issued credentials search (make search handle invalid)
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// search_handle: Search handle (created by indy_issuer_search_issued_credentials)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_close_issued_credentials_search(command_handle: CommandHandle,
+                                                          search_handle: SearchHandle,
+                                                          cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_issuer_close_issued_credentials_search: >>> search_handle: {:?}", search_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_issuer_close_issued_credentials_search: entities >>> search_handle: {:?}", search_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CloseIssuedCredentialsSearch(
+                    search_handle,
+                    Box::new(move |result| {
+                        let err = prepare_result!(result);
+                        trace!("indy_issuer_close_issued_credentials_search:");
+                        cb(command_handle, err)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_close_issued_credentials_search: <<< res: {:?}", res);
+
+    res
+}
+
 /*/// Recover a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
 ///
 /// The corresponding credential definition and revocation registry must be already
@@ -2259,6 +2673,105 @@ pub extern fn indy_verifier_verify_proof(command_handle: CommandHandle,
     res
 }
 
+/// Verifies a proof (of multiple credential) as `indy_verifier_verify_proof` does
+/// and explains the result per referent of the proof request.
+///
+/// The report describes which restrictions the credential matched or failed,
+/// whether the revealed values correspond to their encodings in the cryptographic proof,
+/// whether the non-revocation requirements are met and whether the predicates are proven.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// proof_request_json: proof request json (see `indy_verifier_verify_proof`)
+/// proof_json: created for request proof json (see `indy_verifier_verify_proof`)
+/// schemas_json: all schemas participating in the proof (see `indy_verifier_verify_proof`)
+/// credential_defs_json: all credential definitions participating in the proof (see `indy_verifier_verify_proof`)
+/// rev_reg_defs_json: all revocation registry definitions participating in the proof (see `indy_verifier_verify_proof`)
+/// rev_regs_json: all revocation registries participating in the proof (see `indy_verifier_verify_proof`)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// report_json: verification report
+///     {
+///         "valid": bool, - the same result as `indy_verifier_verify_proof` returns
+///         "error": Optional<string>, - the error failing the verification of the requested proof before the cryptographic one
+///         "proof": <check_result>, - result of the cryptographic proof verification
+///         "requested_attributes": {
+///             "<attr_referent>": {
+///                 "sub_proof_index": Optional<int>,
+///                 "self_attested": bool,
+///                 "restrictions": [<restriction_report>, ...],
+///                 "encoding": <check_result>, - revealed values correspond to their encodings in the cryptographic proof
+///                 "non_revoked": <check_result>,
+///             },
+///         },
+///         "requested_predicates": {
+///             "<predicate_referent>": {
+///                 "sub_proof_index": Optional<int>,
+///                 "restrictions": [<restriction_report>, ...],
+///                 "non_revoked": <check_result>,
+///                 "predicate": <check_result>, - predicates are proven by the cryptographic proof as a whole
+///             },
+///         },
+///     }
+/// where
+/// restriction_report: result of the single alternative of the restrictions (joined with "$or")
+///     {
+///         "restriction": <wql query>,
+///         "result": <check_result>,
+///     }
+/// check_result:
+///     {
+///         "status": "passed" | "failed" | "skipped", - "skipped" if the check does not apply to the referent
+///         "reason": Optional<string>, - the reason of the failure
+///     }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_verifier_verify_proof_verbose(command_handle: CommandHandle,
+                                                 proof_request_json: *const c_char,
+                                                 proof_json: *const c_char,
+                                                 schemas_json: *const c_char,
+                                                 credential_defs_json: *const c_char,
+                                                 rev_reg_defs_json: *const c_char,
+                                                 rev_regs_json: *const c_char,
+                                                 cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                      report_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verifier_verify_proof_verbose: >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam2, ProofRequest);
+    check_useful_validatable_json!(proof_json, ErrorCode::CommonInvalidParam3, Proof);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam4, Schemas);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam5, CredentialDefinitions);
+    check_useful_json!(rev_reg_defs_json, ErrorCode::CommonInvalidParam6, RevocationRegistryDefinitions);
+    check_useful_json!(rev_regs_json, ErrorCode::CommonInvalidParam7, RevocationRegistries);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_verifier_verify_proof_verbose: entities >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProofVerbose(
+            proof_request_json,
+            proof_json,
+            schemas_json,
+            credential_defs_json,
+            rev_reg_defs_json,
+            rev_regs_json,
+            boxed_callback_string!("indy_verifier_verify_proof_verbose", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_verify_proof_verbose: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create revocation state for a credential that corresponds to a particular time.
 ///
 /// Note that revocation delta must cover the whole registry existence time.
@@ -2400,6 +2913,93 @@ pub extern fn indy_update_revocation_state(command_handle: CommandHandle,
     res
 }
 
+/// Create revocation states required to build the proof for the proof request.
+///
+/// The revocation state is created for every requested revocable credential if either the referent
+/// has `non_revoked` interval (own or the global one of the proof request) or `timestamp` is set in the requested credentials.
+/// The state is built at the requested `timestamp`, the end of `non_revoked` interval or the current time (in this order)
+/// from the revocation registry definition and delta read from the ledger.
+///
+/// The states are cached in the wallet per revocation registry and timestamp. The latest cached state of the credential
+/// is updated with the delta since its timestamp instead of calculating the witness from scratch.
+/// The cache can be cleaned with `indy_purge_rev_state_cache`.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
+/// proof_request_json: proof request json (see `indy_prover_create_proof`)
+/// requested_credentials_json: requested credentials json (see `indy_prover_create_proof`); `timestamp` fields can be omitted
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// rev_states_json: revocation states to pass to `indy_prover_create_proof`
+///     {
+///         "rev_reg_def1_id": {
+///             "timestamp1": <rev_state1>,
+///             "timestamp2": <rev_state2>,
+///         },
+///         ...
+///     }
+/// requested_credentials_json: requested credentials json with `timestamp` of the revocation state set for every referent
+///                             that needs the state; pass it to `indy_prover_create_proof`
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_prover_create_revocation_states(command_handle: CommandHandle,
+                                                   wallet_handle: WalletHandle,
+                                                   pool_handle: PoolHandle,
+                                                   submitter_did: *const c_char,
+                                                   blob_storage_reader_handle: IndyHandle,
+                                                   proof_request_json: *const c_char,
+                                                   requested_credentials_json: *const c_char,
+                                                   cb: Option<extern fn(
+                                                       command_handle_: CommandHandle, err: ErrorCode,
+                                                       rev_states_json: *const c_char,
+                                                       requested_credentials_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_create_revocation_states: >>> wallet_handle: {:?}, pool_handle: {:?}, submitter_did: {:?}, blob_storage_reader_handle: {:?}, \
+    proof_request_json: {:?}, requested_credentials_json: {:?}",
+           wallet_handle, pool_handle, submitter_did, blob_storage_reader_handle, proof_request_json, requested_credentials_json);
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam6, ProofRequest);
+    check_useful_validatable_json!(requested_credentials_json, ErrorCode::CommonInvalidParam7, RequestedCredentials);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_prover_create_revocation_states: entities >>> wallet_handle: {:?}, pool_handle: {:?}, submitter_did: {:?}, blob_storage_reader_handle: {:?}, \
+    proof_request_json: {:?}, requested_credentials_json: {:?}",
+           wallet_handle, pool_handle, submitter_did, blob_storage_reader_handle, proof_request_json, requested_credentials_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateRevocationStates(
+            wallet_handle,
+            pool_handle,
+            submitter_did,
+            blob_storage_reader_handle,
+            proof_request_json,
+            requested_credentials_json,
+            Box::new(move |result| {
+                let (err, rev_states_json, requested_credentials_json) = prepare_result_2!(result, String::new(), String::new());
+                trace!("indy_prover_create_revocation_states: rev_states_json: {:?}, requested_credentials_json: {:?}", rev_states_json, requested_credentials_json);
+                let rev_states_json = ctypes::string_to_cstring(rev_states_json);
+                let requested_credentials_json = ctypes::string_to_cstring(requested_credentials_json);
+                cb(command_handle, err, rev_states_json.as_ptr(), requested_credentials_json.as_ptr())
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_create_revocation_states: <<< res: {:?}", res);
+
+    res
+}
+
 
 ///  Generates 80-bit numbers that can be used as a nonce for proof request.
 ///
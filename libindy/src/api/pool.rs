@@ -18,7 +18,18 @@ use libc::c_char;
 /// {
 ///     "genesis_txn": string (optional), A path to genesis transaction file. If NULL, then a default one will be used.
 ///                    If file doesn't exists default one will be created.
+///     "genesis_txns": array<object> (optional), Genesis transactions inlined into the config (for apps that embed them).
+///                    Can't be specified together with `genesis_txn`.
+///     "genesis_hash": string (optional), Expected base58 encoded merkle root hash of genesis transactions.
+///     "genesis_signature": object (optional), Steward signature of the merkle root hash of genesis transactions:
+///         {
+///             "verkey": string, base58 encoded verkey of the signer,
+///             "signature": string, base58 encoded ed25519 signature
+///         }
 /// }
+/// Genesis transactions that don't match `genesis_hash` or `genesis_signature` are rejected.
+/// Verified genesis is pinned by its hash, so genesis transactions modified after creation
+/// are rejected on opening of the pool.
 ///
 /// #Returns
 /// Error code
@@ -79,6 +90,16 @@ pub extern fn indy_create_pool_ledger_config(command_handle: CommandHandle,
 ///     "number_read_nodes": int (optional) - the number of nodes to send read requests (2 by default)
 ///         By default Libindy sends a read requests to 2 nodes in the pool.
 ///         If response isn't received or `state proof` is invalid Libindy sends the request again but to 2 (`number_read_nodes`) * 2 = 4 nodes and so far until completion.
+///     "read_consensus": string (optional) - consensus policy for read requests ("fast" by default)
+///         "fast" - first reply with verified state proof or f+1 matching replies
+///         "state_proof" - only reply with verified state proof is accepted
+///         "consensus" - f+1 matching replies are required
+///         "all" - request is sent to all nodes, divergent replies are returned as a diff (see indy_submit_request_with_options)
+///         Can be overridden for a single request by indy_submit_request_with_options.
+///     "transport": object (optional) - network transport used to communicate with the pool (ZMQ by default)
+///         {
+///             "type": string - "zmq"
+///         }
 /// }
 ///
 /// #Returns
@@ -122,6 +143,10 @@ pub extern fn indy_open_pool_ledger(command_handle: CommandHandle,
 
 /// Refreshes a local copy of a pool ledger and updates pool nodes connections.
 ///
+/// Note: the pool ledger is also refreshed in background without interrupting requests in progress
+/// when libindy detects that the pool membership is outdated (replies are signed by unknown nodes
+/// or more than f nodes don't reply in time).
+///
 /// #Params
 /// handle: pool handle returned by indy_open_pool_ledger
 ///
@@ -159,6 +184,102 @@ pub extern fn indy_refresh_pool_ledger(command_handle: CommandHandle,
     res
 }
 
+/// Returns health information about an opened pool ledger collected by libindy from node replies.
+///
+/// Only nodes libindy has communicated with are listed.
+///
+/// #Params
+/// handle: pool handle returned by indy_open_pool_ledger
+///
+/// #Returns
+/// Pool status json:
+/// {
+///     "state": string - state of the pool worker, one of
+///         "initialization", "getting_catchup_target", "active", "sync_catchup", "terminated", "closed"
+///     "nodes": [{
+///         "alias": string - node alias
+///         "last_seen": int or null - unix time (in seconds) of the last message received from the node
+///         "avg_latency_ms": int or null - average time between sending a request and receiving the node's reply
+///         "replies": int - count of REPLY messages received from the node
+///         "nacks": int - count of REQNACK and REJECT messages received from the node
+///         "timeouts": int - count of requests the node didn't reply in time
+///         "invalid_replies": int - count of messages received from the node which can't be parsed
+///         "ledger_size": int or null - size of the pool ledger reported by the node on the last catchup
+///     }]
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_pool_status(command_handle: CommandHandle,
+                                   handle: PoolHandle,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode,
+                                                        status_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_pool_status: >>> handle: {:?}", handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_get_pool_status: entities >>> handle: {:?}", handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pool(PoolCommand::GetStatus(
+            handle,
+            boxed_callback_string!("indy_get_pool_status", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_pool_status: <<< res: {:?}", res);
+
+    res
+}
+
+/// Returns BLS keys of validator nodes of a pool ledger config.
+///
+/// The keys are read from the locally stored pool ledger (genesis and caught up transactions),
+/// so the pool doesn't need to be opened. The result can be pinned and used later
+/// to verify saved replies offline (see indy_verify_reply_offline).
+///
+/// #Params
+/// config_name: Name of the pool ledger configuration.
+///
+/// #Returns
+/// BLS keys json:
+/// {
+///     <node alias>: <base58 BLS key> or null if node has no BLS key
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_pool_bls_keys(command_handle: CommandHandle,
+                                     config_name: *const c_char,
+                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                          err: ErrorCode,
+                                                          bls_keys_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_pool_bls_keys: >>> config_name: {:?}", config_name);
+
+    check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_get_pool_bls_keys: entities >>> config_name: {:?}", config_name);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pool(PoolCommand::GetBlsKeys(
+            config_name,
+            boxed_callback_string!("indy_get_pool_bls_keys", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_pool_bls_keys: <<< res: {:?}", res);
+
+    res
+}
+
 /// Lists names of created pool ledgers
 ///
 /// #Params
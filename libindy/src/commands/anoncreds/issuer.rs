@@ -1,6 +1,7 @@
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use ursa::cl::{
     new_nonce,
@@ -11,6 +12,7 @@ use ursa::cl::{CredentialKeyCorrectnessProof, CredentialPrivateKey};
 
 use crate::commands::{Command, CommandExecutor, BoxedCallbackStringStringSend};
 use crate::commands::anoncreds::AnoncredsCommand;
+use crate::commands::ledger::LedgerCommand;
 use crate::domain::anoncreds::credential::{CredentialValues, Credential};
 use crate::domain::anoncreds::credential_definition::{
     CredentialDefinition,
@@ -25,6 +27,12 @@ use crate::domain::anoncreds::credential_definition::{
 };
 use crate::domain::anoncreds::credential_offer::CredentialOffer;
 use crate::domain::anoncreds::credential_request::CredentialRequest;
+use crate::domain::anoncreds::issued_credential::{
+    CredentialIssuance,
+    IssuedCredential,
+    IssuedCredentialInfo,
+    RevocationRegistryRollover,
+};
 use crate::domain::anoncreds::revocation_registry::{
     RevocationRegistry,
     RevocationRegistryV1,
@@ -38,7 +46,8 @@ use crate::domain::anoncreds::revocation_registry_definition::{
     RevocationRegistryDefinitionV1,
     RevocationRegistryDefinitionValue,
     RevocationRegistryInfo,
-    RevocationRegistryId
+    RevocationRegistryId,
+    RevocationRegistryRolloverConfig,
 };
 use crate::domain::anoncreds::revocation_registry_delta::{
     RevocationRegistryDelta,
@@ -53,11 +62,11 @@ use crate::services::anoncreds::helpers::parse_cred_rev_id;
 use crate::services::blob_storage::BlobStorageService;
 use crate::services::crypto::CryptoService;
 use crate::services::pool::PoolService;
-use indy_wallet::{RecordOptions, WalletService};
+use indy_wallet::{RecordOptions, WalletSearch, WalletService};
 
 use super::tails::{SDKTailsAccessor, store_tails_from_generator};
-use indy_api_types::{WalletHandle, CommandHandle};
-use indy_utils::next_command_handle;
+use indy_api_types::{WalletHandle, CommandHandle, SearchHandle};
+use indy_utils::{next_command_handle, next_search_handle};
 
 pub enum IssuerCommand {
     CreateSchema(
@@ -125,6 +134,19 @@ pub enum IssuerCommand {
         Option<RevocationRegistryId>, // revocation registry id
         Option<i32>, // blob storage reader config handle
         Box<dyn Fn(IndyResult<(String, Option<String>, Option<String>)>) + Send>),
+    CreateCredentialWithRollover(
+        WalletHandle,
+        CredentialOffer, // credential offer
+        CredentialRequest, // credential request
+        CredentialValues, // credential values
+        RevocationRegistryId, // revocation registry id
+        i32, // blob storage reader config handle
+        i32, // tails writer handle
+        RevocationRegistryRolloverConfig, // rollover config
+        BoxedCallbackStringStringSend),
+    CreateCredentialWithRolloverContinue(
+        IndyResult<(String, String)>, // revoc reg def request, revoc reg entry request
+        CommandHandle),
     RevokeCredential(
         WalletHandle,
         i32, // blob storage reader config handle
@@ -141,6 +163,31 @@ pub enum IssuerCommand {
         RevocationRegistryDelta, //revocation registry delta
         RevocationRegistryDelta, //other revocation registry delta
         Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildRevocRegEntryRequest(
+        WalletHandle,
+        i32, // blob storage reader config handle
+        DidValue, // submitter did
+        RevocationRegistryId, // revocation registry id
+        Vec<u32>, // indices to revoke
+        Vec<u32>, // indices to unrevoke
+        BoxedCallbackStringStringSend),
+    SetIssuedCredentialTags(
+        WalletHandle,
+        RevocationRegistryId, // revocation registry id
+        String, // credential revoc id
+        Tags, // tags
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    SearchIssuedCredentials(
+        WalletHandle,
+        Option<String>, // query json
+        Box<dyn Fn(IndyResult<(SearchHandle, usize)>) + Send>),
+    FetchIssuedCredentials(
+        SearchHandle,
+        usize, // count
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    CloseIssuedCredentialsSearch(
+        SearchHandle,
+        Box<dyn Fn(IndyResult<()>) + Send>),
 }
 
 pub struct IssuerCommandExecutor {
@@ -151,6 +198,20 @@ pub struct IssuerCommandExecutor {
     pub crypto_service: Rc<CryptoService>,
     pending_str_str_callbacks: RefCell<HashMap<CommandHandle, BoxedCallbackStringStringSend>>,
     pending_str_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>) + Send>>>,
+    pending_rollovers: RefCell<HashMap<CommandHandle, PendingRollover>>,
+    searches: RefCell<HashMap<SearchHandle, Box<WalletSearch>>>,
+}
+
+/// Issuance waiting for the requests to publish the next registry to be built.
+struct PendingRollover {
+    wallet_handle: WalletHandle,
+    cred_offer: CredentialOffer,
+    cred_request: CredentialRequest,
+    cred_values: CredentialValues,
+    rev_reg_id: RevocationRegistryId,
+    blob_storage_reader_handle: i32,
+    next_rev_reg_id: RevocationRegistryId,
+    cb: BoxedCallbackStringStringSend,
 }
 
 impl IssuerCommandExecutor {
@@ -167,6 +228,8 @@ impl IssuerCommandExecutor {
             crypto_service,
             pending_str_str_callbacks: RefCell::new(HashMap::new()),
             pending_str_callbacks: RefCell::new(HashMap::new()),
+            pending_rollovers: RefCell::new(HashMap::new()),
+            searches: RefCell::new(HashMap::new()),
         }
     }
 
@@ -216,6 +279,16 @@ impl IssuerCommandExecutor {
                 debug!(target: "issuer_command_executor", "CreateCredential command received");
                 cb(self.new_credential(wallet_handle, &cred_offer, &cred_req, &cred_values, rev_reg_id.as_ref(), blob_storage_reader_handle));
             }
+            IssuerCommand::CreateCredentialWithRollover(wallet_handle, cred_offer, cred_req, cred_values, rev_reg_id, blob_storage_reader_handle,
+                                                        tails_writer_handle, config, cb) => {
+                debug!(target: "issuer_command_executor", "CreateCredentialWithRollover command received");
+                self.create_credential_with_rollover(wallet_handle, cred_offer, cred_req, cred_values, &rev_reg_id, blob_storage_reader_handle,
+                                                     tails_writer_handle, &config, cb);
+            }
+            IssuerCommand::CreateCredentialWithRolloverContinue(result, cb_id) => {
+                debug!(target: "issuer_command_executor", "CreateCredentialWithRolloverContinue command received");
+                self._create_credential_with_rollover_continue(cb_id, result);
+            }
             IssuerCommand::RevokeCredential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id, cb) => {
                 debug!(target: "issuer_command_executor", "RevokeCredential command received");
                 cb(self.revoke_credential(wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_revoc_id));
@@ -229,6 +302,26 @@ impl IssuerCommandExecutor {
                 cb(self.merge_revocation_registry_deltas(&mut RevocationRegistryDeltaV1::from(rev_reg_delta),
                                                          &RevocationRegistryDeltaV1::from(other_rev_reg_delta)));
             }
+            IssuerCommand::BuildRevocRegEntryRequest(wallet_handle, blob_storage_reader_handle, submitter_did, rev_reg_id, revoked, unrevoked, cb) => {
+                debug!(target: "issuer_command_executor", "BuildRevocRegEntryRequest command received");
+                self.build_revoc_reg_entry_request(wallet_handle, blob_storage_reader_handle, submitter_did, rev_reg_id, &revoked, &unrevoked, cb);
+            }
+            IssuerCommand::SetIssuedCredentialTags(wallet_handle, rev_reg_id, cred_revoc_id, tags, cb) => {
+                debug!(target: "issuer_command_executor", "SetIssuedCredentialTags command received");
+                cb(self.set_issued_credential_tags(wallet_handle, &rev_reg_id, &cred_revoc_id, &tags));
+            }
+            IssuerCommand::SearchIssuedCredentials(wallet_handle, query_json, cb) => {
+                debug!(target: "issuer_command_executor", "SearchIssuedCredentials command received");
+                cb(self.search_issued_credentials(wallet_handle, query_json.as_ref().map(String::as_str)));
+            }
+            IssuerCommand::FetchIssuedCredentials(search_handle, count, cb) => {
+                debug!(target: "issuer_command_executor", "FetchIssuedCredentials command received");
+                cb(self.fetch_issued_credentials(search_handle, count));
+            }
+            IssuerCommand::CloseIssuedCredentialsSearch(search_handle, cb) => {
+                debug!(target: "issuer_command_executor", "CloseIssuedCredentialsSearch command received");
+                cb(self.close_issued_credentials_search(search_handle));
+            }
         };
     }
 
@@ -629,6 +722,7 @@ impl IssuerCommandExecutor {
             id: rev_reg_id.clone(),
             curr_id: 0,
             used_ids: HashSet::new(),
+            next_id: None,
         };
 
         self.wallet_service.add_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg_info, &HashMap::new())?;
@@ -674,6 +768,18 @@ impl IssuerCommandExecutor {
                       cred_values: &CredentialValues,
                       rev_reg_id: Option<&RevocationRegistryId>,
                       blob_storage_reader_handle: Option<i32>) -> IndyResult<(String, Option<String>, Option<String>)> {
+        self._new_credential(wallet_handle, cred_offer, cred_request, cred_values, rev_reg_id, blob_storage_reader_handle, None)
+    }
+
+    /// Issues the credential linking the registry to the next one in the same update of its info.
+    fn _new_credential(&self,
+                       wallet_handle: WalletHandle,
+                       cred_offer: &CredentialOffer,
+                       cred_request: &CredentialRequest,
+                       cred_values: &CredentialValues,
+                       rev_reg_id: Option<&RevocationRegistryId>,
+                       blob_storage_reader_handle: Option<i32>,
+                       next_rev_reg_id: Option<&RevocationRegistryId>) -> IndyResult<(String, Option<String>, Option<String>)> {
         debug!("new_credential >>> wallet_handle: {:?}, cred_offer: {:?}, cred_req: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, blob_storage_reader_handle: {:?}",
                wallet_handle, secret!(&cred_offer), secret!(&cred_request), secret!(&cred_values), rev_reg_id, blob_storage_reader_handle);
 
@@ -715,6 +821,10 @@ impl IssuerCommandExecutor {
                     rev_reg_info.used_ids.insert(rev_reg_info.curr_id);
                 }
 
+                if let Some(next_rev_reg_id) = next_rev_reg_id {
+                    rev_reg_info.next_id = Some(next_rev_reg_id.clone());
+                }
+
                 // TODO: FIXME: Review error kind!
                 let blob_storage_reader_handle = blob_storage_reader_handle
                     .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "TailsReaderHandle not found"))?;
@@ -787,6 +897,8 @@ impl IssuerCommandExecutor {
 
             self.wallet_service.update_indy_object(wallet_handle, &r_reg_id.0, &revoc_reg)?;
             self.wallet_service.update_indy_object(wallet_handle, &r_reg_id.0, &r_reg_info)?;
+
+            self._wallet_add_issued_credential(wallet_handle, &cred_def_id, r_reg_id, r_reg_info.curr_id)?;
         };
 
         let cred_rev_id = rev_reg_info.map(|r_reg_info| r_reg_info.curr_id.to_string());
@@ -796,6 +908,223 @@ impl IssuerCommandExecutor {
         Ok((cred_json, cred_rev_id, rev_reg_delta_json))
     }
 
+    fn create_credential_with_rollover(&self,
+                                       wallet_handle: WalletHandle,
+                                       cred_offer: CredentialOffer,
+                                       cred_request: CredentialRequest,
+                                       cred_values: CredentialValues,
+                                       rev_reg_id: &RevocationRegistryId,
+                                       blob_storage_reader_handle: i32,
+                                       tails_writer_handle: i32,
+                                       config: &RevocationRegistryRolloverConfig,
+                                       cb: BoxedCallbackStringStringSend) {
+        debug!("create_credential_with_rollover >>> wallet_handle: {:?}, cred_offer: {:?}, cred_req: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, \
+               blob_storage_reader_handle: {:?}, tails_writer_handle: {:?}, config: {:?}",
+               wallet_handle, secret!(&cred_offer), secret!(&cred_request), secret!(&cred_values), rev_reg_id, blob_storage_reader_handle,
+               tails_writer_handle, config);
+
+        let rev_reg_id = try_cb!(self._active_revocation_registry(wallet_handle, rev_reg_id), cb);
+
+        let rollover = try_cb!(self._rollover_revocation_registry(wallet_handle, &rev_reg_id, tails_writer_handle, config), cb);
+
+        let (issuer_did, next_rev_reg_id, rev_reg_def, rev_reg_delta) = match rollover {
+            Some(rollover) => rollover,
+            None => {
+                return cb(self._issue_credential_with_rollover(wallet_handle, &cred_offer, &cred_request, &cred_values, &rev_reg_id,
+                                                               blob_storage_reader_handle, None));
+            }
+        };
+
+        let cb_id = next_command_handle();
+        self.pending_rollovers.borrow_mut().insert(cb_id, PendingRollover {
+            wallet_handle,
+            cred_offer,
+            cred_request,
+            cred_values,
+            rev_reg_id,
+            blob_storage_reader_handle,
+            next_rev_reg_id: next_rev_reg_id.clone(),
+            cb,
+        });
+
+        let rev_def_type = rev_reg_def.revoc_def_type.to_str().to_string();
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::BuildRevocRegDefRequest(
+                    issuer_did.clone(),
+                    RevocationRegistryDefinition::RevocationRegistryDefinitionV1(rev_reg_def),
+                    Box::new(move |rev_reg_def_request| {
+                        let rev_reg_def_request = match rev_reg_def_request {
+                            Ok(rev_reg_def_request) => rev_reg_def_request,
+                            Err(err) => {
+                                return CommandExecutor::instance().send(
+                                    Command::Anoncreds(
+                                        AnoncredsCommand::Issuer(
+                                            IssuerCommand::CreateCredentialWithRolloverContinue(Err(err), cb_id)
+                                        )
+                                    )).unwrap();
+                            }
+                        };
+
+                        CommandExecutor::instance().send(
+                            Command::Ledger(
+                                LedgerCommand::BuildRevocRegEntryRequest(
+                                    issuer_did.clone(),
+                                    next_rev_reg_id.clone(),
+                                    rev_def_type.clone(),
+                                    RevocationRegistryDelta::RevocationRegistryDeltaV1(rev_reg_delta.clone()),
+                                    Box::new(move |rev_reg_entry_request| {
+                                        CommandExecutor::instance().send(
+                                            Command::Anoncreds(
+                                                AnoncredsCommand::Issuer(
+                                                    IssuerCommand::CreateCredentialWithRolloverContinue(
+                                                        rev_reg_entry_request.map(|rev_reg_entry_request| (rev_reg_def_request.clone(), rev_reg_entry_request)),
+                                                        cb_id)
+                                                )
+                                            )).unwrap();
+                                    })
+                                )
+                            )).unwrap();
+                    })
+                )
+            )).unwrap();
+    }
+
+    fn _create_credential_with_rollover_continue(&self,
+                                                 cb_id: CommandHandle,
+                                                 result: IndyResult<(String, String)>) {
+        let PendingRollover {
+            wallet_handle, cred_offer, cred_request, cred_values, rev_reg_id, blob_storage_reader_handle, next_rev_reg_id, cb
+        } = self.pending_rollovers.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let rollover = try_cb!(result
+            .and_then(|(rev_reg_def_request, rev_reg_entry_request)| Ok(RevocationRegistryRollover {
+                rev_reg_id: next_rev_reg_id,
+                rev_reg_def_request: serde_json::from_str(&rev_reg_def_request)
+                    .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize REVOC_REG_DEF request")?,
+                rev_reg_entry_request: serde_json::from_str(&rev_reg_entry_request)
+                    .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize REVOC_REG_ENTRY request")?,
+            })), cb);
+
+        cb(self._issue_credential_with_rollover(wallet_handle, &cred_offer, &cred_request, &cred_values, &rev_reg_id,
+                                                blob_storage_reader_handle, Some(rollover)))
+    }
+
+    /// Issues the credential once the next registry (if any) is ready to be published.
+    ///
+    /// The registry is linked to the next one only if the credential is issued,
+    /// so the failed issuance can be retried and returns the requests to publish the next registry again.
+    fn _issue_credential_with_rollover(&self,
+                                       wallet_handle: WalletHandle,
+                                       cred_offer: &CredentialOffer,
+                                       cred_request: &CredentialRequest,
+                                       cred_values: &CredentialValues,
+                                       rev_reg_id: &RevocationRegistryId,
+                                       blob_storage_reader_handle: i32,
+                                       rollover: Option<RevocationRegistryRollover>) -> IndyResult<(String, String)> {
+        let (cred_json, cred_rev_id, rev_reg_delta_json) =
+            self._new_credential(wallet_handle, cred_offer, cred_request, cred_values, Some(rev_reg_id), Some(blob_storage_reader_handle),
+                                 rollover.as_ref().map(|rollover| &rollover.rev_reg_id))?;
+
+        let cred_rev_id = cred_rev_id
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Credential revocation id hasn't been calculated"))?;
+
+        let rev_reg_delta = rev_reg_delta_json
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Revocation registry delta hasn't been calculated"))
+            .and_then(|rev_reg_delta_json| serde_json::from_str::<RevocationRegistryDelta>(&rev_reg_delta_json)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistryDelta"))?;
+
+        let issuance = CredentialIssuance {
+            rev_reg_id: rev_reg_id.clone(),
+            cred_rev_id,
+            rev_reg_delta,
+            rollover,
+        };
+
+        let issuance_json = serde_json::to_string(&issuance)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CredentialIssuance")?;
+
+        debug!("create_credential_with_rollover <<< cred_json: {:?}, issuance_json: {:?}", secret!(&cred_json), issuance_json);
+
+        Ok((cred_json, issuance_json))
+    }
+
+    /// Follows the chain of the rolled over registries up to the first one having free indices.
+    fn _active_revocation_registry(&self,
+                                   wallet_handle: WalletHandle,
+                                   rev_reg_id: &RevocationRegistryId) -> IndyResult<RevocationRegistryId> {
+        let mut rev_reg_id = rev_reg_id.clone();
+
+        loop {
+            let rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, &rev_reg_id)?;
+
+            let rev_reg_def = RevocationRegistryDefinitionV1::from(self._wallet_get_rev_reg_def(wallet_handle, &rev_reg_id)?);
+
+            match rev_reg_info.next_id {
+                Some(next_id) if rev_reg_info.curr_id >= rev_reg_def.value.max_cred_num => rev_reg_id = next_id,
+                _ => return Ok(rev_reg_id)
+            }
+        }
+    }
+
+    /// Creates the next registry if issuing one more credential makes the utilization of the current one cross the threshold.
+    ///
+    /// The next registry isn't linked to the current one here. The one left unlinked by the failed issuance is reused.
+    /// Returns the issuer DID, the id, the definition and the initial state of the next registry.
+    fn _rollover_revocation_registry(&self,
+                                     wallet_handle: WalletHandle,
+                                     rev_reg_id: &RevocationRegistryId,
+                                     tails_writer_handle: i32,
+                                     config: &RevocationRegistryRolloverConfig)
+                                     -> IndyResult<Option<(DidValue, RevocationRegistryId, RevocationRegistryDefinitionV1, RevocationRegistryDeltaV1)>> {
+        let rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, rev_reg_id)?;
+
+        let rev_reg_def = RevocationRegistryDefinitionV1::from(self._wallet_get_rev_reg_def(wallet_handle, rev_reg_id)?);
+
+        if rev_reg_info.next_id.is_some() ||
+            f64::from(rev_reg_info.curr_id + 1) < config.threshold() * f64::from(rev_reg_def.value.max_cred_num) {
+            return Ok(None);
+        }
+
+        let (issuer_did, _, _, _) = rev_reg_id.parts()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Revocation registry id {:?} is invalid", rev_reg_id)))?;
+
+        let tag = config.next_tag(&rev_reg_def.tag);
+
+        let next_rev_reg_id = RevocationRegistryId::new(&issuer_did, &rev_reg_def.cred_def_id, rev_reg_def.revoc_def_type.to_str(), &tag);
+
+        if self.wallet_service.record_exists::<RevocationRegistryDefinition>(wallet_handle, &next_rev_reg_id.0)? &&
+            self._wallet_get_rev_reg_info(wallet_handle, &next_rev_reg_id)?.curr_id > 0 {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Revocation registry {:?} to roll over to already exists", next_rev_reg_id)));
+        }
+
+        let rev_reg_config = RevocationRegistryConfig {
+            issuance_type: Some(rev_reg_def.value.issuance_type.clone()),
+            max_cred_num: Some(config.max_cred_num.unwrap_or(rev_reg_def.value.max_cred_num)),
+        };
+
+        let (_, next_rev_reg_def_json, next_rev_reg_json) =
+            self.create_and_store_revocation_registry(wallet_handle,
+                                                      &issuer_did,
+                                                      Some(rev_reg_def.revoc_def_type.to_str()),
+                                                      &tag,
+                                                      &rev_reg_def.cred_def_id,
+                                                      &rev_reg_config,
+                                                      tails_writer_handle)?;
+
+        let next_rev_reg_def = serde_json::from_str::<RevocationRegistryDefinition>(&next_rev_reg_def_json)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistryDefinition")?;
+
+        let next_rev_reg_delta = serde_json::from_str::<RevocationRegistryDelta>(&next_rev_reg_json)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistryDelta")?;
+
+        Ok(Some((issuer_did,
+                 next_rev_reg_id,
+                 RevocationRegistryDefinitionV1::from(next_rev_reg_def),
+                 RevocationRegistryDeltaV1::from(next_rev_reg_delta))))
+    }
+
     fn revoke_credential(&self,
                          wallet_handle: WalletHandle,
                          blob_storage_reader_handle: i32,
@@ -850,6 +1179,8 @@ impl IssuerCommandExecutor {
         self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg)?;
         self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg_info)?;
 
+        self._wallet_set_issued_credential_revoked(wallet_handle, rev_reg_id, cred_revoc_id, true)?;
+
         debug!("revoke_credential <<< rev_reg_delta_json: {:?}", rev_reg_delta_json);
 
         Ok(rev_reg_delta_json)
@@ -909,6 +1240,8 @@ impl IssuerCommandExecutor {
         self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg)?;
         self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg_info)?;
 
+        self._wallet_set_issued_credential_revoked(wallet_handle, rev_reg_id, cred_revoc_id, false)?;
+
         debug!("recovery_credential <<< rev_reg_delta_json: {:?}", rev_reg_delta_json);
 
         Ok(rev_reg_delta_json)
@@ -931,6 +1264,284 @@ impl IssuerCommandExecutor {
         Ok(merged_rev_reg_delta_json)
     }
 
+    fn build_revoc_reg_entry_request(&self,
+                                     wallet_handle: WalletHandle,
+                                     blob_storage_reader_handle: i32,
+                                     submitter_did: DidValue,
+                                     rev_reg_id: RevocationRegistryId,
+                                     revoked: &[u32],
+                                     unrevoked: &[u32],
+                                     cb: BoxedCallbackStringStringSend) {
+        debug!("build_revoc_reg_entry_request >>> wallet_handle: {:?}, blob_storage_reader_handle: {:?}, submitter_did: {:?}, rev_reg_id: {:?}, revoked: {:?}, unrevoked: {:?}",
+               wallet_handle, blob_storage_reader_handle, submitter_did, rev_reg_id, secret!(revoked), secret!(unrevoked));
+
+        try_cb!(self.crypto_service.validate_did(&submitter_did), cb);
+
+        let (rev_def_type, rev_reg_delta) = try_cb!(self._update_revocation_registry(wallet_handle, blob_storage_reader_handle, &rev_reg_id, revoked, unrevoked), cb);
+
+        let rev_reg_delta_json = try_cb!(serde_json::to_string(&rev_reg_delta)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryDelta"), cb);
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::BuildRevocRegEntryRequest(
+                    submitter_did,
+                    rev_reg_id,
+                    rev_def_type,
+                    rev_reg_delta,
+                    Box::new(move |request| cb(request.map(|request| (request, rev_reg_delta_json.clone()))))
+                )
+            )
+        ).unwrap();
+    }
+
+    /// Revokes and unrevokes the credentials at once.
+    ///
+    /// The registry is stored to the wallet only if all the indices are processed successfully.
+    /// Returns the type of the registry and the delta merged from the changes of all indices.
+    fn _update_revocation_registry(&self,
+                                   wallet_handle: WalletHandle,
+                                   blob_storage_reader_handle: i32,
+                                   rev_reg_id: &RevocationRegistryId,
+                                   revoked: &[u32],
+                                   unrevoked: &[u32]) -> IndyResult<(String, RevocationRegistryDelta)> {
+        if revoked.is_empty() && unrevoked.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "No indices to revoke or unrevoke have been passed"));
+        }
+
+        if let Some(idx) = revoked.iter().find(|&idx| unrevoked.contains(idx)) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Index {} is both revoked and unrevoked", idx)));
+        }
+
+        let revocation_registry_definition: RevocationRegistryDefinitionV1 =
+            RevocationRegistryDefinitionV1::from(
+                self._wallet_get_rev_reg_def(wallet_handle, rev_reg_id)?);
+
+        let mut rev_reg: RevocationRegistryV1 =
+            RevocationRegistryV1::from(
+                self._wallet_get_rev_reg(wallet_handle, rev_reg_id)?);
+
+        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
+                                                       blob_storage_reader_handle,
+                                                       &revocation_registry_definition)?;
+
+        let mut rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, rev_reg_id)?;
+
+        let max_cred_num = revocation_registry_definition.value.max_cred_num;
+        let issuance_by_default = revocation_registry_definition.value.issuance_type == IssuanceType::ISSUANCE_BY_DEFAULT;
+
+        let mut rev_reg_delta: Option<CryptoRevocationRegistryDelta> = None;
+
+        for (&cred_revoc_id, revoke) in revoked.iter().map(|idx| (idx, true)).chain(unrevoked.iter().map(|idx| (idx, false))) {
+            if cred_revoc_id < 1 || cred_revoc_id > max_cred_num {
+                return Err(err_msg(IndyErrorKind::InvalidUserRevocId, format!("Revocation id: {:?} not found in RevocationRegistry", cred_revoc_id)));
+            }
+
+            // used ids are the issued credentials for ISSUANCE_ON_DEMAND and the revoked ones for ISSUANCE_BY_DEFAULT
+            let updated = if revoke == issuance_by_default {
+                rev_reg_info.used_ids.insert(cred_revoc_id)
+            } else {
+                rev_reg_info.used_ids.remove(&cred_revoc_id)
+            };
+
+            if !updated {
+                return Err(err_msg(IndyErrorKind::InvalidUserRevocId,
+                                   format!("Credential with revocation id: {:?} is already {}", cred_revoc_id, if revoke { "revoked" } else { "unrevoked" })));
+            }
+
+            let delta = if revoke {
+                self.anoncreds_service.issuer.revoke(&mut rev_reg.value, max_cred_num, cred_revoc_id, &sdk_tails_accessor)?
+            } else {
+                self.anoncreds_service.issuer.recovery(&mut rev_reg.value, max_cred_num, cred_revoc_id, &sdk_tails_accessor)?
+            };
+
+            match rev_reg_delta {
+                Some(ref mut rev_reg_delta) => rev_reg_delta.merge(&delta)?,
+                None => rev_reg_delta = Some(delta)
+            };
+        }
+
+        let rev_reg_delta = rev_reg_delta
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Revocation registry delta hasn't been calculated"))?;
+
+        let rev_reg = RevocationRegistry::RevocationRegistryV1(rev_reg);
+
+        self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg)?;
+        self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg_info)?;
+
+        for (&cred_revoc_id, revoke) in revoked.iter().map(|idx| (idx, true)).chain(unrevoked.iter().map(|idx| (idx, false))) {
+            self._wallet_set_issued_credential_revoked(wallet_handle, rev_reg_id, cred_revoc_id, revoke)?;
+        }
+
+        Ok((revocation_registry_definition.revoc_def_type.to_str().to_string(),
+            RevocationRegistryDelta::RevocationRegistryDeltaV1(RevocationRegistryDeltaV1 { value: rev_reg_delta })))
+    }
+
+    fn set_issued_credential_tags(&self,
+                                  wallet_handle: WalletHandle,
+                                  rev_reg_id: &RevocationRegistryId,
+                                  cred_revoc_id: &str,
+                                  tags: &Tags) -> IndyResult<()> {
+        debug!("set_issued_credential_tags >>> wallet_handle: {:?}, rev_reg_id: {:?}, cred_revoc_id: {:?}, tags: {:?}",
+               wallet_handle, rev_reg_id, secret!(cred_revoc_id), tags);
+
+        if let Some(tag) = tags.keys().find(|tag| IssuedCredential::is_reserved_tag(tag)) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Tag \"{}\" is reserved for the issued credential record", tag)));
+        }
+
+        let id = IssuedCredential::id(rev_reg_id, cred_revoc_id);
+
+        let issued_credential: IssuedCredential = self.wallet_service.get_indy_object(wallet_handle, &id, &RecordOptions::id_value())?;
+
+        let mut all_tags = issued_credential.tags();
+        all_tags.extend(tags.clone());
+
+        self.wallet_service.update_record_tags(wallet_handle, &self.wallet_service.add_prefix("IssuedCredential"), &id, &all_tags)?;
+
+        debug!("set_issued_credential_tags <<<");
+
+        Ok(())
+    }
+
+    fn search_issued_credentials(&self,
+                                 wallet_handle: WalletHandle,
+                                 query_json: Option<&str>) -> IndyResult<(SearchHandle, usize)> {
+        debug!("search_issued_credentials >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+        let options_json = json!({
+            "retrieveRecords": true,
+            "retrieveTotalCount": true,
+            "retrieveType": false,
+            "retrieveValue": true,
+            "retrieveTags": true,
+        }).to_string();
+
+        let search = self.wallet_service.search_indy_records::<IssuedCredential>(wallet_handle, query_json.unwrap_or("{}"), &options_json)?;
+
+        let total_count = search.get_total_count()?.unwrap_or(0);
+
+        let handle: SearchHandle = next_search_handle();
+
+        self.searches.borrow_mut().insert(handle, Box::new(search));
+
+        let res = (handle, total_count);
+
+        debug!("search_issued_credentials <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn fetch_issued_credentials(&self,
+                                search_handle: SearchHandle,
+                                count: usize) -> IndyResult<String> {
+        trace!("fetch_issued_credentials >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+        let mut searches = self.searches.borrow_mut();
+        let search = searches.get_mut(&search_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown IssuedCredentialsSearch handle: {:?}", search_handle)))?;
+
+        let mut issued_credentials: Vec<IssuedCredentialInfo> = Vec::new();
+
+        for _ in 0..count {
+            let record = match search.fetch_next_record()? {
+                Some(record) => record,
+                None => break
+            };
+
+            let credential: IssuedCredential = record.get_value()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "IssuedCredential not found in the record"))
+                .and_then(|value| serde_json::from_str(value)
+                    .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize IssuedCredential"))?;
+
+            let tags = record.get_tags()
+                .map(|tags| tags.iter()
+                    .filter(|&(tag, _)| !IssuedCredential::is_reserved_tag(tag))
+                    .map(|(tag, value)| (tag.clone(), value.clone()))
+                    .collect())
+                .unwrap_or_default();
+
+            issued_credentials.push(IssuedCredentialInfo { credential, tags });
+        }
+
+        let issued_credentials_json = serde_json::to_string(&issued_credentials)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize list of IssuedCredentialInfo")?;
+
+        trace!("fetch_issued_credentials <<< issued_credentials_json: {:?}", issued_credentials_json);
+
+        Ok(issued_credentials_json)
+    }
+
+    fn close_issued_credentials_search(&self, search_handle: SearchHandle) -> IndyResult<()> {
+        trace!("close_issued_credentials_search >>> search_handle: {:?}", search_handle);
+
+        match self.searches.borrow_mut().remove(&search_handle) {
+            Some(_) => Ok(()),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown IssuedCredentialsSearch handle: {:?}", search_handle)))
+        }?;
+
+        trace!("close_issued_credentials_search <<< res: ()");
+
+        Ok(())
+    }
+
+    fn _wallet_add_issued_credential(&self,
+                                     wallet_handle: WalletHandle,
+                                     cred_def_id: &CredentialDefinitionId,
+                                     rev_reg_id: &RevocationRegistryId,
+                                     cred_revoc_id: u32) -> IndyResult<()> {
+        let issuance_time = SystemTime::now().duration_since(UNIX_EPOCH)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot get current time")?
+            .as_secs();
+
+        let issued_credential = IssuedCredential {
+            cred_def_id: cred_def_id.clone(),
+            rev_reg_id: rev_reg_id.clone(),
+            cred_rev_id: cred_revoc_id.to_string(),
+            issuance_time,
+            revoked: false,
+        };
+
+        let id = IssuedCredential::id(rev_reg_id, &issued_credential.cred_rev_id);
+
+        self.wallet_service.add_indy_object(wallet_handle, &id, &issued_credential, &issued_credential.tags())?;
+
+        Ok(())
+    }
+
+    /// Credentials revoked by default (ISSUANCE_BY_DEFAULT) may have no record so they are skipped.
+    fn _wallet_set_issued_credential_revoked(&self,
+                                             wallet_handle: WalletHandle,
+                                             rev_reg_id: &RevocationRegistryId,
+                                             cred_revoc_id: u32,
+                                             revoked: bool) -> IndyResult<()> {
+        let id = IssuedCredential::id(rev_reg_id, &cred_revoc_id.to_string());
+
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": true,
+            "retrieveTags": true,
+        }).to_string();
+
+        let record = match self.wallet_service.get_indy_record::<IssuedCredential>(wallet_handle, &id, &options_json) {
+            Ok(record) => record,
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => return Ok(()),
+            Err(err) => return Err(err)
+        };
+
+        let mut issued_credential: IssuedCredential = record.get_value()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, format!("IssuedCredential not found for id: {}", id)))
+            .and_then(|value| serde_json::from_str(value)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize IssuedCredential"))?;
+
+        issued_credential.revoked = revoked;
+
+        let mut tags = record.get_tags().cloned().unwrap_or_default();
+        tags.extend(issued_credential.tags());
+
+        self.wallet_service.update_indy_object(wallet_handle, &id, &issued_credential)?;
+        self.wallet_service.update_record_tags(wallet_handle, &self.wallet_service.add_prefix("IssuedCredential"), &id, &tags)
+    }
+
     // TODO: DELETE IT
     fn _wallet_set_schema_id(&self, wallet_handle: WalletHandle, id: &str, schema_id: &SchemaId) -> IndyResult<()> {
         self.wallet_service.add_record(wallet_handle, &self.wallet_service.add_prefix("SchemaId"), id, &schema_id.0, &Tags::new())
@@ -1,6 +1,7 @@
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use ursa::cl::{new_nonce, RevocationRegistry, Witness};
 
@@ -15,7 +16,7 @@ use crate::domain::anoncreds::credential_request::{CredentialRequest, Credential
 use crate::domain::anoncreds::master_secret::MasterSecret;
 use crate::domain::anoncreds::proof_request::{NonRevocedInterval, PredicateInfo, ProofRequest, ProofRequestExtraQuery};
 use crate::domain::anoncreds::requested_credential::RequestedCredentials;
-use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
+use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1, RevocationRegistryId};
 use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use crate::domain::anoncreds::revocation_state::{RevocationState, RevocationStates};
 use crate::domain::anoncreds::schema::{schemas_map_to_schemas_v1_map, SchemaV1, SchemaId, Schemas};
@@ -26,12 +27,16 @@ use crate::services::anoncreds::helpers::{parse_cred_rev_id, get_non_revoc_inter
 use crate::services::blob_storage::BlobStorageService;
 use crate::services::crypto::CryptoService;
 use indy_wallet::{RecordOptions, SearchOptions, WalletRecord, WalletSearch, WalletService};
-use indy_utils::{next_search_handle};
+use indy_utils::{next_command_handle, next_search_handle};
 use crate::utils::wql::Query;
 
 use super::tails::SDKTailsAccessor;
-use indy_api_types::{WalletHandle, SearchHandle};
-use crate::commands::BoxedCallbackStringStringSend;
+use indy_api_types::{WalletHandle, PoolHandle, SearchHandle, CommandHandle};
+use indy_api_types::domain::wallet::Tags;
+use crate::commands::{Command, CommandExecutor, BoxedCallbackStringStringSend};
+use crate::commands::anoncreds::AnoncredsCommand;
+use crate::commands::cache::REV_STATE_CACHE;
+use crate::commands::ledger::LedgerCommand;
 
 pub enum ProverCommand {
     CreateMasterSecret(
@@ -126,7 +131,25 @@ pub enum ProverCommand {
         RevocationRegistryDelta, // revocation registry delta
         u64, //timestamp
         String, //credential revocation id
-        Box<dyn Fn(IndyResult<String>) + Send>)
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    CreateRevocationStates(
+        WalletHandle,
+        PoolHandle,
+        Option<DidValue>, // submitter did
+        i32, // blob storage reader handle
+        ProofRequest, // proof request
+        RequestedCredentials, // requested credentials
+        BoxedCallbackStringStringSend),
+    CreateRevocationStatesDefContinue(
+        RevocationRegistryId, // revocation registry id
+        IndyResult<(String, String)>, // ledger response
+        CommandHandle),
+    CreateRevocationStatesDeltaContinue(
+        RevocationRegistryId, // revocation registry id
+        Option<u64>, // from
+        u64, // to
+        IndyResult<(String, String, u64)>, // ledger response
+        CommandHandle),
 }
 
 struct SearchForProofRequest {
@@ -147,6 +170,30 @@ impl SearchForProofRequest {
     }
 }
 
+/// Revocation state the referent of the proof request needs: the state of the credential actual at `to` time.
+/// `from` is the timestamp of the latest cached state that can be updated instead of creating the new one.
+struct RequiredRevocationState {
+    referent: String,
+    predicate: bool,
+    rev_reg_id: RevocationRegistryId,
+    cred_rev_id: String,
+    from: Option<u64>,
+    to: u64,
+}
+
+/// Revocation registry definitions and deltas requested from the ledger to build revocation states.
+struct RevocationStatesBuild {
+    wallet_handle: WalletHandle,
+    blob_storage_reader_handle: i32,
+    requested_credentials: RequestedCredentials,
+    required: Vec<RequiredRevocationState>,
+    pending: usize,
+    rev_reg_defs: HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+    rev_reg_deltas: HashMap<(RevocationRegistryId, Option<u64>, u64), (RevocationRegistryDeltaV1, u64)>,
+    error: Option<IndyError>,
+    cb: BoxedCallbackStringStringSend,
+}
+
 pub struct ProverCommandExecutor {
     anoncreds_service: Rc<AnoncredsService>,
     wallet_service: Rc<WalletService>,
@@ -154,6 +201,7 @@ pub struct ProverCommandExecutor {
     blob_storage_service: Rc<BlobStorageService>,
     searches: RefCell<HashMap<SearchHandle, Box<WalletSearch>>>,
     searches_for_proof_requests: RefCell<HashMap<SearchHandle, Box<HashMap<String, SearchForProofRequest>>>>,
+    revocation_states_builds: RefCell<HashMap<CommandHandle, RevocationStatesBuild>>,
 }
 
 impl ProverCommandExecutor {
@@ -168,6 +216,7 @@ impl ProverCommandExecutor {
             blob_storage_service,
             searches: RefCell::new(HashMap::new()),
             searches_for_proof_requests: RefCell::new(HashMap::new()),
+            revocation_states_builds: RefCell::new(HashMap::new()),
         }
     }
 
@@ -254,6 +303,18 @@ impl ProverCommandExecutor {
                 debug!(target: "prover_command_executor", "UpdateRevocationState command received");
                 cb(self.update_revocation_state(blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, &cred_rev_id));
             }
+            ProverCommand::CreateRevocationStates(wallet_handle, pool_handle, submitter_did, blob_storage_reader_handle, proof_req, requested_credentials, cb) => {
+                debug!(target: "prover_command_executor", "CreateRevocationStates command received");
+                self.create_revocation_states(wallet_handle, pool_handle, submitter_did, blob_storage_reader_handle, &proof_req, requested_credentials, cb);
+            }
+            ProverCommand::CreateRevocationStatesDefContinue(rev_reg_id, ledger_response, cb_id) => {
+                debug!(target: "prover_command_executor", "CreateRevocationStatesDefContinue command received");
+                self._create_revocation_states_def_continue(rev_reg_id, ledger_response, cb_id);
+            }
+            ProverCommand::CreateRevocationStatesDeltaContinue(rev_reg_id, from, to, ledger_response, cb_id) => {
+                debug!(target: "prover_command_executor", "CreateRevocationStatesDeltaContinue command received");
+                self._create_revocation_states_delta_continue(rev_reg_id, from, to, ledger_response, cb_id);
+            }
         };
     }
 
@@ -731,33 +792,42 @@ impl ProverCommandExecutor {
 
         let revoc_reg_def = RevocationRegistryDefinitionV1::from(revoc_reg_def);
 
+        let rev_reg_delta = RevocationRegistryDeltaV1::from(rev_reg_delta);
+
+        let revocation_state = self._create_revocation_state(blob_storage_reader_handle, &revoc_reg_def, rev_reg_delta, timestamp, cred_rev_id)?;
+
+        let revocation_state_json = serde_json::to_string(&revocation_state)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
+
+        debug!("create_revocation_state <<< revocation_state_json: {:?}", revocation_state_json);
+
+        Ok(revocation_state_json)
+    }
+
+    fn _create_revocation_state(&self,
+                                blob_storage_reader_handle: i32,
+                                revoc_reg_def: &RevocationRegistryDefinitionV1,
+                                rev_reg_delta: RevocationRegistryDeltaV1,
+                                timestamp: u64,
+                                cred_rev_id: &str) -> IndyResult<RevocationState> {
         let rev_idx = parse_cred_rev_id(cred_rev_id)?;
 
         let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
                                                        blob_storage_reader_handle,
-                                                       &revoc_reg_def)?;
-
-        let rev_reg_delta = RevocationRegistryDeltaV1::from(rev_reg_delta);
+                                                       revoc_reg_def)?;
 
         let witness = Witness::new(rev_idx, revoc_reg_def.value.max_cred_num, revoc_reg_def.value.issuance_type.to_bool(), &rev_reg_delta.value, &sdk_tails_accessor)?;
 
-        let revocation_state = RevocationState {
+        Ok(RevocationState {
             witness,
             rev_reg: RevocationRegistry::from(rev_reg_delta.value),
             timestamp,
-        };
-
-        let revocation_state_json = serde_json::to_string(&revocation_state)
-            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
-
-        debug!("create_revocation_state <<< revocation_state_json: {:?}", revocation_state_json);
-
-        Ok(revocation_state_json)
+        })
     }
 
     fn update_revocation_state(&self,
                                blob_storage_reader_handle: i32,
-                               mut rev_state: RevocationState,
+                               rev_state: RevocationState,
                                rev_reg_def: RevocationRegistryDefinition,
                                rev_reg_delta: RevocationRegistryDelta,
                                timestamp: u64,
@@ -769,23 +839,413 @@ impl ProverCommandExecutor {
 
         let rev_reg_delta = RevocationRegistryDeltaV1::from(rev_reg_delta);
 
+        let rev_state = self._update_revocation_state(blob_storage_reader_handle, rev_state, &revocation_registry_definition, rev_reg_delta, timestamp, cred_rev_id)?;
+
+        let rev_state_json = serde_json::to_string(&rev_state)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
+
+        debug!("update_revocation_state <<< rev_state: {:?}", rev_state_json);
+
+        Ok(rev_state_json)
+    }
+
+    fn _update_revocation_state(&self,
+                                blob_storage_reader_handle: i32,
+                                mut rev_state: RevocationState,
+                                revocation_registry_definition: &RevocationRegistryDefinitionV1,
+                                rev_reg_delta: RevocationRegistryDeltaV1,
+                                timestamp: u64,
+                                cred_rev_id: &str) -> IndyResult<RevocationState> {
         let rev_idx = parse_cred_rev_id(cred_rev_id)?;
 
         let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
                                                        blob_storage_reader_handle,
-                                                       &revocation_registry_definition)?;
+                                                       revocation_registry_definition)?;
 
         rev_state.witness.update(rev_idx, revocation_registry_definition.value.max_cred_num, &rev_reg_delta.value, &sdk_tails_accessor)?;
 
         rev_state.rev_reg = RevocationRegistry::from(rev_reg_delta.value);
         rev_state.timestamp = timestamp;
 
-        let rev_state_json = serde_json::to_string(&rev_state)
+        Ok(rev_state)
+    }
+
+    fn create_revocation_states(&self,
+                                wallet_handle: WalletHandle,
+                                pool_handle: PoolHandle,
+                                submitter_did: Option<DidValue>,
+                                blob_storage_reader_handle: i32,
+                                proof_req: &ProofRequest,
+                                requested_credentials: RequestedCredentials,
+                                cb: BoxedCallbackStringStringSend) {
+        debug!("create_revocation_states >>> wallet_handle: {:?}, pool_handle: {:?}, submitter_did: {:?}, blob_storage_reader_handle: {:?}, proof_req: {:?}, requested_credentials: {:?}",
+               wallet_handle, pool_handle, submitter_did, blob_storage_reader_handle, proof_req, requested_credentials);
+
+        let required = try_cb!(self._required_revocation_states(wallet_handle, proof_req, &requested_credentials), cb);
+
+        let rev_reg_ids: HashSet<RevocationRegistryId> = required.iter()
+            .map(|required| required.rev_reg_id.clone())
+            .collect();
+
+        let rev_reg_deltas: HashSet<(RevocationRegistryId, Option<u64>, u64)> = required.iter()
+            .map(|required| (required.rev_reg_id.clone(), required.from, required.to))
+            .collect();
+
+        let cb_id = next_command_handle();
+
+        self.revocation_states_builds.borrow_mut().insert(cb_id, RevocationStatesBuild {
+            wallet_handle,
+            blob_storage_reader_handle,
+            requested_credentials,
+            required,
+            pending: rev_reg_ids.len() + rev_reg_deltas.len(),
+            rev_reg_defs: HashMap::new(),
+            rev_reg_deltas: HashMap::new(),
+            error: None,
+            cb,
+        });
+
+        if rev_reg_ids.is_empty() {
+            return self._finish_revocation_states_build(cb_id);
+        }
+
+        for rev_reg_id in rev_reg_ids {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegDef(
+                        pool_handle,
+                        submitter_did.clone(),
+                        rev_reg_id.clone(),
+                        Box::new(move |ledger_response| {
+                            CommandExecutor::instance().send(
+                                Command::Anoncreds(
+                                    AnoncredsCommand::Prover(
+                                        ProverCommand::CreateRevocationStatesDefContinue(
+                                            rev_reg_id.clone(),
+                                            ledger_response,
+                                            cb_id,
+                                        )
+                                    )
+                                )
+                            ).unwrap();
+                        })
+                    )
+                )
+            ).unwrap();
+        }
+
+        // the delta since the cached state lets to update it, the full delta lets to create the witness from scratch
+        for (rev_reg_id, from, to) in rev_reg_deltas {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegDelta(
+                        pool_handle,
+                        submitter_did.clone(),
+                        rev_reg_id.clone(),
+                        from.map(|from| from as i64),
+                        to as i64,
+                        Box::new(move |ledger_response| {
+                            CommandExecutor::instance().send(
+                                Command::Anoncreds(
+                                    AnoncredsCommand::Prover(
+                                        ProverCommand::CreateRevocationStatesDeltaContinue(
+                                            rev_reg_id.clone(),
+                                            from,
+                                            to,
+                                            ledger_response,
+                                            cb_id,
+                                        )
+                                    )
+                                )
+                            ).unwrap();
+                        })
+                    )
+                )
+            ).unwrap();
+        }
+    }
+
+    /// Collects revocation states needed by the requested credentials.
+    ///
+    /// The state is needed if the credential is revocable and either the referent has non-revoked interval
+    /// or the timestamp of the state is set explicitly in the requested credentials.
+    /// The state must be actual at the requested timestamp, the end of the interval or the current time.
+    /// The latest cached state of the credential that isn't newer than this time is used as the base for the update.
+    fn _required_revocation_states(&self,
+                                   wallet_handle: WalletHandle,
+                                   proof_req: &ProofRequest,
+                                   requested_credentials: &RequestedCredentials) -> IndyResult<Vec<RequiredRevocationState>> {
+        let proof_req = proof_req.value();
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot get current time")?
+            .as_secs();
+
+        let mut required = Vec::new();
+
+        for (referent, requested_attr) in requested_credentials.requested_attributes.iter() {
+            let attr_info = proof_req.requested_attributes.get(referent)
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Attribute with referent \"{}\" not found in ProofRequest", referent)))?;
+
+            let interval = get_non_revoc_interval(&proof_req.non_revoked, &attr_info.non_revoked);
+
+            if let Some((rev_reg_id, cred_rev_id, from, to)) = self._required_revocation_state(wallet_handle, &requested_attr.cred_id, requested_attr.timestamp, interval, now)? {
+                required.push(RequiredRevocationState { referent: referent.to_string(), predicate: false, rev_reg_id, cred_rev_id, from, to });
+            }
+        }
+
+        for (referent, requested_predicate) in requested_credentials.requested_predicates.iter() {
+            let predicate_info = proof_req.requested_predicates.get(referent)
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Predicate with referent \"{}\" not found in ProofRequest", referent)))?;
+
+            let interval = get_non_revoc_interval(&proof_req.non_revoked, &predicate_info.non_revoked);
+
+            if let Some((rev_reg_id, cred_rev_id, from, to)) = self._required_revocation_state(wallet_handle, &requested_predicate.cred_id, requested_predicate.timestamp, interval, now)? {
+                required.push(RequiredRevocationState { referent: referent.to_string(), predicate: true, rev_reg_id, cred_rev_id, from, to });
+            }
+        }
+
+        Ok(required)
+    }
+
+    fn _required_revocation_state(&self,
+                                  wallet_handle: WalletHandle,
+                                  cred_id: &str,
+                                  timestamp: Option<u64>,
+                                  interval: Option<NonRevocedInterval>,
+                                  now: u64) -> IndyResult<Option<(RevocationRegistryId, String, Option<u64>, u64)>> {
+        if timestamp.is_none() && interval.is_none() {
+            return Ok(None);
+        }
+
+        let credential: Credential = self.wallet_service.get_indy_object(wallet_handle, cred_id, &RecordOptions::id_value())?;
+
+        let rev_reg_id = match credential.rev_reg_id {
+            Some(rev_reg_id) => rev_reg_id,
+            None => return Ok(None)
+        };
+
+        let cred_rev_id = credential.signature.extract_index()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, format!("Revocation index not found in the credential {}", cred_id)))?;
+
+        let cred_rev_id = cred_rev_id.to_string();
+
+        let to = timestamp
+            .or_else(|| interval.and_then(|interval| interval.to))
+            .unwrap_or(now);
+
+        let from = self._latest_cached_revocation_state(wallet_handle, &rev_reg_id, &cred_rev_id, to)?;
+
+        Ok(Some((rev_reg_id, cred_rev_id, from, to)))
+    }
+
+    fn _create_revocation_states_def_continue(&self,
+                                              rev_reg_id: RevocationRegistryId,
+                                              ledger_response: IndyResult<(String, String)>,
+                                              cb_id: CommandHandle) {
+        let rev_reg_def = ledger_response
+            .and_then(|(_, rev_reg_def_json)| serde_json::from_str::<RevocationRegistryDefinition>(&rev_reg_def_json)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistryDefinition"));
+
+        let finished = {
+            let mut builds = self.revocation_states_builds.borrow_mut();
+            let build = builds.get_mut(&cb_id).expect("FIXME INVALID STATE");
+
+            match rev_reg_def {
+                Ok(rev_reg_def) => { build.rev_reg_defs.insert(rev_reg_id, RevocationRegistryDefinitionV1::from(rev_reg_def)); }
+                Err(err) => { build.error.get_or_insert(err); }
+            }
+            build.pending -= 1;
+
+            build.pending == 0
+        };
+
+        if finished {
+            self._finish_revocation_states_build(cb_id);
+        }
+    }
+
+    fn _create_revocation_states_delta_continue(&self,
+                                                rev_reg_id: RevocationRegistryId,
+                                                from: Option<u64>,
+                                                to: u64,
+                                                ledger_response: IndyResult<(String, String, u64)>,
+                                                cb_id: CommandHandle) {
+        let rev_reg_delta = ledger_response
+            .and_then(|(_, rev_reg_delta_json, timestamp)| serde_json::from_str::<RevocationRegistryDelta>(&rev_reg_delta_json)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistryDelta")
+                .map(|rev_reg_delta| (RevocationRegistryDeltaV1::from(rev_reg_delta), timestamp)));
+
+        let finished = {
+            let mut builds = self.revocation_states_builds.borrow_mut();
+            let build = builds.get_mut(&cb_id).expect("FIXME INVALID STATE");
+
+            match rev_reg_delta {
+                Ok(rev_reg_delta) => { build.rev_reg_deltas.insert((rev_reg_id, from, to), rev_reg_delta); }
+                Err(err) => { build.error.get_or_insert(err); }
+            }
+            build.pending -= 1;
+
+            build.pending == 0
+        };
+
+        if finished {
+            self._finish_revocation_states_build(cb_id);
+        }
+    }
+
+    fn _finish_revocation_states_build(&self, cb_id: CommandHandle) {
+        let build = self.revocation_states_builds.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let res = self._build_revocation_states(build.wallet_handle, build.blob_storage_reader_handle, build.requested_credentials,
+                                                &build.required, &build.rev_reg_defs, &build.rev_reg_deltas, build.error);
+
+        debug!("create_revocation_states <<< res: {:?}", res);
+
+        (build.cb)(res)
+    }
+
+    fn _build_revocation_states(&self,
+                                wallet_handle: WalletHandle,
+                                blob_storage_reader_handle: i32,
+                                mut requested_credentials: RequestedCredentials,
+                                required: &[RequiredRevocationState],
+                                rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                                rev_reg_deltas: &HashMap<(RevocationRegistryId, Option<u64>, u64), (RevocationRegistryDeltaV1, u64)>,
+                                error: Option<IndyError>) -> IndyResult<(String, String)> {
+        if let Some(err) = error {
+            return Err(err);
+        }
+
+        let mut rev_states: RevocationStates = HashMap::new();
+
+        for required in required {
+            let rev_reg_def = rev_reg_defs.get(&required.rev_reg_id)
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Revocation registry definition hasn't been fetched"))?;
+
+            let (rev_reg_delta, timestamp) = rev_reg_deltas.get(&(required.rev_reg_id.clone(), required.from, required.to))
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Revocation registry delta hasn't been fetched"))?;
+
+            let rev_states_for_timestamps = rev_states.entry(required.rev_reg_id.0.clone()).or_insert_with(HashMap::new);
+
+            if !rev_states_for_timestamps.contains_key(timestamp) {
+                let rev_state = match self._get_cached_revocation_state(wallet_handle, &required.rev_reg_id, &required.cred_rev_id, *timestamp)? {
+                    Some(rev_state) => rev_state,
+                    None => {
+                        let rev_state = match required.from {
+                            Some(from) => {
+                                let cached_rev_state = self._get_cached_revocation_state(wallet_handle, &required.rev_reg_id, &required.cred_rev_id, from)?
+                                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Cached revocation state has been purged"))?;
+                                self._update_revocation_state(blob_storage_reader_handle, cached_rev_state, rev_reg_def, rev_reg_delta.clone(), *timestamp, &required.cred_rev_id)?
+                            }
+                            None => self._create_revocation_state(blob_storage_reader_handle, rev_reg_def, rev_reg_delta.clone(), *timestamp, &required.cred_rev_id)?
+                        };
+                        self._cache_revocation_state(wallet_handle, &required.rev_reg_id, &required.cred_rev_id, &rev_state)?;
+                        rev_state
+                    }
+                };
+
+                rev_states_for_timestamps.insert(*timestamp, rev_state);
+            }
+
+            // the proof refers the state by the ledger time of the accumulator
+            if required.predicate {
+                if let Some(requested_predicate) = requested_credentials.requested_predicates.get_mut(&required.referent) {
+                    requested_predicate.timestamp = Some(*timestamp);
+                }
+            } else if let Some(requested_attr) = requested_credentials.requested_attributes.get_mut(&required.referent) {
+                requested_attr.timestamp = Some(*timestamp);
+            }
+        }
+
+        let rev_states_json = serde_json::to_string(&rev_states)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationStates")?;
+
+        let requested_credentials_json = serde_json::to_string(&requested_credentials)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RequestedCredentials")?;
+
+        Ok((rev_states_json, requested_credentials_json))
+    }
+
+    fn _get_cached_revocation_state(&self,
+                                    wallet_handle: WalletHandle,
+                                    rev_reg_id: &RevocationRegistryId,
+                                    cred_rev_id: &str,
+                                    timestamp: u64) -> IndyResult<Option<RevocationState>> {
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": true,
+            "retrieveTags": false,
+        }).to_string();
+
+        let record = match self.wallet_service.get_record(wallet_handle, REV_STATE_CACHE, &rev_state_cache_id(rev_reg_id, cred_rev_id, timestamp), &options_json) {
+            Ok(record) => record,
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => return Ok(None),
+            Err(err) => return Err(err)
+        };
+
+        let rev_state_json = record.get_value()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Revocation state not found in the cache record"))?;
+
+        serde_json::from_str(rev_state_json)
+            .map(Some)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationState")
+    }
+
+    fn _latest_cached_revocation_state(&self,
+                                       wallet_handle: WalletHandle,
+                                       rev_reg_id: &RevocationRegistryId,
+                                       cred_rev_id: &str,
+                                       to: u64) -> IndyResult<Option<u64>> {
+        let query_json = json!({
+            "rev_reg_id": rev_reg_id.0,
+            "cred_rev_id": cred_rev_id,
+        }).to_string();
+
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": false,
+            "retrieveTags": true,
+        }).to_string();
+
+        let mut search = self.wallet_service.search_records(wallet_handle, REV_STATE_CACHE, &query_json, &options_json)?;
+
+        let mut latest = None;
+
+        while let Some(record) = search.fetch_next_record()? {
+            let timestamp = record.get_tags()
+                .and_then(|tags| tags.get("rev_state_timestamp"))
+                .and_then(|timestamp| timestamp.parse::<u64>().ok());
+
+            if let Some(timestamp) = timestamp {
+                if timestamp <= to && Some(timestamp) > latest {
+                    latest = Some(timestamp);
+                }
+            }
+        }
+
+        Ok(latest)
+    }
+
+    fn _cache_revocation_state(&self,
+                               wallet_handle: WalletHandle,
+                               rev_reg_id: &RevocationRegistryId,
+                               cred_rev_id: &str,
+                               rev_state: &RevocationState) -> IndyResult<()> {
+        let rev_state_json = serde_json::to_string(rev_state)
             .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
 
-        debug!("update_revocation_state <<< rev_state: {:?}", rev_state_json);
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot get current time")?
+            .as_secs();
 
-        Ok(rev_state_json)
+        let mut tags = Tags::new();
+        tags.insert("timestamp".to_string(), now.to_string());
+        tags.insert("rev_reg_id".to_string(), rev_reg_id.0.clone());
+        tags.insert("cred_rev_id".to_string(), cred_rev_id.to_string());
+        tags.insert("rev_state_timestamp".to_string(), rev_state.timestamp.to_string());
+
+        self.wallet_service.add_record(wallet_handle, REV_STATE_CACHE, &rev_state_cache_id(rev_reg_id, cred_rev_id, rev_state.timestamp), &rev_state_json, &tags)
     }
 
     fn _get_credential_info(&self,
@@ -883,3 +1343,6 @@ impl ProverCommandExecutor {
     }
 }
 
+fn rev_state_cache_id(rev_reg_id: &RevocationRegistryId, cred_rev_id: &str, timestamp: u64) -> String {
+    format!("{}:{}:{}", rev_reg_id.0, cred_rev_id, timestamp)
+}
@@ -19,6 +19,14 @@ pub enum VerifierCommand {
         RevocationRegistryDefinitions, // rev reg defs
         RevocationRegistries, // rev reg entries
         Box<dyn Fn(IndyResult<bool>) + Send>),
+    VerifyProofVerbose(
+        ProofRequest, // proof request
+        Proof, // proof
+        Schemas, // credential schemas
+        CredentialDefinitions, // credential defs
+        RevocationRegistryDefinitions, // rev reg defs
+        RevocationRegistries, // rev reg entries
+        Box<dyn Fn(IndyResult<String>) + Send>),
     GenerateNonce(
         Box<dyn Fn(IndyResult<String>) + Send>)
 }
@@ -44,6 +52,14 @@ impl VerifierCommandExecutor {
                                      &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
                                      &rev_regs_map_to_rev_regs_local_map(rev_regs)));
             }
+            VerifierCommand::VerifyProofVerbose(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, cb) => {
+                debug!(target: "verifier_command_executor", "VerifyProofVerbose command received");
+                cb(self.verify_proof_verbose(&proof_request.value(), proof,
+                                             &schemas_map_to_schemas_v1_map(schemas),
+                                             &cred_defs_map_to_cred_defs_v1_map(credential_defs),
+                                             &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
+                                             &rev_regs_map_to_rev_regs_local_map(rev_regs)));
+            }
             VerifierCommand::GenerateNonce(cb) => {
                 debug!(target: "verifier_command_executor", "GenerateNonce command received");
                 cb(self.generate_nonce());
@@ -74,6 +90,32 @@ impl VerifierCommandExecutor {
         Ok(result)
     }
 
+    fn verify_proof_verbose(&self,
+                            proof_req: &ProofRequestPayload,
+                            proof: Proof,
+                            schemas: &HashMap<SchemaId, SchemaV1>,
+                            cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                            rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                            rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<String> {
+        debug!("verify_proof_verbose >>> proof_req: {:?}, proof: {:?}, schemas: {:?}, cred_defs: {:?},  \
+               rev_reg_defs: {:?}, rev_regs: {:?}",
+               proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs);
+
+        let report = self.anoncreds_service.verifier.verify_verbose(&proof,
+                                                                    &proof_req,
+                                                                    schemas,
+                                                                    cred_defs,
+                                                                    rev_reg_defs,
+                                                                    rev_regs)?;
+
+        let report_json = serde_json::to_string(&report)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize VerificationReport")?;
+
+        debug!("verify_proof_verbose <<< report_json: {:?}", report_json);
+
+        Ok(report_json)
+    }
+
     fn generate_nonce(&self) -> IndyResult<String> {
         debug!("generate_nonce >>> ");
 
@@ -18,6 +18,7 @@ use indy_utils::next_command_handle;
 
 const CRED_DEF_CACHE: &str = "cred_def_cache";
 const SCHEMA_CACHE: &str = "schema_cache";
+pub const REV_STATE_CACHE: &str = "rev_state_cache";
 
 pub enum CacheCommand {
     GetSchema(PoolHandle,
@@ -50,6 +51,9 @@ pub enum CacheCommand {
     PurgeCredDefCache(WalletHandle,
                       PurgeOptions, // options
                       Box<dyn Fn(IndyResult<()>) + Send>),
+    PurgeRevStateCache(WalletHandle,
+                       PurgeOptions, // options
+                       Box<dyn Fn(IndyResult<()>) + Send>),
 }
 
 pub struct CacheCommandExecutor {
@@ -113,6 +117,10 @@ impl CacheCommandExecutor {
                 debug!(target: "non_secrets_command_executor", "PurgeCredDefCache command received");
                 cb(self.purge_cred_def_cache(wallet_handle, options));
             }
+            CacheCommand::PurgeRevStateCache(wallet_handle, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "PurgeRevStateCache command received");
+                cb(self.purge_rev_state_cache(wallet_handle, options));
+            }
         }
     }
 
@@ -346,4 +354,34 @@ impl CacheCommandExecutor {
 
         Ok(())
     }
+
+    fn purge_rev_state_cache(&self,
+                             wallet_handle: WalletHandle,
+                             options: PurgeOptions) -> IndyResult<()> {
+        trace!("purge_rev_state_cache >>> wallet_handle: {:?}, options: {:?}", wallet_handle, options);
+
+        let max_age = options.max_age.unwrap_or(-1);
+        let query_json = CacheCommandExecutor::build_query_json(max_age)?;
+
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": false,
+            "retrieveTags": false,
+        }).to_string();
+
+        let mut search = self.wallet_service.search_records(
+            wallet_handle,
+            REV_STATE_CACHE,
+            &query_json,
+            &options_json,
+        )?;
+
+        while let Some(record) = search.fetch_next_record()? {
+            self.wallet_service.delete_record(wallet_handle, REV_STATE_CACHE, record.get_id())?;
+        }
+
+        trace!("purge_rev_state_cache <<< res: ()");
+
+        Ok(())
+    }
 }
@@ -1,12 +1,16 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::rc::Rc;
 use std::string::ToString;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
 
 use indy_api_types::{CommandHandle, PoolHandle, WalletHandle};
 use indy_api_types::errors::prelude::*;
 use indy_utils::next_command_handle;
-use rust_base58::ToBase58;
+use rust_base58::{FromBase58, ToBase58};
 use serde_json;
 use serde_json::Value;
 
@@ -20,18 +24,27 @@ use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelt
 use crate::domain::anoncreds::schema::{Schema, SchemaId, SchemaV1};
 use crate::domain::crypto::did::{Did, DidValue};
 use crate::domain::crypto::key::Key;
-use crate::domain::ledger::auth_rule::{AuthRules, Constraint};
+use crate::domain::ledger::auth_rule::{AuthRules, AuthSigner, Constraint};
+use crate::domain::ledger::batch::{LedgerObjectIds, LedgerObjectKind, LedgerObjectResult, LedgerObjects};
 use crate::domain::ledger::author_agreement::{AcceptanceMechanisms, GetTxnAuthorAgreementData};
 use crate::domain::ledger::node::NodeOperationData;
+use crate::domain::ledger::nym::NymData;
+use crate::domain::ledger::endorsement::{EndorsementEnvelope, EndorsementSignatures, EndorsementSigner, EndorsementStatus};
 use crate::domain::ledger::pool::Schedule;
 use crate::domain::ledger::request::Request;
+use crate::domain::ledger::response::{WriteOutcome, WriteStatus};
+use crate::domain::ledger::rich_schema::RichSchemaObject;
+use crate::domain::ledger::txn::{LedgerSubscriptionOptions, LedgerType, TxnRangePage, VerifiedTxn};
+use crate::domain::pool::{DEFAULT_WRITE_RETRIES, RequestOptions};
 use crate::services::crypto::CryptoService;
 use crate::services::ledger::LedgerService;
+use crate::services::ledger::merkletree::frontier::MerkleTreeFrontier;
 use crate::services::pool::{
     parse_response_metadata,
     PoolService
 };
 use crate::utils::crypto::signature_serializer::serialize_signature;
+use crate::utils::qualifier;
 
 pub enum LedgerCommand {
     SignAndSubmitRequest(
@@ -40,10 +53,31 @@ pub enum LedgerCommand {
         DidValue, // submitter did
         String, // request json
         Box<dyn Fn(IndyResult<String>) + Send>),
+    SignAndSubmitRequestWithOptions(
+        PoolHandle, // pool handle
+        WalletHandle,
+        DidValue, // submitter did
+        String, // request json
+        RequestOptions, // request options
+        Box<dyn Fn(IndyResult<String>) + Send>),
     SubmitRequest(
         PoolHandle, // pool handle
         String, // request json
         Box<dyn Fn(IndyResult<String>) + Send>),
+    SubmitRequestWithOptions(
+        PoolHandle, // pool handle
+        String, // request json
+        RequestOptions, // request options
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    SubmitWriteRequest(
+        PoolHandle, // pool handle
+        String, // request json
+        RequestOptions, // request options
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    CheckWriteOutcomeContinue(
+        IndyResult<String>, // GET_* or GET_TXN reply
+        CommandHandle,
+    ),
     SubmitAck(
         CommandHandle,
         IndyResult<String>, // result json or error
@@ -54,6 +88,11 @@ pub enum LedgerCommand {
         Option<String>, // nodes
         Option<i32>, // timeout
         Box<dyn Fn(IndyResult<String>) + Send>),
+    SubmitActionWithOptions(
+        PoolHandle, // pool handle
+        String, // request json
+        RequestOptions, // request options
+        Box<dyn Fn(IndyResult<String>) + Send>),
     SignRequest(
         WalletHandle,
         DidValue, // submitter did
@@ -68,6 +107,31 @@ pub enum LedgerCommand {
         Option<DidValue>, // submitter did
         DidValue, // target did
         Box<dyn Fn(IndyResult<String>) + Send>),
+    ResolveDid(
+        PoolHandle, // pool handle
+        DidValue, // did
+        Option<RequestOptions>, // options
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ResolveDidContinue(
+        IndyResult<String>, // GET_NYM or GET_ATTR reply
+        CommandHandle,
+    ),
+    GetLedgerObjects(
+        PoolHandle, // pool handle
+        Option<DidValue>, // submitter did
+        LedgerObjectIds, // ids
+        Option<RequestOptions>, // options
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    GetLedgerObjectsContinue(
+        LedgerObjectKind,
+        String, // id
+        IndyResult<String>, // GET_* reply
+        CommandHandle,
+    ),
+    VerifyReplyOffline(
+        String, // reply
+        HashMap<String, Option<String>>, // bls keys
+        Box<dyn Fn(IndyResult<String>) + Send>),
     BuildNymRequest(
         DidValue, // submitter did
         DidValue, // target did
@@ -131,6 +195,39 @@ pub enum LedgerCommand {
         Option<String>, // ledger type
         i32, // data
         Box<dyn Fn(IndyResult<String>) + Send>),
+    GetTxnRange(
+        PoolHandle, // pool handle
+        Option<DidValue>, // submitter did
+        Option<String>, // ledger type
+        i32, // from
+        Option<i32>, // to
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    GetTxnRangeContinue(
+        IndyResult<String>, // GET_TXN reply
+        CommandHandle,
+    ),
+    SubscribeLedger(
+        PoolHandle, // pool handle
+        Option<String>, // ledger type
+        Option<i32>, // from
+        LedgerSubscriptionOptions, // options
+        Box<dyn Fn(IndyResult<CommandHandle>) + Send>,
+        Box<dyn Fn(IndyResult<String>) + Send>), // txn callback
+    SyncLedgerSubscription(
+        CommandHandle, // subscription handle
+    ),
+    SyncLedgerSubscriptionContinue(
+        IndyResult<String>, // ledger catch-up reply
+        CommandHandle, // subscription handle
+    ),
+    SeedLedgerSubscriptionContinue(
+        IndyResult<String>, // GET_TXN reply
+        CommandHandle, // subscription handle
+        i32, // seq_no
+    ),
+    UnsubscribeLedger(
+        CommandHandle, // subscription handle
+        Box<dyn Fn(IndyResult<()>) + Send>),
     BuildPoolConfigRequest(
         DidValue, // submitter did
         bool, // writes
@@ -154,6 +251,16 @@ pub enum LedgerCommand {
         bool, // force
         Option<String>, // package
         Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildLedgersFreezeRequest(
+        DidValue, // submitter did
+        Vec<u64>, // ledgers ids
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildGetFrozenLedgersRequest(
+        Option<DidValue>, // submitter did
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ParseUpgradeStatus(
+        Vec<Value>, // config ledger txns
+        Box<dyn Fn(IndyResult<String>) + Send>),
     BuildRevocRegDefRequest(
         DidValue, // submitter did
         RevocationRegistryDefinition, // data
@@ -188,6 +295,23 @@ pub enum LedgerCommand {
     ParseGetRevocRegDeltaResponse(
         String, // get revocation registry delta response
         Box<dyn Fn(IndyResult<(String, String, u64)>) + Send>),
+    BuildRichSchemaObjectRequest(
+        DidValue, // submitter did
+        RichSchemaObject, // rich schema object
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildGetRichSchemaObjectByIdRequest(
+        Option<DidValue>, // submitter did
+        String, // id
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildGetRichSchemaObjectByMetadataRequest(
+        Option<DidValue>, // submitter did
+        String, // rs type
+        String, // rs name
+        String, // rs version
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ParseGetRichSchemaObjectResponse(
+        String, // get rich schema object response json
+        BoxedCallbackStringStringSend),
     RegisterSPParser(
         String, // txn type
         CustomTransactionParser,
@@ -239,6 +363,28 @@ pub enum LedgerCommand {
         IndyResult<String>,
         CommandHandle,
     ),
+    GetRevocRegDef(
+        PoolHandle,
+        Option<DidValue>,
+        RevocationRegistryId,
+        BoxedCallbackStringStringSend,
+    ),
+    GetRevocRegDefContinue(
+        IndyResult<String>,
+        CommandHandle,
+    ),
+    GetRevocRegDelta(
+        PoolHandle,
+        Option<DidValue>,
+        RevocationRegistryId,
+        Option<i64>, // from
+        i64, // to
+        Box<dyn Fn(IndyResult<(String, String, u64)>) + Send>,
+    ),
+    GetRevocRegDeltaContinue(
+        IndyResult<String>,
+        CommandHandle,
+    ),
     BuildTxnAuthorAgreementRequest(
         DidValue, // submitter did
         Option<String>, // text
@@ -276,6 +422,100 @@ pub enum LedgerCommand {
         String, // request json
         DidValue, // endorser did
         Box<dyn Fn(IndyResult<String>) + Send>),
+    PrepareEndorsement(
+        String, // request json
+        String, // get auth rule response
+        Option<DidValue>, // owner did
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    EndorsementAddSignature(
+        WalletHandle,
+        String, // endorsement json
+        DidValue, // signer did
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    EndorsementCheckSignatures(
+        String, // endorsement json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    EndorsementStatus(
+        String, // endorsement json
+        String, // get nym responses json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    SubmitEndorsement(
+        PoolHandle, // pool handle
+        String, // endorsement json
+        String, // get nym responses json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    EvaluateAuthRule(
+        String, // request json
+        String, // auth action
+        Option<String>, // old value
+        String, // get auth rule response
+        Vec<AuthSigner>, // signers
+        Box<dyn Fn(IndyResult<String>) + Send>),
+}
+
+const TXN_RANGE_PAGE_SIZE: i32 = 100;
+const TXN_RANGE_MAX_RESTARTS: usize = 3;
+
+/// State of the page of transactions being fetched by GET_TXN requests one by one.
+struct TxnRangeFetch {
+    pool_handle: PoolHandle,
+    submitter_did: Option<DidValue>,
+    ledger_type: Option<String>,
+    from: i32,
+    page_to: i32,
+    has_more: bool,
+    next: i32,
+    restarts: usize,
+    txns: Vec<Value>,
+    ledger: Option<(u64, String)>,
+    bls_keys: HashMap<String, Option<String>>,
+    cb: Box<dyn Fn(IndyResult<String>)>,
+}
+
+/// Write request submitted to the pool and the callers waiting for its outcome.
+///
+/// If the pool didn't reach consensus on the request, the written object is read by GET_* request
+/// and then its transaction is read by GET_TXN request to find out whether the request was written.
+struct PendingWrite {
+    pool_handle: PoolHandle,
+    request_json: String,
+    options: RequestOptions,
+    write_key: String,
+    // set when GET_* reply with the seq_no of the object transaction is received
+    seq_no: Option<i32>,
+    cbs: Vec<Box<dyn Fn(IndyResult<String>)>>,
+}
+
+/// State of the DID being resolved by GET_NYM and then GET_ATTR(endpoint) requests.
+struct DidResolution {
+    pool_handle: PoolHandle,
+    did: DidValue,
+    options: RequestOptions,
+    // set when GET_NYM reply is received
+    nym_data: Option<NymData>,
+    cb: Box<dyn Fn(IndyResult<String>)>,
+}
+
+/// State of the batch read of the ledger objects.
+struct LedgerObjectsRead {
+    // number of requests waiting for the reply
+    pending: usize,
+    objects: LedgerObjects,
+    cb: Box<dyn Fn(IndyResult<String>)>,
+}
+
+/// State of the subscription to newly committed transactions of the ledger.
+struct LedgerSubscription {
+    pool_handle: PoolHandle,
+    ledger_id: u8,
+    // seq_no of the first transaction to report, None until the end of the ledger is known
+    from: Option<i32>,
+    // right edge of the ledger merkle tree preceding the transactions to report, None until it is seeded
+    frontier: Option<MerkleTreeFrontier>,
+    bls_keys: HashMap<String, Option<String>>,
+    syncing: bool,
+    stopped: Arc<AtomicBool>,
+    txn_cb: Box<dyn Fn(IndyResult<String>) + Send>,
 }
 
 pub struct LedgerCommandExecutor {
@@ -286,6 +526,13 @@ pub struct LedgerCommandExecutor {
 
     send_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>)>>>,
     pending_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<(String, String)>)>>>,
+    pending_delta_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<(String, String, u64)>)>>>,
+    write_callbacks: RefCell<HashMap<CommandHandle, PendingWrite>>,
+    pending_writes: RefCell<HashMap<String, CommandHandle>>,
+    txn_range_fetches: RefCell<HashMap<CommandHandle, TxnRangeFetch>>,
+    did_resolutions: RefCell<HashMap<CommandHandle, DidResolution>>,
+    ledger_objects_reads: RefCell<HashMap<CommandHandle, LedgerObjectsRead>>,
+    subscriptions: RefCell<HashMap<CommandHandle, LedgerSubscription>>,
 }
 
 impl LedgerCommandExecutor {
@@ -300,6 +547,13 @@ impl LedgerCommandExecutor {
             ledger_service,
             send_callbacks: RefCell::new(HashMap::new()),
             pending_callbacks: RefCell::new(HashMap::new()),
+            pending_delta_callbacks: RefCell::new(HashMap::new()),
+            write_callbacks: RefCell::new(HashMap::new()),
+            pending_writes: RefCell::new(HashMap::new()),
+            txn_range_fetches: RefCell::new(HashMap::new()),
+            did_resolutions: RefCell::new(HashMap::new()),
+            ledger_objects_reads: RefCell::new(HashMap::new()),
+            subscriptions: RefCell::new(HashMap::new()),
         }
     }
 
@@ -309,17 +563,34 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "SignAndSubmitRequest command received");
                 self.sign_and_submit_request(pool_handle, wallet_handle, &submitter_did, &request_json, cb);
             }
+            LedgerCommand::SignAndSubmitRequestWithOptions(pool_handle, wallet_handle, submitter_did, request_json, options, cb) => {
+                debug!(target: "ledger_command_executor", "SignAndSubmitRequestWithOptions command received");
+                self.sign_and_submit_request_with_options(pool_handle, wallet_handle, &submitter_did, &request_json, &options, cb);
+            }
             LedgerCommand::SubmitRequest(handle, request_json, cb) => {
                 debug!(target: "ledger_command_executor", "SubmitRequest command received");
                 self.submit_request(handle, &request_json, cb);
             }
+            LedgerCommand::SubmitRequestWithOptions(handle, request_json, options, cb) => {
+                debug!(target: "ledger_command_executor", "SubmitRequestWithOptions command received");
+                self.submit_request_with_options(handle, &request_json, &options, cb);
+            }
+            LedgerCommand::SubmitWriteRequest(handle, request_json, options, cb) => {
+                debug!(target: "ledger_command_executor", "SubmitWriteRequest command received");
+                self.submit_write_request(handle, &request_json, &options, cb);
+            }
             LedgerCommand::SubmitAck(handle, result) => {
                 debug!(target: "ledger_command_executor", "SubmitAck command received");
-                match self.send_callbacks.borrow_mut().remove(&handle) {
-                    Some(cb) => cb(result.map_err(IndyError::from)),
-                    None => {
-                        error!("Can't process LedgerCommand::SubmitAck for handle {:?} with result {:?} - appropriate callback not found!",
-                               handle, result);
+                let pending_write = self.write_callbacks.borrow_mut().remove(&handle);
+                if let Some(pending_write) = pending_write {
+                    self._submit_write_request_continue(result, pending_write);
+                } else {
+                    match self.send_callbacks.borrow_mut().remove(&handle) {
+                        Some(cb) => cb(result.map_err(IndyError::from)),
+                        None => {
+                            error!("Can't process LedgerCommand::SubmitAck for handle {:?} with result {:?} - appropriate callback not found!",
+                                   handle, result);
+                        }
                     }
                 }
             }
@@ -327,6 +598,10 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "SubmitRequest command received");
                 self.submit_action(handle, &request_json, nodes.as_ref().map(String::as_str), timeout, cb);
             }
+            LedgerCommand::SubmitActionWithOptions(handle, request_json, options, cb) => {
+                debug!(target: "ledger_command_executor", "SubmitActionWithOptions command received");
+                self.submit_action_with_options(handle, &request_json, &options, cb);
+            }
             LedgerCommand::RegisterSPParser(txn_type, parser, free, cb) => {
                 debug!(target: "ledger_command_executor", "RegisterSPParser command received");
                 cb(self.register_sp_parser(&txn_type, parser, free));
@@ -343,6 +618,30 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "BuildGetDdoRequest command received");
                 cb(self.build_get_ddo_request(submitter_did.as_ref(), &target_did));
             }
+            LedgerCommand::ResolveDid(pool_handle, did, options, cb) => {
+                debug!(target: "ledger_command_executor", "ResolveDid command received");
+                self.resolve_did(pool_handle, did, options, cb);
+            }
+            LedgerCommand::CheckWriteOutcomeContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "CheckWriteOutcomeContinue command received");
+                self._check_write_outcome_continue(pool_response, cb_id);
+            }
+            LedgerCommand::ResolveDidContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "ResolveDidContinue command received");
+                self._resolve_did_continue(pool_response, cb_id);
+            }
+            LedgerCommand::GetLedgerObjects(pool_handle, submitter_did, ids, options, cb) => {
+                debug!(target: "ledger_command_executor", "GetLedgerObjects command received");
+                self.get_ledger_objects(pool_handle, submitter_did.as_ref(), ids, options, cb);
+            }
+            LedgerCommand::GetLedgerObjectsContinue(kind, id, pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetLedgerObjectsContinue command received");
+                self._get_ledger_objects_continue(kind, id, pool_response, cb_id);
+            }
+            LedgerCommand::VerifyReplyOffline(reply, bls_keys, cb) => {
+                debug!(target: "ledger_command_executor", "VerifyReplyOffline command received");
+                cb(self.verify_reply_offline(&reply, &bls_keys));
+            }
             LedgerCommand::BuildNymRequest(submitter_did, target_did, verkey, alias, role, cb) => {
                 debug!(target: "ledger_command_executor", "BuildNymRequest command received");
                 cb(self.build_nym_request(&submitter_did, &target_did,
@@ -408,6 +707,34 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "BuildGetTxnRequest command received");
                 cb(self.build_get_txn_request(submitter_did.as_ref(), ledger_type.as_ref().map(String::as_str), seq_no));
             }
+            LedgerCommand::GetTxnRange(pool_handle, submitter_did, ledger_type, from, to, cb) => {
+                debug!(target: "ledger_command_executor", "GetTxnRange command received");
+                self.get_txn_range(pool_handle, submitter_did, ledger_type, from, to, cb);
+            }
+            LedgerCommand::GetTxnRangeContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetTxnRangeContinue command received");
+                self._get_txn_range_continue(pool_response, cb_id);
+            }
+            LedgerCommand::SubscribeLedger(pool_handle, ledger_type, from, options, cb, txn_cb) => {
+                debug!(target: "ledger_command_executor", "SubscribeLedger command received");
+                cb(self.subscribe_ledger(pool_handle, ledger_type, from, options, txn_cb));
+            }
+            LedgerCommand::SyncLedgerSubscription(subscription_handle) => {
+                debug!(target: "ledger_command_executor", "SyncLedgerSubscription command received");
+                self._sync_ledger_subscription(subscription_handle);
+            }
+            LedgerCommand::SyncLedgerSubscriptionContinue(pool_response, subscription_handle) => {
+                debug!(target: "ledger_command_executor", "SyncLedgerSubscriptionContinue command received");
+                self._sync_ledger_subscription_continue(pool_response, subscription_handle);
+            }
+            LedgerCommand::SeedLedgerSubscriptionContinue(pool_response, subscription_handle, seq_no) => {
+                debug!(target: "ledger_command_executor", "SeedLedgerSubscriptionContinue command received");
+                self._seed_ledger_subscription_continue(pool_response, subscription_handle, seq_no);
+            }
+            LedgerCommand::UnsubscribeLedger(subscription_handle, cb) => {
+                debug!(target: "ledger_command_executor", "UnsubscribeLedger command received");
+                cb(self.unsubscribe_ledger(subscription_handle));
+            }
             LedgerCommand::BuildPoolConfigRequest(submitter_did, writes, force, cb) => {
                 debug!(target: "ledger_command_executor", "BuildPoolConfigRequest command received");
                 cb(self.build_pool_config_request(&submitter_did, writes, force));
@@ -423,6 +750,18 @@ impl LedgerCommandExecutor {
                                                    justification.as_ref().map(String::as_str),
                                                    reinstall, force, package.as_ref().map(String::as_str)));
             }
+            LedgerCommand::BuildLedgersFreezeRequest(submitter_did, ledgers_ids, cb) => {
+                debug!(target: "ledger_command_executor", "BuildLedgersFreezeRequest command received");
+                cb(self.build_ledgers_freeze_request(&submitter_did, ledgers_ids));
+            }
+            LedgerCommand::BuildGetFrozenLedgersRequest(submitter_did, cb) => {
+                debug!(target: "ledger_command_executor", "BuildGetFrozenLedgersRequest command received");
+                cb(self.build_get_frozen_ledgers_request(submitter_did.as_ref()));
+            }
+            LedgerCommand::ParseUpgradeStatus(txns, cb) => {
+                debug!(target: "ledger_command_executor", "ParseUpgradeStatus command received");
+                cb(self.parse_upgrade_status(&txns));
+            }
             LedgerCommand::BuildRevocRegDefRequest(submitter_did, data, cb) => {
                 debug!(target: "ledger_command_executor", "BuildRevocRegDefRequest command received");
                 cb(self.build_revoc_reg_def_request(&submitter_did, RevocationRegistryDefinitionV1::from(data)));
@@ -455,6 +794,22 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "ParseGetRevocRegDeltaResponse command received");
                 cb(self.parse_revoc_reg_delta_response(&get_revoc_reg_delta_response));
             }
+            LedgerCommand::BuildRichSchemaObjectRequest(submitter_did, object, cb) => {
+                debug!(target: "ledger_command_executor", "BuildRichSchemaObjectRequest command received");
+                cb(self.build_rich_schema_object_request(&submitter_did, object));
+            }
+            LedgerCommand::BuildGetRichSchemaObjectByIdRequest(submitter_did, id, cb) => {
+                debug!(target: "ledger_command_executor", "BuildGetRichSchemaObjectByIdRequest command received");
+                cb(self.build_get_rich_schema_object_by_id_request(submitter_did.as_ref(), &id));
+            }
+            LedgerCommand::BuildGetRichSchemaObjectByMetadataRequest(submitter_did, rs_type, rs_name, rs_version, cb) => {
+                debug!(target: "ledger_command_executor", "BuildGetRichSchemaObjectByMetadataRequest command received");
+                cb(self.build_get_rich_schema_object_by_metadata_request(submitter_did.as_ref(), &rs_type, &rs_name, &rs_version));
+            }
+            LedgerCommand::ParseGetRichSchemaObjectResponse(get_rich_schema_object_response, cb) => {
+                debug!(target: "ledger_command_executor", "ParseGetRichSchemaObjectResponse command received");
+                cb(self.parse_get_rich_schema_object_response(&get_rich_schema_object_response));
+            }
             LedgerCommand::GetResponseMetadata(response, cb) => {
                 debug!(target: "ledger_command_executor", "GetResponseMetadata command received");
                 cb(self.get_response_metadata(&response));
@@ -492,6 +847,22 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "GetCredDefContinue command received");
                 self._get_cred_def_continue(id, pool_response, cb_id);
             }
+            LedgerCommand::GetRevocRegDef(pool_handle, submitter_did, id, cb) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDef command received");
+                self.get_revoc_reg_def(pool_handle, submitter_did.as_ref(), &id, cb);
+            }
+            LedgerCommand::GetRevocRegDefContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDefContinue command received");
+                self._get_revoc_reg_def_continue(pool_response, cb_id);
+            }
+            LedgerCommand::GetRevocRegDelta(pool_handle, submitter_did, id, from, to, cb) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDelta command received");
+                self.get_revoc_reg_delta(pool_handle, submitter_did.as_ref(), &id, from, to, cb);
+            }
+            LedgerCommand::GetRevocRegDeltaContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDeltaContinue command received");
+                self._get_revoc_reg_delta_continue(pool_response, cb_id);
+            }
             LedgerCommand::BuildTxnAuthorAgreementRequest(submitter_did, text, version, ratification_ts, retirement_ts, cb) => {
                 debug!(target: "ledger_command_executor", "BuildTxnAuthorAgreementRequest command received");
                 cb(self.build_txn_author_agreement_request(&submitter_did, text.as_ref().map(String::as_str), &version, ratification_ts, retirement_ts));
@@ -528,6 +899,30 @@ impl LedgerCommandExecutor {
                 cb(self.append_request_endorser(&request_json,
                                                 &endorser_did));
             }
+            LedgerCommand::PrepareEndorsement(request_json, get_auth_rule_response, owner_did, cb) => {
+                debug!(target: "ledger_command_executor", "PrepareEndorsement command received");
+                cb(self.prepare_endorsement(&request_json, &get_auth_rule_response, owner_did.as_ref()));
+            }
+            LedgerCommand::EndorsementAddSignature(wallet_handle, endorsement_json, signer_did, cb) => {
+                debug!(target: "ledger_command_executor", "EndorsementAddSignature command received");
+                cb(self.endorsement_add_signature(wallet_handle, &endorsement_json, &signer_did));
+            }
+            LedgerCommand::EndorsementCheckSignatures(endorsement_json, cb) => {
+                debug!(target: "ledger_command_executor", "EndorsementCheckSignatures command received");
+                cb(self.endorsement_check_signatures(&endorsement_json));
+            }
+            LedgerCommand::EndorsementStatus(endorsement_json, get_nym_responses_json, cb) => {
+                debug!(target: "ledger_command_executor", "EndorsementStatus command received");
+                cb(self.endorsement_status(&endorsement_json, &get_nym_responses_json));
+            }
+            LedgerCommand::SubmitEndorsement(pool_handle, endorsement_json, get_nym_responses_json, cb) => {
+                debug!(target: "ledger_command_executor", "SubmitEndorsement command received");
+                self.submit_endorsement(pool_handle, &endorsement_json, &get_nym_responses_json, cb);
+            }
+            LedgerCommand::EvaluateAuthRule(request_json, auth_action, old_value, get_auth_rule_response, signers, cb) => {
+                debug!(target: "ledger_command_executor", "EvaluateAuthRule command received");
+                cb(self.evaluate_auth_rule(&request_json, &auth_action, old_value.as_ref().map(String::as_str), &get_auth_rule_response, &signers));
+            }
         };
     }
 
@@ -555,6 +950,22 @@ impl LedgerCommandExecutor {
         }
     }
 
+    fn sign_and_submit_request_with_options(&self,
+                                            pool_handle: PoolHandle,
+                                            wallet_handle: WalletHandle,
+                                            submitter_did: &DidValue,
+                                            request_json: &str,
+                                            options: &RequestOptions,
+                                            cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("sign_and_submit_request_with_options >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}, options: {:?}",
+               pool_handle, wallet_handle, submitter_did, request_json, options);
+
+        match self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Single) {
+            Ok(signed_request) => self.submit_request_with_options(pool_handle, signed_request.as_str(), options, cb),
+            Err(err) => cb(Err(err))
+        }
+    }
+
     fn _sign_request(&self,
                      wallet_handle: WalletHandle,
                      submitter_did: &DidValue,
@@ -620,6 +1031,156 @@ impl LedgerCommandExecutor {
         };
     }
 
+    fn submit_request_with_options(&self,
+                                   handle: PoolHandle,
+                                   request_json: &str,
+                                   options: &RequestOptions,
+                                   cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("submit_request_with_options >>> handle: {:?}, request_json: {:?}, options: {:?}", handle, request_json, options);
+
+        if let Err(err) = serde_json::from_str::<Request<serde_json::Value>>(&request_json) {
+            return cb(Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Request is invalid json: {:?}", err))));
+        }
+
+        let x: IndyResult<CommandHandle> = self.pool_service.send_tx_with_options(handle, request_json, options);
+        match x {
+            Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
+            Err(err) => { cb(Err(err)); }
+        };
+    }
+
+    fn submit_write_request(&self,
+                            handle: PoolHandle,
+                            request_json: &str,
+                            options: &RequestOptions,
+                            cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("submit_write_request >>> handle: {:?}, request_json: {:?}, options: {:?}", handle, request_json, options);
+
+        let request: Request<serde_json::Value> = match serde_json::from_str(&request_json) {
+            Ok(request) => request,
+            Err(err) => return cb(Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Request is invalid json: {:?}", err))))
+        };
+
+        // the same signed request is submitted only once, all callers get the same outcome
+        let write_key = format!("{}:{}:{}", handle, request.identifier.map(|did| did.0).unwrap_or_default(), request.req_id);
+
+        if let Some(cmd_id) = self.pending_writes.borrow().get(&write_key) {
+            if let Some(pending_write) = self.write_callbacks.borrow_mut().get_mut(cmd_id) {
+                debug!("submit_write_request <<< request {} is already in progress", write_key);
+                pending_write.cbs.push(cb);
+                return;
+            }
+        }
+
+        let options = RequestOptions {
+            retries: Some(options.retries.unwrap_or(DEFAULT_WRITE_RETRIES)),
+            ..options.clone()
+        };
+
+        match self.pool_service.send_tx_with_options(handle, request_json, &options) {
+            Ok(cmd_id) => {
+                let mut cbs: Vec<Box<dyn Fn(IndyResult<String>)>> = Vec::new();
+                cbs.push(cb);
+                self.pending_writes.borrow_mut().insert(write_key.clone(), cmd_id);
+                self.write_callbacks.borrow_mut().insert(cmd_id, PendingWrite {
+                    pool_handle: handle,
+                    request_json: request_json.to_string(),
+                    options,
+                    write_key,
+                    seq_no: None,
+                    cbs,
+                });
+            }
+            Err(err) => { cb(Err(err)); }
+        };
+    }
+
+    fn _submit_write_request_continue(&self, result: IndyResult<String>, pending_write: PendingWrite) {
+        let outcome = _build_write_outcome(result);
+
+        if let Ok(WriteOutcome { status: WriteStatus::Unknown, .. }) = outcome {
+            match self.ledger_service.build_get_written_object_request(&pending_write.request_json) {
+                Ok(Some(request_json)) => return self._submit_write_check_request(&request_json, pending_write),
+                Ok(None) => {}
+                Err(err) => warn!("Can't check whether request {} is written: {:?}", pending_write.write_key, err)
+            }
+        }
+
+        self._complete_write(outcome, pending_write);
+    }
+
+    fn _submit_write_check_request(&self, request_json: &str, pending_write: PendingWrite) {
+        let cb_id = next_command_handle();
+        let pool_handle = pending_write.pool_handle;
+        let options = RequestOptions { retries: None, ..pending_write.options.clone() };
+
+        // the same request submitted during the check joins it
+        self.pending_writes.borrow_mut().insert(pending_write.write_key.clone(), cb_id);
+        self.write_callbacks.borrow_mut().insert(cb_id, pending_write);
+
+        self.submit_request_with_options(pool_handle, request_json, &options, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::CheckWriteOutcomeContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _check_write_outcome_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let mut pending_write = self.write_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let status = match pending_write.seq_no {
+            None => {
+                match pool_response.as_ref().map_err(Clone::clone)
+                    .and_then(|response| self.ledger_service.parse_written_object_seq_no(response)) {
+                    Ok(Some(seq_no)) => {
+                        match self.ledger_service.build_get_txn_request(None, None, seq_no) {
+                            Ok(request_json) => {
+                                pending_write.seq_no = Some(seq_no);
+                                return self._submit_write_check_request(&request_json, pending_write);
+                            }
+                            Err(err) => Err(err)
+                        }
+                    }
+                    // the object can be written by the request later, so a miss doesn't prove anything
+                    Ok(None) => Ok(WriteStatus::Unknown),
+                    Err(err) => Err(err)
+                }
+            }
+            Some(_) => {
+                pool_response.as_ref().map_err(Clone::clone)
+                    .and_then(|response| self.ledger_service.is_txn_written_by_request(response, &pending_write.request_json))
+                    .map(|written| if written { WriteStatus::Committed } else { WriteStatus::NotWritten })
+            }
+        };
+
+        let outcome = match status {
+            Ok(status) => WriteOutcome { status, reply: pool_response.ok().and_then(|response| serde_json::from_str(&response).ok()) },
+            Err(err) => {
+                // the outcome stays unknown if the pool can't be read either
+                warn!("Can't check whether request {} is written: {:?}", pending_write.write_key, err);
+                WriteOutcome { status: WriteStatus::Unknown, reply: None }
+            }
+        };
+
+        self._complete_write(Ok(outcome), pending_write);
+    }
+
+    fn _complete_write(&self, outcome: IndyResult<WriteOutcome>, pending_write: PendingWrite) {
+        self.pending_writes.borrow_mut().remove(&pending_write.write_key);
+
+        let res = outcome.and_then(|outcome| serde_json::to_string(&outcome)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize WriteOutcome"));
+
+        debug!("submit_write_request <<< res: {:?}", res);
+
+        pending_write.cbs.iter().for_each(|cb| cb(res.clone()));
+    }
+
     fn submit_action(&self,
                      handle: PoolHandle,
                      request_json: &str,
@@ -639,6 +1200,24 @@ impl LedgerCommandExecutor {
         };
     }
 
+    fn submit_action_with_options(&self,
+                                  handle: PoolHandle,
+                                  request_json: &str,
+                                  options: &RequestOptions,
+                                  cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("submit_action_with_options >>> handle: {:?}, request_json: {:?}, options: {:?}", handle, request_json, options);
+
+        if let Err(err) = self.ledger_service.validate_action(request_json) {
+            return cb(Err(err));
+        }
+
+        let x: IndyResult<CommandHandle> = self.pool_service.send_action_with_options(handle, request_json, None, None, options);
+        match x {
+            Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
+            Err(err) => { cb(Err(err)); }
+        };
+    }
+
     fn sign_request(&self,
                     wallet_handle: WalletHandle,
                     submitter_did: &DidValue,
@@ -954,6 +1533,43 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn build_ledgers_freeze_request(&self, submitter_did: &DidValue, ledgers_ids: Vec<u64>) -> IndyResult<String> {
+        debug!("build_ledgers_freeze_request >>> submitter_did: {:?}, ledgers_ids: {:?}", submitter_did, ledgers_ids);
+
+        self.crypto_service.validate_did(&submitter_did)?;
+
+        let res = self.ledger_service.build_ledgers_freeze_request(&submitter_did, ledgers_ids)?;
+
+        debug!("build_ledgers_freeze_request  <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_get_frozen_ledgers_request(&self, submitter_did: Option<&DidValue>) -> IndyResult<String> {
+        debug!("build_get_frozen_ledgers_request >>> submitter_did: {:?}", submitter_did);
+
+        self.validate_opt_did(submitter_did)?;
+
+        let res = self.ledger_service.build_get_frozen_ledgers_request(submitter_did)?;
+
+        debug!("build_get_frozen_ledgers_request  <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_upgrade_status(&self, txns: &[Value]) -> IndyResult<String> {
+        debug!("parse_upgrade_status >>> txns: {:?}", txns);
+
+        let status = self.ledger_service.parse_upgrade_status(txns)?;
+
+        let res = serde_json::to_string(&status)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize UpgradeStatus")?;
+
+        debug!("parse_upgrade_status <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_revoc_reg_def_request(&self,
                                    submitter_did: &DidValue,
                                    data: RevocationRegistryDefinitionV1) -> IndyResult<String> {
@@ -1063,6 +1679,62 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn build_rich_schema_object_request(&self,
+                                        submitter_did: &DidValue,
+                                        object: RichSchemaObject) -> IndyResult<String> {
+        debug!("build_rich_schema_object_request >>> submitter_did: {:?}, object: {:?}", submitter_did, object);
+
+        self.crypto_service.validate_did(submitter_did)?;
+
+        let res = self.ledger_service.build_rich_schema_object_request(submitter_did, object)?;
+
+        debug!("build_rich_schema_object_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_get_rich_schema_object_by_id_request(&self,
+                                                  submitter_did: Option<&DidValue>,
+                                                  id: &str) -> IndyResult<String> {
+        debug!("build_get_rich_schema_object_by_id_request >>> submitter_did: {:?}, id: {:?}", submitter_did, id);
+
+        self.validate_opt_did(submitter_did)?;
+
+        let res = self.ledger_service.build_get_rich_schema_object_by_id_request(submitter_did, id)?;
+
+        debug!("build_get_rich_schema_object_by_id_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_get_rich_schema_object_by_metadata_request(&self,
+                                                        submitter_did: Option<&DidValue>,
+                                                        rs_type: &str,
+                                                        rs_name: &str,
+                                                        rs_version: &str) -> IndyResult<String> {
+        debug!("build_get_rich_schema_object_by_metadata_request >>> submitter_did: {:?}, rs_type: {:?}, rs_name: {:?}, rs_version: {:?}",
+               submitter_did, rs_type, rs_name, rs_version);
+
+        self.validate_opt_did(submitter_did)?;
+
+        let res = self.ledger_service.build_get_rich_schema_object_by_metadata_request(submitter_did, rs_type, rs_name, rs_version)?;
+
+        debug!("build_get_rich_schema_object_by_metadata_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_rich_schema_object_response(&self,
+                                             get_rich_schema_object_response: &str) -> IndyResult<(String, String)> {
+        debug!("parse_get_rich_schema_object_response >>> get_rich_schema_object_response: {:?}", get_rich_schema_object_response);
+
+        let res = self.ledger_service.parse_get_rich_schema_object_response(get_rich_schema_object_response)?;
+
+        debug!("parse_get_rich_schema_object_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn get_response_metadata(&self,
                              response: &str) -> IndyResult<String> {
         debug!("get_response_metadata >>> response: {:?}", response);
@@ -1250,9 +1922,199 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
-    fn validate_opt_did(&self, did: Option<&DidValue>) -> IndyResult<()> {
-        match did {
-            Some(did) => Ok(self.crypto_service.validate_did(did)?),
+    fn prepare_endorsement(&self,
+                           request_json: &str,
+                           get_auth_rule_response: &str,
+                           owner_did: Option<&DidValue>) -> IndyResult<String> {
+        debug!("prepare_endorsement >>> request_json: {:?}, get_auth_rule_response: {:?}, owner_did: {:?}",
+               request_json, get_auth_rule_response, owner_did);
+
+        self.validate_opt_did(owner_did)?;
+
+        let envelope = self.ledger_service.prepare_endorsement(request_json, get_auth_rule_response, owner_did)?;
+
+        let res = serde_json::to_string(&envelope)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize EndorsementEnvelope")?;
+
+        debug!("prepare_endorsement <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn endorsement_add_signature(&self,
+                                 wallet_handle: WalletHandle,
+                                 endorsement_json: &str,
+                                 signer_did: &DidValue) -> IndyResult<String> {
+        debug!("endorsement_add_signature >>> wallet_handle: {:?}, endorsement_json: {:?}, signer_did: {:?}",
+               wallet_handle, endorsement_json, signer_did);
+
+        self.crypto_service.validate_did(signer_did)?;
+
+        let mut envelope = LedgerCommandExecutor::_parse_endorsement(endorsement_json)?;
+
+        let my_did: Did = self.wallet_service.get_indy_object(wallet_handle, &signer_did.0, &RecordOptions::id_value())?;
+
+        let request = self._sign_request(wallet_handle, signer_did, &envelope.request.to_string(), SignatureType::Multi)?;
+
+        envelope.request = serde_json::from_str(&request)
+            .to_indy(IndyErrorKind::InvalidState, "Signed request is invalid json")?;
+        envelope.signers.insert(signer_did.to_short().0, EndorsementSigner { verkey: my_did.verkey });
+
+        let res = serde_json::to_string(&envelope)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize EndorsementEnvelope")?;
+
+        debug!("endorsement_add_signature <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn endorsement_check_signatures(&self,
+                                    endorsement_json: &str) -> IndyResult<String> {
+        debug!("endorsement_check_signatures >>> endorsement_json: {:?}", endorsement_json);
+
+        let envelope = LedgerCommandExecutor::_parse_endorsement(endorsement_json)?;
+
+        let signatures = self._check_endorsement_signatures(&envelope)?;
+
+        let res = serde_json::to_string(&signatures)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize EndorsementSignatures")?;
+
+        debug!("endorsement_check_signatures <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn endorsement_status(&self,
+                          endorsement_json: &str,
+                          get_nym_responses_json: &str) -> IndyResult<String> {
+        debug!("endorsement_status >>> endorsement_json: {:?}, get_nym_responses_json: {:?}", endorsement_json, get_nym_responses_json);
+
+        let status = self._endorsement_status(endorsement_json, get_nym_responses_json)?.1;
+
+        let res = serde_json::to_string(&status)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize EndorsementStatus")?;
+
+        debug!("endorsement_status <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn submit_endorsement(&self,
+                          pool_handle: PoolHandle,
+                          endorsement_json: &str,
+                          get_nym_responses_json: &str,
+                          cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("submit_endorsement >>> pool_handle: {:?}, endorsement_json: {:?}, get_nym_responses_json: {:?}",
+               pool_handle, endorsement_json, get_nym_responses_json);
+
+        let request = try_cb!(self._ready_endorsement_request(endorsement_json, get_nym_responses_json), cb);
+
+        self.submit_request(pool_handle, &request, cb);
+
+        debug!("submit_endorsement <<<");
+    }
+
+    fn _ready_endorsement_request(&self, endorsement_json: &str, get_nym_responses_json: &str) -> IndyResult<String> {
+        let (envelope, status) = self._endorsement_status(endorsement_json, get_nym_responses_json)?;
+
+        if !status.ready {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Request isn't ready to be submitted: unmet constraints {:?}, invalid signatures {:?}, unresolved signers {:?}",
+                                       status.unmet, status.signatures.invalid, status.unresolved)));
+        }
+
+        serde_json::to_string(&envelope.request)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize request")
+    }
+
+    fn evaluate_auth_rule(&self,
+                          request_json: &str,
+                          auth_action: &str,
+                          old_value: Option<&str>,
+                          get_auth_rule_response: &str,
+                          signers: &[AuthSigner]) -> IndyResult<String> {
+        debug!("evaluate_auth_rule >>> request_json: {:?}, auth_action: {:?}, old_value: {:?}, get_auth_rule_response: {:?}, signers: {:?}",
+               request_json, auth_action, old_value, get_auth_rule_response, signers);
+
+        let evaluation = self.ledger_service.evaluate_auth_rule(request_json, auth_action, old_value, get_auth_rule_response, signers)?;
+
+        let res = serde_json::to_string(&evaluation)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize AuthRuleEvaluation")?;
+
+        debug!("evaluate_auth_rule <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _endorsement_status(&self, endorsement_json: &str, get_nym_responses_json: &str) -> IndyResult<(EndorsementEnvelope, EndorsementStatus)> {
+        let envelope = LedgerCommandExecutor::_parse_endorsement(endorsement_json)?;
+
+        let get_nym_responses: Vec<Value> = serde_json::from_str(get_nym_responses_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize list of GET_NYM responses")?;
+
+        let get_nym_responses: Vec<String> = get_nym_responses.iter()
+            .map(|response| match response {
+                Value::String(response) => response.clone(),
+                response => response.to_string()
+            })
+            .collect();
+
+        let signatures = self._check_endorsement_signatures(&envelope)?;
+        let status = self.ledger_service.endorsement_status(&envelope, signatures, &get_nym_responses)?;
+
+        Ok((envelope, status))
+    }
+
+    fn _parse_endorsement(endorsement_json: &str) -> IndyResult<EndorsementEnvelope> {
+        let envelope: EndorsementEnvelope = serde_json::from_str(endorsement_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize EndorsementEnvelope")?;
+
+        if !envelope.request.is_object() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Request of the endorsement isn't json object"));
+        }
+
+        Ok(envelope)
+    }
+
+    fn _check_endorsement_signatures(&self, envelope: &EndorsementEnvelope) -> IndyResult<EndorsementSignatures> {
+        let serialized_request = serialize_signature(envelope.request.clone())?;
+
+        let mut signatures: BTreeMap<String, String> = envelope.request["signatures"].as_object()
+            .map(|signatures| signatures.iter()
+                .filter_map(|(did, signature)| signature.as_str().map(|signature| (did.to_string(), signature.to_string())))
+                .collect())
+            .unwrap_or_default();
+
+        if let (Some(identifier), Some(signature)) = (envelope.request["identifier"].as_str(), envelope.request["signature"].as_str()) {
+            signatures.insert(identifier.to_string(), signature.to_string());
+        }
+
+        let mut res = EndorsementSignatures::default();
+
+        for (did, signature) in signatures.iter() {
+            match envelope.signers.get(did) {
+                Some(signer) => {
+                    let valid = signature.from_base58().ok()
+                        .and_then(|signature| self.crypto_service.verify(&signer.verkey, serialized_request.as_bytes(), &signature).ok())
+                        .unwrap_or(false);
+
+                    if valid { res.valid.push(did.to_string()) } else { res.invalid.push(did.to_string()) }
+                }
+                None => res.unknown.push(did.to_string())
+            }
+        }
+
+        res.missing = envelope.signers.keys()
+            .filter(|did| !signatures.contains_key(*did))
+            .cloned()
+            .collect();
+
+        Ok(res)
+    }
+
+    fn validate_opt_did(&self, did: Option<&DidValue>) -> IndyResult<()> {
+        match did {
+            Some(did) => Ok(self.crypto_service.validate_did(did)?),
             None => Ok(())
         }
     }
@@ -1308,9 +2170,687 @@ impl LedgerCommandExecutor {
         let pool_response = try_cb!(pool_response, cb);
         cb(self.ledger_service.parse_get_cred_def_response(&pool_response, id.get_method().as_ref().map(String::as_str)))
     }
+
+    fn get_revoc_reg_def(&self, pool_handle: i32, submitter_did: Option<&DidValue>, id: &RevocationRegistryId, cb: BoxedCallbackStringStringSend) {
+        let request_json = try_cb!(self.build_get_revoc_reg_def_request(submitter_did, id), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegDefContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_revoc_reg_def_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pool_response = try_cb!(pool_response, cb);
+        cb(self.ledger_service.parse_get_revoc_reg_def_response(&pool_response))
+    }
+
+    fn get_revoc_reg_delta(&self,
+                           pool_handle: i32,
+                           submitter_did: Option<&DidValue>,
+                           id: &RevocationRegistryId,
+                           from: Option<i64>,
+                           to: i64,
+                           cb: Box<dyn Fn(IndyResult<(String, String, u64)>) + Send>) {
+        let request_json = try_cb!(self.build_get_revoc_reg_delta_request(submitter_did, id, from, to), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_delta_callbacks.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegDeltaContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_revoc_reg_delta_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let cb = self.pending_delta_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pool_response = try_cb!(pool_response, cb);
+        cb(self.ledger_service.parse_get_revoc_reg_delta_response(&pool_response))
+    }
+
+    fn get_txn_range(&self,
+                     pool_handle: PoolHandle,
+                     submitter_did: Option<DidValue>,
+                     ledger_type: Option<String>,
+                     from: i32,
+                     to: Option<i32>,
+                     cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("get_txn_range >>> pool_handle: {:?}, submitter_did: {:?}, ledger_type: {:?}, from: {:?}, to: {:?}",
+               pool_handle, submitter_did, ledger_type, from, to);
+
+        if from < 1 || to.map(|to| to < from).unwrap_or(false) {
+            return cb(Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid range of transactions: {} - {:?}", from, to))));
+        }
+
+        let bls_keys = try_cb!(self.pool_service.get_open_pool_bls_keys(pool_handle), cb);
+
+        let page_to = from.saturating_add(TXN_RANGE_PAGE_SIZE - 1);
+
+        let fetch = TxnRangeFetch {
+            pool_handle,
+            submitter_did,
+            ledger_type,
+            from,
+            page_to: to.map(|to| ::std::cmp::min(to, page_to)).unwrap_or(page_to),
+            has_more: to.map(|to| to > page_to).unwrap_or(true),
+            next: from,
+            restarts: 0,
+            txns: Vec::new(),
+            ledger: None,
+            bls_keys,
+            cb,
+        };
+
+        self._fetch_next_txn(fetch);
+    }
+
+    fn _fetch_next_txn(&self, fetch: TxnRangeFetch) {
+        let request_json = match self.build_get_txn_request(fetch.submitter_did.as_ref(),
+                                                            fetch.ledger_type.as_ref().map(String::as_str),
+                                                            fetch.next) {
+            Ok(request_json) => request_json,
+            Err(err) => return (fetch.cb)(Err(err))
+        };
+
+        let cb_id = next_command_handle();
+        let pool_handle = fetch.pool_handle;
+        self.txn_range_fetches.borrow_mut().insert(cb_id, fetch);
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetTxnRangeContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_txn_range_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let mut fetch = self.txn_range_fetches.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let txn = match pool_response.and_then(|response| self._parse_get_txn_range_reply(&response, &fetch)) {
+            Ok(txn) => txn,
+            Err(err) => return (fetch.cb)(Err(err))
+        };
+
+        let end_of_ledger = match txn {
+            Some(txn) => {
+                let ledger_changed = fetch.ledger.as_ref()
+                    .map(|&(ledger_size, ref root_hash)| ledger_size != txn.ledger_size || *root_hash != txn.root_hash)
+                    .unwrap_or(false);
+
+                if ledger_changed {
+                    // the ledger has grown since the page was started, so it's fetched again against the new root
+                    if fetch.restarts == TXN_RANGE_MAX_RESTARTS {
+                        return (fetch.cb)(Err(err_msg(IndyErrorKind::InvalidState, "Ledger was changing while the range of transactions was fetched")));
+                    }
+
+                    fetch.restarts += 1;
+                    fetch.next = fetch.from;
+                    fetch.txns.clear();
+                    fetch.ledger = None;
+
+                    return self._fetch_next_txn(fetch);
+                }
+
+                let end_of_ledger = i64::from(fetch.next) >= txn.ledger_size as i64;
+
+                fetch.txns.push(txn.txn);
+                fetch.ledger = Some((txn.ledger_size, txn.root_hash));
+                fetch.next += 1;
+
+                end_of_ledger
+            }
+            None => true
+        };
+
+        if !end_of_ledger && fetch.next <= fetch.page_to {
+            return self._fetch_next_txn(fetch);
+        }
+
+        let (ledger_size, root_hash) = match fetch.ledger {
+            Some((ledger_size, root_hash)) => (Some(ledger_size), Some(root_hash)),
+            None => (None, None)
+        };
+
+        let page = TxnRangePage {
+            txns: fetch.txns,
+            ledger_size,
+            root_hash,
+            next: if !end_of_ledger && fetch.has_more { Some(fetch.next) } else { None },
+        };
+
+        let res = serde_json::to_string(&page)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize TxnRangePage");
+
+        debug!("get_txn_range <<< res: {:?}", res);
+
+        (fetch.cb)(res)
+    }
+
+    fn _parse_get_txn_range_reply(&self, response: &str, fetch: &TxnRangeFetch) -> IndyResult<Option<VerifiedTxn>> {
+        let txn = self.ledger_service.parse_get_txn_range_reply(response, fetch.next)?;
+
+        // the signed root is verified once per page, the following replies are proven against the same root
+        if txn.is_some() && fetch.ledger.is_none() && !self.pool_service.verify_reply_offline(response, &fetch.bls_keys)?.verified {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, "GET_TXN reply isn't verified by BLS multi-signature of the pool"));
+        }
+
+        Ok(txn)
+    }
+
+    fn verify_reply_offline(&self,
+                            reply: &str,
+                            bls_keys: &HashMap<String, Option<String>>) -> IndyResult<String> {
+        debug!("verify_reply_offline >>> reply: {:?}, bls_keys: {:?}", reply, bls_keys);
+
+        let verification = self.pool_service.verify_reply_offline(reply, bls_keys)?;
+
+        let res = serde_json::to_string(&verification)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize ReplyVerification")?;
+
+        debug!("verify_reply_offline <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn resolve_did(&self,
+                   pool_handle: PoolHandle,
+                   did: DidValue,
+                   options: Option<RequestOptions>,
+                   cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("resolve_did >>> pool_handle: {:?}, did: {:?}, options: {:?}", pool_handle, did, options);
+
+        if did.get_method().map(|method| method != "sov").unwrap_or(false) {
+            return cb(Err(err_msg(IndyErrorKind::InvalidStructure, format!("Only did:sov DIDs can be resolved: {}", did.0))));
+        }
+
+        // replies are accepted only with verified state proof unless the caller chose the read policy explicitly
+        let mut options = options.unwrap_or_default();
+        if options.read_consensus.is_none() && options.require_state_proof.is_none() {
+            options.require_state_proof = Some(true);
+        }
+
+        let request_json = try_cb!(self.build_get_nym_request(None, &did), cb);
+
+        let resolution = DidResolution {
+            pool_handle,
+            did,
+            options,
+            nym_data: None,
+            cb,
+        };
+
+        self._submit_did_resolution_request(&request_json, resolution);
+    }
+
+    fn _submit_did_resolution_request(&self, request_json: &str, resolution: DidResolution) {
+        let cb_id = next_command_handle();
+        let pool_handle = resolution.pool_handle;
+        let options = resolution.options.clone();
+        self.did_resolutions.borrow_mut().insert(cb_id, resolution);
+
+        self.submit_request_with_options(pool_handle, request_json, &options, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::ResolveDidContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _resolve_did_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let mut resolution = self.did_resolutions.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let pool_response = match pool_response {
+            Ok(pool_response) => pool_response,
+            Err(err) => return (resolution.cb)(Err(err))
+        };
+
+        let nym_data = match resolution.nym_data.take() {
+            Some(nym_data) => nym_data,
+            None => {
+                let nym_data = match self._parse_resolved_nym(&resolution.did, &pool_response) {
+                    Ok(nym_data) => nym_data,
+                    Err(err) => return (resolution.cb)(Err(err))
+                };
+
+                let request_json = match self.build_get_attrib_request(None, &resolution.did, Some("endpoint"), None, None) {
+                    Ok(request_json) => request_json,
+                    Err(err) => return (resolution.cb)(Err(err))
+                };
+
+                resolution.nym_data = Some(nym_data);
+                return self._submit_did_resolution_request(&request_json, resolution);
+            }
+        };
+
+        let res = self.ledger_service.parse_get_attrib_raw_response(&pool_response)
+            .and_then(|endpoint| self.ledger_service.build_did_document(&nym_data, endpoint.as_ref().map(String::as_str)))
+            .and_then(|did_doc| serde_json::to_string(&did_doc)
+                .to_indy(IndyErrorKind::InvalidState, "Can't serialize DidDocument"));
+
+        debug!("resolve_did <<< res: {:?}", res);
+
+        (resolution.cb)(res)
+    }
+
+    fn get_ledger_objects(&self,
+                          pool_handle: PoolHandle,
+                          submitter_did: Option<&DidValue>,
+                          ids: LedgerObjectIds,
+                          options: Option<RequestOptions>,
+                          cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("get_ledger_objects >>> pool_handle: {:?}, submitter_did: {:?}, ids: {:?}, options: {:?}",
+               pool_handle, submitter_did, ids, options);
+
+        try_cb!(self.validate_opt_did(submitter_did), cb);
+
+        // replies are accepted only with verified state proof unless the caller chose the read policy explicitly
+        let mut options = options.unwrap_or_default();
+        if options.read_consensus.is_none() && options.require_state_proof.is_none() {
+            options.require_state_proof = Some(true);
+        }
+
+        let mut objects = LedgerObjects::default();
+        let mut requests: Vec<(LedgerObjectKind, String, String)> = Vec::new();
+
+        {
+            let mut add_request = |kind: LedgerObjectKind, id: String, request: IndyResult<String>| {
+                match request {
+                    Ok(request) => requests.push((kind, id, request)),
+                    Err(err) => objects.insert(kind, id, LedgerObjectResult::from(err))
+                }
+            };
+
+            for id in ids.schemas.iter().collect::<HashSet<&SchemaId>>() {
+                add_request(LedgerObjectKind::Schema, id.0.clone(), self.build_get_schema_request(submitter_did, id));
+            }
+
+            for id in ids.cred_defs.iter().collect::<HashSet<&CredentialDefinitionId>>() {
+                add_request(LedgerObjectKind::CredDef, id.0.clone(), self.build_get_cred_def_request(submitter_did, id));
+            }
+
+            for id in ids.rev_reg_defs.iter().collect::<HashSet<&RevocationRegistryId>>() {
+                add_request(LedgerObjectKind::RevRegDef, id.0.clone(), self.build_get_revoc_reg_def_request(submitter_did, id));
+            }
+        }
+
+        if requests.is_empty() {
+            return cb(LedgerCommandExecutor::_serialize_ledger_objects(&objects));
+        }
+
+        let cb_id = next_command_handle();
+        self.ledger_objects_reads.borrow_mut().insert(cb_id, LedgerObjectsRead {
+            pending: requests.len(),
+            objects,
+            cb,
+        });
+
+        // all the requests are sent at once, the pool processes them concurrently
+        for (kind, id, request) in requests {
+            self.submit_request_with_options(pool_handle, &request, &options, Box::new(move |response| {
+                CommandExecutor::instance().send(
+                    Command::Ledger(
+                        LedgerCommand::GetLedgerObjectsContinue(
+                            kind,
+                            id.clone(),
+                            response,
+                            cb_id
+                        )
+                    )
+                ).unwrap();
+            }));
+        }
+    }
+
+    fn _get_ledger_objects_continue(&self, kind: LedgerObjectKind, id: String, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let result = pool_response
+            .and_then(|pool_response| self._parse_ledger_object(kind, &id, &pool_response))
+            .map(LedgerObjectResult::Object)
+            .unwrap_or_else(LedgerObjectResult::from);
+
+        let finished = {
+            let mut reads = self.ledger_objects_reads.borrow_mut();
+            let read = reads.get_mut(&cb_id).expect("FIXME INVALID STATE");
+
+            read.objects.insert(kind, id, result);
+            read.pending -= 1;
+
+            read.pending == 0
+        };
+
+        if finished {
+            let read = self.ledger_objects_reads.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+            let res = LedgerCommandExecutor::_serialize_ledger_objects(&read.objects);
+
+            debug!("get_ledger_objects <<< res: {:?}", res);
+
+            (read.cb)(res)
+        }
+    }
+
+    fn _parse_ledger_object(&self, kind: LedgerObjectKind, id: &str, pool_response: &str) -> IndyResult<Value> {
+        let (object_id, object) = match kind {
+            LedgerObjectKind::Schema => {
+                let method = SchemaId(id.to_string()).get_method();
+                self.ledger_service.parse_get_schema_response(pool_response, method.as_ref().map(String::as_str))?
+            }
+            LedgerObjectKind::CredDef => {
+                let method = CredentialDefinitionId(id.to_string()).get_method();
+                self.ledger_service.parse_get_cred_def_response(pool_response, method.as_ref().map(String::as_str))?
+            }
+            LedgerObjectKind::RevRegDef => self.ledger_service.parse_get_revoc_reg_def_response(pool_response)?
+        };
+
+        if qualifier::to_unqualified(&object_id) != qualifier::to_unqualified(id) {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, format!("Reply contains the object {} instead of {}", object_id, id)));
+        }
+
+        serde_json::from_str(&object)
+            .to_indy(IndyErrorKind::InvalidState, "Can't deserialize parsed ledger object")
+    }
+
+    fn _serialize_ledger_objects(objects: &LedgerObjects) -> IndyResult<String> {
+        serde_json::to_string(objects)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize LedgerObjects")
+    }
+
+    fn _parse_resolved_nym(&self, did: &DidValue, get_nym_response: &str) -> IndyResult<NymData> {
+        let nym_data = self.ledger_service.parse_get_nym_response(get_nym_response)?;
+
+        let nym_data: NymData = serde_json::from_str(&nym_data)
+            .to_indy(IndyErrorKind::InvalidState, "Can't deserialize NymData")?;
+
+        if nym_data.did != did.to_short() {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, format!("GET_NYM reply contains DID other than {}", did.0)));
+        }
+
+        Ok(nym_data)
+    }
+
+    fn subscribe_ledger(&self,
+                        pool_handle: PoolHandle,
+                        ledger_type: Option<String>,
+                        from: Option<i32>,
+                        options: LedgerSubscriptionOptions,
+                        txn_cb: Box<dyn Fn(IndyResult<String>) + Send>) -> IndyResult<CommandHandle> {
+        debug!("subscribe_ledger >>> pool_handle: {:?}, ledger_type: {:?}, from: {:?}, options: {:?}",
+               pool_handle, ledger_type, from, options);
+
+        if from.map(|from| from < 1).unwrap_or(false) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid seq_no of the first transaction: {:?}", from)));
+        }
+
+        let ledger_id = self.ledger_service.parse_ledger_type(ledger_type.as_ref().map(String::as_str))?;
+
+        if ledger_id == LedgerType::POOL.to_id() || ledger_id < 0 || ledger_id > i32::from(u8::max_value()) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Subscription to the ledger {} is not supported", ledger_id)));
+        }
+
+        let bls_keys = self.pool_service.get_open_pool_bls_keys(pool_handle)?;
+
+        let subscription_handle = next_command_handle();
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        self.subscriptions.borrow_mut().insert(subscription_handle, LedgerSubscription {
+            pool_handle,
+            ledger_id: ledger_id as u8,
+            from,
+            // nothing precedes the first transaction of the ledger
+            frontier: if from == Some(1) { Some(MerkleTreeFrontier::default()) } else { None },
+            bls_keys,
+            syncing: false,
+            stopped: stopped.clone(),
+            txn_cb,
+        });
+
+        let interval = Duration::from_secs(options.interval);
+
+        thread::spawn(move || {
+            while !stopped.load(Ordering::SeqCst) {
+                CommandExecutor::instance().send(
+                    Command::Ledger(
+                        LedgerCommand::SyncLedgerSubscription(subscription_handle)
+                    )
+                ).unwrap();
+
+                thread::sleep(interval);
+            }
+        });
+
+        let res = Ok(subscription_handle);
+
+        debug!("subscribe_ledger <<< res: {:?}", res);
+
+        res
+    }
+
+    fn _sync_ledger_subscription(&self, subscription_handle: CommandHandle) {
+        let (pool_handle, ledger_id, from, ledger_status) = {
+            let mut subscriptions = self.subscriptions.borrow_mut();
+
+            let subscription = match subscriptions.get_mut(&subscription_handle) {
+                Some(subscription) => subscription,
+                None => return
+            };
+
+            if subscription.syncing {
+                return;
+            }
+
+            subscription.syncing = true;
+
+            let ledger_status = subscription.frontier.as_ref()
+                .map(|frontier| frontier.root_hash().map(|root_hash| (frontier.count, root_hash)));
+
+            (subscription.pool_handle, subscription.ledger_id, subscription.from, ledger_status)
+        };
+
+        let (ledger_size, root_hash) = match ledger_status {
+            Some(Ok(ledger_status)) => ledger_status,
+            Some(Err(err)) => return self._sync_ledger_subscription_continue(Err(err), subscription_handle),
+            None => return self._seed_ledger_subscription(subscription_handle, pool_handle, ledger_id, from)
+        };
+
+        match self.pool_service.catchup_ledger(pool_handle, ledger_id, ledger_size, &root_hash) {
+            Ok(cmd_id) => {
+                self.send_callbacks.borrow_mut().insert(cmd_id, Box::new(move |response| {
+                    CommandExecutor::instance().send(
+                        Command::Ledger(
+                            LedgerCommand::SyncLedgerSubscriptionContinue(
+                                response,
+                                subscription_handle
+                            )
+                        )
+                    ).unwrap();
+                }));
+            }
+            Err(err) => self._sync_ledger_subscription_continue(Err(err), subscription_handle)
+        }
+    }
+
+    fn _sync_ledger_subscription_continue(&self, pool_response: IndyResult<String>, subscription_handle: CommandHandle) {
+        let mut subscriptions = self.subscriptions.borrow_mut();
+
+        let subscription = match subscriptions.get_mut(&subscription_handle) {
+            Some(subscription) => subscription,
+            None => return
+        };
+
+        subscription.syncing = false;
+
+        let frontier = match subscription.frontier.as_mut() {
+            Some(frontier) => frontier,
+            None => return
+        };
+
+        let txns = match pool_response.and_then(|response| self.ledger_service.parse_ledger_catchup_reply(&response, frontier)) {
+            Ok(txns) => txns,
+            Err(err) => return _fail_ledger_subscription(&mut subscriptions, subscription_handle, err)
+        };
+
+        // the frontier precedes the first transaction to report, so all caught up transactions are reported
+        for txn in txns {
+            let txn_json = serde_json::to_string(&txn)
+                .to_indy(IndyErrorKind::InvalidState, "Can't serialize transaction");
+
+            (subscription.txn_cb)(txn_json);
+        }
+    }
+
+    /// Seeds the subscription with the right edge of the ledger merkle tree ending with the transaction preceding
+    /// the first one to report, so the ledger history before it isn't caught up.
+    /// If the first transaction isn't set, the end of the ledger is found out by GET_TXN of the first transaction before.
+    fn _seed_ledger_subscription(&self, subscription_handle: CommandHandle, pool_handle: PoolHandle, ledger_id: u8, from: Option<i32>) {
+        let seq_no = from.map(|from| from - 1).unwrap_or(1);
+
+        let request_json = match self.build_get_txn_request(None, Some(&ledger_id.to_string()), seq_no) {
+            Ok(request_json) => request_json,
+            Err(err) => return self._seed_ledger_subscription_continue(Err(err), subscription_handle, seq_no)
+        };
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::SeedLedgerSubscriptionContinue(
+                        response,
+                        subscription_handle,
+                        seq_no
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _seed_ledger_subscription_continue(&self, pool_response: IndyResult<String>, subscription_handle: CommandHandle, seq_no: i32) {
+        {
+            let mut subscriptions = self.subscriptions.borrow_mut();
+
+            let subscription = match subscriptions.get_mut(&subscription_handle) {
+                Some(subscription) => subscription,
+                None => return
+            };
+
+            subscription.syncing = false;
+
+            let txn = match pool_response.and_then(|response| self._parse_ledger_subscription_seed(&response, seq_no, &subscription.bls_keys)) {
+                Ok(txn) => txn,
+                Err(err) => return _fail_ledger_subscription(&mut subscriptions, subscription_handle, err)
+            };
+
+            // only transactions committed after the subscription are reported if the first one isn't set
+            let from = *subscription.from.get_or_insert(txn.as_ref().map(|txn| txn.ledger_size as i32 + 1).unwrap_or(1));
+
+            if from == 1 {
+                subscription.frontier = Some(MerkleTreeFrontier::default());
+                return;
+            }
+
+            if from - 1 == seq_no {
+                // the ledger may not reach the transaction yet, the seeding is repeated on the next sync then
+                if let Some(txn) = txn {
+                    match self.ledger_service.build_ledger_frontier(&txn, seq_no) {
+                        Ok(frontier) => subscription.frontier = Some(frontier),
+                        Err(err) => return _fail_ledger_subscription(&mut subscriptions, subscription_handle, err)
+                    }
+                }
+                return;
+            }
+        }
+
+        // the end of the ledger is known now, so the subscription is seeded at it
+        self._sync_ledger_subscription(subscription_handle);
+    }
+
+    fn _parse_ledger_subscription_seed(&self, response: &str, seq_no: i32, bls_keys: &HashMap<String, Option<String>>) -> IndyResult<Option<VerifiedTxn>> {
+        let txn = self.ledger_service.parse_get_txn_range_reply(response, seq_no)?;
+
+        if txn.is_some() && !self.pool_service.verify_reply_offline(response, bls_keys)?.verified {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, "GET_TXN reply isn't verified by BLS multi-signature of the pool"));
+        }
+
+        Ok(txn)
+    }
+
+    fn unsubscribe_ledger(&self, subscription_handle: CommandHandle) -> IndyResult<()> {
+        debug!("unsubscribe_ledger >>> subscription_handle: {:?}", subscription_handle);
+
+        let subscription = self.subscriptions.borrow_mut().remove(&subscription_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidParam(2), format!("Unknown ledger subscription handle: {}", subscription_handle)))?;
+
+        subscription.stopped.store(true, Ordering::SeqCst);
+
+        let res = Ok(());
+
+        debug!("unsubscribe_ledger <<< res: {:?}", res);
+
+        res
+    }
 }
 
 enum SignatureType {
     Single,
     Multi
 }
+
+// The error is reported to the subscriber, the subscription is stopped only if the pool is closed.
+fn _fail_ledger_subscription(subscriptions: &mut HashMap<CommandHandle, LedgerSubscription>, subscription_handle: CommandHandle, err: IndyError) {
+    let subscription = match subscriptions.get(&subscription_handle) {
+        Some(subscription) => subscription,
+        None => return
+    };
+
+    let pool_closed = err.kind() == IndyErrorKind::InvalidPoolHandle;
+    (subscription.txn_cb)(Err(err));
+
+    if pool_closed {
+        subscription.stopped.store(true, Ordering::SeqCst);
+        subscriptions.remove(&subscription_handle);
+    }
+}
+
+fn _build_write_outcome(result: IndyResult<String>) -> IndyResult<WriteOutcome> {
+    let outcome = match result {
+        Ok(reply) => {
+            let reply: Value = serde_json::from_str(&reply)
+                .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
+
+            let status = match reply["op"].as_str() {
+                Some("REPLY") => WriteStatus::Committed,
+                Some("REJECT") | Some("REQNACK") => WriteStatus::Rejected,
+                _ => WriteStatus::Unknown
+            };
+
+            WriteOutcome { status, reply: Some(reply) }
+        }
+        Err(ref err) if err.kind() == IndyErrorKind::PoolTimeout => WriteOutcome { status: WriteStatus::Unknown, reply: None },
+        Err(err) => return Err(err)
+    };
+
+    Ok(outcome)
+}
@@ -35,6 +35,14 @@ pub enum PoolCommand {
         Box<dyn Fn(IndyResult<()>) + Send>),
     RefreshAck(CommandHandle,
                IndyResult<()>),
+    GetStatus(
+        PoolHandle, // pool handle
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    GetStatusAck(CommandHandle,
+                 IndyResult<String>),
+    GetBlsKeys(
+        String, // name
+        Box<dyn Fn(IndyResult<String>) + Send>),
     SetProtocolVersion(
         usize, // protocol version
         Box<dyn Fn(IndyResult<()>) + Send>),
@@ -45,6 +53,7 @@ pub struct PoolCommandExecutor {
     close_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<()>)>>>,
     refresh_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<()>)>>>,
     open_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<PoolHandle>)>>>,
+    status_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>)>>>,
 }
 
 impl PoolCommandExecutor {
@@ -54,6 +63,7 @@ impl PoolCommandExecutor {
             close_callbacks: RefCell::new(HashMap::new()),
             refresh_callbacks: RefCell::new(HashMap::new()),
             open_callbacks: RefCell::new(HashMap::new()),
+            status_callbacks: RefCell::new(HashMap::new()),
         }
     }
 
@@ -128,6 +138,29 @@ impl PoolCommandExecutor {
                     Err(err) => { error!("{:?}", err); }
                 }
             }
+            PoolCommand::GetStatus(handle, cb) => {
+                debug!(target: "pool_command_executor", "GetStatus command received");
+                self.get_status(handle, cb);
+            }
+            PoolCommand::GetStatusAck(handle, result) => {
+                debug!(target: "pool_command_executor", "GetStatusAck command received");
+                match self.status_callbacks.try_borrow_mut() {
+                    Ok(mut cbs) => {
+                        match cbs.remove(&handle) {
+                            Some(cb) => cb(result),
+                            None => {
+                                error!("Can't process PoolCommand::GetStatusAck for handle {:?} with result {:?} - appropriate callback not found!",
+                                       handle, result);
+                            }
+                        }
+                    }
+                    Err(err) => { error!("{:?}", err); }
+                }
+            }
+            PoolCommand::GetBlsKeys(name, cb) => {
+                debug!(target: "pool_command_executor", "GetBlsKeys command received");
+                cb(self.get_bls_keys(&name));
+            }
             PoolCommand::SetProtocolVersion(protocol_version, cb) => {
                 debug!(target: "pool_command_executor", "SetProtocolVersion command received");
                 cb(self.set_protocol_version(protocol_version));
@@ -185,6 +218,18 @@ impl PoolCommandExecutor {
         Ok(res)
     }
 
+    fn get_bls_keys(&self, name: &str) -> IndyResult<String> {
+        debug!("get_bls_keys >>> name: {:?}", name);
+
+        let res = self.pool_service
+            .get_bls_keys(name)
+            .and_then(|bls_keys| ::serde_json::to_string(&bls_keys)
+                .to_indy(IndyErrorKind::InvalidState, "Can't serialize BLS keys"))?;
+
+        debug!("get_bls_keys << res: {:?}", res);
+        Ok(res)
+    }
+
     fn close(&self, pool_handle: PoolHandle, cb: Box<dyn Fn(IndyResult<()>) + Send>) {
         debug!("close >>> handle: {:?}", pool_handle);
 
@@ -221,6 +266,24 @@ impl PoolCommandExecutor {
         debug!("refresh <<<");
     }
 
+    fn get_status(&self, handle: PoolHandle, cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("get_status >>> handle: {:?}", handle);
+
+        let result = self.pool_service.get_status(handle)
+            .and_then(|cmd_id| {
+                match self.status_callbacks.try_borrow_mut() {
+                    Ok(cbs) => Ok((cbs, cmd_id)),
+                    Err(err) => Err(err.into())
+                }
+            });
+        match result {
+            Err(err) => { cb(Err(err)); }
+            Ok((mut cbs, cmd_id)) => { cbs.insert(cmd_id, cb); /* TODO check if map contains same key */ }
+        };
+
+        debug!("get_status <<<");
+    }
+
     fn set_protocol_version(&self, version: usize) -> IndyResult<()> {
         debug!("set_protocol_version >>> version: {:?}", version);
 
@@ -0,0 +1,111 @@
+use indy_api_types::domain::wallet::Tags;
+use serde_json::Value;
+
+use super::credential_definition::CredentialDefinitionId;
+use super::revocation_registry_definition::RevocationRegistryId;
+use super::revocation_registry_delta::RevocationRegistryDelta;
+
+/// Issuer's record of the credential issued from the revocation registry.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct IssuedCredential {
+    pub cred_def_id: CredentialDefinitionId,
+    pub rev_reg_id: RevocationRegistryId,
+    pub cred_rev_id: String,
+    pub issuance_time: u64,
+    pub revoked: bool,
+}
+
+impl IssuedCredential {
+    pub const RESERVED_TAGS: [&'static str; 5] = ["cred_def_id", "rev_reg_id", "cred_rev_id", "issuance_time", "revoked"];
+
+    pub fn id(rev_reg_id: &RevocationRegistryId, cred_rev_id: &str) -> String {
+        format!("{}:{}", rev_reg_id.0, cred_rev_id)
+    }
+
+    /// Tags the record can be searched by along with the tags set by the issuer.
+    ///
+    /// Issuance time and revocation status are unencrypted tags, so the issuance time can be queried by ranges.
+    pub fn tags(&self) -> Tags {
+        let mut tags = Tags::new();
+        tags.insert("cred_def_id".to_string(), self.cred_def_id.0.clone());
+        tags.insert("rev_reg_id".to_string(), self.rev_reg_id.0.clone());
+        tags.insert("cred_rev_id".to_string(), self.cred_rev_id.clone());
+        tags.insert("~issuance_time".to_string(), self.issuance_time.to_string());
+        tags.insert("~revoked".to_string(), self.revoked.to_string());
+        tags
+    }
+
+    pub fn is_reserved_tag(tag: &str) -> bool {
+        Self::RESERVED_TAGS.contains(&tag.trim_start_matches('~'))
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct IssuedCredentialInfo {
+    #[serde(flatten)]
+    pub credential: IssuedCredential,
+    pub tags: Tags,
+}
+
+/// Result of the credential issuance with the revocation registry rollover.
+#[derive(Debug, Serialize)]
+pub struct CredentialIssuance {
+    /// Id of the registry the credential has actually been issued from.
+    pub rev_reg_id: RevocationRegistryId,
+    pub cred_rev_id: String,
+    pub rev_reg_delta: RevocationRegistryDelta,
+    pub rollover: Option<RevocationRegistryRollover>,
+}
+
+/// The registry created to replace the filling up one along with the requests to publish it.
+#[derive(Debug, Serialize)]
+pub struct RevocationRegistryRollover {
+    pub rev_reg_id: RevocationRegistryId,
+    pub rev_reg_def_request: Value,
+    pub rev_reg_entry_request: Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _issued_credential() -> IssuedCredential {
+        IssuedCredential {
+            cred_def_id: CredentialDefinitionId("NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag".to_string()),
+            rev_reg_id: RevocationRegistryId("NcYxiDXkpYi6ov5FcYDi1e:4:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag:CL_ACCUM:TAG_1".to_string()),
+            cred_rev_id: "1".to_string(),
+            issuance_time: 1_600_000_000,
+            revoked: false,
+        }
+    }
+
+    #[test]
+    fn issued_credential_tags_works() {
+        let tags = _issued_credential().tags();
+
+        assert_eq!(IssuedCredential::RESERVED_TAGS.len(), tags.len());
+        assert_eq!("1", tags["cred_rev_id"]);
+        assert_eq!("1600000000", tags["~issuance_time"]);
+        assert_eq!("false", tags["~revoked"]);
+    }
+
+    #[test]
+    fn issued_credential_is_reserved_tag_works() {
+        assert!(IssuedCredential::is_reserved_tag("revoked"));
+        assert!(IssuedCredential::is_reserved_tag("~rev_reg_id"));
+        assert!(!IssuedCredential::is_reserved_tag("holder_did"));
+    }
+
+    #[test]
+    fn issued_credential_info_serialization_works() {
+        let mut tags = Tags::new();
+        tags.insert("holder_did".to_string(), "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW".to_string());
+
+        let info = IssuedCredentialInfo { credential: _issued_credential(), tags };
+
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!("1", json["cred_rev_id"]);
+        assert_eq!(false, json["revoked"]);
+        assert_eq!("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW", json["tags"]["holder_did"]);
+    }
+}
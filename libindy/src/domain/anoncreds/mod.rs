@@ -4,6 +4,7 @@ pub mod credential_definition;
 pub mod credential_for_proof_request;
 pub mod credential_offer;
 pub mod credential_request;
+pub mod issued_credential;
 pub mod proof;
 pub mod proof_request;
 pub mod requested_credential;
@@ -12,6 +13,7 @@ pub mod revocation_registry_delta;
 pub mod revocation_registry;
 pub mod revocation_state;
 pub mod schema;
+pub mod verification_report;
 pub mod master_secret;
 
 pub const DELIMITER: &str = ":";
@@ -24,6 +24,34 @@ pub struct RevocationRegistryConfig {
     pub max_cred_num: Option<u32>
 }
 
+/// Config of the switch to the next revocation registry when the current one fills up.
+#[derive(Deserialize, Debug, Serialize, Default)]
+pub struct RevocationRegistryRolloverConfig {
+    pub threshold: Option<f64>,
+    pub max_cred_num: Option<u32>,
+    pub tag: Option<String>,
+}
+
+impl RevocationRegistryRolloverConfig {
+    pub const DEFAULT_THRESHOLD: f64 = 0.9;
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold.unwrap_or(Self::DEFAULT_THRESHOLD)
+    }
+
+    /// The tag of the next registry: the configured one or the tag of the current registry with the incremented `-<number>` suffix.
+    pub fn next_tag(&self, tag: &str) -> String {
+        if let Some(ref tag) = self.tag {
+            return tag.clone();
+        }
+
+        match tag.rfind('-').map(|pos| (&tag[..pos], tag[pos + 1..].parse::<u32>())) {
+            Some((base, Ok(number))) => format!("{}-{}", base, number + 1),
+            _ => format!("{}-1", tag)
+        }
+    }
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Deserialize, Debug, Serialize, PartialEq, Clone)]
 pub enum IssuanceType {
@@ -126,7 +154,9 @@ pub struct RevocationRegistryDefinitionPrivate {
 pub struct RevocationRegistryInfo {
     pub id: RevocationRegistryId,
     pub curr_id: u32,
-    pub used_ids: HashSet<u32>
+    pub used_ids: HashSet<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_id: Option<RevocationRegistryId>
 }
 
 qualifiable_type!(RevocationRegistryId);
@@ -170,6 +200,22 @@ impl Validatable for RevocationRegistryConfig {
     }
 }
 
+impl Validatable for RevocationRegistryRolloverConfig {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(threshold) = self.threshold {
+            if !(threshold > 0.0 && threshold <= 1.0) {
+                return Err(String::from("RevocationRegistryRolloverConfig validation failed: `threshold` must be in (0, 1] range"));
+            }
+        }
+        if let Some(num_) = self.max_cred_num {
+            if num_ == 0 {
+                return Err(String::from("RevocationRegistryRolloverConfig validation failed: `max_cred_num` must be greater than 0"));
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Validatable for RevocationRegistryId {
     fn validate(&self) -> Result<(), String> {
         self.parts().ok_or(format!("Revocation Registry Id validation failed: {:?}, doesn't match pattern", self.0))?;
@@ -256,6 +302,33 @@ mod tests {
         }
     }
 
+    mod rollover_config {
+        use super::*;
+
+        #[test]
+        fn test_rollover_config_next_tag_works() {
+            let config = RevocationRegistryRolloverConfig::default();
+            assert_eq!("TAG_1-1", config.next_tag("TAG_1"));
+            assert_eq!("TAG_1-2", config.next_tag("TAG_1-1"));
+            assert_eq!("TAG-A-1", config.next_tag("TAG-A"));
+        }
+
+        #[test]
+        fn test_rollover_config_next_tag_works_for_configured_tag() {
+            let config = RevocationRegistryRolloverConfig { tag: Some("TAG_2".to_string()), ..RevocationRegistryRolloverConfig::default() };
+            assert_eq!("TAG_2", config.next_tag("TAG_1"));
+        }
+
+        #[test]
+        fn test_rollover_config_validate_works() {
+            assert!(RevocationRegistryRolloverConfig::default().validate().is_ok());
+            assert!(RevocationRegistryRolloverConfig { threshold: Some(1.0), ..RevocationRegistryRolloverConfig::default() }.validate().is_ok());
+            assert!(RevocationRegistryRolloverConfig { threshold: Some(0.0), ..RevocationRegistryRolloverConfig::default() }.validate().is_err());
+            assert!(RevocationRegistryRolloverConfig { threshold: Some(1.5), ..RevocationRegistryRolloverConfig::default() }.validate().is_err());
+            assert!(RevocationRegistryRolloverConfig { max_cred_num: Some(0), ..RevocationRegistryRolloverConfig::default() }.validate().is_err());
+        }
+    }
+
     mod validate {
         use super::*;
 
@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use indy_api_types::errors::IndyResult;
+
+use crate::utils::wql::Query;
+
+/// Outcome of the single check of the proof.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum CheckResult {
+    Passed,
+    Failed { reason: String },
+    /// The check does not apply to the referent (e.g. non-revocation is not requested).
+    Skipped,
+}
+
+impl CheckResult {
+    pub fn failed<D: ToString>(reason: D) -> CheckResult {
+        CheckResult::Failed { reason: reason.to_string() }
+    }
+
+    pub fn is_failed(&self) -> bool {
+        match *self {
+            CheckResult::Failed { .. } => true,
+            _ => false
+        }
+    }
+}
+
+impl From<IndyResult<()>> for CheckResult {
+    fn from(result: IndyResult<()>) -> CheckResult {
+        match result {
+            Ok(()) => CheckResult::Passed,
+            Err(err) => CheckResult::failed(err)
+        }
+    }
+}
+
+/// Result of the single alternative of the requested restrictions.
+#[derive(Debug, Serialize)]
+pub struct RestrictionReport {
+    pub restriction: Query,
+    pub result: CheckResult,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AttributeReport {
+    pub sub_proof_index: Option<u32>,
+    pub self_attested: bool,
+    pub restrictions: Vec<RestrictionReport>,
+    pub encoding: CheckResult,
+    pub non_revoked: CheckResult,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PredicateReport {
+    pub sub_proof_index: Option<u32>,
+    pub restrictions: Vec<RestrictionReport>,
+    pub non_revoked: CheckResult,
+    pub predicate: CheckResult,
+}
+
+/// Explanation of the proof verification per referent of the proof request.
+#[derive(Debug, Serialize)]
+pub struct VerificationReport {
+    /// The same result `verify` returns for the proof.
+    pub valid: bool,
+    /// The first error failing the verification of the requested proof before the cryptographic one.
+    pub error: Option<String>,
+    pub proof: CheckResult,
+    pub requested_attributes: BTreeMap<String, AttributeReport>,
+    pub requested_predicates: BTreeMap<String, PredicateReport>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indy_api_types::errors::{err_msg, IndyErrorKind};
+
+    #[test]
+    fn check_result_serialization_works() {
+        assert_eq!(json!({"status": "passed"}), serde_json::to_value(&CheckResult::Passed).unwrap());
+        assert_eq!(json!({"status": "skipped"}), serde_json::to_value(&CheckResult::Skipped).unwrap());
+        assert_eq!(json!({"status": "failed", "reason": "timestamp is missing"}),
+                   serde_json::to_value(&CheckResult::failed("timestamp is missing")).unwrap());
+    }
+
+    #[test]
+    fn check_result_from_result_works() {
+        assert_eq!(CheckResult::Passed, CheckResult::from(Ok(())));
+
+        let result = CheckResult::from(Err(err_msg(IndyErrorKind::ProofRejected, "values are different")));
+        assert!(result.is_failed());
+        assert!(serde_json::to_string(&result).unwrap().contains("values are different"));
+    }
+}
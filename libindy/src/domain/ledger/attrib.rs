@@ -1,5 +1,5 @@
 use super::constants::{ATTRIB, GET_ATTR};
-use super::response::GetReplyResultV1;
+use super::response::{GetReplyResultV1, ReplyType};
 use super::super::crypto::did::ShortDidValue;
 
 #[derive(Serialize, PartialEq, Debug)]
@@ -60,6 +60,18 @@ pub enum GetAttrReplyResult {
     GetAttrReplyResultV1(GetReplyResultV1<GetAttResultDataV1>)
 }
 
+/// GET_ATTR reply result with `data` left as is. `data` is None if the attribute isn't set.
+#[derive(Debug, Deserialize)]
+pub struct GetAttrRawReplyResult {
+    pub data: Option<String>
+}
+
+impl ReplyType for GetAttrRawReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_ATTR
+    }
+}
+
 #[derive(Deserialize, Eq, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct GetAttResultV0 {
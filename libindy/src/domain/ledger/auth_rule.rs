@@ -70,6 +70,150 @@ pub struct CombinationConstraint {
 #[serde(deny_unknown_fields)]
 pub struct ForbiddenConstraint {}
 
+/// Any role marker of the role constraint.
+pub const ANY_ROLE: &str = "*";
+
+/// Any field and any value markers of the auth rule.
+pub const ANY_FIELD: &str = "*";
+pub const ANY_VALUE: &str = "*";
+
+/**
+   Signer of the transaction as seen by the constraint evaluation
+    # parameters
+   role - The role of the signer on the ledger (None for the DID without role).
+   owner - The flag specifying if the signer is an owner of the transaction.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct AuthSigner {
+    pub role: Option<String>,
+    #[serde(default)]
+    pub owner: bool,
+}
+
+/**
+   The part of the constraint which isn't met by the signers
+    # parameters
+   constraint - The role or forbidden constraint which isn't met.
+   signatures - The number of signatures which match the constraint.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct UnmetConstraint {
+    pub constraint: Constraint,
+    pub signatures: u32,
+}
+
+impl UnmetConstraint {
+    fn missing_signatures(&self) -> u32 {
+        match self.constraint {
+            Constraint::RoleConstraint(ref role) => role.sig_count.saturating_sub(self.signatures),
+            _ => ::std::u32::MAX
+        }
+    }
+}
+
+impl RoleConstraint {
+    fn is_matched_by(&self, signer: &AuthSigner) -> bool {
+        let role_matches = match self.role.as_ref().map(String::as_str) {
+            Some(ANY_ROLE) => true,
+            None | Some("") => signer.role.as_ref().map(String::is_empty).unwrap_or(true),
+            Some(role) => signer.role.as_ref().map(String::as_str) == Some(role)
+        };
+
+        role_matches && (!self.need_to_be_owner || signer.owner)
+    }
+}
+
+impl Constraint {
+    /// Evaluates the constraint against the signers of the transaction.
+    ///
+    /// Returns the leaf constraints which are not met, empty if the constraint is satisfied.
+    /// For the OR constraint which isn't satisfied the unmet constraints of the alternative
+    /// which is the closest to be satisfied are returned.
+    pub fn unmet(&self, signers: &[AuthSigner]) -> Vec<UnmetConstraint> {
+        match self {
+            Constraint::RoleConstraint(role) => {
+                let signatures = signers.iter().filter(|signer| role.is_matched_by(signer)).count() as u32;
+
+                if signatures >= role.sig_count {
+                    Vec::new()
+                } else {
+                    vec![UnmetConstraint { constraint: self.clone(), signatures }]
+                }
+            }
+            Constraint::AndConstraint(and) =>
+                and.auth_constraints.iter()
+                    .flat_map(|constraint| constraint.unmet(signers))
+                    .collect(),
+            Constraint::OrConstraint(or) => {
+                let missing = |unmet: &[UnmetConstraint]| unmet.iter()
+                    .fold(0u32, |sum, constraint| sum.saturating_add(constraint.missing_signatures()));
+
+                let mut closest: Option<Vec<UnmetConstraint>> = None;
+
+                for unmet in or.auth_constraints.iter().map(|constraint| constraint.unmet(signers)) {
+                    if unmet.is_empty() {
+                        return unmet;
+                    }
+
+                    if closest.as_ref().map(|closest| missing(&unmet) < missing(closest)).unwrap_or(true) {
+                        closest = Some(unmet);
+                    }
+                }
+
+                closest.unwrap_or_else(|| vec![UnmetConstraint { constraint: self.clone(), signatures: 0 }])
+            }
+            Constraint::ForbiddenConstraint(_) => vec![UnmetConstraint { constraint: self.clone(), signatures: 0 }]
+        }
+    }
+
+    /// Role constraints by which the signers satisfy the constraint.
+    ///
+    /// Whether the constraint is satisfied is decided by `unmet`.
+    /// For the OR constraint the first satisfied alternative is taken as the ledger does.
+    pub fn satisfying_roles(&self, signers: &[AuthSigner]) -> Vec<&RoleConstraint> {
+        match self {
+            Constraint::RoleConstraint(role) => vec![role],
+            Constraint::AndConstraint(and) =>
+                and.auth_constraints.iter()
+                    .flat_map(|constraint| constraint.satisfying_roles(signers))
+                    .collect(),
+            Constraint::OrConstraint(or) =>
+                or.auth_constraints.iter()
+                    .find(|constraint| constraint.unmet(signers).is_empty())
+                    .map(|constraint| constraint.satisfying_roles(signers))
+                    .unwrap_or_default(),
+            Constraint::ForbiddenConstraint(_) => Vec::new()
+        }
+    }
+
+    /// Sets of role constraints any of which satisfies the constraint.
+    ///
+    /// The forbidden constraint has no such sets.
+    pub fn alternatives(&self) -> Vec<Vec<RoleConstraint>> {
+        match self {
+            Constraint::RoleConstraint(role) => vec![vec![role.clone()]],
+            Constraint::OrConstraint(or) =>
+                or.auth_constraints.iter()
+                    .flat_map(Constraint::alternatives)
+                    .collect(),
+            Constraint::AndConstraint(and) =>
+                and.auth_constraints.iter()
+                    .fold(vec![Vec::new()], |alternatives, constraint| {
+                        let other = constraint.alternatives();
+
+                        alternatives.iter()
+                            .flat_map(|alternative| other.iter().map(move |roles| {
+                                let mut alternative = alternative.clone();
+                                alternative.extend(roles.iter().cloned());
+                                alternative
+                            }))
+                            .collect()
+                    }),
+            Constraint::ForbiddenConstraint(_) => Vec::new()
+        }
+    }
+}
+
 #[derive(Serialize, PartialEq, Debug)]
 #[serde(untagged)]
 pub enum AuthRuleOperation {
@@ -236,6 +380,112 @@ pub struct AuthRule {
     pub constraint: Constraint,
 }
 
+impl AuthRule {
+    /// Checks whether the rule can be applied to the operation of the request.
+    ///
+    /// The rule must be defined for the action the request performs on the ledger.
+    /// `*` field matches any operation. Otherwise the operation must contain the field with the value
+    /// equal to `new_value` (`*` matches any value including the absent one, empty string matches the absent field).
+    /// For EDIT rules the current value of the field must be equal to `old_value` the same way.
+    pub fn matches(&self, auth_action: &AuthAction, old_value: Option<&str>, operation: &Value) -> bool {
+        let action_matches = match auth_action {
+            AuthAction::ADD => self.auth_action == "ADD",
+            AuthAction::EDIT => self.auth_action == "EDIT",
+        };
+
+        if !action_matches {
+            return false;
+        }
+
+        if self.field == ANY_FIELD {
+            return true;
+        }
+
+        let new_value = match operation.get(self.field.as_str()) {
+            None | Some(Value::Null) => None,
+            Some(Value::String(value)) => Some(value.to_string()),
+            Some(value) => Some(value.to_string()),
+        };
+
+        let old_value_matches = match auth_action {
+            AuthAction::ADD => true,
+            AuthAction::EDIT => AuthRule::_value_matches(self.old_value.as_ref().map(String::as_str), old_value),
+        };
+
+        old_value_matches && AuthRule::_value_matches(self.new_value.as_ref().map(String::as_str), new_value.as_ref().map(String::as_str))
+    }
+
+    fn _value_matches(expected: Option<&str>, actual: Option<&str>) -> bool {
+        match expected {
+            Some(ANY_VALUE) => true,
+            None | Some("") => actual.map(str::is_empty).unwrap_or(true),
+            Some(expected) => actual == Some(expected)
+        }
+    }
+}
+
+/**
+   Result of the local evaluation of the auth rules for the request
+    # parameters
+   accepted - There are rules applicable to the action of the request and all of them are satisfied by the signers.
+   rules - The evaluation of each applicable rule.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct AuthRuleEvaluation {
+    pub accepted: bool,
+    pub rules: Vec<AuthRuleCheck>,
+}
+
+/**
+   Evaluation of the single auth rule
+    # parameters
+   auth_action - The action of the rule (ADD or EDIT).
+   field - The field of the rule.
+   old_value - The old value of the field.
+   new_value - The new value of the field.
+   satisfied - The constraint of the rule is met by the signers.
+   unmet - The parts of the constraint which are not met.
+   fees - Fee aliases from the metadata of the role constraints the signers satisfy the rule by.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct AuthRuleCheck {
+    pub auth_action: String,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub satisfied: bool,
+    pub unmet: Vec<UnmetConstraint>,
+    pub fees: Vec<String>,
+}
+
+impl AuthRuleCheck {
+    pub fn new(rule: AuthRule, signers: &[AuthSigner]) -> AuthRuleCheck {
+        let unmet = rule.constraint.unmet(signers);
+        let satisfied = unmet.is_empty();
+
+        let mut fees: Vec<String> = Vec::new();
+
+        if satisfied {
+            for fee in rule.constraint.satisfying_roles(signers).iter()
+                .filter_map(|role| role.metadata.as_ref().and_then(|metadata| metadata["fees"].as_str())) {
+                if !fees.iter().any(|alias| alias == fee) {
+                    fees.push(fee.to_string());
+                }
+            }
+        }
+
+        AuthRuleCheck {
+            auth_action: rule.auth_action,
+            field: rule.field,
+            old_value: rule.old_value,
+            new_value: rule.new_value,
+            satisfied,
+            unmet,
+            fees,
+        }
+    }
+}
+
 #[derive(Serialize, PartialEq, Debug)]
 pub struct AuthRulesOperation {
     #[serde(rename = "type")]
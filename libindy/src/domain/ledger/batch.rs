@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+
+use indy_api_types::ErrorCode;
+use indy_api_types::errors::IndyError;
+use indy_api_types::validation::Validatable;
+use serde_json::Value;
+
+use crate::domain::anoncreds::credential_definition::CredentialDefinitionId;
+use crate::domain::anoncreds::revocation_registry_definition::RevocationRegistryId;
+use crate::domain::anoncreds::schema::SchemaId;
+
+/**
+   Ids of the ledger objects to be read in one batch
+    # parameters
+   schemas - Ids of the schemas.
+   cred_defs - Ids of the credential definitions.
+   rev_reg_defs - Ids of the revocation registry definitions.
+*/
+#[derive(Deserialize, Debug, Default)]
+pub struct LedgerObjectIds {
+    #[serde(default)]
+    pub schemas: Vec<SchemaId>,
+    #[serde(default)]
+    pub cred_defs: Vec<CredentialDefinitionId>,
+    #[serde(default)]
+    pub rev_reg_defs: Vec<RevocationRegistryId>,
+}
+
+impl Validatable for LedgerObjectIds {
+    fn validate(&self) -> Result<(), String> {
+        if self.schemas.is_empty() && self.cred_defs.is_empty() && self.rev_reg_defs.is_empty() {
+            return Err("No ids of the ledger objects have been passed".to_string());
+        }
+
+        for id in self.schemas.iter() {
+            id.validate()?;
+        }
+
+        for id in self.cred_defs.iter() {
+            id.validate()?;
+        }
+
+        for id in self.rev_reg_defs.iter() {
+            id.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LedgerObjectKind {
+    Schema,
+    CredDef,
+    RevRegDef,
+}
+
+/// Result of the read of the single ledger object: either the object or the error.
+#[derive(Serialize, Debug, PartialEq)]
+pub enum LedgerObjectResult {
+    #[serde(rename = "object")]
+    Object(Value),
+    #[serde(rename = "error")]
+    Error(LedgerObjectError),
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct LedgerObjectError {
+    pub code: i32,
+    pub message: String,
+}
+
+impl From<IndyError> for LedgerObjectResult {
+    fn from(err: IndyError) -> LedgerObjectResult {
+        LedgerObjectResult::Error(LedgerObjectError {
+            code: ErrorCode::from(err.kind()) as i32,
+            message: err.to_string(),
+        })
+    }
+}
+
+/// Results of the batch read keyed by the ids of the objects.
+#[derive(Serialize, Debug, Default, PartialEq)]
+pub struct LedgerObjects {
+    pub schemas: BTreeMap<String, LedgerObjectResult>,
+    pub cred_defs: BTreeMap<String, LedgerObjectResult>,
+    pub rev_reg_defs: BTreeMap<String, LedgerObjectResult>,
+}
+
+impl LedgerObjects {
+    pub fn insert(&mut self, kind: LedgerObjectKind, id: String, result: LedgerObjectResult) {
+        let results = match kind {
+            LedgerObjectKind::Schema => &mut self.schemas,
+            LedgerObjectKind::CredDef => &mut self.cred_defs,
+            LedgerObjectKind::RevRegDef => &mut self.rev_reg_defs,
+        };
+
+        results.insert(id, result);
+    }
+}
@@ -6,6 +6,8 @@ pub const TXN_AUTHR_AGRMT_AML: &str = "5";
 pub const GET_TXN_AUTHR_AGRMT: &str = "6";
 pub const GET_TXN_AUTHR_AGRMT_AML: &str = "7";
 pub const DISABLE_ALL_TXN_AUTHR_AGRMTS: &str = "8";
+pub const LEDGERS_FREEZE: &str = "9";
+pub const GET_FROZEN_LEDGERS: &str = "10";
 pub const ATTRIB: &str = "100";
 pub const SCHEMA: &str = "101";
 pub const CRED_DEF: &str = "102";
@@ -14,6 +16,7 @@ pub const GET_NYM: &str = "105";
 pub const GET_SCHEMA: &str = "107";
 pub const GET_CRED_DEF: &str = "108";
 pub const POOL_UPGRADE: &str = "109";
+pub const NODE_UPGRADE: &str = "110";
 pub const POOL_RESTART: &str = "118";
 pub const POOL_CONFIG: &str = "111";
 pub const REVOC_REG_DEF: &str = "113";
@@ -26,11 +29,21 @@ pub const AUTH_RULE: &str = "120";
 pub const GET_AUTH_RULE: &str = "121";
 pub const AUTH_RULES: &str = "122";
 pub const GET_DDO: &str = "120";//TODO change number
+pub const JSON_LD_CONTEXT: &str = "200";
+pub const RICH_SCHEMA: &str = "201";
+pub const RICH_SCHEMA_ENCODING: &str = "202";
+pub const RICH_SCHEMA_MAPPING: &str = "203";
+pub const RICH_SCHEMA_CRED_DEF: &str = "204";
+pub const RICH_SCHEMA_PRES_DEF: &str = "205";
+pub const GET_RICH_SCHEMA_OBJECT_BY_ID: &str = "300";
+pub const GET_RICH_SCHEMA_OBJECT_BY_METADATA: &str = "301";
 
-pub const REQUESTS: [&str; 25] = [NODE, NYM, GET_TXN, ATTRIB, SCHEMA, CRED_DEF, GET_ATTR, GET_NYM, GET_SCHEMA,
+pub const REQUESTS: [&str; 35] = [NODE, NYM, GET_TXN, ATTRIB, SCHEMA, CRED_DEF, GET_ATTR, GET_NYM, GET_SCHEMA,
     GET_CRED_DEF, POOL_UPGRADE, POOL_RESTART, POOL_CONFIG, REVOC_REG_DEF, REVOC_REG_ENTRY, GET_REVOC_REG_DEF,
     GET_REVOC_REG, GET_REVOC_REG_DELTA, GET_VALIDATOR_INFO, AUTH_RULE, GET_DDO, TXN_AUTHR_AGRMT, TXN_AUTHR_AGRMT_AML,
-    GET_TXN_AUTHR_AGRMT, GET_TXN_AUTHR_AGRMT_AML];
+    GET_TXN_AUTHR_AGRMT, GET_TXN_AUTHR_AGRMT_AML, LEDGERS_FREEZE, GET_FROZEN_LEDGERS, JSON_LD_CONTEXT, RICH_SCHEMA,
+    RICH_SCHEMA_ENCODING, RICH_SCHEMA_MAPPING, RICH_SCHEMA_CRED_DEF, RICH_SCHEMA_PRES_DEF, GET_RICH_SCHEMA_OBJECT_BY_ID,
+    GET_RICH_SCHEMA_OBJECT_BY_METADATA];
 
 pub const TRUSTEE: &str = "0";
 pub const STEWARD: &str = "2";
@@ -40,6 +53,17 @@ pub const ROLE_REMOVE: &str = "";
 
 pub const ROLES: [&str; 4] = [TRUSTEE, STEWARD, ENDORSER, NETWORK_MONITOR];
 
+pub fn role_name_to_code(role: &str) -> Option<&str> {
+    match role {
+        "STEWARD" => Some(STEWARD),
+        "TRUSTEE" => Some(TRUSTEE),
+        "TRUST_ANCHOR" | "ENDORSER" => Some(ENDORSER),
+        "NETWORK_MONITOR" => Some(NETWORK_MONITOR),
+        role if ROLES.contains(&role) => Some(role),
+        _ => None
+    }
+}
+
 pub fn txn_name_to_code(txn: &str) -> Option<&str> {
     if REQUESTS.contains(&txn) {
         return Some(txn)
@@ -71,6 +95,16 @@ pub fn txn_name_to_code(txn: &str) -> Option<&str> {
         "TXN_AUTHR_AGRMT_AML" => Some(TXN_AUTHR_AGRMT_AML),
         "GET_TXN_AUTHR_AGRMT" => Some(GET_TXN_AUTHR_AGRMT),
         "GET_TXN_AUTHR_AGRMT_AML" => Some(GET_TXN_AUTHR_AGRMT_AML),
+        "LEDGERS_FREEZE" => Some(LEDGERS_FREEZE),
+        "GET_FROZEN_LEDGERS" => Some(GET_FROZEN_LEDGERS),
+        "JSON_LD_CONTEXT" => Some(JSON_LD_CONTEXT),
+        "RICH_SCHEMA" => Some(RICH_SCHEMA),
+        "RICH_SCHEMA_ENCODING" => Some(RICH_SCHEMA_ENCODING),
+        "RICH_SCHEMA_MAPPING" => Some(RICH_SCHEMA_MAPPING),
+        "RICH_SCHEMA_CRED_DEF" => Some(RICH_SCHEMA_CRED_DEF),
+        "RICH_SCHEMA_PRES_DEF" => Some(RICH_SCHEMA_PRES_DEF),
+        "GET_RICH_SCHEMA_OBJECT_BY_ID" => Some(GET_RICH_SCHEMA_OBJECT_BY_ID),
+        "GET_RICH_SCHEMA_OBJECT_BY_METADATA" => Some(GET_RICH_SCHEMA_OBJECT_BY_METADATA),
         val => Some(val)
     }
 }
\ No newline at end of file
@@ -15,4 +15,61 @@ impl GetDdoOperation {
             dest
         }
     }
-}
\ No newline at end of file
+}
+
+pub const DID_DOCUMENT_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+pub const ED25519_VERIFICATION_KEY_TYPE: &str = "Ed25519VerificationKey2018";
+pub const ENDPOINT_SERVICE_TYPE: &str = "endpoint";
+
+/// W3C DID document of `did:sov` DID built from its NYM and `endpoint` ATTRIB.
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    #[serde(rename = "@context")]
+    pub context: String,
+    pub id: String,
+    pub verification_method: Vec<VerificationMethod>,
+    pub authentication: Vec<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub service: Vec<DidService>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub controller: String,
+    pub public_key_base58: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DidService {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub service_endpoint: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub routing_keys: Vec<String>,
+}
+
+/// Value of raw `endpoint` ATTRIB.
+///
+/// Both the libindy format (`ha`) and the Sovrin DID method format (`endpoint`) are accepted.
+#[derive(Deserialize, Debug)]
+pub struct EndpointAttrib {
+    pub endpoint: ServiceEndpoint,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceEndpoint {
+    pub endpoint: Option<String>,
+    pub ha: Option<String>,
+    #[serde(default)]
+    pub routing_keys: Vec<String>,
+}
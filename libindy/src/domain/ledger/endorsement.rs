@@ -0,0 +1,63 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use super::auth_rule::{Constraint, RoleConstraint, UnmetConstraint};
+
+/**
+   Request which needs signatures of several parties together with the rule it must satisfy
+    # parameters
+   request - The request being signed.
+   constraint - The constraint of the auth rule for the request (as returned by GET_AUTH_RULE).
+   owner - DID of the owner of the transaction if the constraint needs the owner signature.
+   required_signers - Sets of signers any of which satisfies the constraint.
+   signers - Signers which have added their signatures by the endorsement API.
+*/
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EndorsementEnvelope {
+    pub request: Value,
+    pub constraint: Constraint,
+    #[serde(default)]
+    pub owner: Option<String>,
+    pub required_signers: Vec<Vec<RoleConstraint>>,
+    #[serde(default)]
+    pub signers: BTreeMap<String, EndorsementSigner>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EndorsementSigner {
+    pub verkey: String,
+}
+
+/**
+   Result of the signatures check of the request
+    # parameters
+   valid - Signers whose signatures match the request.
+   invalid - Signers whose signatures don't match the request (most probably it was changed after signing).
+   unknown - DIDs which have signed the request without the endorsement API, so their keys and roles are unknown.
+   missing - Signers added by the endorsement API whose signatures were removed from the request.
+*/
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct EndorsementSignatures {
+    pub valid: Vec<String>,
+    pub invalid: Vec<String>,
+    pub unknown: Vec<String>,
+    pub missing: Vec<String>,
+}
+
+/**
+   Readiness of the request to be submitted
+    # parameters
+   ready - The constraint is satisfied by the valid signatures of the signers with roles from their NYMs.
+   unmet - The parts of the constraint which are still not met.
+   signatures - The signatures check result.
+   unresolved - Valid signers without NYM in GET_NYM responses or whose NYM verkey differs from the signing one,
+                they don't count towards the constraint as their roles are unknown.
+*/
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct EndorsementStatus {
+    pub ready: bool,
+    pub unmet: Vec<UnmetConstraint>,
+    pub signatures: EndorsementSignatures,
+    pub unresolved: Vec<String>,
+}
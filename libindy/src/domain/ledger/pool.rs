@@ -1,6 +1,6 @@
 use super::constants::{POOL_CONFIG, POOL_UPGRADE, POOL_RESTART};
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct PoolConfigOperation {
@@ -81,3 +81,34 @@ impl PoolUpgradeOperation {
 }
 
 pub type Schedule = HashMap<String, String>;
+
+/// Latest pool upgrade scheduled by POOL_UPGRADE transaction of the config ledger.
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct PoolUpgradeStatus {
+    pub name: String,
+    pub version: String,
+    pub action: String,
+    pub schedule: Option<Schedule>,
+    #[serde(default)]
+    pub seq_no: Option<u64>,
+    #[serde(default)]
+    pub txn_time: Option<u64>,
+}
+
+/// Latest upgrade state reported by the node with NODE_UPGRADE transaction of the config ledger.
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct NodeUpgradeStatus {
+    pub action: String,
+    pub version: String,
+    #[serde(default)]
+    pub seq_no: Option<u64>,
+    #[serde(default)]
+    pub txn_time: Option<u64>,
+}
+
+#[derive(Serialize, PartialEq, Debug, Default)]
+pub struct UpgradeStatus {
+    pub pool_upgrade: Option<PoolUpgradeStatus>,
+    /// Node upgrade statuses by the DID of the node
+    pub nodes: BTreeMap<String, NodeUpgradeStatus>,
+}
@@ -73,6 +73,11 @@ pub enum Message<T> {
 
 pub trait ReplyType {
     fn get_type<'a>() -> &'a str;
+
+    /// Whether the reply of the given type can be parsed as this one.
+    fn is_type(type_: &str) -> bool {
+        type_ == Self::get_type()
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -86,4 +91,29 @@ pub struct ResponseMetadata {
     pub last_txn_time: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_seq_no: Option<u64>,
-}
\ No newline at end of file
+}
+
+/// Outcome of the write request submitted with retries.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum WriteStatus {
+    Committed,
+    Rejected,
+    /// The pool didn't reach consensus on the request and the follow-up read found no transaction of it.
+    NotWritten,
+    Unknown,
+}
+
+#[derive(Serialize, Debug)]
+pub struct WriteOutcome {
+    pub status: WriteStatus,
+    pub reply: Option<serde_json::Value>,
+}
+
+/// Result of the offline verification of the saved reply against the pinned BLS keys of the pool.
+#[derive(Serialize, Debug)]
+pub struct ReplyVerification {
+    pub verified: bool,
+    /// Time the proven state was signed by the pool, set only for the verified reply.
+    pub timestamp: Option<u64>,
+}
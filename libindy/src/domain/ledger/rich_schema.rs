@@ -0,0 +1,203 @@
+use super::constants::{GET_RICH_SCHEMA_OBJECT_BY_ID, GET_RICH_SCHEMA_OBJECT_BY_METADATA, JSON_LD_CONTEXT, RICH_SCHEMA,
+                       RICH_SCHEMA_CRED_DEF, RICH_SCHEMA_ENCODING, RICH_SCHEMA_MAPPING, RICH_SCHEMA_PRES_DEF};
+use super::response::ReplyType;
+
+use indy_api_types::validation::Validatable;
+
+pub const RICH_SCHEMA_OBJECT_VERSION: &str = "1";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum RichSchemaType {
+    #[serde(rename = "ctx")]
+    Context,
+    #[serde(rename = "sch")]
+    Schema,
+    #[serde(rename = "enc")]
+    Encoding,
+    #[serde(rename = "map")]
+    Mapping,
+    #[serde(rename = "cdf")]
+    CredDef,
+    #[serde(rename = "pdf")]
+    PresDef,
+}
+
+impl RichSchemaType {
+    pub fn to_str(&self) -> &'static str {
+        match *self {
+            RichSchemaType::Context => "ctx",
+            RichSchemaType::Schema => "sch",
+            RichSchemaType::Encoding => "enc",
+            RichSchemaType::Mapping => "map",
+            RichSchemaType::CredDef => "cdf",
+            RichSchemaType::PresDef => "pdf",
+        }
+    }
+
+    pub fn from_str(rs_type: &str) -> Option<RichSchemaType> {
+        match rs_type {
+            "ctx" => Some(RichSchemaType::Context),
+            "sch" => Some(RichSchemaType::Schema),
+            "enc" => Some(RichSchemaType::Encoding),
+            "map" => Some(RichSchemaType::Mapping),
+            "cdf" => Some(RichSchemaType::CredDef),
+            "pdf" => Some(RichSchemaType::PresDef),
+            _ => None
+        }
+    }
+
+    /// Type of the ledger transaction writing the object of this type.
+    pub fn txn_type(&self) -> &'static str {
+        match *self {
+            RichSchemaType::Context => JSON_LD_CONTEXT,
+            RichSchemaType::Schema => RICH_SCHEMA,
+            RichSchemaType::Encoding => RICH_SCHEMA_ENCODING,
+            RichSchemaType::Mapping => RICH_SCHEMA_MAPPING,
+            RichSchemaType::CredDef => RICH_SCHEMA_CRED_DEF,
+            RichSchemaType::PresDef => RICH_SCHEMA_PRES_DEF,
+        }
+    }
+}
+
+/// Rich schema object as it is stored on the ledger.
+/// `content` is JSON-LD document serialized to string.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RichSchemaObject {
+    pub id: String,
+    pub content: String,
+    pub rs_name: String,
+    pub rs_version: String,
+    pub rs_type: RichSchemaType,
+    #[serde(default = "default_rich_schema_object_version")]
+    pub ver: String,
+}
+
+fn default_rich_schema_object_version() -> String {
+    RICH_SCHEMA_OBJECT_VERSION.to_string()
+}
+
+impl Validatable for RichSchemaObject {
+    fn validate(&self) -> Result<(), String> {
+        if self.id.is_empty() {
+            return Err("Rich Schema object validation failed: empty id has been passed".to_string());
+        }
+
+        if self.rs_name.is_empty() {
+            return Err("Rich Schema object validation failed: empty rsName has been passed".to_string());
+        }
+
+        if self.rs_version.is_empty() {
+            return Err("Rich Schema object validation failed: empty rsVersion has been passed".to_string());
+        }
+
+        let content: serde_json::Value = serde_json::from_str(&self.content)
+            .map_err(|err| format!("Rich Schema object validation failed: content is invalid json: {}", err))?;
+
+        if !content.is_object() {
+            return Err("Rich Schema object validation failed: content must be json object".to_string());
+        }
+
+        match self.rs_type {
+            RichSchemaType::Context => {
+                if content["@context"].is_null() {
+                    return Err("Rich Schema object validation failed: JSON-LD context must contain `@context`".to_string());
+                }
+            }
+            RichSchemaType::Schema => {
+                if content["@id"].as_str() != Some(self.id.as_str()) {
+                    return Err(format!("Rich Schema object validation failed: `@id` of the schema must be equal to `{}`", self.id));
+                }
+                if content["@type"].is_null() {
+                    return Err("Rich Schema object validation failed: schema must contain `@type`".to_string());
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RichSchemaObjectOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub id: String,
+    pub content: String,
+    pub rs_name: String,
+    pub rs_version: String,
+    pub rs_type: RichSchemaType,
+    pub ver: String,
+}
+
+impl RichSchemaObjectOperation {
+    pub fn new(object: RichSchemaObject) -> RichSchemaObjectOperation {
+        RichSchemaObjectOperation {
+            _type: object.rs_type.txn_type().to_string(),
+            id: object.id,
+            content: object.content,
+            rs_name: object.rs_name,
+            rs_version: object.rs_version,
+            rs_type: object.rs_type,
+            ver: object.ver,
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct GetRichSchemaObjectByIdOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub id: String,
+}
+
+impl GetRichSchemaObjectByIdOperation {
+    pub fn new(id: String) -> GetRichSchemaObjectByIdOperation {
+        GetRichSchemaObjectByIdOperation {
+            _type: GET_RICH_SCHEMA_OBJECT_BY_ID.to_string(),
+            id,
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRichSchemaObjectByMetadataOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub rs_type: RichSchemaType,
+    pub rs_name: String,
+    pub rs_version: String,
+}
+
+impl GetRichSchemaObjectByMetadataOperation {
+    pub fn new(rs_type: RichSchemaType, rs_name: String, rs_version: String) -> GetRichSchemaObjectByMetadataOperation {
+        GetRichSchemaObjectByMetadataOperation {
+            _type: GET_RICH_SCHEMA_OBJECT_BY_METADATA.to_string(),
+            rs_type,
+            rs_name,
+            rs_version,
+        }
+    }
+}
+
+/// Both GET_RICH_SCHEMA_OBJECT_BY_ID and GET_RICH_SCHEMA_OBJECT_BY_METADATA replies have this shape.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRichSchemaObjectReplyResult {
+    pub data: Option<RichSchemaObject>,
+    pub seq_no: Option<u64>,
+    pub txn_time: Option<u64>,
+}
+
+impl ReplyType for GetRichSchemaObjectReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_RICH_SCHEMA_OBJECT_BY_ID
+    }
+
+    fn is_type(type_: &str) -> bool {
+        type_ == GET_RICH_SCHEMA_OBJECT_BY_ID || type_ == GET_RICH_SCHEMA_OBJECT_BY_METADATA
+    }
+}
@@ -1,3 +1,7 @@
+use std::collections::HashMap;
+
+use indy_api_types::validation::Validatable;
+
 use super::constants::GET_TXN;
 
 #[derive(Serialize, PartialEq, Debug)]
@@ -23,7 +27,8 @@ impl GetTxnOperation {
 pub enum LedgerType {
     POOL = 0,
     DOMAIN = 1,
-    CONFIG = 2
+    CONFIG = 2,
+    AUDIT = 3
 }
 
 impl LedgerType {
@@ -32,6 +37,68 @@ impl LedgerType {
             LedgerType::POOL => LedgerType::POOL as i32,
             LedgerType::DOMAIN => LedgerType::DOMAIN as i32,
             LedgerType::CONFIG => LedgerType::CONFIG as i32,
+            LedgerType::AUDIT => LedgerType::AUDIT as i32,
+        }
+    }
+}
+
+/// Transaction from GET_TXN reply which audit path is checked against the ledger root.
+#[derive(Debug)]
+pub struct VerifiedTxn {
+    pub txn: serde_json::Value,
+    pub ledger_size: u64,
+    pub root_hash: String,
+    pub audit_path: Vec<Vec<u8>>,
+}
+
+/// Page of the ledger transactions fetched one by one and verified against the same ledger root.
+#[derive(Serialize, Debug)]
+pub struct TxnRangePage {
+    pub txns: Vec<serde_json::Value>,
+    pub ledger_size: Option<u64>,
+    pub root_hash: Option<String>,
+    pub next: Option<i32>,
+}
+
+/// Transactions of the ledger caught up by the pool and the ledger root agreed by the nodes.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerCatchupReply {
+    pub ledger_id: usize,
+    pub txn_seq_no: usize,
+    pub merkle_root: String,
+    pub txns: HashMap<String, serde_json::Value>,
+}
+
+pub const DEFAULT_SUBSCRIPTION_INTERVAL: u64 = 10;
+
+/// Options of the subscription to newly committed transactions of the ledger.
+#[derive(Deserialize, Debug)]
+pub struct LedgerSubscriptionOptions {
+    /// Interval between syncs with the ledger (in sec).
+    #[serde(default = "LedgerSubscriptionOptions::default_interval")]
+    pub interval: u64,
+}
+
+impl LedgerSubscriptionOptions {
+    fn default_interval() -> u64 {
+        DEFAULT_SUBSCRIPTION_INTERVAL
+    }
+}
+
+impl Default for LedgerSubscriptionOptions {
+    fn default() -> Self {
+        LedgerSubscriptionOptions {
+            interval: LedgerSubscriptionOptions::default_interval(),
+        }
+    }
+}
+
+impl Validatable for LedgerSubscriptionOptions {
+    fn validate(&self) -> Result<(), String> {
+        if self.interval == 0 {
+            return Err(String::from("`interval` must be greater than 0"));
         }
+        Ok(())
     }
 }
@@ -1,3 +1,8 @@
+#[cfg(feature = "in_process_pool")]
+use std::collections::HashMap;
+
+use serde_json::Value;
+
 use indy_api_types::validation::Validatable;
 
 pub const POOL_CON_ACTIVE_TO: i64 = 5;
@@ -8,14 +13,43 @@ pub const NUMBER_READ_NODES: u8 = 2;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PoolConfig {
-    pub genesis_txn: String
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genesis_txn: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genesis_txns: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genesis_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genesis_signature: Option<GenesisSignature>,
+}
+
+/// Detached ed25519 signature of a steward over the merkle root hash of the genesis transactions.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GenesisSignature {
+    pub verkey: String,
+    pub signature: String,
 }
 
 impl PoolConfig {
     pub fn default_for_name(name: &str) -> PoolConfig {
         let mut txn = name.to_string();
         txn += ".txn";
-        PoolConfig { genesis_txn: txn }
+        PoolConfig {
+            genesis_txn: Some(txn),
+            genesis_txns: None,
+            genesis_hash: None,
+            genesis_signature: None,
+        }
+    }
+}
+
+impl Validatable for PoolConfig {
+    fn validate(&self) -> Result<(), String> {
+        match (&self.genesis_txn, &self.genesis_txns) {
+            (Some(_), Some(_)) => Err(String::from("Only one of `genesis_txn` and `genesis_txns` can be specified")),
+            (None, None) => Err(String::from("Either `genesis_txn` or `genesis_txns` must be specified")),
+            _ => Ok(())
+        }
     }
 }
 
@@ -33,6 +67,140 @@ pub struct PoolOpenConfig {
     pub preordered_nodes: Vec<String>,
     #[serde(default = "PoolOpenConfig::default_number_read_nodes")]
     pub number_read_nodes: u8,
+    #[serde(default)]
+    pub transport: PoolTransport,
+    #[serde(default)]
+    pub read_consensus: ReadConsensusPolicy,
+}
+
+/// Defines when replies of the nodes on a read request are enough to return the result.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadConsensusPolicy {
+    /// First reply with verified state proof or f+1 matching replies.
+    Fast,
+    /// Only a reply with verified state proof is accepted.
+    StateProof,
+    /// f+1 matching replies are required even if the state proof is present.
+    Consensus,
+    /// All nodes are queried, divergent replies are returned as a diff.
+    All,
+}
+
+impl Default for ReadConsensusPolicy {
+    fn default() -> Self {
+        ReadConsensusPolicy::Fast
+    }
+}
+
+pub const DEFAULT_WRITE_RETRIES: usize = 1;
+
+/// Options of the single request which override pool defaults.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RequestOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_consensus: Option<ReadConsensusPolicy>,
+    /// How many times the request requiring consensus (write) is resent to the pool
+    /// if consensus wasn't reached because of timeouts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<usize>,
+    /// Timeout (in sec) of waiting for the node reply or REQACK, pool `timeout` if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<i64>,
+    /// Timeout (in sec) of waiting for the node reply after REQACK, pool `extended_timeout` if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extended_timeout: Option<i64>,
+    /// Nodes asked first by the requests sent to one node at a time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_nodes: Option<Vec<String>>,
+    /// Nodes the request is never sent to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excluded_nodes: Option<Vec<String>>,
+    /// Accept only the replies with valid state proof (same as `state_proof` read consensus).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_state_proof: Option<bool>,
+}
+
+impl RequestOptions {
+    /// Read consensus policy taking `require_state_proof` into account.
+    pub fn read_consensus(&self) -> Option<ReadConsensusPolicy> {
+        if self.require_state_proof == Some(true) {
+            Some(ReadConsensusPolicy::StateProof)
+        } else {
+            self.read_consensus
+        }
+    }
+}
+
+impl Validatable for RequestOptions {
+    fn validate(&self) -> Result<(), String> {
+        if self.timeout.map(|timeout| timeout <= 0).unwrap_or(false) {
+            return Err(String::from("`timeout` must be greater than 0"));
+        }
+        if self.extended_timeout.map(|timeout| timeout <= 0).unwrap_or(false) {
+            return Err(String::from("`extended_timeout` must be greater than 0"));
+        }
+        if self.require_state_proof == Some(true) &&
+            self.read_consensus.map(|policy| policy != ReadConsensusPolicy::StateProof).unwrap_or(false) {
+            return Err(String::from("`require_state_proof` conflicts with `read_consensus`"));
+        }
+        if let (Some(preferred), Some(excluded)) = (&self.preferred_nodes, &self.excluded_nodes) {
+            if let Some(node) = preferred.iter().find(|node| excluded.contains(node)) {
+                return Err(format!("Node {} is both preferred and excluded", node));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Network transport used to communicate with the pool nodes.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PoolTransport {
+    /// ZMQ CurveCP sockets connected to the real pool nodes.
+    Zmq,
+    /// In-process ledger that replays canned messages instead of talking to the nodes.
+    #[cfg(feature = "in_process_pool")]
+    InProcess(InProcessLedgerConfig),
+}
+
+impl Default for PoolTransport {
+    fn default() -> Self {
+        PoolTransport::Zmq
+    }
+}
+
+/// Canned messages replayed by the in-process transport.
+///
+/// Every message is a json object in the format of the corresponding node message without `op` field.
+#[cfg(feature = "in_process_pool")]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct InProcessLedgerConfig {
+    /// LEDGER_STATUS sent in reply to LEDGER_STATUS. If not set, the received status is echoed (pool is in sync).
+    #[serde(default)]
+    pub ledger_status: Option<Value>,
+    /// CONSISTENCY_PROOF sent in reply to LEDGER_STATUS instead of `ledger_status`.
+    #[serde(default)]
+    pub consistency_proof: Option<Value>,
+    /// CATCHUP_REP sent in reply to CATCHUP_REQ.
+    #[serde(default)]
+    pub catchup_rep: Option<Value>,
+    /// Results of REPLY messages by operation type, `reqId` is filled from the request.
+    #[serde(default)]
+    pub replies: HashMap<String, Value>,
+    /// Results of REPLY messages by node alias and operation type. Overrides `replies`.
+    #[serde(default)]
+    pub node_replies: HashMap<String, HashMap<String, Value>>,
+    /// Aliases of nodes which never reply.
+    #[serde(default)]
+    pub silent_nodes: Vec<String>,
+    /// Transactions of the ledgers by ledger id in the format of GET_TXN reply data.
+    /// GET_TXN is answered with the transaction, its audit path and the root of the ledger.
+    #[serde(default)]
+    pub txns: HashMap<String, Vec<Value>>,
+    /// Seeds of BLS sign keys by node alias. If set, the ledger root of GET_TXN reply is multi-signed by these nodes.
+    #[serde(default)]
+    pub bls_seeds: HashMap<String, String>,
 }
 
 impl Validatable for PoolOpenConfig {
@@ -65,6 +233,8 @@ impl Default for PoolOpenConfig {
             conn_active_timeout: PoolOpenConfig::default_conn_active_timeout(),
             preordered_nodes: PoolOpenConfig::default_preordered_nodes(),
             number_read_nodes: PoolOpenConfig::default_number_read_nodes(),
+            transport: PoolTransport::default(),
+            read_consensus: ReadConsensusPolicy::default(),
         }
     }
 }
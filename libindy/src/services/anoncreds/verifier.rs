@@ -1,11 +1,12 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::domain::anoncreds::credential_definition::{CredentialDefinitionV1, CredentialDefinitionId};
-use crate::domain::anoncreds::proof::{Proof, RequestedProof, Identifier, RevealedAttributeInfo};
+use crate::domain::anoncreds::proof::{Proof, RequestedProof, Identifier, RevealedAttributeInfo, RevealedAttributeGroupInfo};
 use crate::domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, ProofRequestPayload, NonRevocedInterval};
 use crate::domain::anoncreds::revocation_registry::RevocationRegistryV1;
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinitionV1, RevocationRegistryId};
 use crate::domain::anoncreds::schema::{SchemaV1, SchemaId};
+use crate::domain::anoncreds::verification_report::{AttributeReport, CheckResult, PredicateReport, RestrictionReport, VerificationReport};
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::helpers::*;
 
@@ -51,27 +52,128 @@ impl Verifier {
         let received_predicates: HashMap<String, Identifier> = Verifier::_received_predicates(&full_proof)?;
         let received_self_attested_attrs: HashSet<String> = Verifier::_received_self_attested_attrs(&full_proof);
 
+        Verifier::_verify_requested_proof(full_proof,
+                                          proof_req,
+                                          &received_revealed_attrs,
+                                          &received_unrevealed_attrs,
+                                          &received_predicates,
+                                          &received_self_attested_attrs)?;
+
+        let valid = Verifier::_verify_crypto_proof(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs)?;
+
+        trace!("verify <<< valid: {:?}", valid);
+
+        Ok(valid)
+    }
+
+    /// Verifies the proof as `verify` does and explains the result per referent of the proof request.
+    pub fn verify_verbose(&self,
+                          full_proof: &Proof,
+                          proof_req: &ProofRequestPayload,
+                          schemas: &HashMap<SchemaId, SchemaV1>,
+                          cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                          rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                          rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<VerificationReport> {
+        trace!("verify_verbose >>> full_proof: {:?}, proof_req: {:?}, schemas: {:?}, cred_defs: {:?}, rev_reg_defs: {:?} rev_regs: {:?}",
+               full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs);
+
+        let received_revealed_attrs: HashMap<String, Identifier> = Verifier::_received_revealed_attrs(&full_proof)?;
+        let received_unrevealed_attrs: HashMap<String, Identifier> = Verifier::_received_unrevealed_attrs(&full_proof)?;
+        let received_predicates: HashMap<String, Identifier> = Verifier::_received_predicates(&full_proof)?;
+        let received_self_attested_attrs: HashSet<String> = Verifier::_received_self_attested_attrs(&full_proof);
+
+        let error = Verifier::_verify_requested_proof(full_proof,
+                                                      proof_req,
+                                                      &received_revealed_attrs,
+                                                      &received_unrevealed_attrs,
+                                                      &received_predicates,
+                                                      &received_self_attested_attrs)
+            .err()
+            .map(|err| err.to_string());
+
+        let proof = match Verifier::_verify_crypto_proof(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs) {
+            Ok(true) => CheckResult::Passed,
+            Ok(false) => CheckResult::failed("Cryptographic proof verification failed"),
+            Err(err) => CheckResult::failed(err)
+        };
+
+        let proof_attr_identifiers: HashMap<String, Identifier> = received_revealed_attrs
+            .into_iter()
+            .chain(received_unrevealed_attrs)
+            .collect();
+
+        let requested_attributes: BTreeMap<String, AttributeReport> = proof_req.requested_attributes
+            .iter()
+            .map(|(referent, info)| (referent.to_string(),
+                                     Verifier::_attribute_report(referent, info, full_proof, proof_req, &proof_attr_identifiers,
+                                                                 &received_self_attested_attrs, rev_regs)))
+            .collect();
+
+        let requested_predicates: BTreeMap<String, PredicateReport> = proof_req.requested_predicates
+            .iter()
+            .map(|(referent, info)| (referent.to_string(),
+                                     Verifier::_predicate_report(referent, info, full_proof, proof_req, &received_predicates, &proof, rev_regs)))
+            .collect();
+
+        let report = VerificationReport {
+            valid: error.is_none() && proof == CheckResult::Passed,
+            error,
+            proof,
+            requested_attributes,
+            requested_predicates,
+        };
+
+        trace!("verify_verbose <<< report: {:?}", report);
+
+        Ok(report)
+    }
+
+    pub fn generate_nonce(&self) -> IndyResult<Nonce> {
+        trace!("generate_nonce >>> ");
+
+        let nonce = new_nonce()?;
+
+        trace!("generate_nonce <<< nonce: {:?} ", nonce);
+
+        Ok(nonce)
+    }
+
+    fn _verify_requested_proof(full_proof: &Proof,
+                               proof_req: &ProofRequestPayload,
+                               received_revealed_attrs: &HashMap<String, Identifier>,
+                               received_unrevealed_attrs: &HashMap<String, Identifier>,
+                               received_predicates: &HashMap<String, Identifier>,
+                               received_self_attested_attrs: &HashSet<String>) -> IndyResult<()> {
         Verifier::_compare_attr_from_proof_and_request(proof_req,
-                                                       &received_revealed_attrs,
-                                                       &received_unrevealed_attrs,
-                                                       &received_self_attested_attrs,
-                                                       &received_predicates)?;
+                                                       received_revealed_attrs,
+                                                       received_unrevealed_attrs,
+                                                       received_self_attested_attrs,
+                                                       received_predicates)?;
 
-        Verifier::_verify_revealed_attribute_values(&proof_req, &full_proof)?;
+        Verifier::_verify_revealed_attribute_values(proof_req, full_proof)?;
 
-        Verifier::_verify_requested_restrictions(&proof_req,
+        Verifier::_verify_requested_restrictions(proof_req,
                                                  &full_proof.requested_proof,
-                                                 &received_revealed_attrs,
-                                                 &received_unrevealed_attrs,
-                                                 &received_predicates,
-                                                 &received_self_attested_attrs)?;
+                                                 received_revealed_attrs,
+                                                 received_unrevealed_attrs,
+                                                 received_predicates,
+                                                 received_self_attested_attrs)?;
 
         Verifier::_compare_timestamps_from_proof_and_request(proof_req,
-                                                             &received_revealed_attrs,
-                                                             &received_unrevealed_attrs,
-                                                             &received_self_attested_attrs,
-                                                             &received_predicates)?;
+                                                             received_revealed_attrs,
+                                                             received_unrevealed_attrs,
+                                                             received_self_attested_attrs,
+                                                             received_predicates)?;
+
+        Ok(())
+    }
 
+    fn _verify_crypto_proof(full_proof: &Proof,
+                            proof_req: &ProofRequestPayload,
+                            schemas: &HashMap<SchemaId, SchemaV1>,
+                            cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                            rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                            rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
         let mut proof_verifier = CryptoVerifier::new_proof_verifier()?;
         let non_credential_schema = build_non_credential_schema()?;
 
@@ -123,19 +225,182 @@ impl Verifier {
 
         let valid = proof_verifier.verify(&full_proof.proof, &proof_req.nonce)?;
 
-        trace!("verify <<< valid: {:?}", valid);
-
         Ok(valid)
     }
 
-    pub fn generate_nonce(&self) -> IndyResult<Nonce> {
-        trace!("generate_nonce >>> ");
+    fn _attribute_report(referent: &str,
+                         info: &AttributeInfo,
+                         proof: &Proof,
+                         proof_req: &ProofRequestPayload,
+                         proof_attr_identifiers: &HashMap<String, Identifier>,
+                         self_attested_attrs: &HashSet<String>,
+                         rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> AttributeReport {
+        let requested_proof = &proof.requested_proof;
+
+        let self_attested = self_attested_attrs.contains(referent);
+
+        let sub_proof_index = requested_proof.revealed_attrs.get(referent).map(|attr| attr.sub_proof_index)
+            .or_else(|| requested_proof.revealed_attr_groups.get(referent).map(|attrs| attrs.sub_proof_index))
+            .or_else(|| requested_proof.unrevealed_attrs.get(referent).map(|attr| attr.sub_proof_index));
+
+        if sub_proof_index.is_none() && !self_attested {
+            let missing = CheckResult::failed(format!("Attribute with referent \"{}\" not found in Proof", referent));
+            return AttributeReport {
+                sub_proof_index,
+                self_attested,
+                restrictions: Vec::new(),
+                encoding: missing.clone(),
+                non_revoked: missing,
+            };
+        }
 
-        let nonce = new_nonce()?;
+        let restrictions = match info.restrictions {
+            Some(ref query) if !Verifier::_is_self_attested(referent, info, self_attested_attrs) =>
+                Verifier::_restrictions_report(query,
+                                               Verifier::_gather_filter_info(referent, proof_attr_identifiers),
+                                               Verifier::_attr_value_map(referent, info, requested_proof)),
+            _ => Vec::new()
+        };
+
+        let encoding = match (requested_proof.revealed_attrs.get(referent), requested_proof.revealed_attr_groups.get(referent)) {
+            (Some(attr_info), _) => info.name.as_ref()
+                .ok_or_else(|| err_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" is requested without \"name\"", referent)))
+                .and_then(|attr_name| Verifier::_verify_revealed_attribute_value(attr_name, proof, attr_info))
+                .into(),
+            (None, Some(attr_infos)) => info.names.as_ref()
+                .ok_or_else(|| err_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" is requested without \"names\"", referent)))
+                .and_then(|attr_names| Verifier::_verify_revealed_attribute_group_values(proof_req, referent, attr_names, proof, attr_infos))
+                .into(),
+            (None, None) => CheckResult::Skipped
+        };
+
+        let non_revoked = if self_attested {
+            CheckResult::Skipped
+        } else {
+            Verifier::_non_revoked_report(referent, proof_attr_identifiers, &proof_req.non_revoked, &info.non_revoked, rev_regs)
+        };
+
+        AttributeReport {
+            sub_proof_index,
+            self_attested,
+            restrictions,
+            encoding,
+            non_revoked,
+        }
+    }
 
-        trace!("generate_nonce <<< nonce: {:?} ", nonce);
+    fn _predicate_report(referent: &str,
+                         info: &PredicateInfo,
+                         proof: &Proof,
+                         proof_req: &ProofRequestPayload,
+                         received_predicates: &HashMap<String, Identifier>,
+                         proof_check: &CheckResult,
+                         rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> PredicateReport {
+        let requested_proof = &proof.requested_proof;
+
+        let sub_proof_index = requested_proof.predicates.get(referent).map(|predicate| predicate.sub_proof_index);
+
+        if sub_proof_index.is_none() {
+            let missing = CheckResult::failed(format!("Predicate with referent \"{}\" not found in Proof", referent));
+            return PredicateReport {
+                sub_proof_index,
+                restrictions: Vec::new(),
+                non_revoked: missing.clone(),
+                predicate: missing,
+            };
+        }
 
-        Ok(nonce)
+        let restrictions = match info.restrictions {
+            Some(ref query) =>
+                Verifier::_restrictions_report(query,
+                                               Verifier::_gather_filter_info(referent, received_predicates),
+                                               Verifier::_predicate_attr_value_map(referent, info, requested_proof, &proof_req.requested_attributes)),
+            None => Vec::new()
+        };
+
+        let non_revoked = Verifier::_non_revoked_report(referent, received_predicates, &proof_req.non_revoked, &info.non_revoked, rev_regs);
+
+        // predicates are proven by the aggregated cryptographic proof only
+        let predicate = match *proof_check {
+            CheckResult::Passed => CheckResult::Passed,
+            _ => CheckResult::failed(format!("Predicate \"{}\" {} {} is not proven: cryptographic proof verification failed", info.name, info.p_type, info.p_value))
+        };
+
+        PredicateReport {
+            sub_proof_index,
+            restrictions,
+            non_revoked,
+            predicate,
+        }
+    }
+
+    /// Checks every alternative of the restrictions joined with `$or` separately.
+    fn _restrictions_report(query: &Query,
+                            filter: IndyResult<Filter>,
+                            attr_value_map: IndyResult<HashMap<String, Option<&str>>>) -> Vec<RestrictionReport> {
+        let (filter, attr_value_map) = match (filter, attr_value_map) {
+            (Ok(filter), Ok(attr_value_map)) => (filter, attr_value_map),
+            (Err(err), _) | (_, Err(err)) => {
+                return vec![RestrictionReport { restriction: query.clone(), result: CheckResult::failed(err) }];
+            }
+        };
+
+        let alternatives: Vec<&Query> = match *query {
+            Query::Or(ref operators) if !operators.is_empty() => operators.iter().collect(),
+            ref query => vec![query]
+        };
+
+        alternatives
+            .into_iter()
+            .map(|restriction| RestrictionReport {
+                restriction: restriction.clone(),
+                result: Verifier::_explain_operator(&attr_value_map, restriction, &filter),
+            })
+            .collect()
+    }
+
+    /// Evaluates the restriction as `_do_process_operator` does but keeps the reason of the first failed `$eq` condition.
+    fn _explain_operator(attr_value_map: &HashMap<String, Option<&str>>,
+                         restriction_op: &Query,
+                         filter: &Filter) -> CheckResult {
+        match *restriction_op {
+            Query::Eq(ref tag_name, ref tag_value) => Verifier::_process_filter(attr_value_map, &tag_name, &tag_value, filter).into(),
+            Query::And(ref operators) => {
+                operators
+                    .iter()
+                    .map(|op| Verifier::_explain_operator(attr_value_map, op, filter))
+                    .find(CheckResult::is_failed)
+                    .unwrap_or(CheckResult::Passed)
+            }
+            ref op => Verifier::_do_process_operator(attr_value_map, op, filter).into()
+        }
+    }
+
+    fn _non_revoked_report(referent: &str,
+                           identifiers: &HashMap<String, Identifier>,
+                           global_interval: &Option<NonRevocedInterval>,
+                           local_interval: &Option<NonRevocedInterval>,
+                           rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> CheckResult {
+        if get_non_revoc_interval(global_interval, local_interval).is_none() {
+            return CheckResult::Skipped;
+        }
+
+        let identifier = match identifiers.get(referent) {
+            Some(identifier) => identifier,
+            None => return CheckResult::failed(format!("Identifier not found for referent: {}", referent))
+        };
+
+        match (identifier.rev_reg_id.as_ref(), identifier.timestamp) {
+            (_, None) => CheckResult::failed("Non-revocation is requested but Proof does not contain timestamp"),
+            (None, Some(_)) => CheckResult::failed("Revocation Registry Id not found"),
+            (Some(rev_reg_id), Some(timestamp)) => {
+                if rev_regs.get(rev_reg_id).map(|rev_regs| rev_regs.contains_key(&timestamp)).unwrap_or(false) {
+                    CheckResult::Passed
+                } else {
+                    CheckResult::failed(format!("RevocationRegistry {:?} not found for timestamp: {:?}", rev_reg_id.0, timestamp))
+                }
+            }
+        }
     }
 
     fn _get_revealed_attributes_for_credential(sub_proof_index: usize,
@@ -339,19 +604,28 @@ impl Verifier {
                 .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?
                 .names.as_ref()
                 .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?;
-            if attr_infos.values.len() != attr_names.len() {
-                error!("Proof Revealed Attr Group does not match Proof Request Attribute Group, proof request attrs: {:?}, referent: {:?}, attr_infos: {:?}", proof_req.requested_attributes, attr_referent, attr_infos);
-                return Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof Revealed Attr Group does not match Proof Request Attribute Group"))
-            }
-            for attr_name in attr_names {
-                let attr_info = &attr_infos.values.get(attr_name)
-                    .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof Revealed Attr Group does not match Proof Request Attribute Group"))?;
-                Verifier::_verify_revealed_attribute_value(attr_name, proof, &RevealedAttributeInfo {
-                    sub_proof_index: attr_infos.sub_proof_index,
-                    raw: attr_info.raw.clone(),
-                    encoded: attr_info.encoded.clone()
-                })?;
-            }
+            Verifier::_verify_revealed_attribute_group_values(proof_req, attr_referent, attr_names, proof, attr_infos)?;
+        }
+        Ok(())
+    }
+
+    fn _verify_revealed_attribute_group_values(proof_req: &ProofRequestPayload,
+                                               attr_referent: &str,
+                                               attr_names: &[String],
+                                               proof: &Proof,
+                                               attr_infos: &RevealedAttributeGroupInfo) -> IndyResult<()> {
+        if attr_infos.values.len() != attr_names.len() {
+            error!("Proof Revealed Attr Group does not match Proof Request Attribute Group, proof request attrs: {:?}, referent: {:?}, attr_infos: {:?}", proof_req.requested_attributes, attr_referent, attr_infos);
+            return Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof Revealed Attr Group does not match Proof Request Attribute Group"))
+        }
+        for attr_name in attr_names {
+            let attr_info = &attr_infos.values.get(attr_name)
+                .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof Revealed Attr Group does not match Proof Request Attribute Group"))?;
+            Verifier::_verify_revealed_attribute_value(attr_name, proof, &RevealedAttributeInfo {
+                sub_proof_index: attr_infos.sub_proof_index,
+                raw: attr_info.raw.clone(),
+                encoded: attr_info.encoded.clone()
+            })?;
         }
         Ok(())
     }
@@ -397,27 +671,11 @@ impl Verifier {
             .map(|(referent, info)| (referent.to_string(), info.clone()))
             .collect();
 
-        for (referent, info) in requested_attrs.clone() {
+        for (referent, info) in requested_attrs.iter() {
             if let Some(ref query) = info.restrictions {
                 let filter = Verifier::_gather_filter_info(&referent, &proof_attr_identifiers)?;
 
-                let name_value_map: HashMap<String, Option<&str>> = if let Some(name) = info.name {
-                    let mut map = HashMap::new();
-                    map.insert(name.clone(), requested_proof.revealed_attrs.get(&referent).map(|attr| attr.raw.as_str()));
-                    map
-                } else if let Some(names) = info.names {
-                    let mut map = HashMap::new();
-                    let attrs = requested_proof.revealed_attr_groups.get(&referent)
-                        .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof does not have referent from proof request"))?;
-                    for name in names {
-                        let val = attrs.values.get(&name).map(|attr| attr.raw.as_str());
-                        map.insert(name, val);
-                    }
-                    map
-                } else {
-                    error!(r#"Proof Request attribute restriction should contain "name" or "names" param. Current proof request: {:?}"#, proof_req);
-                    return Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, r#"Proof Request attribute restriction should contain "name" or "names" param"#));
-                };
+                let name_value_map = Verifier::_attr_value_map(referent, info, requested_proof)?;
 
                 Verifier::_do_process_operator(&name_value_map, &query, &filter)
                     .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{:?}\" attributes", &name_value_map)))?;
@@ -428,32 +686,7 @@ impl Verifier {
             if let Some(ref query) = info.restrictions {
                 let filter = Verifier::_gather_filter_info(&referent, received_predicates)?;
 
-                // start with the predicate requested attribute, which is un-revealed
-                let mut attr_value_map = HashMap::new();
-                attr_value_map.insert(info.name.to_string(), None);
-
-                // include any revealed attributes for the same credential (based on sub_proof_index)
-                let pred_sub_proof_index = requested_proof.predicates.get(referent).unwrap().sub_proof_index;
-                for attr_referent in requested_proof.revealed_attrs.keys() {
-                    let attr_info = requested_proof.revealed_attrs.get(attr_referent).unwrap();
-                    let attr_sub_proof_index = attr_info.sub_proof_index;
-                    if pred_sub_proof_index == attr_sub_proof_index {
-                        let attr_name = requested_attrs.get(attr_referent).unwrap().name.clone();
-                        if let Some(name) = attr_name {
-                            attr_value_map.insert(name, Some(attr_info.raw.as_str()));
-                        }
-                    }
-                }
-                for attr_referent in requested_proof.revealed_attr_groups.keys() {
-                    let attr_info = requested_proof.revealed_attr_groups.get(attr_referent).unwrap();
-                    let attr_sub_proof_index = attr_info.sub_proof_index;
-                    if pred_sub_proof_index == attr_sub_proof_index {
-                        for name in attr_info.values.keys() {
-                            let raw_val = attr_info.values.get(name).unwrap().raw.as_str();
-                            attr_value_map.insert(name.clone(), Some(raw_val.clone()));
-                        }
-                    }
-                }
+                let attr_value_map = Verifier::_predicate_attr_value_map(referent, info, requested_proof, &requested_attrs)?;
 
                 Verifier::_do_process_operator(&attr_value_map, &query, &filter)
                     .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{}\" predicate", &info.name)))?;
@@ -467,6 +700,58 @@ impl Verifier {
         Ok(())
     }
 
+    fn _attr_value_map<'a>(referent: &str,
+                           info: &AttributeInfo,
+                           requested_proof: &'a RequestedProof) -> IndyResult<HashMap<String, Option<&'a str>>> {
+        if let Some(ref name) = info.name {
+            let mut map = HashMap::new();
+            map.insert(name.clone(), requested_proof.revealed_attrs.get(referent).map(|attr| attr.raw.as_str()));
+            Ok(map)
+        } else if let Some(ref names) = info.names {
+            let mut map = HashMap::new();
+            let attrs = requested_proof.revealed_attr_groups.get(referent)
+                .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof does not have referent from proof request"))?;
+            for name in names {
+                let val = attrs.values.get(name).map(|attr| attr.raw.as_str());
+                map.insert(name.clone(), val);
+            }
+            Ok(map)
+        } else {
+            error!(r#"Proof Request attribute restriction should contain "name" or "names" param. Current attribute: {:?}"#, info);
+            Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, r#"Proof Request attribute restriction should contain "name" or "names" param"#))
+        }
+    }
+
+    fn _predicate_attr_value_map<'a>(referent: &str,
+                                     info: &PredicateInfo,
+                                     requested_proof: &'a RequestedProof,
+                                     requested_attrs: &HashMap<String, AttributeInfo>) -> IndyResult<HashMap<String, Option<&'a str>>> {
+        // start with the predicate requested attribute, which is un-revealed
+        let mut attr_value_map = HashMap::new();
+        attr_value_map.insert(info.name.to_string(), None);
+
+        // include any revealed attributes for the same credential (based on sub_proof_index)
+        let pred_sub_proof_index = requested_proof.predicates.get(referent)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Predicate with referent \"{}\" not found in Proof", referent)))?
+            .sub_proof_index;
+        for (attr_referent, attr_info) in requested_proof.revealed_attrs.iter() {
+            if pred_sub_proof_index == attr_info.sub_proof_index {
+                if let Some(name) = requested_attrs.get(attr_referent).and_then(|info| info.name.clone()) {
+                    attr_value_map.insert(name, Some(attr_info.raw.as_str()));
+                }
+            }
+        }
+        for attr_info in requested_proof.revealed_attr_groups.values() {
+            if pred_sub_proof_index == attr_info.sub_proof_index {
+                for (name, value) in attr_info.values.iter() {
+                    attr_value_map.insert(name.clone(), Some(value.raw.as_str()));
+                }
+            }
+        }
+
+        Ok(attr_value_map)
+    }
+
     fn _is_self_attested(referent: &str, info: &AttributeInfo, self_attested_attrs: &HashSet<String>) -> bool {
         match info.restrictions.as_ref() {
             Some(&Query::And(ref array)) | Some(&Query::Or(ref array)) if array.is_empty() =>
@@ -916,4 +1201,65 @@ mod tests {
         Verifier::_validate_timestamp(&_received(), "referent_2", &None, &Some(_interval())).unwrap_err();
         Verifier::_validate_timestamp(&_received(), "referent_3", &None, &Some(_interval())).unwrap_err();
     }
+
+    fn _revealed_attr_values() -> HashMap<String, Option<&'static str>> {
+        let mut map = HashMap::new();
+        map.insert("zip".to_string(), Some("value"));
+        map
+    }
+
+    #[test]
+    fn explain_operator_works() {
+        let op = Query::And(vec![
+            Query::Eq(attr_tag_value(), "value".to_string()),
+            Query::Eq(schema_issuer_did_tag(), SCHEMA_ISSUER_DID.to_string()),
+        ]);
+        assert_eq!(CheckResult::Passed, Verifier::_explain_operator(&_revealed_attr_values(), &op, &filter()));
+    }
+
+    #[test]
+    fn explain_operator_works_for_failed_eq() {
+        let op = Query::And(vec![
+            Query::Eq(attr_tag_value(), "value".to_string()),
+            Query::Eq(schema_issuer_did_tag(), "NOT HERE".to_string()),
+        ]);
+
+        match Verifier::_explain_operator(&_revealed_attr_values(), &op, &filter()) {
+            CheckResult::Failed { reason } => assert!(reason.contains("\"schema_issuer_did\" values are different")),
+            result => panic!("Unexpected result: {:?}", result)
+        }
+    }
+
+    #[test]
+    fn restrictions_report_works_for_alternatives() {
+        let op = Query::Or(vec![
+            Query::Eq(cred_def_id_tag(), "NOT HERE".to_string()),
+            Query::Eq(cred_def_id_tag(), CRED_DEF_ID.to_string()),
+        ]);
+
+        let report = Verifier::_restrictions_report(&op, Ok(filter()), Ok(_revealed_attr_values()));
+        assert_eq!(2, report.len());
+        assert!(report[0].result.is_failed());
+        assert_eq!(Query::Eq(cred_def_id_tag(), "NOT HERE".to_string()), report[0].restriction);
+        assert_eq!(CheckResult::Passed, report[1].result);
+    }
+
+    #[test]
+    fn restrictions_report_works_for_missed_identifier() {
+        let op = Query::Eq(cred_def_id_tag(), CRED_DEF_ID.to_string());
+
+        let report = Verifier::_restrictions_report(&op, Verifier::_gather_filter_info("referent_3", &_received()), Ok(_revealed_attr_values()));
+        assert_eq!(1, report.len());
+        assert!(report[0].result.is_failed());
+    }
+
+    #[test]
+    fn non_revoked_report_works() {
+        let rev_regs = HashMap::new();
+
+        assert_eq!(CheckResult::Skipped, Verifier::_non_revoked_report("referent_2", &_received(), &None, &None, &rev_regs));
+        assert!(Verifier::_non_revoked_report("referent_1", &_received(), &Some(_interval()), &None, &rev_regs).is_failed());
+        assert!(Verifier::_non_revoked_report("referent_2", &_received(), &None, &Some(_interval()), &rev_regs).is_failed());
+        assert!(Verifier::_non_revoked_report("referent_3", &_received(), &None, &Some(_interval()), &rev_regs).is_failed());
+    }
 }
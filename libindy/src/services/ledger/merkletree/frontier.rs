@@ -0,0 +1,136 @@
+use indy_api_types::errors::prelude::*;
+use crate::services::ledger::merkletree::tree::TreeLeafData;
+use indy_utils::crypto::hash::{Hash, EMPTY_HASH_BYTES};
+
+/// Right edge of a Merkle tree: hashes of the perfect subtrees its leaves are split into, the biggest first.
+/// It is enough to append new leaves and to get the root hash, so the leaves themselves aren't kept.
+#[derive(Clone, Debug, Default)]
+pub struct MerkleTreeFrontier {
+
+    /// The number of leaves in the tree
+    pub count: usize,
+
+    /// Hashes of the perfect subtrees, one per set bit of `count`
+    pub hashes: Vec<Vec<u8>>
+}
+
+impl MerkleTreeFrontier {
+
+    /// Constructs the frontier of the tree ending with the leaf with `index` (starting from 0)
+    /// from the audit path of the leaf in the tree of `tree_size` leaves.
+    /// The audit path must be checked against the root of that tree before.
+    pub fn from_audit_path(leaf: &TreeLeafData, index: usize, tree_size: usize, audit_path: &[Vec<u8>]) -> IndyResult<Self> {
+        if index >= tree_size {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Leaf {} doesn't belong to tree with {} leaves", index, tree_size)));
+        }
+
+        let mut node = index;
+        let mut last_node = tree_size - 1;
+        let mut proofs = audit_path.iter();
+        let mut hashes = Vec::new();
+
+        // the left siblings on the path are the perfect subtrees preceding the leaf
+        while last_node != 0 {
+            if node % 2 != 0 || node < last_node {
+                let hash = proofs.next()
+                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Audit path is too short"))?;
+
+                if node % 2 != 0 {
+                    hashes.push(hash.clone());
+                }
+            }
+            node /= 2;
+            last_node /= 2;
+        }
+
+        hashes.reverse();
+
+        let mut frontier = MerkleTreeFrontier { count: index, hashes };
+        frontier.append(leaf)?;
+        Ok(frontier)
+    }
+
+    /// Returns the root hash of the tree
+    pub fn root_hash(&self) -> IndyResult<Vec<u8>> {
+        let mut hashes = self.hashes.iter().rev();
+
+        let mut root = match hashes.next() {
+            Some(hash) => hash.clone(),
+            None => return Ok(EMPTY_HASH_BYTES.to_vec())
+        };
+
+        for hash in hashes {
+            root = Hash::hash_nodes(hash, &root)?;
+        }
+
+        Ok(root)
+    }
+
+    pub fn append(&mut self, leaf: &TreeLeafData) -> IndyResult<()> {
+        let mut hash = Hash::hash_leaf(leaf)?;
+        let mut count = self.count;
+
+        // the subtrees of the same size as the new one are merged with it
+        while count % 2 != 0 {
+            let left = self.hashes.pop()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Merkle tree frontier doesn't match its size"))?;
+            hash = Hash::hash_nodes(&left, &hash)?;
+            count /= 2;
+        }
+
+        self.hashes.push(hash);
+        self.count += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::ledger::merkletree::merkletree::MerkleTree;
+
+    fn _values(count: usize) -> Vec<TreeLeafData> {
+        (1..=count).map(|value| value.to_string().as_bytes().to_vec()).collect()
+    }
+
+    #[test]
+    fn root_hash_works() {
+        let mut frontier = MerkleTreeFrontier::default();
+        assert_eq!(MerkleTree::default().root_hash(), &frontier.root_hash().unwrap());
+
+        for count in 1..=17 {
+            frontier.append(&_values(count)[count - 1]).unwrap();
+
+            assert_eq!(count, frontier.count);
+            assert_eq!(count.count_ones() as usize, frontier.hashes.len());
+            assert_eq!(MerkleTree::from_vec(_values(count)).unwrap().root_hash(), &frontier.root_hash().unwrap());
+        }
+    }
+
+    #[test]
+    fn from_audit_path_works() {
+        let tree_size = 11;
+        let values = _values(tree_size);
+        let mt = MerkleTree::from_vec(values.clone()).unwrap();
+
+        for index in 0..tree_size {
+            let audit_path = mt.gen_audit_path(index).unwrap();
+            let frontier = MerkleTreeFrontier::from_audit_path(&values[index], index, tree_size, &audit_path).unwrap();
+
+            assert_eq!(index + 1, frontier.count);
+            assert_eq!(MerkleTree::from_vec(values[..=index].to_vec()).unwrap().root_hash(), &frontier.root_hash().unwrap());
+        }
+    }
+
+    #[test]
+    fn from_audit_path_works_for_short_path() {
+        let values = _values(5);
+        let mt = MerkleTree::from_vec(values.clone()).unwrap();
+
+        let mut audit_path = mt.gen_audit_path(2).unwrap();
+        audit_path.pop();
+
+        assert_kind!(IndyErrorKind::InvalidStructure, MerkleTreeFrontier::from_audit_path(&values[2], 2, 5, &audit_path));
+    }
+}
@@ -42,16 +42,28 @@ impl MerkleTree {
             return Ok(MerkleTree::default());
         }
 
-        let count = values.len();
-        let mut nodes_count = 0;
-        let mut height = 0;
-        let mut cur    = Vec::with_capacity(count);
+        let mut leaves = Vec::with_capacity(values.len());
 
         for v in values {
             let leaf = Tree::new_leaf(v)?;
-            cur.push(leaf);
+            leaves.push(leaf);
         }
 
+        MerkleTree::from_leaves(leaves)
+    }
+
+    /// Constructs a Merkle Tree from the already hashed leaves, so their hashes aren't computed again.
+    pub(super) fn from_leaves(leaves: Vec<Tree>) -> IndyResult<Self> {
+
+        if leaves.is_empty() {
+            return Ok(MerkleTree::default());
+        }
+
+        let count = leaves.len();
+        let mut nodes_count = 0;
+        let mut height = 0;
+        let mut cur    = leaves;
+
         while cur.len() > 1 {
             let mut next = Vec::new();
             while !cur.is_empty() {
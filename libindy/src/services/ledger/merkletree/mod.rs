@@ -1,6 +1,7 @@
 pub mod tree;
 pub mod proof;
 pub mod merkletree;
+pub mod frontier;
 
 use self::tree::*;
 use self::merkletree::*;
@@ -126,6 +127,123 @@ impl MerkleTree {
         Ok(true)
     }
 
+    /// Generates a consistency proof between the first `old_size` leaves and the whole tree (RFC 6962 SUBPROOF).
+    /// The proof is accepted by `consistency_proof` of the tree built from the first `old_size` leaves.
+    pub fn gen_consistency_proof(&self, old_size: usize) -> IndyResult<Vec<Vec<u8>>> {
+        if old_size == 0 || old_size > self.count {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Can't build consistency proof for size {} of tree with {} leaves", old_size, self.count)));
+        }
+
+        let mut proof = Vec::new();
+        MerkleTree::_subproof(&self.root, old_size, true, &mut proof);
+        Ok(proof)
+    }
+
+    fn _subproof(tree: &Tree, old_size: usize, complete: bool, proof: &mut Vec<Vec<u8>>) {
+        if old_size == tree.get_count() {
+            if !complete {
+                proof.push(tree.hash().clone());
+            }
+            return;
+        }
+
+        if let Tree::Node { ref left, ref right, .. } = *tree {
+            let left_size = left.get_count();
+            if old_size <= left_size {
+                MerkleTree::_subproof(left, old_size, complete, proof);
+                proof.push(right.hash().clone());
+            } else {
+                MerkleTree::_subproof(right, old_size - left_size, false, proof);
+                proof.push(left.hash().clone());
+            }
+        }
+    }
+
+    /// Generates an audit path of the leaf with `index` (starting from 0), the hashes go from the leaf to the root (RFC 6962 PATH).
+    pub fn gen_audit_path(&self, index: usize) -> IndyResult<Vec<Vec<u8>>> {
+        if index >= self.count {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Can't build audit path for leaf {} of tree with {} leaves", index, self.count)));
+        }
+
+        let mut path = Vec::new();
+        MerkleTree::_audit_path(&self.root, index, &mut path);
+        Ok(path)
+    }
+
+    fn _audit_path(tree: &Tree, index: usize, path: &mut Vec<Vec<u8>>) {
+        if let Tree::Node { ref left, ref right, .. } = *tree {
+            let left_size = left.get_count();
+            if index < left_size {
+                MerkleTree::_audit_path(left, index, path);
+                path.push(right.hash().clone());
+            } else {
+                MerkleTree::_audit_path(right, index - left_size, path);
+                path.push(left.hash().clone());
+            }
+        }
+    }
+
+    /// Checks that the leaf with `index` (starting from 0) belongs to the tree of `tree_size` leaves with `root_hash`.
+    pub fn verify_audit_path(leaf: &TreeLeafData, index: usize, tree_size: usize,
+                             audit_path: &[Vec<u8>], root_hash: &[u8]) -> IndyResult<bool> {
+        if index >= tree_size {
+            return Ok(false);
+        }
+
+        let mut node = index;
+        let mut last_node = tree_size - 1;
+        let mut proofs = audit_path.iter();
+        let mut hash = Hash::hash_leaf(leaf)?;
+
+        while last_node != 0 {
+            if node % 2 != 0 {
+                hash = Hash::hash_nodes(unwrap_opt_or_return!(proofs.next(), Ok(false)), &hash)?;
+            } else if node < last_node {
+                hash = Hash::hash_nodes(&hash, unwrap_opt_or_return!(proofs.next(), Ok(false)))?;
+            }
+            node /= 2;
+            last_node /= 2;
+        }
+
+        Ok(proofs.next().is_none() && hash.as_slice() == root_hash)
+    }
+
+    /// Copy of the tree with the leaf values dropped, so only the hashes of the nodes and the size are kept.
+    /// New leaves can still be appended to it as `append` doesn't rehash the existing leaves.
+    pub fn hashes(&self) -> MerkleTree {
+        MerkleTree {
+            root: MerkleTree::_drop_values(&self.root),
+            height: self.height,
+            count: self.count,
+            nodes_count: self.nodes_count,
+        }
+    }
+
+    fn _drop_values(tree: &Tree) -> Tree {
+        match *tree {
+            Tree::Empty { ref hash } => Tree::empty(hash.clone()),
+            Tree::Leaf { ref hash, .. } => Tree::new(hash.clone(), Vec::new()),
+            Tree::Node { ref hash, ref left, ref right } => Tree::Node {
+                hash: hash.clone(),
+                left: Box::new(MerkleTree::_drop_values(left)),
+                right: Box::new(MerkleTree::_drop_values(right)),
+            }
+        }
+    }
+
+    fn _leaves(tree: &Tree, leaves: &mut Vec<Tree>) {
+        match *tree {
+            Tree::Empty { .. } => {}
+            Tree::Leaf { .. } => leaves.push(tree.clone()),
+            Tree::Node { ref left, ref right, .. } => {
+                MerkleTree::_leaves(left, leaves);
+                MerkleTree::_leaves(right, leaves);
+            }
+        }
+    }
+
     pub fn append(&mut self, node: TreeLeafData) -> IndyResult<()> {
         if self.count == 0 {
             // empty tree
@@ -135,9 +253,10 @@ impl MerkleTree {
             // add to right subtree
             match self.root.clone() {
                 Tree::Node { ref left, ref right, .. } => {
-                    let mut iter = right.iter().map(|x| (*x).clone()).collect::<Vec<TreeLeafData>>();
-                    iter.push(node);
-                    let new_right = MerkleTree::from_vec(iter)?;
+                    let mut leaves = Vec::new();
+                    MerkleTree::_leaves(right, &mut leaves);
+                    leaves.push(Tree::new_leaf(node)?);
+                    let new_right = MerkleTree::from_leaves(leaves)?;
                     let combined_hash = Hash::hash_nodes(
                         left.hash(),
                         new_right.root_hash() as &Vec<u8>
@@ -171,13 +290,13 @@ impl MerkleTree {
                     self.count += 1;
                     self.nodes_count += 1;
                 }
-                Tree::Leaf { ref hash, ref value } => {
+                Tree::Leaf { ref hash, .. } => {
                     let combined_hash = Hash::hash_nodes(
                         hash,
                         new_right.root_hash()
                     )?;
                     self.root = Tree::Node {
-                        left: Box::new(Tree::new_leaf((*value).clone())?),
+                        left: Box::new(self.root.clone()),
                         right: Box::new(new_right.root),
                         hash: combined_hash.to_vec()
                     };
@@ -215,6 +334,98 @@ mod tests {
         assert_eq!(mt.root_hash_hex(), "1285070cf01debc1155cef8dfd5ba54c05abb919a4c08c8632b079fb1e1e5e7c");
     }
 
+    #[test]
+    fn gen_consistency_proof_works() {
+        let values = vec!["1", "2", "3", "4", "5", "6", "7", "8", "9"];
+        let mut full = MerkleTree::from_vec(vec![]).unwrap();
+
+        for i in values.iter() {
+            full.append(String::from(*i).as_bytes().to_vec()).unwrap();
+        }
+
+        for old_size in 1..values.len() + 1 {
+            let mut old = MerkleTree::from_vec(vec![]).unwrap();
+            for i in values[0..old_size].iter() {
+                old.append(String::from(*i).as_bytes().to_vec()).unwrap();
+            }
+
+            let proof = full.gen_consistency_proof(old_size).unwrap();
+            assert!(old.consistency_proof(full.root_hash(), full.count(), &proof).unwrap(), "old size {}", old_size);
+        }
+    }
+
+    #[test]
+    fn gen_consistency_proof_works_for_invalid_size() {
+        let mut mt = MerkleTree::from_vec(vec![]).unwrap();
+        mt.append(b"1".to_vec()).unwrap();
+
+        assert!(mt.gen_consistency_proof(0).is_err());
+        assert!(mt.gen_consistency_proof(2).is_err());
+    }
+
+    #[test]
+    fn gen_audit_path_works() {
+        let values = vec!["1", "2", "3", "4", "5", "6", "7"];
+        let mut mt = MerkleTree::from_vec(vec![]).unwrap();
+
+        for i in values.iter() {
+            mt.append(String::from(*i).as_bytes().to_vec()).unwrap();
+        }
+
+        for (index, value) in values.iter().enumerate() {
+            let path = mt.gen_audit_path(index).unwrap();
+            let leaf = String::from(*value).as_bytes().to_vec();
+            assert!(MerkleTree::verify_audit_path(&leaf, index, mt.count(), &path, mt.root_hash()).unwrap(), "index {}", index);
+        }
+    }
+
+    #[test]
+    fn verify_audit_path_works_for_three_leaves() {
+        let (a, b, c) = (b"a".to_vec(), b"b".to_vec(), b"c".to_vec());
+        let mt = MerkleTree::from_vec(vec![a.clone(), b.clone(), c.clone()]).unwrap();
+
+        let ab = Hash::hash_nodes(&Hash::hash_leaf(&a).unwrap(), &Hash::hash_leaf(&b).unwrap()).unwrap();
+
+        assert!(MerkleTree::verify_audit_path(&c, 2, 3, &[ab.clone()], mt.root_hash()).unwrap());
+        assert!(!MerkleTree::verify_audit_path(&a, 2, 3, &[ab.clone()], mt.root_hash()).unwrap());
+        assert!(!MerkleTree::verify_audit_path(&c, 1, 3, &[ab.clone()], mt.root_hash()).unwrap());
+        assert!(!MerkleTree::verify_audit_path(&c, 2, 3, &[ab.clone(), ab], mt.root_hash()).unwrap());
+        assert!(!MerkleTree::verify_audit_path(&c, 3, 3, &[], mt.root_hash()).unwrap());
+    }
+
+    #[test]
+    fn gen_audit_path_works_for_invalid_index() {
+        let mt = MerkleTree::from_vec(vec![b"1".to_vec()]).unwrap();
+
+        assert_eq!(Vec::<Vec<u8>>::new(), mt.gen_audit_path(0).unwrap());
+        assert!(mt.gen_audit_path(1).is_err());
+    }
+
+    #[test]
+    fn append_works_for_hashes() {
+        let values = vec!["1", "2", "3", "4", "5", "6", "7"].iter().map(|x| x.as_bytes().to_vec()).collect::<Vec<_>>();
+        let mut mt = MerkleTree::from_vec(vec![]).unwrap();
+        for value in values.iter() {
+            mt.append(value.clone()).unwrap();
+        }
+
+        let mut hashes = mt.hashes();
+        assert_eq!(0, hashes.iter().map(|value| value.len()).sum::<usize>());
+        assert_eq!(mt.root_hash(), hashes.root_hash());
+
+        mt.append(b"8".to_vec()).unwrap();
+        hashes.append(b"8".to_vec()).unwrap();
+        assert_eq!(mt.root_hash(), hashes.root_hash());
+        assert_eq!(mt.count(), hashes.count());
+
+        mt.append(b"9".to_vec()).unwrap();
+        hashes.append(b"9".to_vec()).unwrap();
+        assert_eq!(mt.root_hash(), hashes.root_hash());
+
+        let appended = hashes.iter().filter(|value| !value.is_empty()).cloned().collect::<Vec<_>>();
+        assert_eq!(vec![b"8".to_vec(), b"9".to_vec()], appended);
+    }
+
     #[test]
     fn find_hash_works() {
         let values = vec!["1", "2", "3", "4", "5", "6", "7", "8", "9"];
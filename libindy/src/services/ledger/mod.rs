@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use hex::FromHex;
 use ursa::cl::RevocationRegistryDelta as CryproRevocationRegistryDelta;
 use serde::de::DeserializeOwned;
@@ -10,25 +12,36 @@ use crate::domain::anoncreds::revocation_registry::RevocationRegistry;
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1, RevocationRegistryId};
 use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use crate::domain::anoncreds::schema::{Schema, SchemaV1, SchemaId};
-use crate::domain::crypto::did::DidValue;
-use crate::domain::ledger::attrib::{AttribOperation, GetAttribOperation};
-use crate::domain::ledger::constants::{GET_VALIDATOR_INFO, POOL_RESTART, ROLE_REMOVE, STEWARD, ENDORSER, TRUSTEE, NETWORK_MONITOR, ROLES, txn_name_to_code};
+use crate::domain::crypto::did::{DidValue, ShortDidValue};
+use crate::domain::ledger::attrib::{AttribOperation, GetAttribOperation, GetAttrRawReplyResult};
+use crate::domain::ledger::constants::{ATTRIB, CRED_DEF, GET_VALIDATOR_INFO, NODE_UPGRADE, NYM, POOL_RESTART, POOL_UPGRADE, ROLE_REMOVE, SCHEMA, role_name_to_code, txn_name_to_code};
 use crate::domain::ledger::cred_def::{CredDefOperation, GetCredDefOperation, GetCredDefReplyResult};
-use crate::domain::ledger::ddo::GetDdoOperation;
+use crate::domain::ledger::ddo::{DID_DOCUMENT_CONTEXT, DidDocument, DidService, ED25519_VERIFICATION_KEY_TYPE, ENDPOINT_SERVICE_TYPE, EndpointAttrib, GetDdoOperation, VerificationMethod};
+use crate::domain::ledger::endorsement::{EndorsementEnvelope, EndorsementSignatures, EndorsementStatus};
+use crate::domain::ledger::ledgers_freeze::{GetFrozenLedgersOperation, LedgersFreezeOperation};
 use crate::domain::ledger::node::{NodeOperation, NodeOperationData};
 use crate::domain::ledger::nym::{GetNymOperation, GetNymReplyResult, GetNymResultDataV0, NymData, NymOperation};
-use crate::domain::ledger::pool::{PoolConfigOperation, PoolRestartOperation, PoolUpgradeOperation, Schedule};
+use crate::domain::ledger::pool::{NodeUpgradeStatus, PoolConfigOperation, PoolRestartOperation, PoolUpgradeOperation, PoolUpgradeStatus, Schedule, UpgradeStatus};
 use crate::domain::ledger::request::{TxnAuthrAgrmtAcceptanceData, Request};
 use crate::domain::ledger::response::{Message, Reply, ReplyType};
 use crate::domain::ledger::rev_reg::{GetRevocRegDeltaReplyResult, GetRevocRegReplyResult, GetRevRegDeltaOperation, GetRevRegOperation, RevRegEntryOperation};
 use crate::domain::ledger::rev_reg_def::{GetRevocRegDefReplyResult, GetRevRegDefOperation, RevRegDefOperation};
+use crate::domain::ledger::rich_schema::{GetRichSchemaObjectByIdOperation, GetRichSchemaObjectByMetadataOperation, GetRichSchemaObjectReplyResult, RichSchemaObject, RichSchemaObjectOperation, RichSchemaType};
 use crate::domain::ledger::schema::{GetSchemaOperation, GetSchemaOperationData, GetSchemaReplyResult, SchemaOperation, SchemaOperationData};
-use crate::domain::ledger::txn::{GetTxnOperation, LedgerType};
+use crate::domain::ledger::txn::{GetTxnOperation, LedgerCatchupReply, LedgerType, VerifiedTxn};
 use crate::domain::ledger::validator_info::GetValidatorInfoOperation;
 use crate::domain::ledger::auth_rule::*;
 use crate::domain::ledger::author_agreement::*;
 use indy_api_types::errors::prelude::*;
 use indy_utils::crypto::hash::hash as openssl_hash;
+use rust_base58::{FromBase58, ToBase58};
+
+use crate::services::crypto::DEFAULT_CRYPTO_TYPE;
+use crate::utils::crypto::signature_serializer::serialize_signature;
+use crate::utils::crypto::verkey_builder::{build_full_verkey, split_verkey};
+
+use self::merkletree::frontier::MerkleTreeFrontier;
+use self::merkletree::merkletree::MerkleTree;
 
 pub mod merkletree;
 
@@ -63,14 +76,8 @@ impl LedgerService {
                     Value::Null
                 } else {
                     json!(
-                    match r {
-                        "STEWARD" => STEWARD,
-                        "TRUSTEE" => TRUSTEE,
-                        "TRUST_ANCHOR" | "ENDORSER" => ENDORSER,
-                        "NETWORK_MONITOR" => NETWORK_MONITOR,
-                        role if ROLES.contains(&role) => role,
-                        role => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid role: {}", role)))
-                    }
+                    role_name_to_code(r)
+                        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid role: {}", r)))?
                 )
                 }
             )
@@ -125,6 +132,67 @@ impl LedgerService {
         build_result!(GetDdoOperation, identifier, dest.to_short())
     }
 
+    #[logfn(Info)]
+    pub fn parse_get_attrib_raw_response(&self, get_attrib_response: &str) -> IndyResult<Option<String>> {
+        let reply: Reply<GetAttrRawReplyResult> = LedgerService::parse_response(get_attrib_response)?;
+        Ok(reply.result().data)
+    }
+
+    /// Builds DID document of `did:sov` DID from its NYM data and the value of raw `endpoint` ATTRIB.
+    ///
+    /// Abbreviated verkey is expanded to the full one. DID without verkey has no verification methods.
+    #[logfn(Info)]
+    pub fn build_did_document(&self, nym_data: &NymData, endpoint: Option<&str>) -> IndyResult<DidDocument> {
+        let id = nym_data.did.qualify(Some(String::from("sov"))).0;
+
+        let verification_method = match nym_data.verkey {
+            Some(ref verkey) => {
+                let verkey = build_full_verkey(&nym_data.did.0, Some(verkey.as_str()))?;
+                let (verkey, crypto_type) = split_verkey(&verkey);
+
+                if crypto_type != DEFAULT_CRYPTO_TYPE {
+                    return Err(err_msg(IndyErrorKind::InvalidTransaction, format!("Unsupported type of verkey: {}", crypto_type)));
+                }
+
+                vec![VerificationMethod {
+                    id: format!("{}#key-1", id),
+                    type_: ED25519_VERIFICATION_KEY_TYPE.to_string(),
+                    controller: id.clone(),
+                    public_key_base58: verkey.to_string(),
+                }]
+            }
+            None => Vec::new()
+        };
+
+        let service = match endpoint.map(serde_json::from_str::<EndpointAttrib>) {
+            Some(Ok(EndpointAttrib { endpoint })) => {
+                match endpoint.endpoint.or(endpoint.ha) {
+                    Some(service_endpoint) => vec![DidService {
+                        id: format!("{}#endpoint", id),
+                        type_: ENDPOINT_SERVICE_TYPE.to_string(),
+                        service_endpoint,
+                        routing_keys: endpoint.routing_keys,
+                    }],
+                    None => Vec::new()
+                }
+            }
+            Some(Err(err)) => {
+                // the attribute is set by the DID owner, so malformed one doesn't prevent the resolution
+                warn!("Endpoint attribute of {} is skipped as invalid: {:?}", id, err);
+                Vec::new()
+            }
+            None => Vec::new()
+        };
+
+        Ok(DidDocument {
+            context: DID_DOCUMENT_CONTEXT.to_string(),
+            authentication: verification_method.iter().map(|method| method.id.clone()).collect(),
+            id,
+            verification_method,
+            service,
+        })
+    }
+
     #[logfn(Info)]
     pub fn build_attrib_request(&self, identifier: &DidValue, dest: &DidValue, hash: Option<&str>,
                                 raw: Option<&serde_json::Value>, enc: Option<&str>) -> IndyResult<String> {
@@ -196,16 +264,21 @@ impl LedgerService {
 
     #[logfn(Info)]
     pub fn build_get_txn_request(&self, identifier: Option<&DidValue>, ledger_type: Option<&str>, seq_no: i32) -> IndyResult<String> {
-        let ledger_id = match ledger_type {
+        let ledger_id = self.parse_ledger_type(ledger_type)?;
+
+        build_result!(GetTxnOperation, identifier, seq_no, ledger_id)
+    }
+
+    /// Id of the ledger by its predefined name or number, DOMAIN ledger if not set.
+    pub fn parse_ledger_type(&self, ledger_type: Option<&str>) -> IndyResult<i32> {
+        match ledger_type {
             Some(type_) =>
                 serde_json::from_str::<LedgerType>(&format!(r#""{}""#, type_))
                     .map(|type_| type_.to_id())
                     .or_else(|_| type_.parse::<i32>())
-                    .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid Ledger type: {}", type_))?,
-            None => LedgerType::DOMAIN.to_id()
-        };
-
-        build_result!(GetTxnOperation, identifier, seq_no, ledger_id)
+                    .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid Ledger type: {}", type_)),
+            None => Ok(LedgerType::DOMAIN.to_id())
+        }
     }
 
     #[logfn(Info)]
@@ -225,6 +298,61 @@ impl LedgerService {
         build_result!(PoolUpgradeOperation, Some(identifier), name, version, action, sha256, timeout, schedule, justification, reinstall, force, package)
     }
 
+    #[logfn(Info)]
+    pub fn build_ledgers_freeze_request(&self, identifier: &DidValue, ledgers_ids: Vec<u64>) -> IndyResult<String> {
+        if ledgers_ids.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "List of ledgers to freeze is empty"));
+        }
+
+        build_result!(LedgersFreezeOperation, Some(identifier), ledgers_ids)
+    }
+
+    #[logfn(Info)]
+    pub fn build_get_frozen_ledgers_request(&self, identifier: Option<&DidValue>) -> IndyResult<String> {
+        build_result!(GetFrozenLedgersOperation, identifier)
+    }
+
+    #[logfn(Info)]
+    pub fn parse_upgrade_status(&self, txns: &[Value]) -> IndyResult<UpgradeStatus> {
+        let mut txns = txns.iter().collect::<Vec<&Value>>();
+        txns.sort_by_key(|txn| txn["txnMetadata"]["seqNo"].as_u64());
+
+        let mut status = UpgradeStatus::default();
+
+        for txn in txns {
+            let data = &txn["txn"]["data"];
+            let (seq_no, txn_time) = (txn["txnMetadata"]["seqNo"].as_u64(), txn["txnMetadata"]["txnTime"].as_u64());
+
+            match txn["txn"]["type"].as_str() {
+                Some(POOL_UPGRADE) => {
+                    let mut pool_upgrade: PoolUpgradeStatus = serde_json::from_value(data.clone())
+                        .to_indy(IndyErrorKind::InvalidStructure, "Invalid POOL_UPGRADE transaction")?;
+                    pool_upgrade.seq_no = seq_no;
+                    pool_upgrade.txn_time = txn_time;
+
+                    status.pool_upgrade = Some(pool_upgrade);
+                }
+                Some(NODE_UPGRADE) => {
+                    let node = txn["txn"]["metadata"]["from"].as_str()
+                        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "NODE_UPGRADE transaction has no sender"))?;
+
+                    // Node reports its upgrade state either inside of nested `data` or directly
+                    let data = if data["data"].is_object() { &data["data"] } else { data };
+
+                    let mut node_upgrade: NodeUpgradeStatus = serde_json::from_value(data.clone())
+                        .to_indy(IndyErrorKind::InvalidStructure, "Invalid NODE_UPGRADE transaction")?;
+                    node_upgrade.seq_no = seq_no;
+                    node_upgrade.txn_time = txn_time;
+
+                    status.nodes.insert(node.to_string(), node_upgrade);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(status)
+    }
+
     #[logfn(Info)]
     pub fn build_revoc_reg_def_request(&self, identifier: &DidValue, mut rev_reg_def: RevocationRegistryDefinitionV1) -> IndyResult<String> {
         rev_reg_def.id = rev_reg_def.id.to_unqualified();
@@ -381,6 +509,38 @@ impl LedgerService {
         Ok(res)
     }
 
+    #[logfn(Info)]
+    pub fn build_rich_schema_object_request(&self, identifier: &DidValue, object: RichSchemaObject) -> IndyResult<String> {
+        build_result!(RichSchemaObjectOperation, Some(identifier), object)
+    }
+
+    #[logfn(Info)]
+    pub fn build_get_rich_schema_object_by_id_request(&self, identifier: Option<&DidValue>, id: &str) -> IndyResult<String> {
+        build_result!(GetRichSchemaObjectByIdOperation, identifier, id.to_string())
+    }
+
+    #[logfn(Info)]
+    pub fn build_get_rich_schema_object_by_metadata_request(&self, identifier: Option<&DidValue>, rs_type: &str, rs_name: &str, rs_version: &str) -> IndyResult<String> {
+        let rs_type = RichSchemaType::from_str(rs_type)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported Rich Schema object type: {}", rs_type)))?;
+
+        build_result!(GetRichSchemaObjectByMetadataOperation, identifier, rs_type, rs_name.to_string(), rs_version.to_string())
+    }
+
+    #[logfn(Info)]
+    pub fn parse_get_rich_schema_object_response(&self, get_rich_schema_object_response: &str) -> IndyResult<(String, String)> {
+        let reply: Reply<GetRichSchemaObjectReplyResult> = LedgerService::parse_response(get_rich_schema_object_response)?;
+
+        let object = reply.result().data
+            .ok_or_else(|| err_msg(IndyErrorKind::LedgerItemNotFound, "Rich Schema object not found"))?;
+
+        let res = (object.id.clone(),
+                   serde_json::to_string(&object)
+                       .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RichSchemaObject")?);
+
+        Ok(res)
+    }
+
     #[logfn(Info)]
     pub fn build_auth_rule_request(&self, submitter_did: &DidValue, txn_type: &str, action: &str, field: &str,
                                    old_value: Option<&str>, new_value: Option<&str>, constraint: Constraint) -> IndyResult<String> {
@@ -460,7 +620,7 @@ impl LedgerService {
         let message: serde_json::Value = serde_json::from_str(&response)
             .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
 
-        if message["op"] == json!("REPLY") && message["result"]["type"] != json!(T::get_type()) {
+        if message["op"] == json!("REPLY") && !T::is_type(message["result"]["type"].as_str().unwrap_or_default()) {
             return Err(err_msg(IndyErrorKind::InvalidTransaction, "Invalid response type"));
         }
 
@@ -553,6 +713,392 @@ impl LedgerService {
 
         Ok(res)
     }
+
+    /// Wraps the request into the envelope collecting signatures to satisfy the auth rule constraint.
+    ///
+    /// The GET_AUTH_RULE response must contain the only rule for the type of the request.
+    pub fn prepare_endorsement(&self, request_json: &str, get_auth_rule_response: &str, owner: Option<&DidValue>) -> IndyResult<EndorsementEnvelope> {
+        let request: Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        let txn_type = request["operation"]["type"].as_str()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Request doesn't contain operation type"))?;
+
+        let mut rules = self.parse_get_auth_rule_response(get_auth_rule_response)?;
+
+        if rules.len() != 1 {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("GET_AUTH_RULE response must contain exactly one rule, but contains {}", rules.len())));
+        }
+
+        let rule = rules.remove(0);
+
+        if txn_name_to_code(&rule.auth_type) != Some(txn_type) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Auth rule for transaction type {} can't be applied to the request of type {}", rule.auth_type, txn_type)));
+        }
+
+        Ok(EndorsementEnvelope {
+            request,
+            required_signers: rule.constraint.alternatives(),
+            constraint: rule.constraint,
+            owner: owner.map(|owner| owner.to_short().0),
+            signers: BTreeMap::new(),
+        })
+    }
+
+    /// Evaluates the constraint of the envelope against the signers with valid signatures.
+    ///
+    /// Roles of the signers are taken from their NYMs in GET_NYM responses instead of the signers' own claims.
+    /// A signer without NYM or whose NYM verkey differs from the signing one is unresolved and doesn't count.
+    pub fn endorsement_status(&self, envelope: &EndorsementEnvelope, signatures: EndorsementSignatures,
+                              get_nym_responses: &[String]) -> IndyResult<EndorsementStatus> {
+        let mut nyms: BTreeMap<String, NymData> = BTreeMap::new();
+
+        for get_nym_response in get_nym_responses {
+            let nym_data = match self.parse_get_nym_response(get_nym_response) {
+                Ok(nym_data) => nym_data,
+                Err(ref err) if err.kind() == IndyErrorKind::LedgerItemNotFound => continue,
+                Err(err) => return Err(err)
+            };
+
+            let nym_data: NymData = serde_json::from_str(&nym_data)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize NYM data")?;
+
+            nyms.insert(nym_data.did.0.clone(), nym_data);
+        }
+
+        let mut signers: Vec<AuthSigner> = Vec::new();
+        let mut unresolved: Vec<String> = Vec::new();
+
+        for did in signatures.valid.iter() {
+            let signer = match envelope.signers.get(did) {
+                Some(signer) => signer,
+                None => continue
+            };
+
+            let nym_data = match nyms.get(did) {
+                Some(nym_data) if build_full_verkey(did, nym_data.verkey.as_ref().map(String::as_str))? == signer.verkey => nym_data,
+                _ => {
+                    unresolved.push(did.to_string());
+                    continue;
+                }
+            };
+
+            signers.push(AuthSigner {
+                role: nym_data.role.clone(),
+                owner: envelope.owner.as_ref() == Some(did),
+            });
+        }
+
+        let unmet = envelope.constraint.unmet(&signers);
+
+        Ok(EndorsementStatus {
+            ready: unmet.is_empty() && signatures.invalid.is_empty(),
+            unmet,
+            signatures,
+            unresolved,
+        })
+    }
+
+    /// Evaluates the auth rules from GET_AUTH_RULE response applicable to the request against the signers.
+    ///
+    /// The ledger chooses ADD or EDIT rule depending on its state, so the caller specifies the action
+    /// the request performs and the current value of the field for EDIT.
+    /// The request isn't accepted if there is no applicable rule as the ledger rejects such requests too.
+    pub fn evaluate_auth_rule(&self, request_json: &str, auth_action: &str, old_value: Option<&str>,
+                              get_auth_rule_response: &str, signers: &[AuthSigner]) -> IndyResult<AuthRuleEvaluation> {
+        trace!("evaluate_auth_rule >>> request_json: {:?}, auth_action: {:?}, old_value: {:?}, get_auth_rule_response: {:?}, signers: {:?}",
+               request_json, auth_action, old_value, get_auth_rule_response, signers);
+
+        let request: Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        let auth_action = serde_json::from_str::<AuthAction>(&format!("\"{}\"", auth_action))
+            .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Cannot parse auth action: {}", err)))?;
+
+        let operation = &request["operation"];
+
+        let txn_type = operation["type"].as_str()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Request doesn't contain operation type"))?;
+
+        let signers = signers.iter()
+            .map(|signer| {
+                let role = match signer.role.as_ref().map(String::as_str) {
+                    None | Some("") => None,
+                    Some(role) => Some(role_name_to_code(role)
+                        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid role: {}", role)))?
+                        .to_string())
+                };
+
+                Ok(AuthSigner { role, owner: signer.owner })
+            })
+            .collect::<IndyResult<Vec<AuthSigner>>>()?;
+
+        let rules: Vec<AuthRuleCheck> = self.parse_get_auth_rule_response(get_auth_rule_response)?
+            .into_iter()
+            .filter(|rule| txn_name_to_code(&rule.auth_type) == Some(txn_type) && rule.matches(&auth_action, old_value, operation))
+            .map(|rule| AuthRuleCheck::new(rule, &signers))
+            .collect();
+
+        let res = AuthRuleEvaluation {
+            accepted: !rules.is_empty() && rules.iter().all(|rule| rule.satisfied),
+            rules,
+        };
+
+        trace!("evaluate_auth_rule <<< {:?}", res);
+
+        Ok(res)
+    }
+
+    /// Parses GET_TXN reply and checks the audit path of the returned transaction against the root of the ledger.
+    /// The root must be the one covered by the multi-signature of the reply; the signature itself is checked by the pool service.
+    ///
+    /// Returns `None` if there is no transaction with `seq_no` on the ledger.
+    pub fn parse_get_txn_range_reply(&self, reply: &str, seq_no: i32) -> IndyResult<Option<VerifiedTxn>> {
+        trace!("parse_get_txn_range_reply >>> reply: {:?}, seq_no: {:?}", reply, seq_no);
+
+        let reply: Value = serde_json::from_str(reply)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
+
+        if reply["op"] != json!("REPLY") {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, format!("Transaction has been failed: {:?}", reply["reason"])));
+        }
+
+        let data = &reply["result"]["data"];
+
+        let leaf_data = match _txn_leaf_data(data)? {
+            Some(leaf_data) => leaf_data,
+            None => return Ok(None)
+        };
+
+        if data["txnMetadata"]["seqNo"].as_i64() != Some(i64::from(seq_no)) {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, format!("GET_TXN reply contains transaction other than {}", seq_no)));
+        }
+
+        let (audit_path, root_hash, ledger_size) = match (data["auditPath"].as_array(), data["rootHash"].as_str(), data["ledgerSize"].as_u64()) {
+            (Some(audit_path), Some(root_hash), Some(ledger_size)) => (audit_path, root_hash, ledger_size),
+            _ => return Err(err_msg(IndyErrorKind::InvalidTransaction, "GET_TXN reply has no audit proof"))
+        };
+
+        let multi_signature_root = reply["result"]["state_proof"]["multi_signature"]["value"]["txn_root_hash"].as_str()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidTransaction, "GET_TXN reply has no multi-signature of the ledger root"))?;
+
+        if multi_signature_root != root_hash {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, "Ledger root of GET_TXN reply differs from the signed one"));
+        }
+
+        let audit_path = audit_path.iter()
+            .map(|hash| hash.as_str().and_then(|hash| hash.from_base58().ok()))
+            .collect::<Option<Vec<Vec<u8>>>>()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidTransaction, "Invalid audit path of GET_TXN reply"))?;
+
+        let root = root_hash.from_base58()
+            .map_err(|_| err_msg(IndyErrorKind::InvalidTransaction, "Invalid root hash of GET_TXN reply"))?;
+
+        if !MerkleTree::verify_audit_path(&leaf_data, (seq_no - 1) as usize, ledger_size as usize, &audit_path, &root)? {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, format!("Audit path of transaction {} doesn't match the ledger root", seq_no)));
+        }
+
+        let res = VerifiedTxn {
+            txn: data.clone(),
+            ledger_size,
+            root_hash: root_hash.to_string(),
+            audit_path,
+        };
+
+        trace!("parse_get_txn_range_reply <<< {:?}", res);
+
+        Ok(Some(res))
+    }
+
+    /// Right edge of the ledger merkle tree ending with the transaction of GET_TXN reply
+    /// which audit path is checked by `parse_get_txn_range_reply`.
+    pub fn build_ledger_frontier(&self, txn: &VerifiedTxn, seq_no: i32) -> IndyResult<MerkleTreeFrontier> {
+        let leaf_data = _txn_leaf_data(&txn.txn)?
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidTransaction, format!("Transaction {} is empty", seq_no)))?;
+
+        MerkleTreeFrontier::from_audit_path(&leaf_data, (seq_no - 1) as usize, txn.ledger_size as usize, &txn.audit_path)
+            .to_indy(IndyErrorKind::InvalidTransaction, format!("Invalid audit path of transaction {}", seq_no))
+    }
+
+    /// Parses the reply of the ledger catch-up and appends the caught up transactions to the right edge of
+    /// the ledger merkle tree. The frontier is changed only if the transactions extend it to the ledger root agreed by the pool,
+    /// so the previous transactions of the ledger aren't needed to verify them.
+    ///
+    /// Returns the caught up transactions in order of seq_no.
+    pub fn parse_ledger_catchup_reply(&self, reply: &str, frontier: &mut MerkleTreeFrontier) -> IndyResult<Vec<Value>> {
+        trace!("parse_ledger_catchup_reply >>> reply: {:?}, frontier size: {:?}", reply, frontier.count);
+
+        let mut reply: LedgerCatchupReply = serde_json::from_str(reply)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Invalid ledger catch-up reply")?;
+
+        if reply.txn_seq_no < frontier.count {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, "Ledger is shorter than the local merkle tree of it"));
+        }
+
+        let mut caught_up = frontier.clone();
+        let mut txns = Vec::with_capacity(reply.txn_seq_no - frontier.count);
+
+        for seq_no in frontier.count + 1..=reply.txn_seq_no {
+            let txn = reply.txns.remove(&seq_no.to_string())
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidTransaction, format!("Ledger catch-up reply has no transaction {}", seq_no)))?;
+
+            let leaf_data = _txn_leaf_data(&txn)?
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidTransaction, format!("Transaction {} of ledger catch-up reply is empty", seq_no)))?;
+
+            caught_up.append(&leaf_data)?;
+            txns.push(txn);
+        }
+
+        if caught_up.root_hash()?.to_base58() != reply.merkle_root {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, "Transactions of the ledger don't match the ledger root agreed by the pool"));
+        }
+
+        *frontier = caught_up;
+
+        trace!("parse_ledger_catchup_reply <<< txns: {:?}", txns);
+
+        Ok(txns)
+    }
+
+    /// Builds GET_* request of the object written by the write request to find out whether the write landed
+    /// after the pool didn't reach consensus on it.
+    ///
+    /// Returns `None` for the transaction types which can't be checked.
+    pub fn build_get_written_object_request(&self, request_json: &str) -> IndyResult<Option<String>> {
+        let request: Request<Value> = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        let identifier = match request.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => return Ok(None)
+        };
+
+        let operation = &request.operation;
+
+        let invalid_operation = || err_msg(IndyErrorKind::InvalidStructure,
+                                           format!("Invalid operation of {:?} request", operation["type"]));
+
+        let request_json = match operation["type"].as_str() {
+            Some(NYM) => {
+                let dest = operation["dest"].as_str().ok_or_else(invalid_operation)?;
+                build_result!(GetNymOperation, None, ShortDidValue(dest.to_string()))?
+            }
+            Some(SCHEMA) => {
+                let (name, version) = match (operation["data"]["name"].as_str(), operation["data"]["version"].as_str()) {
+                    (Some(name), Some(version)) => (name, version),
+                    _ => return Err(invalid_operation())
+                };
+                let data = GetSchemaOperationData::new(name.to_string(), version.to_string());
+                build_result!(GetSchemaOperation, None, identifier, data)?
+            }
+            Some(CRED_DEF) => {
+                let (ref_, signature_type) = match (operation["ref"].as_i64(), operation["signature_type"].as_str()) {
+                    (Some(ref_), Some(signature_type)) => (ref_ as i32, signature_type),
+                    _ => return Err(invalid_operation())
+                };
+                let tag = operation["tag"].as_str().map(String::from);
+                build_result!(GetCredDefOperation, None, ref_, signature_type.to_string(), identifier, tag)?
+            }
+            _ => return Ok(None)
+        };
+
+        Ok(Some(request_json))
+    }
+
+    /// Seq_no of the transaction which wrote the object of GET_* reply, `None` if the object isn't written.
+    pub fn parse_written_object_seq_no(&self, reply: &str) -> IndyResult<Option<i32>> {
+        let reply: Value = serde_json::from_str(reply)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
+
+        if reply["op"] != json!("REPLY") {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, format!("Transaction has been failed: {:?}", reply["reason"])));
+        }
+
+        Ok(reply["result"]["seqNo"].as_i64().map(|seq_no| seq_no as i32))
+    }
+
+    /// Checks whether the transaction of GET_TXN reply is written by the request.
+    ///
+    /// The transaction is matched by the submitter, reqId and payload digest of the request which are stored in its metadata,
+    /// so other request with the same submitter and reqId isn't taken for it.
+    pub fn is_txn_written_by_request(&self, get_txn_reply: &str, request_json: &str) -> IndyResult<bool> {
+        let reply: Value = serde_json::from_str(get_txn_reply)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
+
+        if reply["op"] != json!("REPLY") {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, format!("Transaction has been failed: {:?}", reply["reason"])));
+        }
+
+        let request_value: Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        let request: Request<Value> = serde_json::from_value(request_value.clone())
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        let metadata = &reply["result"]["data"]["txn"]["metadata"];
+
+        if metadata["from"].as_str().is_none() ||
+            metadata["from"].as_str() != request.identifier.as_ref().map(|identifier| identifier.0.as_str()) ||
+            metadata["reqId"].as_u64() != Some(request.req_id) {
+            return Ok(false);
+        }
+
+        let txn_payload_digest = metadata["payloadDigest"].as_str()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidTransaction, "Transaction metadata has no payload digest"))?;
+
+        Ok(txn_payload_digest == _payload_digest(request_value)?)
+    }
+}
+
+// Digest of the request without signatures, the same as the pool stores in the transaction metadata
+fn _payload_digest(request: Value) -> IndyResult<String> {
+    let serialized = serialize_signature(request)?;
+    Ok(hex::encode(openssl_hash(serialized.as_bytes())?))
+}
+
+fn _txn_leaf_data(data: &Value) -> IndyResult<Option<Vec<u8>>> {
+    match txn_leaf_value(data)? {
+        Some(leaf) => rmp_serde::to_vec(&leaf)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize transaction")
+            .map(Some),
+        None => Ok(None)
+    }
+}
+
+/// Builds the value of the transaction which is hashed into the ledger merkle tree from GET_TXN reply data.
+///
+/// Returns `None` if the reply has no transaction.
+pub fn txn_leaf_value(data: &Value) -> IndyResult<Option<Value>> {
+    if data["txn"].is_null() && data["txnMetadata"].is_null() &&
+        data["ver"].is_null() && data["reqSignature"].is_null() {
+        return Ok(None);
+    }
+
+    let mut value = json!({});
+
+    for field in &["txn", "txnMetadata", "ver", "reqSignature"] {
+        if !data[field].is_null() {
+            value[field] = data[field].clone();
+        }
+    }
+
+    // Adjust attrib transaction to match stored state
+    if value["txn"]["type"].as_str() == Some(ATTRIB) {
+        for field in &["raw", "enc"] {
+            if let Some(attr) = value["txn"]["data"][field].as_str().map(String::from) {
+                value["txn"]["data"][field] = if attr.is_empty() {
+                    Value::from("")
+                } else {
+                    Value::from(hex::encode(openssl_hash(attr.as_bytes())?))
+                };
+                break;
+            }
+        }
+    }
+
+    Ok(Some(value))
 }
 
 #[cfg(test)]
@@ -844,6 +1390,20 @@ mod tests {
         check_request(&request, expected_result);
     }
 
+    #[test]
+    fn build_get_txn_request_works_for_audit_ledger() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_TXN,
+            "data": 1,
+            "ledgerId": 3
+        });
+
+        let request = ledger_service.build_get_txn_request(Some(&identifier()), Some("AUDIT"), 1).unwrap();
+        check_request(&request, expected_result);
+    }
+
     #[test]
     fn build_get_txn_request_works_for_invalid_type() {
         let ledger_service = LedgerService::new();
@@ -1233,6 +1793,1115 @@ mod tests {
         }
     }
 
+    #[test]
+    fn build_ledgers_freeze_request_works() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": LEDGERS_FREEZE,
+            "ledgers_ids": [1, 2, 5]
+        });
+
+        let request = ledger_service.build_ledgers_freeze_request(&identifier(), vec![1, 2, 5]).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_ledgers_freeze_request_works_for_empty_ledgers_ids() {
+        let ledger_service = LedgerService::new();
+
+        let res = ledger_service.build_ledgers_freeze_request(&identifier(), vec![]);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_get_frozen_ledgers_request_works() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_FROZEN_LEDGERS
+        });
+
+        let request = ledger_service.build_get_frozen_ledgers_request(None).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    mod rich_schema {
+        use super::*;
+        use indy_api_types::validation::Validatable;
+
+        const RS_ID: &str = "did:sov:8a9Bpb6Vs8r7W4oBSZ4aeGRAvqBNE3MRpbFaNDYXv2Ps";
+        const RS_NAME: &str = "SimpleRichSchema";
+        const RS_VERSION: &str = "1.0";
+
+        fn rich_schema() -> RichSchemaObject {
+            RichSchemaObject {
+                id: RS_ID.to_string(),
+                content: json!({"@id": RS_ID, "@type": "rdfs:Class", "name": "string"}).to_string(),
+                rs_name: RS_NAME.to_string(),
+                rs_version: RS_VERSION.to_string(),
+                rs_type: RichSchemaType::Schema,
+                ver: "1".to_string(),
+            }
+        }
+
+        fn get_rich_schema_reply(data: serde_json::Value) -> String {
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+                    "rsType": "sch",
+                    "rsName": RS_NAME,
+                    "rsVersion": RS_VERSION,
+                    "data": data,
+                    "seqNo": 10,
+                    "txnTime": 1_600_000_000,
+                }
+            }).to_string()
+        }
+
+        #[test]
+        fn build_rich_schema_object_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": RICH_SCHEMA,
+                "id": RS_ID,
+                "content": rich_schema().content,
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION,
+                "rsType": "sch",
+                "ver": "1"
+            });
+
+            let request = ledger_service.build_rich_schema_object_request(&identifier(), rich_schema()).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_rich_schema_object_request_works_for_each_type() {
+            let ledger_service = LedgerService::new();
+
+            for (rs_type, txn_type) in &[(RichSchemaType::Context, JSON_LD_CONTEXT),
+                                         (RichSchemaType::Encoding, RICH_SCHEMA_ENCODING),
+                                         (RichSchemaType::Mapping, RICH_SCHEMA_MAPPING),
+                                         (RichSchemaType::CredDef, RICH_SCHEMA_CRED_DEF),
+                                         (RichSchemaType::PresDef, RICH_SCHEMA_PRES_DEF)] {
+                let object = RichSchemaObject { rs_type: *rs_type, ..rich_schema() };
+
+                let request = ledger_service.build_rich_schema_object_request(&identifier(), object).unwrap();
+                let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+                assert_eq!(json!(txn_type), request["operation"]["type"]);
+                assert_eq!(json!(rs_type.to_str()), request["operation"]["rsType"]);
+            }
+        }
+
+        #[test]
+        fn rich_schema_object_validation_works() {
+            assert!(rich_schema().validate().is_ok());
+
+            let context = RichSchemaObject {
+                rs_type: RichSchemaType::Context,
+                content: json!({"@context": {"name": "http://schema.org/name"}}).to_string(),
+                ..rich_schema()
+            };
+            assert!(context.validate().is_ok());
+        }
+
+        #[test]
+        fn rich_schema_object_validation_works_for_invalid_content() {
+            let not_json = RichSchemaObject { content: "not json".to_string(), ..rich_schema() };
+            assert!(not_json.validate().is_err());
+
+            let not_object = RichSchemaObject { content: "[]".to_string(), ..rich_schema() };
+            assert!(not_object.validate().is_err());
+
+            let other_id = RichSchemaObject { content: json!({"@id": "other", "@type": "rdfs:Class"}).to_string(), ..rich_schema() };
+            assert!(other_id.validate().is_err());
+
+            let no_context = RichSchemaObject { rs_type: RichSchemaType::Context, ..rich_schema() };
+            assert!(no_context.validate().is_err());
+
+            let no_name = RichSchemaObject { rs_name: String::new(), ..rich_schema() };
+            assert!(no_name.validate().is_err());
+        }
+
+        #[test]
+        fn build_get_rich_schema_object_by_id_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_RICH_SCHEMA_OBJECT_BY_ID,
+                "id": RS_ID
+            });
+
+            let request = ledger_service.build_get_rich_schema_object_by_id_request(None, RS_ID).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_rich_schema_object_by_metadata_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+                "rsType": "sch",
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION
+            });
+
+            let request = ledger_service.build_get_rich_schema_object_by_metadata_request(None, "sch", RS_NAME, RS_VERSION).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_rich_schema_object_by_metadata_request_works_for_unknown_type() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_get_rich_schema_object_by_metadata_request(None, "unknown", RS_NAME, RS_VERSION);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn parse_get_rich_schema_object_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let reply = get_rich_schema_reply(json!({
+                "id": RS_ID,
+                "content": rich_schema().content,
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION,
+                "rsType": "sch",
+                "ver": "1",
+                "from": IDENTIFIER
+            }));
+
+            let (id, object) = ledger_service.parse_get_rich_schema_object_response(&reply).unwrap();
+            assert_eq!(RS_ID, id);
+
+            let object: RichSchemaObject = serde_json::from_str(&object).unwrap();
+            assert_eq!(rich_schema(), object);
+        }
+
+        #[test]
+        fn parse_get_rich_schema_object_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.parse_get_rich_schema_object_response(&get_rich_schema_reply(serde_json::Value::Null));
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn parse_get_rich_schema_object_response_works_for_other_reply_type() {
+            let ledger_service = LedgerService::new();
+
+            let reply = json!({"op": "REPLY", "result": {"type": GET_SCHEMA, "data": null}}).to_string();
+
+            let res = ledger_service.parse_get_rich_schema_object_response(&reply);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+    }
+
+    mod upgrade_status {
+        use super::*;
+
+        const NODE1: &str = "Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv";
+        const NODE2: &str = "8ECVSk179mjsjKRLWiQtssMLgp6EPhWXtaYyStWPSGAb";
+
+        fn _pool_upgrade(seq_no: u64, version: &str) -> Value {
+            json!({
+                "txn": {
+                    "type": POOL_UPGRADE,
+                    "data": {"name": "upgrade", "version": version, "action": "start", "sha256": "abc", "schedule": {NODE1: "2020-01-25T12:49:05.258870+00:00"}},
+                    "metadata": {"from": "V4SGRU86Z58d6TV7PBUe6f"}
+                },
+                "txnMetadata": {"seqNo": seq_no, "txnTime": 1000 + seq_no},
+                "ver": "1"
+            })
+        }
+
+        fn _node_upgrade(seq_no: u64, node: &str, data: Value) -> Value {
+            json!({
+                "txn": {
+                    "type": NODE_UPGRADE,
+                    "data": data,
+                    "metadata": {"from": node}
+                },
+                "txnMetadata": {"seqNo": seq_no, "txnTime": 1000 + seq_no},
+                "ver": "1"
+            })
+        }
+
+        #[test]
+        fn parse_upgrade_status_works() {
+            let ledger_service = LedgerService::new();
+
+            let txns = vec![
+                _node_upgrade(4, NODE1, json!({"data": {"action": "complete", "version": "1.2"}})),
+                _pool_upgrade(1, "1.1"),
+                _node_upgrade(2, NODE1, json!({"data": {"action": "fail", "version": "1.1"}})),
+                _pool_upgrade(3, "1.2"),
+                _node_upgrade(5, NODE2, json!({"action": "in_progress", "version": "1.2"})),
+                json!({"txn": {"type": POOL_CONFIG, "data": {"writes": true, "force": false}}, "txnMetadata": {"seqNo": 6}}),
+            ];
+
+            let status = ledger_service.parse_upgrade_status(&txns).unwrap();
+
+            let pool_upgrade = status.pool_upgrade.unwrap();
+            assert_eq!("1.2", pool_upgrade.version);
+            assert_eq!(Some(3), pool_upgrade.seq_no);
+            assert_eq!(Some(1003), pool_upgrade.txn_time);
+
+            assert_eq!(2, status.nodes.len());
+            assert_eq!(NodeUpgradeStatus { action: "complete".to_string(), version: "1.2".to_string(), seq_no: Some(4), txn_time: Some(1004) },
+                       status.nodes[NODE1]);
+            assert_eq!("in_progress", status.nodes[NODE2].action);
+        }
+
+        #[test]
+        fn parse_upgrade_status_works_for_no_upgrades() {
+            let ledger_service = LedgerService::new();
+
+            let status = ledger_service.parse_upgrade_status(&[]).unwrap();
+            assert_eq!(UpgradeStatus::default(), status);
+        }
+
+        #[test]
+        fn parse_upgrade_status_works_for_invalid_node_upgrade() {
+            let ledger_service = LedgerService::new();
+
+            let txns = vec![_node_upgrade(1, NODE1, json!({"data": {"action": "complete"}}))];
+
+            let res = ledger_service.parse_upgrade_status(&txns);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
+
+    mod get_txn_range {
+        use rust_base58::ToBase58;
+
+        use super::*;
+
+        const LEDGER_SIZE: u64 = 5;
+
+        fn _txn(seq_no: u64) -> serde_json::Value {
+            json!({"txn": {"type": NYM, "data": {"dest": format!("dest{}", seq_no)}}, "txnMetadata": {"seqNo": seq_no}, "ver": "1"})
+        }
+
+        fn _get_txn_reply(seq_no: u64) -> serde_json::Value {
+            let txns = (1..=LEDGER_SIZE).map(_txn).collect::<Vec<serde_json::Value>>();
+            let mt = MerkleTree::from_vec(txns.iter().map(|txn| rmp_serde::to_vec(txn).unwrap()).collect()).unwrap();
+
+            let mut data = txns[seq_no as usize - 1].clone();
+            data["auditPath"] = json!(mt.gen_audit_path(seq_no as usize - 1).unwrap().iter().map(|hash| hash.to_base58()).collect::<Vec<String>>());
+            data["rootHash"] = json!(mt.root_hash().to_base58());
+            data["ledgerSize"] = json!(LEDGER_SIZE);
+
+            let state_proof = json!({"multi_signature": {"value": {"txn_root_hash": mt.root_hash().to_base58()}}});
+
+            json!({"op": "REPLY", "result": {"type": GET_TXN, "seqNo": seq_no, "data": data, "state_proof": state_proof}})
+        }
+
+        #[test]
+        fn parse_get_txn_range_reply_works() {
+            let ledger_service = LedgerService::new();
+
+            for seq_no in 1..=LEDGER_SIZE {
+                let reply = _get_txn_reply(seq_no);
+                let txn = ledger_service.parse_get_txn_range_reply(&reply.to_string(), seq_no as i32).unwrap().unwrap();
+
+                assert_eq!(reply["result"]["data"], txn.txn);
+                assert_eq!(LEDGER_SIZE, txn.ledger_size);
+                assert_eq!(reply["result"]["data"]["rootHash"].as_str().unwrap(), txn.root_hash);
+            }
+        }
+
+        #[test]
+        fn build_ledger_frontier_works() {
+            let ledger_service = LedgerService::new();
+
+            for seq_no in 1..=LEDGER_SIZE {
+                let txn = ledger_service.parse_get_txn_range_reply(&_get_txn_reply(seq_no).to_string(), seq_no as i32).unwrap().unwrap();
+                let frontier = ledger_service.build_ledger_frontier(&txn, seq_no as i32).unwrap();
+
+                let mt = MerkleTree::from_vec((1..=seq_no).map(|seq_no| rmp_serde::to_vec(&_txn(seq_no)).unwrap()).collect()).unwrap();
+                assert_eq!(seq_no as usize, frontier.count);
+                assert_eq!(mt.root_hash(), &frontier.root_hash().unwrap());
+            }
+        }
+
+        #[test]
+        fn parse_get_txn_range_reply_works_for_no_txn() {
+            let ledger_service = LedgerService::new();
+
+            let reply = json!({"op": "REPLY", "result": {"type": GET_TXN, "seqNo": 6, "data": null}});
+            assert!(ledger_service.parse_get_txn_range_reply(&reply.to_string(), 6).unwrap().is_none());
+        }
+
+        #[test]
+        fn parse_get_txn_range_reply_works_for_changed_txn() {
+            let ledger_service = LedgerService::new();
+
+            let mut reply = _get_txn_reply(2);
+            reply["result"]["data"]["txn"]["data"]["dest"] = json!("other");
+
+            let res = ledger_service.parse_get_txn_range_reply(&reply.to_string(), 2);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+
+        #[test]
+        fn parse_get_txn_range_reply_works_for_other_txn() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.parse_get_txn_range_reply(&_get_txn_reply(2).to_string(), 3);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+
+        #[test]
+        fn parse_get_txn_range_reply_works_for_other_signed_root() {
+            let ledger_service = LedgerService::new();
+
+            let mut reply = _get_txn_reply(2);
+            reply["result"]["state_proof"] = json!({"multi_signature": {"value": {"txn_root_hash": "other"}}});
+
+            let res = ledger_service.parse_get_txn_range_reply(&reply.to_string(), 2);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+
+        #[test]
+        fn parse_get_txn_range_reply_works_for_no_multi_signature() {
+            let ledger_service = LedgerService::new();
+
+            let mut reply = _get_txn_reply(2);
+            reply["result"].as_object_mut().unwrap().remove("state_proof");
+
+            let res = ledger_service.parse_get_txn_range_reply(&reply.to_string(), 2);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+
+        #[test]
+        fn parse_get_txn_range_reply_works_for_reqnack() {
+            let ledger_service = LedgerService::new();
+
+            let reply = json!({"op": "REQNACK", "reqId": 1, "reason": "reason"});
+            let res = ledger_service.parse_get_txn_range_reply(&reply.to_string(), 1);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+    }
+
+    mod ledger_catchup {
+        use super::*;
+
+        fn _txn(seq_no: usize) -> serde_json::Value {
+            json!({"txn": {"type": NYM, "data": {"dest": format!("dest{}", seq_no)}}, "txnMetadata": {"seqNo": seq_no}, "ver": "1"})
+        }
+
+        fn _merkle_tree(size: usize) -> MerkleTree {
+            MerkleTree::from_vec((1..=size).map(|seq_no| rmp_serde::to_vec(&_txn(seq_no)).unwrap()).collect()).unwrap()
+        }
+
+        fn _frontier(size: usize) -> MerkleTreeFrontier {
+            let mut frontier = MerkleTreeFrontier::default();
+            for seq_no in 1..=size {
+                frontier.append(&rmp_serde::to_vec(&_txn(seq_no)).unwrap()).unwrap();
+            }
+            frontier
+        }
+
+        fn _ledger_catchup_reply(from: usize, size: usize) -> serde_json::Value {
+            let txns = (from..=size)
+                .map(|seq_no| (seq_no.to_string(), _txn(seq_no)))
+                .collect::<serde_json::Map<String, serde_json::Value>>();
+
+            json!({"ledgerId": 1, "txnSeqNo": size, "merkleRoot": _merkle_tree(size).root_hash().to_base58(), "txns": txns})
+        }
+
+        #[test]
+        fn parse_ledger_catchup_reply_works() {
+            let ledger_service = LedgerService::new();
+
+            let mut frontier = _frontier(2);
+            let txns = ledger_service.parse_ledger_catchup_reply(&_ledger_catchup_reply(3, 5).to_string(), &mut frontier).unwrap();
+
+            assert_eq!(vec![_txn(3), _txn(4), _txn(5)], txns);
+            assert_eq!(5, frontier.count);
+            assert_eq!(_merkle_tree(5).root_hash(), &frontier.root_hash().unwrap());
+        }
+
+        #[test]
+        fn parse_ledger_catchup_reply_works_for_up_to_date_ledger() {
+            let ledger_service = LedgerService::new();
+
+            let mut frontier = _frontier(2);
+            let txns = ledger_service.parse_ledger_catchup_reply(&_ledger_catchup_reply(3, 2).to_string(), &mut frontier).unwrap();
+
+            assert!(txns.is_empty());
+            assert_eq!(2, frontier.count);
+        }
+
+        #[test]
+        fn parse_ledger_catchup_reply_works_for_changed_txn() {
+            let ledger_service = LedgerService::new();
+
+            let mut reply = _ledger_catchup_reply(3, 5);
+            reply["txns"]["4"]["txn"]["data"]["dest"] = json!("other");
+
+            let mut frontier = _frontier(2);
+            let res = ledger_service.parse_ledger_catchup_reply(&reply.to_string(), &mut frontier);
+
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+            assert_eq!(2, frontier.count);
+        }
+
+        #[test]
+        fn parse_ledger_catchup_reply_works_for_missed_txn() {
+            let ledger_service = LedgerService::new();
+
+            let mut reply = _ledger_catchup_reply(3, 5);
+            reply["txns"].as_object_mut().unwrap().remove("4");
+
+            let res = ledger_service.parse_ledger_catchup_reply(&reply.to_string(), &mut _frontier(2));
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+
+        #[test]
+        fn parse_ledger_catchup_reply_works_for_other_local_ledger() {
+            let ledger_service = LedgerService::new();
+
+            let mut frontier = MerkleTreeFrontier::default();
+            frontier.append(&rmp_serde::to_vec(&_txn(10)).unwrap()).unwrap();
+            frontier.append(&rmp_serde::to_vec(&_txn(11)).unwrap()).unwrap();
+            let res = ledger_service.parse_ledger_catchup_reply(&_ledger_catchup_reply(3, 5).to_string(), &mut frontier);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+
+        #[test]
+        fn parse_ledger_type_works() {
+            let ledger_service = LedgerService::new();
+
+            assert_eq!(1, ledger_service.parse_ledger_type(None).unwrap());
+            assert_eq!(0, ledger_service.parse_ledger_type(Some("POOL")).unwrap());
+            assert_eq!(10, ledger_service.parse_ledger_type(Some("10")).unwrap());
+            assert_kind!(IndyErrorKind::InvalidStructure, ledger_service.parse_ledger_type(Some("other")));
+        }
+    }
+
+    mod written_object {
+        use super::*;
+
+        fn _schema_request() -> String {
+            let mut attr_names: AttributeNames = AttributeNames::new();
+            attr_names.0.insert("male".to_string());
+
+            let data = SchemaV1 {
+                id: SchemaId::new(&identifier(), "name", "1.0"),
+                name: "name".to_string(),
+                version: "1.0".to_string(),
+                attr_names,
+                seq_no: None,
+            };
+
+            LedgerService::new().build_schema_request(&identifier(), data).unwrap()
+        }
+
+        fn _get_txn_reply(from: &str, req_id: u64, payload_digest: &str) -> String {
+            json!({
+                "op": "REPLY",
+                "result": {"type": GET_TXN, "seqNo": 5, "data": {"txn": {"type": NYM, "metadata": {"from": from, "reqId": req_id, "payloadDigest": payload_digest}}}}
+            }).to_string()
+        }
+
+        #[test]
+        fn build_get_written_object_request_works_for_nym() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_nym_request(&identifier(), &dest(), None, None, None).unwrap();
+            let get_request = ledger_service.build_get_written_object_request(&request).unwrap().unwrap();
+
+            check_request(&get_request, json!({"type": GET_NYM, "dest": DEST}));
+        }
+
+        #[test]
+        fn build_get_written_object_request_works_for_schema() {
+            let ledger_service = LedgerService::new();
+
+            let get_request = ledger_service.build_get_written_object_request(&_schema_request()).unwrap().unwrap();
+
+            check_request(&get_request, json!({"type": GET_SCHEMA, "dest": IDENTIFIER, "data": {"name": "name", "version": "1.0"}}));
+        }
+
+        #[test]
+        fn build_get_written_object_request_works_for_unsupported_type() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_attrib_request(&identifier(), &dest(), Some("hash"), None, None).unwrap();
+            assert!(ledger_service.build_get_written_object_request(&request).unwrap().is_none());
+        }
+
+        #[test]
+        fn parse_written_object_seq_no_works() {
+            let ledger_service = LedgerService::new();
+
+            let reply = json!({"op": "REPLY", "result": {"type": GET_NYM, "seqNo": 5, "data": "{}"}}).to_string();
+            assert_eq!(Some(5), ledger_service.parse_written_object_seq_no(&reply).unwrap());
+
+            let reply = json!({"op": "REPLY", "result": {"type": GET_NYM, "seqNo": null, "data": null}}).to_string();
+            assert_eq!(None, ledger_service.parse_written_object_seq_no(&reply).unwrap());
+
+            let reply = json!({"op": "REQNACK", "reason": "reason"}).to_string();
+            assert_kind!(IndyErrorKind::InvalidTransaction, ledger_service.parse_written_object_seq_no(&reply));
+        }
+
+        #[test]
+        fn is_txn_written_by_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_nym_request(&identifier(), &dest(), None, None, None).unwrap();
+            let request_value = serde_json::from_str::<serde_json::Value>(&request).unwrap();
+            let req_id = request_value["reqId"].as_u64().unwrap();
+            let payload_digest = _payload_digest(request_value).unwrap();
+
+            assert!(ledger_service.is_txn_written_by_request(&_get_txn_reply(IDENTIFIER, req_id, &payload_digest), &request).unwrap());
+            assert!(!ledger_service.is_txn_written_by_request(&_get_txn_reply(IDENTIFIER, req_id + 1, &payload_digest), &request).unwrap());
+            assert!(!ledger_service.is_txn_written_by_request(&_get_txn_reply(DEST, req_id, &payload_digest), &request).unwrap());
+        }
+
+        #[test]
+        fn is_txn_written_by_request_works_for_other_payload_with_same_req_id() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_nym_request(&identifier(), &dest(), None, None, None).unwrap();
+            let mut request_value = serde_json::from_str::<serde_json::Value>(&request).unwrap();
+            let req_id = request_value["reqId"].as_u64().unwrap();
+
+            request_value["operation"]["role"] = json!("0");
+            let other_payload_digest = _payload_digest(request_value).unwrap();
+
+            assert!(!ledger_service.is_txn_written_by_request(&_get_txn_reply(IDENTIFIER, req_id, &other_payload_digest), &request).unwrap());
+        }
+
+        #[test]
+        fn is_txn_written_by_request_works_for_no_payload_digest() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_nym_request(&identifier(), &dest(), None, None, None).unwrap();
+            let req_id = serde_json::from_str::<serde_json::Value>(&request).unwrap()["reqId"].as_u64().unwrap();
+
+            let reply = json!({
+                "op": "REPLY",
+                "result": {"type": GET_TXN, "seqNo": 5, "data": {"txn": {"type": NYM, "metadata": {"from": IDENTIFIER, "reqId": req_id}}}}
+            }).to_string();
+
+            assert_kind!(IndyErrorKind::InvalidTransaction, ledger_service.is_txn_written_by_request(&reply, &request));
+        }
+    }
+
+    mod did_document {
+        use super::*;
+
+        const ABBREVIATED_VERKEY: &str = "~8DfbjXLth7APvt3qQPgtf";
+        const FULL_VERKEY: &str = "GjZWsBLgZCR18aL468JAT7eiUPAQnHG3T61YxHJz6SEb";
+
+        fn _nym_data(verkey: Option<&str>) -> NymData {
+            NymData {
+                did: dest().to_short(),
+                verkey: verkey.map(String::from),
+                role: None,
+            }
+        }
+
+        #[test]
+        fn build_did_document_works() {
+            let ledger_service = LedgerService::new();
+
+            let endpoint = json!({"endpoint": {"endpoint": "https://agent.example.com", "routingKeys": [VERKEY]}}).to_string();
+            let did_doc = ledger_service.build_did_document(&_nym_data(Some(VERKEY)), Some(&endpoint)).unwrap();
+
+            let expected = json!({
+                "@context": "https://www.w3.org/ns/did/v1",
+                "id": "did:sov:VsKV7grR1BUE29mG2Fm2kX",
+                "verificationMethod": [{
+                    "id": "did:sov:VsKV7grR1BUE29mG2Fm2kX#key-1",
+                    "type": "Ed25519VerificationKey2018",
+                    "controller": "did:sov:VsKV7grR1BUE29mG2Fm2kX",
+                    "publicKeyBase58": VERKEY
+                }],
+                "authentication": ["did:sov:VsKV7grR1BUE29mG2Fm2kX#key-1"],
+                "service": [{
+                    "id": "did:sov:VsKV7grR1BUE29mG2Fm2kX#endpoint",
+                    "type": "endpoint",
+                    "serviceEndpoint": "https://agent.example.com",
+                    "routingKeys": [VERKEY]
+                }]
+            });
+
+            assert_eq!(expected, serde_json::to_value(&did_doc).unwrap());
+        }
+
+        #[test]
+        fn build_did_document_works_for_abbreviated_verkey() {
+            let ledger_service = LedgerService::new();
+
+            let did_doc = ledger_service.build_did_document(&_nym_data(Some(ABBREVIATED_VERKEY)), None).unwrap();
+
+            assert_eq!(FULL_VERKEY, did_doc.verification_method[0].public_key_base58);
+            assert!(did_doc.service.is_empty());
+        }
+
+        #[test]
+        fn build_did_document_works_for_legacy_endpoint() {
+            let ledger_service = LedgerService::new();
+
+            let endpoint = json!({"endpoint": {"ha": "127.0.0.1:9700", "verkey": VERKEY}}).to_string();
+            let did_doc = ledger_service.build_did_document(&_nym_data(Some(VERKEY)), Some(&endpoint)).unwrap();
+
+            assert_eq!("127.0.0.1:9700", did_doc.service[0].service_endpoint);
+            assert!(did_doc.service[0].routing_keys.is_empty());
+        }
+
+        #[test]
+        fn build_did_document_works_for_invalid_endpoint() {
+            let ledger_service = LedgerService::new();
+
+            let did_doc = ledger_service.build_did_document(&_nym_data(Some(VERKEY)), Some("{\"endpoint\": 1}")).unwrap();
+
+            assert!(did_doc.service.is_empty());
+        }
+
+        #[test]
+        fn build_did_document_works_for_no_verkey() {
+            let ledger_service = LedgerService::new();
+
+            let did_doc = ledger_service.build_did_document(&_nym_data(None), None).unwrap();
+
+            assert!(did_doc.verification_method.is_empty());
+            assert!(did_doc.authentication.is_empty());
+        }
+
+        #[test]
+        fn build_did_document_works_for_unsupported_verkey_type() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_did_document(&_nym_data(Some(format!("{}:secp256k1", VERKEY).as_str())), None);
+
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+
+        #[test]
+        fn parse_get_attrib_raw_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let reply = json!({"op": "REPLY", "result": {"type": GET_ATTR, "raw": "endpoint", "data": "{\"endpoint\":{\"ha\":\"127.0.0.1:9700\"}}"}});
+            assert_eq!(Some("{\"endpoint\":{\"ha\":\"127.0.0.1:9700\"}}".to_string()),
+                       ledger_service.parse_get_attrib_raw_response(&reply.to_string()).unwrap());
+
+            let reply = json!({"op": "REPLY", "result": {"type": GET_ATTR, "raw": "endpoint", "data": null}});
+            assert_eq!(None, ledger_service.parse_get_attrib_raw_response(&reply.to_string()).unwrap());
+        }
+    }
+
+    mod endorsement {
+        use super::*;
+        use crate::domain::ledger::endorsement::EndorsementSigner;
+
+        const TRUSTEE_DID: &str = "V4SGRU86Z58d6TV7PBUe6f";
+        const STEWARD_DID: &str = "8wZcEriaNLNKtteJvx7f8i";
+
+        fn role(role: &str, sig_count: u32, need_to_be_owner: bool) -> RoleConstraint {
+            RoleConstraint {
+                sig_count,
+                role: Some(role.to_string()),
+                metadata: None,
+                need_to_be_owner,
+                off_ledger_signature: false,
+            }
+        }
+
+        fn signer(role: Option<&str>, owner: bool) -> AuthSigner {
+            AuthSigner { role: role.map(String::from), owner }
+        }
+
+        fn trustee_or_two_stewards() -> Constraint {
+            Constraint::OrConstraint(CombinationConstraint {
+                auth_constraints: vec![
+                    Constraint::RoleConstraint(role("0", 1, false)),
+                    Constraint::RoleConstraint(role("2", 2, false)),
+                ]
+            })
+        }
+
+        fn get_auth_rule_reply(auth_type: &str, constraint: &Constraint) -> String {
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_AUTH_RULE,
+                    "reqId": 1,
+                    "identifier": IDENTIFIER,
+                    "data": [{
+                        "auth_type": auth_type,
+                        "auth_action": "ADD",
+                        "field": "role",
+                        "old_value": null,
+                        "new_value": "101",
+                        "constraint": constraint
+                    }]
+                }
+            }).to_string()
+        }
+
+        fn nym_request() -> String {
+            json!({
+                "reqId": 1,
+                "identifier": TRUSTEE_DID,
+                "operation": {"type": NYM, "dest": DEST, "role": "101"},
+                "protocolVersion": 2
+            }).to_string()
+        }
+
+        #[test]
+        fn constraint_unmet_works_for_role() {
+            let constraint = Constraint::RoleConstraint(role("2", 2, false));
+
+            assert_eq!(vec![UnmetConstraint { constraint: constraint.clone(), signatures: 1 }],
+                       constraint.unmet(&[signer(Some("2"), false), signer(Some("0"), false)]));
+            assert!(constraint.unmet(&[signer(Some("2"), false), signer(Some("2"), false)]).is_empty());
+        }
+
+        #[test]
+        fn constraint_unmet_works_for_any_role_and_owner() {
+            let constraint = Constraint::RoleConstraint(role(ANY_ROLE, 1, true));
+
+            assert_eq!(1, constraint.unmet(&[signer(Some("0"), false)]).len());
+            assert!(constraint.unmet(&[signer(None, true)]).is_empty());
+        }
+
+        #[test]
+        fn constraint_unmet_works_for_identity_owner() {
+            let constraint = Constraint::RoleConstraint(role("", 1, false));
+
+            assert_eq!(1, constraint.unmet(&[signer(Some("0"), false)]).len());
+            assert!(constraint.unmet(&[signer(None, false)]).is_empty());
+        }
+
+        #[test]
+        fn constraint_unmet_works_for_or() {
+            let constraint = trustee_or_two_stewards();
+
+            assert!(constraint.unmet(&[signer(Some("0"), false)]).is_empty());
+            assert_eq!(vec![UnmetConstraint { constraint: Constraint::RoleConstraint(role("0", 1, false)), signatures: 0 }],
+                       constraint.unmet(&[]));
+        }
+
+        #[test]
+        fn constraint_unmet_works_for_or_closest_alternative() {
+            let constraint = Constraint::OrConstraint(CombinationConstraint {
+                auth_constraints: vec![
+                    Constraint::RoleConstraint(role("0", 2, false)),
+                    Constraint::RoleConstraint(role("2", 2, false)),
+                ]
+            });
+
+            assert_eq!(vec![UnmetConstraint { constraint: Constraint::RoleConstraint(role("2", 2, false)), signatures: 1 }],
+                       constraint.unmet(&[signer(Some("2"), false)]));
+        }
+
+        #[test]
+        fn constraint_unmet_works_for_and() {
+            let constraint = Constraint::AndConstraint(CombinationConstraint {
+                auth_constraints: vec![
+                    Constraint::RoleConstraint(role("0", 1, false)),
+                    Constraint::RoleConstraint(role("2", 1, false)),
+                ]
+            });
+
+            assert_eq!(vec![UnmetConstraint { constraint: Constraint::RoleConstraint(role("2", 1, false)), signatures: 0 }],
+                       constraint.unmet(&[signer(Some("0"), false)]));
+            assert!(constraint.unmet(&[signer(Some("0"), false), signer(Some("2"), false)]).is_empty());
+        }
+
+        #[test]
+        fn constraint_unmet_works_for_forbidden() {
+            let constraint = Constraint::ForbiddenConstraint(ForbiddenConstraint {});
+
+            assert_eq!(1, constraint.unmet(&[signer(Some("0"), false)]).len());
+            assert!(constraint.alternatives().is_empty());
+        }
+
+        #[test]
+        fn constraint_alternatives_works() {
+            let constraint = Constraint::AndConstraint(CombinationConstraint {
+                auth_constraints: vec![
+                    trustee_or_two_stewards(),
+                    Constraint::RoleConstraint(role(ANY_ROLE, 1, true)),
+                ]
+            });
+
+            assert_eq!(vec![
+                vec![role("0", 1, false), role(ANY_ROLE, 1, true)],
+                vec![role("2", 2, false), role(ANY_ROLE, 1, true)],
+            ], constraint.alternatives());
+        }
+
+        #[test]
+        fn prepare_endorsement_works() {
+            let ledger_service = LedgerService::new();
+
+            let constraint = trustee_or_two_stewards();
+
+            let envelope = ledger_service.prepare_endorsement(&nym_request(), &get_auth_rule_reply(NYM, &constraint), Some(&dest())).unwrap();
+
+            assert_eq!(constraint, envelope.constraint);
+            assert_eq!(Some(DEST.to_string()), envelope.owner);
+            assert_eq!(vec![vec![role("0", 1, false)], vec![role("2", 2, false)]], envelope.required_signers);
+            assert!(envelope.signers.is_empty());
+        }
+
+        #[test]
+        fn prepare_endorsement_works_for_other_txn_type() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.prepare_endorsement(&nym_request(), &get_auth_rule_reply(ATTRIB, &trustee_or_two_stewards()), None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        fn get_nym_reply(did: &str, verkey: &str, role: Option<&str>) -> String {
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_NYM,
+                    "data": json!({"dest": did, "verkey": verkey, "role": role}).to_string(),
+                }
+            }).to_string()
+        }
+
+        #[test]
+        fn endorsement_status_works() {
+            let ledger_service = LedgerService::new();
+
+            let mut envelope = ledger_service.prepare_endorsement(&nym_request(), &get_auth_rule_reply(NYM, &trustee_or_two_stewards()), None).unwrap();
+            envelope.signers.insert(STEWARD_DID.to_string(), EndorsementSigner { verkey: VERKEY.to_string() });
+            envelope.signers.insert(TRUSTEE_DID.to_string(), EndorsementSigner { verkey: VERKEY.to_string() });
+
+            let nyms = vec![get_nym_reply(STEWARD_DID, VERKEY, Some("2")), get_nym_reply(TRUSTEE_DID, VERKEY, Some("0"))];
+
+            let signatures = EndorsementSignatures { valid: vec![STEWARD_DID.to_string()], ..EndorsementSignatures::default() };
+            let status = ledger_service.endorsement_status(&envelope, signatures, &nyms).unwrap();
+            assert!(!status.ready);
+            assert_eq!(1, status.unmet.len());
+
+            let signatures = EndorsementSignatures { valid: vec![TRUSTEE_DID.to_string()], ..EndorsementSignatures::default() };
+            let status = ledger_service.endorsement_status(&envelope, signatures, &nyms).unwrap();
+            assert!(status.ready);
+            assert!(status.unmet.is_empty());
+            assert!(status.unresolved.is_empty());
+
+            let signatures = EndorsementSignatures { valid: vec![TRUSTEE_DID.to_string()], invalid: vec![STEWARD_DID.to_string()], ..EndorsementSignatures::default() };
+            let status = ledger_service.endorsement_status(&envelope, signatures, &nyms).unwrap();
+            assert!(!status.ready);
+            assert!(status.unmet.is_empty());
+        }
+
+        #[test]
+        fn endorsement_status_works_for_signer_without_nym() {
+            let ledger_service = LedgerService::new();
+
+            let mut envelope = ledger_service.prepare_endorsement(&nym_request(), &get_auth_rule_reply(NYM, &trustee_or_two_stewards()), None).unwrap();
+            envelope.signers.insert(TRUSTEE_DID.to_string(), EndorsementSigner { verkey: VERKEY.to_string() });
+
+            let nyms = vec![json!({"op": "REPLY", "result": {"type": GET_NYM, "data": null}}).to_string()];
+
+            let signatures = EndorsementSignatures { valid: vec![TRUSTEE_DID.to_string()], ..EndorsementSignatures::default() };
+            let status = ledger_service.endorsement_status(&envelope, signatures, &nyms).unwrap();
+            assert!(!status.ready);
+            assert_eq!(vec![TRUSTEE_DID.to_string()], status.unresolved);
+        }
+
+        #[test]
+        fn endorsement_status_works_for_other_nym_verkey() {
+            let ledger_service = LedgerService::new();
+
+            let mut envelope = ledger_service.prepare_endorsement(&nym_request(), &get_auth_rule_reply(NYM, &trustee_or_two_stewards()), None).unwrap();
+            envelope.signers.insert(TRUSTEE_DID.to_string(), EndorsementSigner { verkey: VERKEY.to_string() });
+
+            let nyms = vec![get_nym_reply(TRUSTEE_DID, "~NcYxiDXkpYi6ov5FcYDi1e", Some("0"))];
+
+            let signatures = EndorsementSignatures { valid: vec![TRUSTEE_DID.to_string()], ..EndorsementSignatures::default() };
+            let status = ledger_service.endorsement_status(&envelope, signatures, &nyms).unwrap();
+            assert!(!status.ready);
+            assert_eq!(vec![TRUSTEE_DID.to_string()], status.unresolved);
+        }
+    }
+
+    mod evaluate_auth_rule {
+        use super::*;
+
+        fn role(role: &str, sig_count: u32, fees: Option<&str>) -> Constraint {
+            Constraint::RoleConstraint(RoleConstraint {
+                sig_count,
+                role: Some(role.to_string()),
+                metadata: fees.map(|fees| json!({"fees": fees})),
+                need_to_be_owner: false,
+                off_ledger_signature: false,
+            })
+        }
+
+        fn signer(role: Option<&str>) -> AuthSigner {
+            AuthSigner { role: role.map(String::from), owner: false }
+        }
+
+        fn rule(auth_action: &str, field: &str, old_value: Option<&str>, new_value: Option<&str>, constraint: Constraint) -> serde_json::Value {
+            json!({
+                "auth_type": NYM,
+                "auth_action": auth_action,
+                "field": field,
+                "old_value": old_value,
+                "new_value": new_value,
+                "constraint": constraint
+            })
+        }
+
+        fn get_auth_rule_reply() -> String {
+            let trustee_or_steward = Constraint::OrConstraint(CombinationConstraint {
+                auth_constraints: vec![role("0", 1, Some("add_endorser_by_trustee")), role("2", 1, Some("add_endorser"))]
+            });
+
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_AUTH_RULE,
+                    "reqId": 1,
+                    "identifier": IDENTIFIER,
+                    "data": [
+                        rule("ADD", "role", None, Some(""), role("101", 1, None)),
+                        rule("ADD", "role", None, Some("101"), trustee_or_steward),
+                        rule("ADD", "role", None, Some("0"), role("0", 1, None)),
+                        rule("EDIT", "verkey", Some("*"), Some("*"), Constraint::ForbiddenConstraint(ForbiddenConstraint {})),
+                    ]
+                }
+            }).to_string()
+        }
+
+        fn nym_request(role: Option<&str>) -> String {
+            json!({
+                "reqId": 1,
+                "identifier": IDENTIFIER,
+                "operation": {"type": NYM, "dest": DEST, "role": role},
+                "protocolVersion": 2
+            }).to_string()
+        }
+
+        #[test]
+        fn evaluate_auth_rule_works() {
+            let ledger_service = LedgerService::new();
+
+            let evaluation = ledger_service.evaluate_auth_rule(&nym_request(Some("101")), "ADD", None, &get_auth_rule_reply(), &[signer(Some("STEWARD"))]).unwrap();
+
+            assert!(evaluation.accepted);
+            assert_eq!(1, evaluation.rules.len());
+            assert_eq!(Some("101".to_string()), evaluation.rules[0].new_value);
+            assert_eq!(vec!["add_endorser".to_string()], evaluation.rules[0].fees);
+        }
+
+        #[test]
+        fn evaluate_auth_rule_works_for_unmet_constraint() {
+            let ledger_service = LedgerService::new();
+
+            let evaluation = ledger_service.evaluate_auth_rule(&nym_request(Some("0")), "ADD", None, &get_auth_rule_reply(), &[signer(Some("2"))]).unwrap();
+
+            assert!(!evaluation.accepted);
+            assert!(!evaluation.rules[0].satisfied);
+            assert_eq!(vec![UnmetConstraint { constraint: role("0", 1, None), signatures: 0 }], evaluation.rules[0].unmet);
+            assert!(evaluation.rules[0].fees.is_empty());
+        }
+
+        #[test]
+        fn evaluate_auth_rule_works_for_new_nym_with_verkey() {
+            let ledger_service = LedgerService::new();
+
+            let request = json!({"reqId": 1, "identifier": IDENTIFIER, "operation": {"type": NYM, "dest": DEST, "verkey": VERKEY}}).to_string();
+
+            let evaluation = ledger_service.evaluate_auth_rule(&request, "ADD", None, &get_auth_rule_reply(), &[signer(Some("ENDORSER"))]).unwrap();
+
+            assert!(evaluation.accepted);
+            assert_eq!(1, evaluation.rules.len());
+            assert_eq!("role", evaluation.rules[0].field);
+        }
+
+        #[test]
+        fn evaluate_auth_rule_works_for_forbidden_rule() {
+            let ledger_service = LedgerService::new();
+
+            let request = json!({"reqId": 1, "identifier": IDENTIFIER, "operation": {"type": NYM, "dest": DEST, "verkey": VERKEY}}).to_string();
+
+            let evaluation = ledger_service.evaluate_auth_rule(&request, "EDIT", Some(VERKEY), &get_auth_rule_reply(), &[signer(Some("0"))]).unwrap();
+
+            assert!(!evaluation.accepted);
+            assert_eq!(1, evaluation.rules.len());
+            assert_eq!("verkey", evaluation.rules[0].field);
+        }
+
+        #[test]
+        fn evaluate_auth_rule_works_for_no_applicable_rule() {
+            let ledger_service = LedgerService::new();
+
+            let evaluation = ledger_service.evaluate_auth_rule(&nym_request(Some("201")), "ADD", None, &get_auth_rule_reply(), &[signer(Some("0"))]).unwrap();
+
+            assert!(!evaluation.accepted);
+            assert!(evaluation.rules.is_empty());
+        }
+
+        #[test]
+        fn evaluate_auth_rule_works_for_invalid_role() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.evaluate_auth_rule(&nym_request(Some("101")), "ADD", None, &get_auth_rule_reply(), &[signer(Some("SUPERUSER"))]);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn evaluate_auth_rule_works_for_invalid_action() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.evaluate_auth_rule(&nym_request(Some("101")), "REMOVE", None, &get_auth_rule_reply(), &[signer(Some("0"))]);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        fn auth_rule(auth_action: &str, field: &str, old_value: Option<&str>, new_value: Option<&str>) -> AuthRule {
+            serde_json::from_value(rule(auth_action, field, old_value, new_value, role("0", 1, None))).unwrap()
+        }
+
+        #[test]
+        fn auth_rule_matches_works_for_action() {
+            let rule = auth_rule("ADD", "role", None, Some("101"));
+
+            assert!(rule.matches(&AuthAction::ADD, None, &json!({"role": "101"})));
+            assert!(!rule.matches(&AuthAction::EDIT, Some("101"), &json!({"role": "101"})));
+        }
+
+        #[test]
+        fn auth_rule_matches_works_for_old_value() {
+            let rule = auth_rule("EDIT", "role", Some("0"), Some("2"));
+
+            assert!(rule.matches(&AuthAction::EDIT, Some("0"), &json!({"role": "2"})));
+            assert!(!rule.matches(&AuthAction::EDIT, Some("101"), &json!({"role": "2"})));
+            assert!(!rule.matches(&AuthAction::EDIT, None, &json!({"role": "2"})));
+
+            let rule = auth_rule("EDIT", "role", Some("*"), Some("2"));
+            assert!(rule.matches(&AuthAction::EDIT, Some("101"), &json!({"role": "2"})));
+        }
+
+        #[test]
+        fn auth_rule_matches_works_for_any_and_absent_value() {
+            let rule = auth_rule("ADD", "role", None, Some("*"));
+            assert!(rule.matches(&AuthAction::ADD, None, &json!({"role": "101"})));
+            assert!(rule.matches(&AuthAction::ADD, None, &json!({})));
+
+            let rule = auth_rule("ADD", "role", None, Some(""));
+            assert!(rule.matches(&AuthAction::ADD, None, &json!({"role": null})));
+            assert!(!rule.matches(&AuthAction::ADD, None, &json!({"role": "101"})));
+        }
+    }
+
     #[test]
     fn datetime_to_date() {
         assert_eq!(0, LedgerService::datetime_to_date_timestamp(0));
@@ -64,12 +64,33 @@ impl From<&IssuerCommand> for CommandIndex {
             IssuerCommand::CreateCredential(_, _, _, _, _, _, _) => {
                 CommandIndex::IssuerCommandCreateCredential
             }
+            IssuerCommand::CreateCredentialWithRollover(_, _, _, _, _, _, _, _, _) => {
+                CommandIndex::IssuerCommandCreateCredentialWithRollover
+            }
+            IssuerCommand::CreateCredentialWithRolloverContinue(_, _) => {
+                CommandIndex::IssuerCommandCreateCredentialWithRolloverContinue
+            }
             IssuerCommand::RevokeCredential(_, _, _, _, _) => {
                 CommandIndex::IssuerCommandRevokeCredential
             }
             IssuerCommand::MergeRevocationRegistryDeltas(_, _, _) => {
                 CommandIndex::IssuerCommandMergeRevocationRegistryDeltas
             }
+            IssuerCommand::BuildRevocRegEntryRequest(_, _, _, _, _, _, _) => {
+                CommandIndex::IssuerCommandBuildRevocRegEntryRequest
+            }
+            IssuerCommand::SetIssuedCredentialTags(_, _, _, _, _) => {
+                CommandIndex::IssuerCommandSetIssuedCredentialTags
+            }
+            IssuerCommand::SearchIssuedCredentials(_, _, _) => {
+                CommandIndex::IssuerCommandSearchIssuedCredentials
+            }
+            IssuerCommand::FetchIssuedCredentials(_, _, _) => {
+                CommandIndex::IssuerCommandFetchIssuedCredentials
+            }
+            IssuerCommand::CloseIssuedCredentialsSearch(_, _) => {
+                CommandIndex::IssuerCommandCloseIssuedCredentialsSearch
+            }
         }
     }
 }
@@ -95,6 +116,9 @@ impl From<&ProverCommand> for CommandIndex {
             ProverCommand::CreateProof(_, _, _, _, _, _, _, _) => { CommandIndex::ProverCommandCreateProof }
             ProverCommand::CreateRevocationState(_, _, _, _, _, _) => { CommandIndex::ProverCommandCreateRevocationState }
             ProverCommand::UpdateRevocationState(_, _, _, _, _, _, _) => { CommandIndex::ProverCommandUpdateRevocationState }
+            ProverCommand::CreateRevocationStates(_, _, _, _, _, _, _) => { CommandIndex::ProverCommandCreateRevocationStates }
+            ProverCommand::CreateRevocationStatesDefContinue(_, _, _) => { CommandIndex::ProverCommandCreateRevocationStatesDefContinue }
+            ProverCommand::CreateRevocationStatesDeltaContinue(_, _, _, _) => { CommandIndex::ProverCommandCreateRevocationStatesDeltaContinue }
         }
     }
 }
@@ -103,6 +127,7 @@ impl From<&VerifierCommand> for CommandIndex {
     fn from(cmd: &VerifierCommand) -> Self {
         match cmd {
             VerifierCommand::VerifyProof(_, _, _, _, _, _, _) => { CommandIndex::VerifierCommandVerifyProof }
+            VerifierCommand::VerifyProofVerbose(_, _, _, _, _, _, _) => { CommandIndex::VerifierCommandVerifyProofVerbose }
             VerifierCommand::GenerateNonce(_) => { CommandIndex::VerifierCommandGenerateNonce }
         }
     }
@@ -144,12 +169,22 @@ impl From<&Command> for CommandIndex {
             Command::Ledger(cmd) => {
                 match cmd {
                     LedgerCommand::SignAndSubmitRequest(_, _, _, _, _) => { CommandIndex::LedgerCommandSignAndSubmitRequest }
+                    LedgerCommand::SignAndSubmitRequestWithOptions(_, _, _, _, _, _) => { CommandIndex::LedgerCommandSignAndSubmitRequestWithOptions }
                     LedgerCommand::SubmitRequest(_, _, _) => { CommandIndex::LedgerCommandSubmitRequest }
+                    LedgerCommand::SubmitRequestWithOptions(_, _, _, _) => { CommandIndex::LedgerCommandSubmitRequestWithOptions }
+                    LedgerCommand::SubmitWriteRequest(_, _, _, _) => { CommandIndex::LedgerCommandSubmitWriteRequest }
+                    LedgerCommand::CheckWriteOutcomeContinue(_, _) => { CommandIndex::LedgerCommandCheckWriteOutcomeContinue }
                     LedgerCommand::SubmitAck(_, _) => { CommandIndex::LedgerCommandSubmitAck }
                     LedgerCommand::SubmitAction(_, _, _, _, _) => { CommandIndex::LedgerCommandSubmitAction }
+                    LedgerCommand::SubmitActionWithOptions(_, _, _, _) => { CommandIndex::LedgerCommandSubmitActionWithOptions }
                     LedgerCommand::SignRequest(_, _, _, _) => { CommandIndex::LedgerCommandSignRequest }
                     LedgerCommand::MultiSignRequest(_, _, _, _) => { CommandIndex::LedgerCommandMultiSignRequest }
                     LedgerCommand::BuildGetDdoRequest(_, _, _) => { CommandIndex::LedgerCommandBuildGetDdoRequest }
+                    LedgerCommand::ResolveDid(_, _, _, _) => { CommandIndex::LedgerCommandResolveDid }
+                    LedgerCommand::ResolveDidContinue(_, _) => { CommandIndex::LedgerCommandResolveDidContinue }
+                    LedgerCommand::GetLedgerObjects(_, _, _, _, _) => { CommandIndex::LedgerCommandGetLedgerObjects }
+                    LedgerCommand::GetLedgerObjectsContinue(_, _, _, _) => { CommandIndex::LedgerCommandGetLedgerObjectsContinue }
+                    LedgerCommand::VerifyReplyOffline(_, _, _) => { CommandIndex::LedgerCommandVerifyReplyOffline }
                     LedgerCommand::BuildNymRequest(_, _, _, _, _, _) => { CommandIndex::LedgerCommandBuildNymRequest }
                     LedgerCommand::BuildAttribRequest(_, _, _, _, _, _) => { CommandIndex::LedgerCommandBuildAttribRequest }
                     LedgerCommand::BuildGetAttribRequest(_, _, _, _, _, _) => { CommandIndex::LedgerCommandBuildGetAttribRequest }
@@ -164,9 +199,19 @@ impl From<&Command> for CommandIndex {
                     LedgerCommand::BuildNodeRequest(_, _, _, _) => { CommandIndex::LedgerCommandBuildNodeRequest }
                     LedgerCommand::BuildGetValidatorInfoRequest(_, _) => { CommandIndex::LedgerCommandBuildGetValidatorInfoRequest }
                     LedgerCommand::BuildGetTxnRequest(_, _, _, _) => { CommandIndex::LedgerCommandBuildGetTxnRequest }
+                    LedgerCommand::GetTxnRange(_, _, _, _, _, _) => { CommandIndex::LedgerCommandGetTxnRange }
+                    LedgerCommand::GetTxnRangeContinue(_, _) => { CommandIndex::LedgerCommandGetTxnRangeContinue }
+                    LedgerCommand::SubscribeLedger(_, _, _, _, _, _) => { CommandIndex::LedgerCommandSubscribeLedger }
+                    LedgerCommand::SyncLedgerSubscription(_) => { CommandIndex::LedgerCommandSyncLedgerSubscription }
+                    LedgerCommand::SyncLedgerSubscriptionContinue(_, _) => { CommandIndex::LedgerCommandSyncLedgerSubscriptionContinue }
+                    LedgerCommand::SeedLedgerSubscriptionContinue(_, _, _) => { CommandIndex::LedgerCommandSeedLedgerSubscriptionContinue }
+                    LedgerCommand::UnsubscribeLedger(_, _) => { CommandIndex::LedgerCommandUnsubscribeLedger }
                     LedgerCommand::BuildPoolConfigRequest(_, _, _, _) => { CommandIndex::LedgerCommandBuildPoolConfigRequest }
                     LedgerCommand::BuildPoolRestartRequest(_, _, _, _) => { CommandIndex::LedgerCommandBuildPoolRestartRequest }
                     LedgerCommand::BuildPoolUpgradeRequest(_, _, _, _, _, _, _, _, _, _, _, _) => { CommandIndex::LedgerCommandBuildPoolUpgradeRequest }
+                    LedgerCommand::BuildLedgersFreezeRequest(_, _, _) => { CommandIndex::LedgerCommandBuildLedgersFreezeRequest }
+                    LedgerCommand::BuildGetFrozenLedgersRequest(_, _) => { CommandIndex::LedgerCommandBuildGetFrozenLedgersRequest }
+                    LedgerCommand::ParseUpgradeStatus(_, _) => { CommandIndex::LedgerCommandParseUpgradeStatus }
                     LedgerCommand::BuildRevocRegDefRequest(_, _, _) => { CommandIndex::LedgerCommandBuildRevocRegDefRequest }
                     LedgerCommand::BuildGetRevocRegDefRequest(_, _, _) => { CommandIndex::LedgerCommandBuildGetRevocRegDefRequest }
                     LedgerCommand::ParseGetRevocRegDefResponse(_, _) => { CommandIndex::LedgerCommandParseGetRevocRegDefResponse }
@@ -175,6 +220,10 @@ impl From<&Command> for CommandIndex {
                     LedgerCommand::ParseGetRevocRegResponse(_, _) => { CommandIndex::LedgerCommandParseGetRevocRegResponse }
                     LedgerCommand::BuildGetRevocRegDeltaRequest(_, _, _, _, _) => { CommandIndex::LedgerCommandBuildGetRevocRegDeltaRequest }
                     LedgerCommand::ParseGetRevocRegDeltaResponse(_, _) => { CommandIndex::LedgerCommandParseGetRevocRegDeltaResponse }
+                    LedgerCommand::BuildRichSchemaObjectRequest(_, _, _) => { CommandIndex::LedgerCommandBuildRichSchemaObjectRequest }
+                    LedgerCommand::BuildGetRichSchemaObjectByIdRequest(_, _, _) => { CommandIndex::LedgerCommandBuildGetRichSchemaObjectByIdRequest }
+                    LedgerCommand::BuildGetRichSchemaObjectByMetadataRequest(_, _, _, _, _) => { CommandIndex::LedgerCommandBuildGetRichSchemaObjectByMetadataRequest }
+                    LedgerCommand::ParseGetRichSchemaObjectResponse(_, _) => { CommandIndex::LedgerCommandParseGetRichSchemaObjectResponse }
                     LedgerCommand::RegisterSPParser(_, _, _, _) => { CommandIndex::LedgerCommandRegisterSPParser }
                     LedgerCommand::GetResponseMetadata(_, _) => { CommandIndex::LedgerCommandGetResponseMetadata }
                     LedgerCommand::BuildAuthRuleRequest(_, _, _, _, _, _, _, _) => { CommandIndex::LedgerCommandBuildAuthRuleRequest }
@@ -184,6 +233,10 @@ impl From<&Command> for CommandIndex {
                     LedgerCommand::GetSchemaContinue(_, _, _) => { CommandIndex::LedgerCommandGetSchemaContinue }
                     LedgerCommand::GetCredDef(_, _, _, _) => { CommandIndex::LedgerCommandGetCredDef }
                     LedgerCommand::GetCredDefContinue(_, _, _) => { CommandIndex::LedgerCommandGetCredDefContinue }
+                    LedgerCommand::GetRevocRegDef(_, _, _, _) => { CommandIndex::LedgerCommandGetRevocRegDef }
+                    LedgerCommand::GetRevocRegDefContinue(_, _) => { CommandIndex::LedgerCommandGetRevocRegDefContinue }
+                    LedgerCommand::GetRevocRegDelta(_, _, _, _, _, _) => { CommandIndex::LedgerCommandGetRevocRegDelta }
+                    LedgerCommand::GetRevocRegDeltaContinue(_, _) => { CommandIndex::LedgerCommandGetRevocRegDeltaContinue }
                     LedgerCommand::BuildTxnAuthorAgreementRequest(_, _, _, _, _, _) => { CommandIndex::LedgerCommandBuildTxnAuthorAgreementRequest }
                     LedgerCommand::BuildDisableAllTxnAuthorAgreementsRequest(_, _) => { CommandIndex::LedgerCommandBuildDisableAllTxnAuthorAgreementsRequest }
                     LedgerCommand::BuildGetTxnAuthorAgreementRequest(_, _, _) => { CommandIndex::LedgerCommandBuildGetTxnAuthorAgreementRequest }
@@ -191,6 +244,12 @@ impl From<&Command> for CommandIndex {
                     LedgerCommand::BuildGetAcceptanceMechanismsRequest(_, _, _, _) => { CommandIndex::LedgerCommandBuildGetAcceptanceMechanismsRequest }
                     LedgerCommand::AppendTxnAuthorAgreementAcceptanceToRequest(_, _, _, _, _, _, _) => { CommandIndex::LedgerCommandAppendTxnAuthorAgreementAcceptanceToRequest }
                     LedgerCommand::AppendRequestEndorser(_, _, _) => { CommandIndex::LedgerCommandAppendRequestEndorser }
+                    LedgerCommand::PrepareEndorsement(_, _, _, _) => { CommandIndex::LedgerCommandPrepareEndorsement }
+                    LedgerCommand::EndorsementAddSignature(_, _, _, _) => { CommandIndex::LedgerCommandEndorsementAddSignature }
+                    LedgerCommand::EndorsementCheckSignatures(_, _) => { CommandIndex::LedgerCommandEndorsementCheckSignatures }
+                    LedgerCommand::EndorsementStatus(_, _, _) => { CommandIndex::LedgerCommandEndorsementStatus }
+                    LedgerCommand::SubmitEndorsement(_, _, _, _) => { CommandIndex::LedgerCommandSubmitEndorsement }
+                    LedgerCommand::EvaluateAuthRule(_, _, _, _) => { CommandIndex::LedgerCommandEvaluateAuthRule }
                 }
             }
             Command::Pool(cmd) => {
@@ -204,6 +263,9 @@ impl From<&Command> for CommandIndex {
                     PoolCommand::CloseAck(_, _) => { CommandIndex::PoolCommandCloseAck }
                     PoolCommand::Refresh(_, _) => { CommandIndex::PoolCommandRefresh }
                     PoolCommand::RefreshAck(_, _) => { CommandIndex::PoolCommandRefreshAck }
+                    PoolCommand::GetStatus(_, _) => { CommandIndex::PoolCommandGetStatus }
+                    PoolCommand::GetStatusAck(_, _) => { CommandIndex::PoolCommandGetStatusAck }
+                    PoolCommand::GetBlsKeys(_, _) => { CommandIndex::PoolCommandGetBlsKeys }
                     PoolCommand::SetProtocolVersion(_, _) => { CommandIndex::PoolCommandSetProtocolVersion }
                 }
             }
@@ -314,6 +376,7 @@ impl From<&Command> for CommandIndex {
                     CacheCommand::GetCredDefContinue(_, _, _, _) => { CommandIndex::CacheCommandGetCredDefContinue }
                     CacheCommand::PurgeSchemaCache(_, _, _) => { CommandIndex::CacheCommandPurgeSchemaCache }
                     CacheCommand::PurgeCredDefCache(_, _, _) => { CommandIndex::CacheCommandPurgeCredDefCache }
+                    CacheCommand::PurgeRevStateCache(_, _, _) => { CommandIndex::CacheCommandPurgeRevStateCache }
                 }
             }
             Command::Metrics(cmd) => {
@@ -337,8 +400,15 @@ pub enum CommandIndex {
     IssuerCommandCreateAndStoreRevocationRegistry,
     IssuerCommandCreateCredentialOffer,
     IssuerCommandCreateCredential,
+    IssuerCommandCreateCredentialWithRollover,
+    IssuerCommandCreateCredentialWithRolloverContinue,
     IssuerCommandRevokeCredential,
     IssuerCommandMergeRevocationRegistryDeltas,
+    IssuerCommandBuildRevocRegEntryRequest,
+    IssuerCommandSetIssuedCredentialTags,
+    IssuerCommandSearchIssuedCredentials,
+    IssuerCommandFetchIssuedCredentials,
+    IssuerCommandCloseIssuedCredentialsSearch,
     // ProverCommand
     ProverCommandCreateMasterSecret,
     ProverCommandCreateCredentialRequest,
@@ -358,8 +428,12 @@ pub enum CommandIndex {
     ProverCommandCreateProof,
     ProverCommandCreateRevocationState,
     ProverCommandUpdateRevocationState,
+    ProverCommandCreateRevocationStates,
+    ProverCommandCreateRevocationStatesDefContinue,
+    ProverCommandCreateRevocationStatesDeltaContinue,
     // VerifierCommand
     VerifierCommandVerifyProof,
+    VerifierCommandVerifyProofVerbose,
     VerifierCommandGenerateNonce,
     // AnoncredsCommand
     AnoncredsCommandToUnqualified,
@@ -379,13 +453,23 @@ pub enum CommandIndex {
     CryptoCommandPackMessage,
     CryptoCommandUnpackMessage,
     LedgerCommandSignAndSubmitRequest,
+    LedgerCommandSignAndSubmitRequestWithOptions,
     // LedgerCommand
     LedgerCommandSubmitRequest,
+    LedgerCommandSubmitRequestWithOptions,
+    LedgerCommandSubmitWriteRequest,
+    LedgerCommandCheckWriteOutcomeContinue,
     LedgerCommandSubmitAck,
     LedgerCommandSubmitAction,
+    LedgerCommandSubmitActionWithOptions,
     LedgerCommandSignRequest,
     LedgerCommandMultiSignRequest,
     LedgerCommandBuildGetDdoRequest,
+    LedgerCommandResolveDid,
+    LedgerCommandResolveDidContinue,
+    LedgerCommandGetLedgerObjects,
+    LedgerCommandGetLedgerObjectsContinue,
+    LedgerCommandVerifyReplyOffline,
     LedgerCommandBuildNymRequest,
     LedgerCommandBuildAttribRequest,
     LedgerCommandBuildGetAttribRequest,
@@ -400,9 +484,19 @@ pub enum CommandIndex {
     LedgerCommandBuildNodeRequest,
     LedgerCommandBuildGetValidatorInfoRequest,
     LedgerCommandBuildGetTxnRequest,
+    LedgerCommandGetTxnRange,
+    LedgerCommandGetTxnRangeContinue,
+    LedgerCommandSubscribeLedger,
+    LedgerCommandSyncLedgerSubscription,
+    LedgerCommandSyncLedgerSubscriptionContinue,
+    LedgerCommandSeedLedgerSubscriptionContinue,
+    LedgerCommandUnsubscribeLedger,
     LedgerCommandBuildPoolConfigRequest,
     LedgerCommandBuildPoolRestartRequest,
     LedgerCommandBuildPoolUpgradeRequest,
+    LedgerCommandBuildLedgersFreezeRequest,
+    LedgerCommandBuildGetFrozenLedgersRequest,
+    LedgerCommandParseUpgradeStatus,
     LedgerCommandBuildRevocRegDefRequest,
     LedgerCommandBuildGetRevocRegDefRequest,
     LedgerCommandParseGetRevocRegDefResponse,
@@ -411,6 +505,10 @@ pub enum CommandIndex {
     LedgerCommandParseGetRevocRegResponse,
     LedgerCommandBuildGetRevocRegDeltaRequest,
     LedgerCommandParseGetRevocRegDeltaResponse,
+    LedgerCommandBuildRichSchemaObjectRequest,
+    LedgerCommandBuildGetRichSchemaObjectByIdRequest,
+    LedgerCommandBuildGetRichSchemaObjectByMetadataRequest,
+    LedgerCommandParseGetRichSchemaObjectResponse,
     LedgerCommandRegisterSPParser,
     LedgerCommandGetResponseMetadata,
     LedgerCommandBuildAuthRuleRequest,
@@ -420,6 +518,10 @@ pub enum CommandIndex {
     LedgerCommandGetSchemaContinue,
     LedgerCommandGetCredDef,
     LedgerCommandGetCredDefContinue,
+    LedgerCommandGetRevocRegDef,
+    LedgerCommandGetRevocRegDefContinue,
+    LedgerCommandGetRevocRegDelta,
+    LedgerCommandGetRevocRegDeltaContinue,
     LedgerCommandBuildTxnAuthorAgreementRequest,
     LedgerCommandBuildDisableAllTxnAuthorAgreementsRequest,
     LedgerCommandBuildGetTxnAuthorAgreementRequest,
@@ -427,6 +529,12 @@ pub enum CommandIndex {
     LedgerCommandBuildGetAcceptanceMechanismsRequest,
     LedgerCommandAppendTxnAuthorAgreementAcceptanceToRequest,
     LedgerCommandAppendRequestEndorser,
+    LedgerCommandPrepareEndorsement,
+    LedgerCommandEndorsementAddSignature,
+    LedgerCommandEndorsementCheckSignatures,
+    LedgerCommandEndorsementStatus,
+    LedgerCommandSubmitEndorsement,
+    LedgerCommandEvaluateAuthRule,
     // PoolCommand
     PoolCommandCreate,
     PoolCommandDelete,
@@ -437,6 +545,9 @@ pub enum CommandIndex {
     PoolCommandCloseAck,
     PoolCommandRefresh,
     PoolCommandRefreshAck,
+    PoolCommandGetStatus,
+    PoolCommandGetStatusAck,
+    PoolCommandGetBlsKeys,
     PoolCommandSetProtocolVersion,
     // DidCommand
     DidCommandCreateAndStoreMyDid,
@@ -529,6 +640,7 @@ pub enum CommandIndex {
     CacheCommandGetCredDefContinue,
     CacheCommandPurgeSchemaCache,
     CacheCommandPurgeCredDefCache,
+    CacheCommandPurgeRevStateCache,
     // MetricsCommand
     MetricsCommandCollectMetrics,
     // Exit
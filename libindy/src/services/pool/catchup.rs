@@ -8,7 +8,9 @@ use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::merkle_tree_factory;
 use rust_base58::{FromBase58, ToBase58};
-use crate::services::pool::types::{CatchupReq, Message};
+use crate::domain::ledger::request::ProtocolVersion;
+use crate::services::pool::events::POOL_LEDGER_ID;
+use crate::services::pool::types::{CatchupReq, LedgerStatus, Message};
 
 pub enum CatchupProgress {
     ShouldBeStarted(
@@ -29,20 +31,42 @@ pub fn build_catchup_req(merkle: &MerkleTree, target_mt_size: usize) -> IndyResu
     let seq_no_start = merkle.count() + 1;
     let seq_no_end = target_mt_size;
 
+    let req_id = format!("{}{}", seq_no_start, seq_no_end);
+
+    let req_json = build_ledger_catchup_req(POOL_LEDGER_ID as usize, seq_no_start, seq_no_end)?;
+
+    trace!("catchup_req msg: {:?}", req_json);
+    Ok(Some((req_id, req_json)))
+}
+
+pub fn build_ledger_catchup_req(ledger_id: usize, seq_no_start: usize, target_mt_size: usize) -> IndyResult<String> {
     let cr = CatchupReq {
-        ledgerId: 0,
+        ledgerId: ledger_id,
         seqNoStart: seq_no_start,
-        seqNoEnd: seq_no_end,
+        seqNoEnd: target_mt_size,
         catchupTill: target_mt_size,
     };
 
-    let req_id = format!("{}{}", seq_no_start, seq_no_end);
+    serde_json::to_string(&Message::CatchupReq(cr))
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CatchupRequest")
+}
 
-    let req_json = serde_json::to_string(&Message::CatchupReq(cr))
-        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CatchupRequest")?;
+pub fn build_ledger_status(ledger_id: u8, merkle: &MerkleTree) -> LedgerStatus {
+    build_ledger_status_for_root(ledger_id, merkle.count(), merkle.root_hash())
+}
 
-    trace!("catchup_req msg: {:?}", req_json);
-    Ok(Some((req_id, req_json)))
+/// Status of the ledger which is known only by its size and root hash.
+pub fn build_ledger_status_for_root(ledger_id: u8, txn_seq_no: usize, merkle_root: &[u8]) -> LedgerStatus {
+    let protocol_version = ProtocolVersion::get();
+
+    LedgerStatus {
+        txnSeqNo: txn_seq_no,
+        merkleRoot: merkle_root.to_base58(),
+        ledgerId: ledger_id,
+        ppSeqNo: None,
+        viewNo: None,
+        protocolVersion: if protocol_version > 1 { Some(protocol_version) } else { None },
+    }
 }
 
 pub fn check_nodes_responses_on_status(nodes_votes: &HashMap<(String, usize, Option<Vec<String>>), HashSet<String>>,
@@ -1,11 +1,14 @@
+use serde_json;
+
 use indy_api_types::errors::prelude::*;
+use crate::domain::pool::RequestOptions;
 use crate::services::pool::events::PoolEvent;
 
 use super::zmq;
 
 use byteorder::{ByteOrder, LittleEndian};
 use indy_api_types::INVALID_COMMAND_HANDLE;
-use crate::services::pool::{COMMAND_CONNECT, COMMAND_EXIT, COMMAND_REFRESH};
+use crate::services::pool::{COMMAND_CONNECT, COMMAND_EXIT, COMMAND_REFRESH, COMMAND_STATUS};
 
 pub struct Commander {
     cmd_socket: zmq::Socket,
@@ -37,21 +40,32 @@ impl Commander {
             Some(PoolEvent::Close(id))
         } else if COMMAND_REFRESH.eq(cmd_s.as_str()) {
             Some(PoolEvent::Refresh(id))
+        } else if COMMAND_STATUS.eq(cmd_s.as_str()) {
+            Some(PoolEvent::GetStatus(id))
         } else if COMMAND_CONNECT.eq(cmd_s.as_str()) {
             Some(PoolEvent::CheckCache(id))
         } else {
             let timeout = LittleEndian::read_i32(cmd_parts[2].as_slice());
             let timeout = if timeout == -1 { None } else { Some(timeout) };
 
-            let nodes = if let Some(nodes) = cmd_parts.get(3) {
-                Some(String::from_utf8(nodes.clone())
-                    .to_indy(IndyErrorKind::InvalidState, "Invalid utf8 sequence in command") // FIXME: review kind
+            let nodes = match cmd_parts.get(3) {
+                Some(nodes) if !nodes.is_empty() => {
+                    Some(String::from_utf8(nodes.clone())
+                        .to_indy(IndyErrorKind::InvalidState, "Invalid utf8 sequence in command") // FIXME: review kind
+                        .map_err(map_err_trace!()).ok()?)
+                }
+                _ => None
+            };
+
+            let options = if let Some(options) = cmd_parts.get(4) {
+                Some(serde_json::from_slice::<RequestOptions>(options)
+                    .to_indy(IndyErrorKind::InvalidState, "Invalid request options in command")
                     .map_err(map_err_trace!()).ok()?)
             } else {
                 None
             };
 
-            Some(PoolEvent::SendRequest(id, cmd_s, timeout, nodes))
+            Some(PoolEvent::SendRequest(id, cmd_s, timeout, nodes, options))
         }
     }
 
@@ -65,6 +79,7 @@ mod commander_tests {
     use super::*;
     use indy_api_types::{CommandHandle};
     use indy_utils::next_command_handle;
+    use crate::domain::pool::ReadConsensusPolicy;
     use crate::services::pool::{COMMAND_REFRESH, COMMAND_EXIT, pool_create_pair_of_sockets};
 
     fn new_commander() -> Commander {
@@ -125,6 +140,19 @@ mod commander_tests {
         assert_match!(Some(PoolEvent::Refresh(cmd_id_)), cmd.fetch_events(), cmd_id_, cmd_id);
     }
 
+    #[test]
+    pub fn commander_fetch_get_status_event_works() {
+        let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("status");
+
+        let cmd = Commander::new(recv_cmd_sock);
+
+        let cmd_id: CommandHandle = next_command_handle();
+        let mut buf = [0u8; 4];
+        LittleEndian::write_i32(&mut buf, cmd_id);
+        send_cmd_sock.send_multipart(&[COMMAND_STATUS.as_bytes(), &buf], zmq::DONTWAIT).expect("FIXME");
+        assert_match!(Some(PoolEvent::GetStatus(cmd_id_)), cmd.fetch_events(), cmd_id_, cmd_id);
+    }
+
     #[test]
     pub fn commander_fetch_check_cache_event_works() {
         let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("check_cache");
@@ -151,9 +179,30 @@ mod commander_tests {
         LittleEndian::write_i32(&mut buf_to, -1);
         let msg = "test";
         send_cmd_sock.send_multipart(&[msg.as_bytes(), &buf, &buf_to], zmq::DONTWAIT).expect("FIXME");
-        assert_match!(Some(PoolEvent::SendRequest(cmd_id_, msg_, None, None)), cmd.fetch_events(),
+        assert_match!(Some(PoolEvent::SendRequest(cmd_id_, msg_, None, None, None)), cmd.fetch_events(),
                       cmd_id_, cmd_id,
                       msg_, msg);
     }
 
+    #[test]
+    pub fn commander_fetch_send_request_event_works_for_options() {
+        let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("send_request_options");
+
+        let cmd = Commander::new(recv_cmd_sock);
+
+        let cmd_id: CommandHandle = next_command_handle();
+        let mut buf = [0u8; 4];
+        LittleEndian::write_i32(&mut buf, cmd_id);
+        let mut buf_to = [0u8; 4];
+        LittleEndian::write_i32(&mut buf_to, -1);
+        let msg = "test";
+        let options = r#"{"read_consensus":"all"}"#;
+        send_cmd_sock.send_multipart(&[msg.as_bytes(), &buf, &buf_to, &[], options.as_bytes()], zmq::DONTWAIT).expect("FIXME");
+
+        let expected_options = RequestOptions { read_consensus: Some(ReadConsensusPolicy::All), ..RequestOptions::default() };
+        assert_match!(Some(PoolEvent::SendRequest(cmd_id_, _, None, None, Some(options_))), cmd.fetch_events(),
+                      cmd_id_, cmd_id,
+                      options_, expected_options);
+    }
+
 }
\ No newline at end of file
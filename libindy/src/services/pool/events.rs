@@ -2,12 +2,13 @@ use serde_json;
 use serde_json::Value as SJsonValue;
 
 use crate::domain::ledger::constants;
+use crate::domain::pool::{ReadConsensusPolicy, RequestOptions};
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::{PoolService, types:: *};
 use indy_api_types::CommandHandle;
 
-pub const REQUESTS_FOR_STATE_PROOFS: [&str; 11] = [
+pub const REQUESTS_FOR_STATE_PROOFS: [&str; 13] = [
     constants::GET_NYM,
     constants::GET_TXN_AUTHR_AGRMT,
     constants::GET_TXN_AUTHR_AGRMT_AML,
@@ -19,6 +20,8 @@ pub const REQUESTS_FOR_STATE_PROOFS: [&str; 11] = [
     constants::GET_REVOC_REG_DELTA,
     constants::GET_AUTH_RULE,
     constants::GET_TXN,
+    constants::GET_RICH_SCHEMA_OBJECT_BY_ID,
+    constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA,
 ];
 
 const REQUEST_FOR_FULL: [&str; 2] = [
@@ -39,12 +42,47 @@ pub const REQUESTS_FOR_MULTI_STATE_PROOFS: [&str; 1] = [
     constants::GET_REVOC_REG_DELTA,
 ];
 
+/// Order in which the request sent to one node at a time walks through the pool nodes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodesPreference {
+    /// Nodes asked first, in the given order.
+    pub preferred: Vec<String>,
+    /// Nodes the request may be sent to, all nodes if not set.
+    pub nodes_to_send: Option<Vec<String>>,
+}
+
+impl NodesPreference {
+    /// Indexes of `nodes` in the order the request should be sent to them.
+    ///
+    /// Fails if none of the nodes is allowed, so the returned order is never empty.
+    pub fn order(preference: Option<&NodesPreference>, nodes: &[RemoteNode]) -> IndyResult<Vec<usize>> {
+        let mut order: Vec<usize> = (0..nodes.len()).collect();
+
+        if let Some(preference) = preference {
+            order.retain(|&idx| preference.nodes_to_send.as_ref().map(|names| names.contains(&nodes[idx].name)).unwrap_or(true));
+
+            order.sort_by_key(|&idx| preference.preferred.iter()
+                .position(|name| nodes[idx].name.eq(name))
+                .unwrap_or(usize::max_value()));
+        }
+
+        if order.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("There is no known node to send the request to, known nodes are {:?}",
+                                       nodes.iter().map(|node| &node.name).collect::<Vec<&String>>())));
+        }
+
+        Ok(order)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum NetworkerEvent {
     SendOneRequest(
         String, //msg
         String, //req_id
         i64, //timeout
+        Option<NodesPreference>, //nodes order, networker order if not set
     ),
     SendAllRequest(
         String, //msg
@@ -69,9 +107,18 @@ pub enum NetworkerEvent {
     Timeout,
 }
 
+/// Id of the pool ledger, it is caught up by the pool itself.
+pub const POOL_LEDGER_ID: u8 = 0;
+
+/// Requests and replies of the catch-up of the ledger other than the pool one are matched by the ledger id.
+pub fn ledger_catchup_req_id(ledger_id: usize) -> String {
+    format!("catchup_{}", ledger_id)
+}
+
 pub const COMMAND_EXIT : &str = "exit";
 pub const COMMAND_CONNECT : &str = "connect";
 pub const COMMAND_REFRESH : &str = "refresh";
+pub const COMMAND_STATUS : &str = "status";
 
 #[derive(Clone, Debug)]
 pub enum PoolEvent {
@@ -86,6 +133,9 @@ pub enum PoolEvent {
     Refresh(
         CommandHandle
     ),
+    GetStatus(
+        CommandHandle
+    ),
     CatchupTargetFound(
         Vec<u8>, //target_mt_root
         usize, //target_mt_size
@@ -95,7 +145,6 @@ pub enum PoolEvent {
         MerkleTree,
     ),
     CatchupTargetNotFound(IndyError),
-    #[allow(dead_code)] //FIXME
     PoolOutdated,
     Synced(
         MerkleTree
@@ -107,6 +156,7 @@ pub enum PoolEvent {
         String, // request
         Option<i32>, // timeout
         Option<String>, // node list
+        Option<RequestOptions>, // options
     ),
     Timeout(
         String, //req_id
@@ -126,6 +176,9 @@ pub enum RequestEvent {
         usize, // target mt size
         Vec<u8>, // target mt root
     ),
+    LedgerCatchup(
+        LedgerStatus, // local status of the ledger other than the pool one
+    ),
     Timeout(
         String, //req_id
         String, //node_alias
@@ -138,17 +191,20 @@ pub enum RequestEvent {
         String, // message
         String, // req_id
         Option<Vec<u8>>, // expected key for State Proof in Reply,
-        (Option<u64>, Option<u64>) // expected timestamps for freshness comparison
+        (Option<u64>, Option<u64>), // expected timestamps for freshness comparison
+        RequestOptions, // options, read consensus policy is pool default if not set
     ),
     CustomConsensusRequest(
         String, // message
         String, // req_id
+        RequestOptions, // options
     ),
     CustomFullRequest(
         String, // message
         String, // req_id
         Option<i32>, // timeout
         Option<String>, // nodes
+        RequestOptions, // options
     ),
     ConsistencyProof(
         ConsistencyProof,
@@ -187,16 +243,30 @@ pub enum RequestEvent {
 impl RequestEvent {
     pub fn get_req_id(&self) -> String {
         match *self {
-            RequestEvent::CustomSingleRequest(_, ref id, _, _) => id.to_string(),
-            RequestEvent::CustomConsensusRequest(_, ref id) => id.to_string(),
-            RequestEvent::CustomFullRequest(_, ref id, _, _) => id.to_string(),
+            RequestEvent::CustomSingleRequest(_, ref id, _, _, _) => id.to_string(),
+            RequestEvent::CustomConsensusRequest(_, ref id, _) => id.to_string(),
+            RequestEvent::CustomFullRequest(_, ref id, _, _, _) => id.to_string(),
             RequestEvent::Reply(_, _, _, ref id) => id.to_string(),
             RequestEvent::ReqACK(_, _, _, ref id) => id.to_string(),
             RequestEvent::ReqNACK(_, _, _, ref id) => id.to_string(),
             RequestEvent::Reject(_, _, _, ref id) => id.to_string(),
+            RequestEvent::LedgerCatchup(ref ls) |
+            RequestEvent::LedgerStatus(ref ls, _, _) if ls.ledgerId != POOL_LEDGER_ID => ledger_catchup_req_id(ls.ledgerId as usize),
+            RequestEvent::ConsistencyProof(ref cp, _) if cp.ledgerId != POOL_LEDGER_ID as usize => ledger_catchup_req_id(cp.ledgerId),
+            RequestEvent::CatchupRep(ref rep, _) if rep.ledgerId != POOL_LEDGER_ID as usize => ledger_catchup_req_id(rep.ledgerId),
             _ => "".to_string()
         }
     }
+
+    pub fn or_read_consensus(self, default: ReadConsensusPolicy) -> Self {
+        match self {
+            RequestEvent::CustomSingleRequest(msg, req_id, sp_key, timestamps, options) => {
+                let read_consensus = options.read_consensus().or(Some(default));
+                RequestEvent::CustomSingleRequest(msg, req_id, sp_key, timestamps, RequestOptions { read_consensus, ..options })
+            }
+            re => re
+        }
+    }
 }
 
 impl Into<Option<RequestEvent>> for PoolEvent {
@@ -232,11 +302,16 @@ impl Into<Option<RequestEvent>> for PoolEvent {
                         Message::Pong => RequestEvent::Pong,
                     })
             }
-            PoolEvent::SendRequest(_, msg, timeout, nodes) => {
+            PoolEvent::SendRequest(_, msg, timeout, nodes, options) => {
+                if let Ok(Message::LedgerStatus(ls)) = serde_json::from_str::<Message>(&msg) {
+                    return Some(RequestEvent::LedgerCatchup(ls));
+                }
+
+                let options = options.unwrap_or_default();
                 let parsed_req = _parse_req_id_and_op(&msg);
                 if let Ok((ref req, ref req_id, ref op)) = parsed_req {
                     if REQUEST_FOR_FULL.contains(&op.as_str()) {
-                        Some(RequestEvent::CustomFullRequest(msg, req_id.clone(), timeout, nodes))
+                        Some(RequestEvent::CustomFullRequest(msg, req_id.clone(), timeout, nodes, options))
                     } else if timeout.is_some() || nodes.is_some() {
                         error!("Timeout {:?} or nodes {:?} is specified for non-supported request operation type {}",
                                timeout, nodes, op);
@@ -244,11 +319,11 @@ impl Into<Option<RequestEvent>> for PoolEvent {
                     } else if REQUESTS_FOR_STATE_PROOFS.contains(&op.as_str()) {
                         let key = super::state_proof::parse_key_from_request_for_builtin_sp(&req);
                         let timestamps = _parse_timestamp_from_req_for_builtin_sp(req, &op);
-                        Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), key, timestamps))
+                        Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), key, timestamps, options))
                     } else if PoolService::get_sp_parser(&op.as_str()).is_some() {
-                        Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), None, (None, None)))
+                        Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), None, (None, None), options))
                     } else {
-                        Some(RequestEvent::CustomConsensusRequest(msg, req_id.clone()))
+                        Some(RequestEvent::CustomConsensusRequest(msg, req_id.clone(), options))
                     }
                 } else {
                     error!("Can't parse parsed_req or op from message {}", msg);
@@ -0,0 +1,602 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use serde_json;
+use serde_json::Value as SJsonValue;
+use rust_base58::ToBase58;
+use time::Tm;
+use ursa::bls::{Bls, MultiSignature, Signature, SignKey};
+
+use crate::domain::ledger::constants::GET_TXN;
+use crate::domain::ledger::txn::LedgerType;
+use crate::domain::pool::InProcessLedgerConfig;
+use crate::services::ledger::merkletree::merkletree::MerkleTree;
+use crate::services::ledger::txn_leaf_value;
+use crate::services::pool::events::*;
+use crate::services::pool::networker::Networker;
+use crate::services::pool::types::*;
+use indy_api_types::errors::prelude::*;
+use indy_utils::sequence;
+
+use super::time::Duration;
+
+use super::zmq::PollItem;
+use super::zmq::Socket as ZSocket;
+
+/// Networker which never leaves the process.
+///
+/// Requests are answered by canned messages from `InProcessLedgerConfig` on behalf of every known node.
+/// Replies are passed through a pair of inproc ZMQ sockets, so the pool thread polls them
+/// the same way as the replies of the real nodes and timeouts are handled as usual.
+pub struct InProcessNetworker {
+    ledger: InProcessLedgerConfig,
+    nodes: Vec<RemoteNode>,
+    preordered_nodes: Vec<String>,
+    resend: HashMap<String, (usize, String, Vec<usize>)>,
+    timeouts: RefCell<HashMap<(String, String), Tm>>,
+    _ctx: zmq::Context,
+    sender: ZSocket,
+    receiver: ZSocket,
+}
+
+impl InProcessNetworker {
+    pub fn set_ledger(&mut self, ledger: InProcessLedgerConfig) {
+        self.ledger = ledger;
+    }
+
+    fn _send_msg_to_one_node(&self, idx: usize, req_id: &str, msg: &str, timeout: i64) -> IndyResult<()> {
+        trace!("InProcessNetworker::_send_msg_to_one_node >> idx {}, req_id {}, msg {}", idx, req_id, msg);
+
+        let node_alias = self.nodes[idx].name.clone();
+
+        self.timeouts.borrow_mut().insert((req_id.to_string(), node_alias.clone()), time::now() + Duration::seconds(timeout));
+
+        if let Some(reply) = self._build_reply(&node_alias, msg)? {
+            self.sender.send_multipart(&[node_alias.as_bytes(), reply.as_bytes()], zmq::DONTWAIT)?;
+        }
+
+        trace!("InProcessNetworker::_send_msg_to_one_node <<");
+        Ok(())
+    }
+
+    fn _build_reply(&self, node_alias: &str, msg: &str) -> IndyResult<Option<String>> {
+        if self.ledger.silent_nodes.iter().any(|node| node == node_alias) {
+            return Ok(None);
+        }
+
+        let reply = match Message::from_raw_str(msg) {
+            Ok(Message::LedgerStatus(ref ls)) if self.ledger.txns.contains_key(&ls.ledgerId.to_string()) => {
+                Some(_with_op("LEDGER_STATUS", &self._build_ledger_status(ls)?))
+            }
+            Ok(Message::CatchupReq(ref req)) if self.ledger.txns.contains_key(&req.ledgerId.to_string()) => {
+                Some(_with_op("CATCHUP_REP", &self._build_catchup_rep(req)?))
+            }
+            Ok(Message::LedgerStatus(ls)) => {
+                match (&self.ledger.consistency_proof, &self.ledger.ledger_status) {
+                    (Some(cp), _) => Some(_with_op("CONSISTENCY_PROOF", cp)),
+                    (None, Some(ls)) => Some(_with_op("LEDGER_STATUS", ls)),
+                    (None, None) => Some(serde_json::to_value(Message::LedgerStatus(ls))
+                        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize LedgerStatus")?),
+                }
+            }
+            Ok(Message::CatchupReq(_)) => self.ledger.catchup_rep.as_ref().map(|cr| _with_op("CATCHUP_REP", cr)),
+            Ok(_) => None,
+            Err(_) => Some(self._build_request_reply(node_alias, msg)?),
+        };
+
+        reply.map(|reply| serde_json::to_string(&reply)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize canned reply"))
+            .transpose()
+    }
+
+    fn _build_ledger_status(&self, ls: &LedgerStatus) -> IndyResult<SJsonValue> {
+        let txns = &self.ledger.txns[&ls.ledgerId.to_string()];
+        let mt = _build_merkle_tree(txns)?;
+
+        let ls = LedgerStatus {
+            txnSeqNo: txns.len(),
+            merkleRoot: mt.root_hash().to_base58(),
+            ..ls.clone()
+        };
+
+        serde_json::to_value(ls)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize LedgerStatus")
+    }
+
+    fn _build_catchup_rep(&self, req: &CatchupReq) -> IndyResult<SJsonValue> {
+        let txns = &self.ledger.txns[&req.ledgerId.to_string()];
+
+        let rep = CatchupRep {
+            ledgerId: req.ledgerId,
+            consProof: Vec::new(),
+            txns: (req.seqNoStart..=req.seqNoEnd.min(txns.len()))
+                .filter(|seq_no| *seq_no > 0)
+                .map(|seq_no| (seq_no.to_string(), txns[seq_no - 1].clone()))
+                .collect(),
+        };
+
+        serde_json::to_value(rep)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CatchupRep")
+    }
+
+    fn _build_request_reply(&self, node_alias: &str, msg: &str) -> IndyResult<SJsonValue> {
+        let request: SJsonValue = serde_json::from_str(msg)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid request json")?;
+
+        let req_id = request["reqId"].clone();
+        let identifier = request["identifier"].clone();
+        let txn_type = request["operation"]["type"].as_str().unwrap_or_default();
+
+        if txn_type == GET_TXN {
+            let ledger_id = request["operation"]["ledgerId"].as_i64().unwrap_or(LedgerType::DOMAIN.to_id() as i64);
+            if let Some(txns) = self.ledger.txns.get(&ledger_id.to_string()) {
+                let seq_no = request["operation"]["data"].clone();
+                let data = _get_txn_data(txns, seq_no.as_u64().unwrap_or_default() as usize)?;
+
+                let mut result = json!({"type": GET_TXN, "identifier": identifier, "reqId": req_id, "seqNo": seq_no, "data": data});
+                if !self.ledger.bls_seeds.is_empty() && !result["data"].is_null() {
+                    result["state_proof"] = self._sign_ledger_root(ledger_id, &result["data"]["rootHash"])?;
+                }
+
+                return Ok(json!({"op": "REPLY", "result": result}));
+            }
+        }
+
+        let result = self.ledger.node_replies.get(node_alias)
+            .and_then(|replies| replies.get(txn_type))
+            .or_else(|| self.ledger.replies.get(txn_type));
+
+        let reply = match result {
+            Some(result) => {
+                let mut result = result.clone();
+                result["reqId"] = req_id;
+                json!({"op": "REPLY", "result": result})
+            }
+            None => json!({
+                "op": "REQNACK",
+                "reqId": req_id,
+                "identifier": identifier,
+                "reason": format!("No canned reply for transaction type {}", txn_type),
+            })
+        };
+
+        Ok(reply)
+    }
+
+    fn _sign_ledger_root(&self, ledger_id: i64, root_hash: &SJsonValue) -> IndyResult<SJsonValue> {
+        let value = json!({
+            "ledger_id": ledger_id,
+            "pool_state_root_hash": "",
+            "state_root_hash": "",
+            "timestamp": time::get_time().sec,
+            "txn_root_hash": root_hash,
+        });
+
+        let message = rmp_serde::to_vec_named(&value)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize multi-signature value")?;
+
+        let signatures = self.ledger.bls_seeds.values()
+            .map(|seed| Ok(Bls::sign(&message, &SignKey::new(Some(seed.as_bytes()))?)?))
+            .collect::<IndyResult<Vec<Signature>>>()?;
+
+        let signature = MultiSignature::new(&signatures.iter().collect::<Vec<&Signature>>())?;
+
+        Ok(json!({
+            "multi_signature": {
+                "value": value,
+                "participants": self.ledger.bls_seeds.keys().collect::<Vec<&String>>(),
+                "signature": signature.as_bytes().to_base58(),
+            }
+        }))
+    }
+}
+
+fn _get_txn_data(txns: &[SJsonValue], seq_no: usize) -> IndyResult<SJsonValue> {
+    if seq_no == 0 || seq_no > txns.len() {
+        return Ok(SJsonValue::Null);
+    }
+
+    let mt = _build_merkle_tree(txns)?;
+
+    let mut data = txns[seq_no - 1].clone();
+    data["auditPath"] = json!(mt.gen_audit_path(seq_no - 1)?.iter().map(|hash| hash.to_base58()).collect::<Vec<String>>());
+    data["rootHash"] = json!(mt.root_hash().to_base58());
+    data["ledgerSize"] = json!(txns.len());
+
+    Ok(data)
+}
+
+fn _build_merkle_tree(txns: &[SJsonValue]) -> IndyResult<MerkleTree> {
+    let leaves = txns.iter()
+        .map(|txn| {
+            let leaf = txn_leaf_value(txn)?
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Canned transaction is empty"))?;
+            rmp_serde::to_vec(&leaf)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot serialize canned transaction")
+        })
+        .collect::<IndyResult<Vec<Vec<u8>>>>()?;
+
+    MerkleTree::from_vec(leaves)
+}
+
+fn _with_op(op: &str, msg: &SJsonValue) -> SJsonValue {
+    let mut msg = msg.clone();
+    msg["op"] = SJsonValue::from(op);
+    msg
+}
+
+impl Networker for InProcessNetworker {
+    fn new(_active_timeout: i64, _conn_limit: usize, preordered_nodes: Vec<String>) -> Self {
+        let ctx = zmq::Context::new();
+        let sender = ctx.socket(zmq::SocketType::PAIR).expect("FIXME");
+        let receiver = ctx.socket(zmq::SocketType::PAIR).expect("FIXME");
+
+        let addr = format!("inproc://in_process_networker_{}", sequence::get_next_id());
+        receiver.bind(&addr).expect("FIXME");
+        sender.connect(&addr).expect("FIXME");
+
+        InProcessNetworker {
+            ledger: InProcessLedgerConfig::default(),
+            nodes: Vec::new(),
+            preordered_nodes,
+            resend: HashMap::new(),
+            timeouts: RefCell::new(HashMap::new()),
+            _ctx: ctx,
+            sender,
+            receiver,
+        }
+    }
+
+    fn fetch_events(&self, poll_items: &[PollItem]) -> Vec<PoolEvent> {
+        let mut events = Vec::new();
+
+        if poll_items.get(0).map(|pi| pi.is_readable()).unwrap_or(false) {
+            while let Ok(parts) = self.receiver.recv_multipart(zmq::DONTWAIT) {
+                if let (Some(node_alias), Some(reply)) = (parts.get(0), parts.get(1)) {
+                    events.push(PoolEvent::NodeReply(
+                        String::from_utf8_lossy(reply).to_string(),
+                        String::from_utf8_lossy(node_alias).to_string(),
+                    ));
+                }
+            }
+        }
+
+        events
+    }
+
+    fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent> {
+        let res = match pe {
+            Some(NetworkerEvent::SendOneRequest(msg, req_id, timeout, preference)) => {
+                NodesPreference::order(preference.as_ref(), &self.nodes)
+                    .and_then(|order| {
+                        let res = self._send_msg_to_one_node(order[0], &req_id, &msg, timeout);
+                        self.resend.insert(req_id, (0, msg, order));
+                        res
+                    })
+            }
+            Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, nodes_to_send)) => {
+                (0..self.nodes.len())
+                    .filter(|&idx| nodes_to_send.as_ref().map(|nodes| nodes.contains(&self.nodes[idx].name)).unwrap_or(true))
+                    .map(|idx| self._send_msg_to_one_node(idx, &req_id, &msg, timeout))
+                    .collect::<IndyResult<Vec<()>>>()
+                    .map(|_| ())
+            }
+            Some(NetworkerEvent::Resend(req_id, timeout)) => {
+                let resend = self.resend.get_mut(&req_id).map(|&mut (ref mut cnt, ref msg, ref order)| {
+                    *cnt += 1;
+                    (order.get(*cnt % order.len().max(1)).cloned(), msg.clone())
+                });
+                match resend {
+                    Some((Some(idx), msg)) => self._send_msg_to_one_node(idx, &req_id, &msg, timeout),
+                    Some(_) => Ok(()),
+                    None => {
+                        error!("Unknown req_id for resending {}", req_id);
+                        Ok(())
+                    }
+                }
+            }
+            Some(NetworkerEvent::NodesStateUpdated(mut nodes)) => {
+                trace!("InProcessNetworker::process_event: nodes_updated {:?}", nodes);
+                let preordered_nodes = &self.preordered_nodes;
+                nodes.sort_by_key(|node| preordered_nodes.iter()
+                    .position(|name| node.name.eq(name))
+                    .unwrap_or(usize::max_value()));
+                self.nodes = nodes;
+                Ok(())
+            }
+            Some(NetworkerEvent::ExtendTimeout(req_id, node_alias, timeout)) => {
+                if let Some(deadline) = self.timeouts.borrow_mut().get_mut(&(req_id, node_alias)) {
+                    *deadline = time::now() + Duration::seconds(timeout);
+                }
+                Ok(())
+            }
+            Some(NetworkerEvent::CleanTimeout(req_id, node_alias)) => {
+                match node_alias {
+                    Some(node_alias) => {
+                        self.timeouts.borrow_mut().remove(&(req_id, node_alias));
+                    }
+                    None => {
+                        self.timeouts.borrow_mut().retain(|&(ref id, _), _| *id != req_id);
+                        self.resend.remove(&req_id);
+                    }
+                }
+                Ok(())
+            }
+            Some(NetworkerEvent::Timeout) | None => Ok(())
+        };
+
+        if let Err(err) = res {
+            error!("InProcessNetworker can't process event: {:?}", err);
+        }
+
+        None
+    }
+
+    fn get_timeout(&self) -> ((String, String), i64) {
+        self.timeouts.borrow().iter()
+            .map(|(key, value)| (key.clone(), (*value - time::now()).num_milliseconds()))
+            .min_by(|&(_, val1), &(_, val2)| val1.cmp(&val2))
+            .unwrap_or((("".to_string(), "".to_string()), ::std::i64::MAX))
+    }
+
+    fn get_poll_items(&self) -> Vec<PollItem> {
+        vec![self.receiver.as_poll_item(zmq::POLLIN)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::domain::pool::POOL_ACK_TIMEOUT;
+
+    const REQ_ID: &str = "1";
+
+    fn _remote_node(alias: &str) -> RemoteNode {
+        RemoteNode {
+            name: alias.to_string(),
+            public_key: vec![],
+            zaddr: String::new(),
+            is_blacklisted: false,
+        }
+    }
+
+    fn _networker(ledger: InProcessLedgerConfig) -> InProcessNetworker {
+        let mut networker = InProcessNetworker::new(0, 0, vec![]);
+        networker.set_ledger(ledger);
+        networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![_remote_node("n1"), _remote_node("n2")])));
+        networker
+    }
+
+    fn _request() -> String {
+        json!({"reqId": 1, "identifier": "V4SGRU86Z58d6TV7PBUe6f", "operation": {"type": "105", "dest": "V4SGRU86Z58d6TV7PBUe6f"}}).to_string()
+    }
+
+    fn _fetch(networker: &InProcessNetworker) -> Vec<(String, String)> {
+        let mut poll_items = networker.get_poll_items();
+        zmq::poll(&mut poll_items, 100).unwrap();
+        networker.fetch_events(&poll_items).into_iter()
+            .map(|event| match event {
+                PoolEvent::NodeReply(reply, node_alias) => (node_alias, reply),
+                _ => panic!("Unexpected event")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn in_process_networker_send_all_request_works() {
+        let mut ledger = InProcessLedgerConfig::default();
+        ledger.replies.insert("105".to_string(), json!({"type": "105", "data": null}));
+
+        let mut networker = _networker(ledger);
+        networker.process_event(Some(NetworkerEvent::SendAllRequest(_request(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
+
+        let replies = _fetch(&networker);
+        assert_eq!(2, replies.len());
+
+        for (_, reply) in replies {
+            let reply: SJsonValue = serde_json::from_str(&reply).unwrap();
+            assert_eq!("REPLY", reply["op"].as_str().unwrap());
+            assert_eq!(1, reply["result"]["reqId"].as_u64().unwrap());
+        }
+    }
+
+    #[test]
+    fn in_process_networker_send_request_works_for_node_replies() {
+        let mut ledger = InProcessLedgerConfig::default();
+        ledger.replies.insert("105".to_string(), json!({"data": "common"}));
+        let mut n2_replies = HashMap::new();
+        n2_replies.insert("105".to_string(), json!({"data": "n2"}));
+        ledger.node_replies.insert("n2".to_string(), n2_replies);
+
+        let mut networker = _networker(ledger);
+        networker.process_event(Some(NetworkerEvent::SendAllRequest(_request(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
+
+        for (node_alias, reply) in _fetch(&networker) {
+            let reply: SJsonValue = serde_json::from_str(&reply).unwrap();
+            let expected = if node_alias == "n2" { "n2" } else { "common" };
+            assert_eq!(expected, reply["result"]["data"].as_str().unwrap());
+        }
+    }
+
+    #[test]
+    fn in_process_networker_send_request_works_for_unknown_type() {
+        let mut networker = _networker(InProcessLedgerConfig::default());
+        networker.process_event(Some(NetworkerEvent::SendOneRequest(_request(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
+
+        let replies = _fetch(&networker);
+        assert_eq!(1, replies.len());
+        assert_eq!("n1", replies[0].0);
+        assert_match!(Ok(Message::ReqNACK(_)), Message::from_raw_str(&replies[0].1));
+    }
+
+    #[test]
+    fn in_process_networker_get_txn_works() {
+        let mut ledger = InProcessLedgerConfig::default();
+        ledger.txns.insert("1".to_string(), vec![
+            json!({"txn": {"type": "1", "data": {"dest": "dest1"}}, "txnMetadata": {"seqNo": 1}}),
+            json!({"txn": {"type": "1", "data": {"dest": "dest2"}}, "txnMetadata": {"seqNo": 2}}),
+        ]);
+
+        let mut networker = _networker(ledger);
+
+        let request = json!({"reqId": 1, "operation": {"type": GET_TXN, "data": 2, "ledgerId": 1}}).to_string();
+        networker.process_event(Some(NetworkerEvent::SendOneRequest(request, REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
+
+        let reply: SJsonValue = serde_json::from_str(&_fetch(&networker)[0].1).unwrap();
+        assert_eq!("REPLY", reply["op"].as_str().unwrap());
+        assert_eq!(2, reply["result"]["data"]["txnMetadata"]["seqNo"].as_u64().unwrap());
+        assert_eq!(2, reply["result"]["data"]["ledgerSize"].as_u64().unwrap());
+        assert_eq!(1, reply["result"]["data"]["auditPath"].as_array().unwrap().len());
+
+        let request = json!({"reqId": 2, "operation": {"type": GET_TXN, "data": 3, "ledgerId": 1}}).to_string();
+        networker.process_event(Some(NetworkerEvent::SendOneRequest(request, "2".to_string(), POOL_ACK_TIMEOUT, None)));
+
+        let reply: SJsonValue = serde_json::from_str(&_fetch(&networker)[0].1).unwrap();
+        assert_eq!("REPLY", reply["op"].as_str().unwrap());
+        assert!(reply["result"]["data"].is_null());
+    }
+
+    #[test]
+    fn in_process_networker_get_txn_works_for_bls_seeds() {
+        let mut ledger = InProcessLedgerConfig::default();
+        ledger.txns.insert("1".to_string(), vec![
+            json!({"txn": {"type": "1", "data": {"dest": "dest1"}}, "txnMetadata": {"seqNo": 1}}),
+        ]);
+        ledger.bls_seeds.insert("n1".to_string(), "00000000000000000000000000000n1".to_string());
+        ledger.bls_seeds.insert("n2".to_string(), "00000000000000000000000000000n2".to_string());
+
+        let mut networker = _networker(ledger);
+
+        let request = json!({"reqId": 1, "operation": {"type": GET_TXN, "data": 1, "ledgerId": 1}}).to_string();
+        networker.process_event(Some(NetworkerEvent::SendOneRequest(request, REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
+
+        let reply: SJsonValue = serde_json::from_str(&_fetch(&networker)[0].1).unwrap();
+        let multi_signature = &reply["result"]["state_proof"]["multi_signature"];
+        assert_eq!(reply["result"]["data"]["rootHash"], multi_signature["value"]["txn_root_hash"]);
+        assert_eq!(1, multi_signature["value"]["ledger_id"].as_u64().unwrap());
+        assert_eq!(2, multi_signature["participants"].as_array().unwrap().len());
+        assert!(multi_signature["signature"].is_string());
+    }
+
+    #[test]
+    fn in_process_networker_resend_works() {
+        let mut networker = _networker(InProcessLedgerConfig::default());
+        networker.process_event(Some(NetworkerEvent::SendOneRequest(_request(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
+        networker.process_event(Some(NetworkerEvent::Resend(REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+
+        let nodes: Vec<String> = _fetch(&networker).into_iter().map(|(node_alias, _)| node_alias).collect();
+        assert_eq!(vec!["n1".to_string(), "n2".to_string()], nodes);
+    }
+
+    #[test]
+    fn in_process_networker_resend_works_for_nodes_preference() {
+        let mut networker = _networker(InProcessLedgerConfig::default());
+        let preference = NodesPreference {
+            preferred: vec!["n2".to_string()],
+            nodes_to_send: Some(vec!["n1".to_string(), "n2".to_string()]),
+        };
+        networker.process_event(Some(NetworkerEvent::SendOneRequest(_request(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, Some(preference))));
+        networker.process_event(Some(NetworkerEvent::Resend(REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+        networker.process_event(Some(NetworkerEvent::Resend(REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+
+        let nodes: Vec<String> = _fetch(&networker).into_iter().map(|(node_alias, _)| node_alias).collect();
+        assert_eq!(vec!["n2".to_string(), "n1".to_string(), "n2".to_string()], nodes);
+    }
+
+    #[test]
+    fn in_process_networker_send_works_for_all_nodes_excluded() {
+        let mut networker = _networker(InProcessLedgerConfig::default());
+        let preference = NodesPreference {
+            preferred: vec![],
+            nodes_to_send: Some(vec!["unknown".to_string()]),
+        };
+        networker.process_event(Some(NetworkerEvent::SendOneRequest(_request(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, Some(preference))));
+        networker.process_event(Some(NetworkerEvent::Resend(REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+
+        assert!(_fetch(&networker).is_empty());
+    }
+
+    #[test]
+    fn in_process_networker_ledger_status_works() {
+        let ls = LedgerStatus {
+            txnSeqNo: 4,
+            merkleRoot: "root".to_string(),
+            ledgerId: 0,
+            ppSeqNo: None,
+            viewNo: None,
+            protocolVersion: Some(2),
+        };
+
+        let mut networker = _networker(InProcessLedgerConfig::default());
+        networker.process_event(Some(NetworkerEvent::SendAllRequest(serde_json::to_string(&Message::LedgerStatus(ls)).unwrap(),
+                                                                    "root".to_string(), POOL_ACK_TIMEOUT, None)));
+
+        for (_, reply) in _fetch(&networker) {
+            match Message::from_raw_str(&reply).unwrap() {
+                Message::LedgerStatus(ls) => {
+                    assert_eq!(4, ls.txnSeqNo);
+                    assert_eq!("root", ls.merkleRoot);
+                }
+                _ => panic!("Unexpected message")
+            }
+        }
+    }
+
+    #[test]
+    fn in_process_networker_ledger_catchup_works() {
+        let mut ledger = InProcessLedgerConfig::default();
+        ledger.txns.insert("1".to_string(), vec![
+            json!({"txn": {"type": "1", "data": {"dest": "dest1"}}, "txnMetadata": {"seqNo": 1}}),
+            json!({"txn": {"type": "1", "data": {"dest": "dest2"}}, "txnMetadata": {"seqNo": 2}}),
+        ]);
+
+        let mut networker = _networker(ledger);
+
+        let ls = LedgerStatus { ledgerId: 1, ..LedgerStatus::default() };
+        networker.process_event(Some(NetworkerEvent::SendAllRequest(serde_json::to_string(&Message::LedgerStatus(ls)).unwrap(),
+                                                                    REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
+
+        let replies = _fetch(&networker);
+        assert_eq!(2, replies.len());
+        match Message::from_raw_str(&replies[0].1).unwrap() {
+            Message::LedgerStatus(ls) => {
+                assert_eq!(1, ls.ledgerId);
+                assert_eq!(2, ls.txnSeqNo);
+            }
+            _ => panic!("Unexpected message")
+        }
+
+        let req = CatchupReq { ledgerId: 1, seqNoStart: 2, seqNoEnd: 2, catchupTill: 2 };
+        networker.process_event(Some(NetworkerEvent::SendOneRequest(serde_json::to_string(&Message::CatchupReq(req)).unwrap(),
+                                                                    REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
+
+        match Message::from_raw_str(&_fetch(&networker)[0].1).unwrap() {
+            Message::CatchupRep(rep) => {
+                assert_eq!(1, rep.ledgerId);
+                assert_eq!(vec!["2"], rep.txns.keys().collect::<Vec<&String>>());
+            }
+            _ => panic!("Unexpected message")
+        }
+    }
+
+    #[test]
+    fn in_process_networker_silent_nodes_works() {
+        let mut ledger = InProcessLedgerConfig::default();
+        ledger.silent_nodes.push("n1".to_string());
+
+        let mut networker = _networker(ledger);
+        networker.process_event(Some(NetworkerEvent::SendAllRequest(_request(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
+
+        let replies = _fetch(&networker);
+        assert_eq!(1, replies.len());
+        assert_eq!("n2", replies[0].0);
+
+        let ((req_id, _), timeout) = networker.get_timeout();
+        assert_eq!(REQ_ID, req_id);
+        assert!(timeout <= POOL_ACK_TIMEOUT * 1000);
+
+        networker.process_event(Some(NetworkerEvent::CleanTimeout(REQ_ID.to_string(), None)));
+        assert_eq!(::std::i64::MAX, networker.get_timeout().1);
+    }
+}
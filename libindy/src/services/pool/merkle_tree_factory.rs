@@ -3,20 +3,40 @@ use std::collections::HashMap;
 use std::io::{BufRead, Read, Write};
 use std::path::PathBuf;
 
+use rust_base58::ToBase58;
 use serde_json;
 use serde_json::Value as SJsonValue;
 
 use crate::domain::ledger::request::ProtocolVersion;
+use crate::domain::pool::PoolConfig;
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::types::{NodeTransaction, NodeTransactionV0, NodeTransactionV1};
 use crate::utils::environment;
+use indy_utils::crypto::hash::hash as openssl_hash;
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 const POOL_EXT : &str = "txn";
+const SNAPSHOT_VERSION: u8 = 3;
+
+/// Header of the merkle tree snapshot stored next to the pool ledger cache.
+///
+/// Snapshot file contains length prefixed message pack of the header followed by message pack of the tree hashes.
+/// Transactions aren't duplicated in the snapshot, they are read from the pool ledger cache only when node state is built.
+#[derive(Debug, Serialize, Deserialize)]
+struct SnapshotHeader {
+    version: u8,
+    size: usize,
+    root_hash: String,
+    cache_len: u64,
+    genesis_digest: String,
+    digest: String,
+}
 
 pub fn create(pool_name: &str) -> IndyResult<MerkleTree> {
+    _check_genesis(pool_name)?;
+
     let mut p = environment::pool_path(pool_name);
 
     let mut p_stored = p.clone();
@@ -35,12 +55,28 @@ pub fn create(pool_name: &str) -> IndyResult<MerkleTree> {
 
         _from_genesis(&p)
     } else {
+        match _from_snapshot(pool_name, &p_stored) {
+            Ok(mt) => {
+                trace!("Merkle tree restored from snapshot");
+                return Ok(mt);
+            }
+            Err(err) => trace!("Can't restore merkle tree from snapshot: {:?}", err)
+        }
+
         trace!("Restoring merkle tree from cache");
-        _from_cache(&p_stored)
+        let mt = _from_cache(&p_stored)?;
+
+        if let Err(err) = save_snapshot(pool_name, &mt) {
+            warn!("Can't save merkle tree snapshot: {:?}", err);
+        }
+
+        Ok(mt)
     }
 }
 
 pub fn drop_cache(pool_name: &str) -> IndyResult<()> {
+    _drop_snapshot(pool_name)?;
+
     let p = get_pool_stored_path(pool_name, false);
     if p.exists() {
         warn!("Cache is invalid -- dropping it!");
@@ -84,6 +120,154 @@ fn _from_cache(file_name: &PathBuf) -> IndyResult<MerkleTree> {
     Ok(mt)
 }
 
+/// Stores verified merkle tree so the next pool opening can skip rebuilding it from the cached txns.
+///
+/// Snapshot is bound to the tree content by digest and to the pool genesis and ledger cache files it was built from.
+pub fn save_snapshot(pool_name: &str, mt: &MerkleTree) -> IndyResult<()> {
+    let tree = rmp_serde::encode::to_vec(&mt.hashes())
+        .to_indy(IndyErrorKind::InvalidState, "Can't encode merkle tree snapshot")?;
+
+    let header = SnapshotHeader {
+        version: SNAPSHOT_VERSION,
+        size: mt.count(),
+        root_hash: mt.root_hash().to_base58(),
+        cache_len: _cache_len(&get_pool_stored_path(pool_name, false))?,
+        genesis_digest: _genesis_digest(pool_name)?,
+        digest: openssl_hash(&tree)?.to_base58(),
+    };
+
+    let header = rmp_serde::encode::to_vec_named(&header)
+        .to_indy(IndyErrorKind::InvalidState, "Can't encode merkle tree snapshot header")?;
+
+    let p = get_pool_snapshot_path(pool_name);
+    let mut p_tmp = p.clone();
+    p_tmp.set_extension("tmp");
+
+    {
+        let mut file = fs::File::create(&p_tmp)
+            .to_indy(IndyErrorKind::IOError, "Can't create merkle tree snapshot file")?;
+
+        _dump_vec_to_file(&[header], &mut file)?;
+
+        file.write_all(&tree)
+            .to_indy(IndyErrorKind::IOError, "Can't write to merkle tree snapshot file")?;
+    }
+
+    fs::rename(&p_tmp, &p)
+        .to_indy(IndyErrorKind::IOError, "Can't store merkle tree snapshot file")
+}
+
+fn _from_snapshot(pool_name: &str, p_stored: &PathBuf) -> IndyResult<MerkleTree> {
+    let p = get_pool_snapshot_path(pool_name);
+
+    if !p.exists() {
+        return Err(err_msg(IndyErrorKind::InvalidState, "Merkle tree snapshot doesn't exist"));
+    }
+
+    _read_snapshot(pool_name, &p, p_stored).map_err(|err| {
+        warn!("Merkle tree snapshot is invalid -- dropping it!");
+        let _ = fs::remove_file(&p);
+        err
+    })
+}
+
+/// Snapshot is trusted once its digest and bindings are checked, so neither the cached txns are read
+/// nor the tree is rehashed here.
+fn _read_snapshot(pool_name: &str, p: &PathBuf, p_stored: &PathBuf) -> IndyResult<MerkleTree> {
+    let mut f = fs::File::open(p)
+        .to_indy(IndyErrorKind::IOError, "Can't open merkle tree snapshot file")?;
+
+    let header_len = f.read_u64::<LittleEndian>()
+        .to_indy(IndyErrorKind::InvalidState, "Malformed merkle tree snapshot file")?;
+
+    let mut header = vec![0; header_len as usize];
+    f.read_exact(header.as_mut())
+        .to_indy(IndyErrorKind::InvalidState, "Malformed merkle tree snapshot file")?;
+
+    let header: SnapshotHeader = rmp_serde::decode::from_slice(&header)
+        .to_indy(IndyErrorKind::InvalidState, "Malformed merkle tree snapshot header")?;
+
+    if header.version != SNAPSHOT_VERSION {
+        return Err(err_msg(IndyErrorKind::InvalidState, format!("Unsupported merkle tree snapshot version {}", header.version)));
+    }
+
+    if header.cache_len != _cache_len(p_stored)? {
+        return Err(err_msg(IndyErrorKind::InvalidState, "Merkle tree snapshot doesn't match pool ledger cache"));
+    }
+
+    if header.genesis_digest != _genesis_digest(pool_name)? {
+        return Err(err_msg(IndyErrorKind::InvalidState, "Merkle tree snapshot was built for other genesis txns"));
+    }
+
+    let mut tree = Vec::new();
+    f.read_to_end(&mut tree)
+        .to_indy(IndyErrorKind::IOError, "Can't read from merkle tree snapshot file")?;
+
+    if openssl_hash(&tree)?.to_base58() != header.digest {
+        return Err(err_msg(IndyErrorKind::InvalidState, "Merkle tree snapshot digest mismatch"));
+    }
+
+    let mt: MerkleTree = rmp_serde::decode::from_slice(&tree)
+        .to_indy(IndyErrorKind::InvalidState, "Malformed merkle tree snapshot")?;
+
+    if mt.count() != header.size || mt.root_hash().to_base58() != header.root_hash {
+        return Err(err_msg(IndyErrorKind::InvalidState, "Merkle tree snapshot doesn't match its header"));
+    }
+
+    Ok(mt)
+}
+
+fn _cache_len(p_stored: &PathBuf) -> IndyResult<u64> {
+    fs::metadata(p_stored)
+        .map(|metadata| metadata.len())
+        .to_indy(IndyErrorKind::IOError, "Can't read pool ledger cache file metadata")
+}
+
+fn _genesis_digest(pool_name: &str) -> IndyResult<String> {
+    let genesis = fs::read(get_pool_stored_path_base(pool_name, false, pool_name, POOL_EXT))
+        .to_indy(IndyErrorKind::IOError, "Can't read genesis txn file")?;
+
+    Ok(openssl_hash(&genesis)?.to_base58())
+}
+
+/// Txns of the merkle tree leaves. Leaves restored from the snapshot keep only their hashes,
+/// so their txns are read from the pool ledger cache in order.
+fn _tree_txns(pool_name: &str, merkle_tree: &MerkleTree) -> IndyResult<Vec<Vec<u8>>> {
+    let mut txns = merkle_tree.iter().cloned().collect::<Vec<Vec<u8>>>();
+
+    let missed = match txns.iter().rposition(|txn| txn.is_empty()) {
+        Some(idx) => idx + 1,
+        None => return Ok(txns)
+    };
+
+    let mut f = fs::File::open(get_pool_stored_path(pool_name, false))
+        .to_indy(IndyErrorKind::IOError, "Can't open pool ledger cache file")?;
+
+    for txn in txns[..missed].iter_mut() {
+        let bytes = f.read_u64::<LittleEndian>()
+            .to_indy(IndyErrorKind::InvalidState, "Pool ledger cache has less txns than merkle tree")?;
+
+        let mut cached = vec![0; bytes as usize];
+        f.read_exact(cached.as_mut())
+            .to_indy(IndyErrorKind::InvalidState, "Malformed pool ledger cache file")?;
+
+        if txn.is_empty() {
+            *txn = cached;
+        }
+    }
+
+    Ok(txns)
+}
+
+fn _drop_snapshot(pool_name: &str) -> IndyResult<()> {
+    let p = get_pool_snapshot_path(pool_name);
+    if p.exists() {
+        fs::remove_file(p)
+            .to_indy(IndyErrorKind::IOError, "Can't drop merkle tree snapshot file")?;
+    }
+    Ok(())
+}
+
 fn _from_genesis(file_name: &PathBuf) -> IndyResult<MerkleTree> {
     let mut mt = MerkleTree::from_vec(Vec::new())?;
 
@@ -103,10 +287,50 @@ fn _from_genesis(file_name: &PathBuf) -> IndyResult<MerkleTree> {
     Ok(mt)
 }
 
+/// Rejects genesis transactions stored for the pool if they don't match the genesis hash
+/// pinned in the pool config at creation time.
+fn _check_genesis(pool_name: &str) -> IndyResult<()> {
+    let p_config = get_pool_stored_path_base(pool_name, false, "config", "json");
+
+    if !p_config.exists() {
+        return Ok(());
+    }
+
+    let config = fs::read_to_string(&p_config)
+        .to_indy(IndyErrorKind::IOError, "Can't read pool config file")?;
+
+    let config: PoolConfig = serde_json::from_str(&config)
+        .to_indy(IndyErrorKind::InvalidState, "Can't deserialize pool config")?;
+
+    let expected_hash = match config.genesis_hash {
+        Some(expected_hash) => expected_hash,
+        None => return Ok(())
+    };
+
+    let p_genesis = get_pool_stored_path_base(pool_name, false, pool_name, POOL_EXT);
+
+    if !p_genesis.exists() {
+        return Err(err_msg(IndyErrorKind::PoolNotCreated, format!("Pool is not created for name: {:?}", pool_name)));
+    }
+
+    let genesis_hash = _from_genesis(&p_genesis)?.root_hash().to_base58();
+
+    if genesis_hash != expected_hash {
+        return Err(err_msg(IndyErrorKind::InvalidState,
+                           format!("Genesis transactions of pool {:?} don't match expected hash: {} != {}", pool_name, genesis_hash, expected_hash)));
+    }
+
+    Ok(())
+}
+
 fn get_pool_stored_path(pool_name: &str, create_dir: bool) -> PathBuf {
     get_pool_stored_path_base(pool_name, create_dir, "stored", "btxn")
 }
 
+fn get_pool_snapshot_path(pool_name: &str) -> PathBuf {
+    get_pool_stored_path_base(pool_name, false, "stored", "snapshot")
+}
+
 fn get_pool_stored_path_base(pool_name: &str, create_dir: bool, filename: &str, ext: &str) -> PathBuf {
     let mut path = environment::pool_path(pool_name);
     if create_dir {
@@ -189,10 +413,10 @@ fn _parse_txn_from_json(txn: &str) -> IndyResult<Vec<u8>> {
         .to_indy(IndyErrorKind::InvalidState, "Can't encode genesis txn as message pack")
 }
 
-pub fn build_node_state(merkle_tree: &MerkleTree) -> IndyResult<HashMap<String, NodeTransactionV1>> {
+pub fn build_node_state(pool_name: &str, merkle_tree: &MerkleTree) -> IndyResult<HashMap<String, NodeTransactionV1>> {
     let mut gen_tnxs: HashMap<String, NodeTransactionV1> = HashMap::new();
 
-    for gen_txn in merkle_tree {
+    for gen_txn in _tree_txns(pool_name, merkle_tree)? {
         let gen_txn: NodeTransaction = rmp_serde::decode::from_slice(gen_txn.as_slice())
             .to_indy(IndyErrorKind::InvalidState, "MerkleTree contains invalid item")?;
 
@@ -232,6 +456,19 @@ pub fn from_file(txn_file: &str) -> IndyResult<MerkleTree> {
     _from_genesis(&PathBuf::from(txn_file))
 }
 
+pub fn from_txns(txns: &[SJsonValue]) -> IndyResult<MerkleTree> {
+    let mut mt = MerkleTree::from_vec(Vec::new())?;
+
+    for txn in txns {
+        let txn = rmp_serde::encode::to_vec_named(txn)
+            .to_indy(IndyErrorKind::InvalidState, "Can't encode genesis txn as message pack")?;
+
+        mt.append(txn)?;
+    }
+
+    Ok(mt)
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -272,7 +509,7 @@ mod tests {
         _write_genesis_txns("pool_worker_build_node_state_works_for_new_txns_format_and_1_protocol_version", &txns_src);
 
         let merkle_tree = super::create("pool_worker_build_node_state_works_for_new_txns_format_and_1_protocol_version").unwrap();
-        let res = super::build_node_state(&merkle_tree);
+        let res = super::build_node_state("pool_worker_build_node_state_works_for_new_txns_format_and_1_protocol_version", &merkle_tree);
         assert_kind!(IndyErrorKind::PoolIncompatibleProtocolVersion, res);
 
         test::cleanup_storage("pool_worker_build_node_state_works_for_new_txns_format_and_1_protocol_version");
@@ -305,7 +542,7 @@ mod tests {
             });
 
             let merkle_tree = super::create(pool_name).unwrap();
-            let _node_state = super::build_node_state(&merkle_tree).unwrap();
+            let _node_state = super::build_node_state(pool_name, &merkle_tree).unwrap();
         }
         test::cleanup_storage("pool_worker_works_for_deserialize_cache");
     }
@@ -328,6 +565,45 @@ mod tests {
         test::cleanup_storage("pool_worker_restore_merkle_tree_works_from_genesis_txns");
     }
 
+    fn _write_pool_config(pool_name: &str, config: &str) {
+        let path = get_pool_stored_path_base(pool_name, true, "config", "json");
+        fs::write(path, config).unwrap();
+    }
+
+    #[test]
+    fn pool_worker_restore_merkle_tree_works_for_pinned_genesis_hash() {
+        test::cleanup_storage("pool_worker_restore_merkle_tree_works_for_pinned_genesis_hash");
+
+        let node_txns = test::gen_txns();
+        let genesis_txns: Vec<SJsonValue> = node_txns[0..2].iter().map(|txn| serde_json::from_str(txn).unwrap()).collect();
+        let genesis_hash = from_txns(&genesis_txns).unwrap().root_hash().to_base58();
+
+        _write_genesis_txns("pool_worker_restore_merkle_tree_works_for_pinned_genesis_hash", &node_txns[0..2].join("\n"));
+        _write_pool_config("pool_worker_restore_merkle_tree_works_for_pinned_genesis_hash", &json!({"genesis_hash": genesis_hash}).to_string());
+
+        let merkle_tree = super::create("pool_worker_restore_merkle_tree_works_for_pinned_genesis_hash").unwrap();
+        assert_eq!(merkle_tree.root_hash().to_base58(), genesis_hash);
+
+        test::cleanup_storage("pool_worker_restore_merkle_tree_works_for_pinned_genesis_hash");
+    }
+
+    #[test]
+    fn pool_worker_restore_merkle_tree_works_for_tampered_genesis() {
+        test::cleanup_storage("pool_worker_restore_merkle_tree_works_for_tampered_genesis");
+
+        let node_txns = test::gen_txns();
+        let genesis_txns: Vec<SJsonValue> = node_txns[0..2].iter().map(|txn| serde_json::from_str(txn).unwrap()).collect();
+        let genesis_hash = from_txns(&genesis_txns).unwrap().root_hash().to_base58();
+
+        _write_genesis_txns("pool_worker_restore_merkle_tree_works_for_tampered_genesis", &node_txns[0..3].join("\n"));
+        _write_pool_config("pool_worker_restore_merkle_tree_works_for_tampered_genesis", &json!({"genesis_hash": genesis_hash}).to_string());
+
+        let res = super::create("pool_worker_restore_merkle_tree_works_for_tampered_genesis");
+        assert_kind!(IndyErrorKind::InvalidState, res);
+
+        test::cleanup_storage("pool_worker_restore_merkle_tree_works_for_tampered_genesis");
+    }
+
     #[test]
     fn pool_worker_build_node_state_works_for_old_format() {
         test::cleanup_storage("pool_worker_build_node_state_works_for_old_format");
@@ -342,7 +618,7 @@ mod tests {
         _write_genesis_txns("pool_worker_build_node_state_works_for_old_format", &txns_src);
 
         let merkle_tree = super::create("pool_worker_build_node_state_works_for_old_format").unwrap();
-        let node_state = super::build_node_state(&merkle_tree).unwrap();
+        let node_state = super::build_node_state("pool_worker_build_node_state_works_for_old_format", &merkle_tree).unwrap();
 
         assert_eq!(1, ProtocolVersion::get());
 
@@ -372,7 +648,7 @@ mod tests {
         _write_genesis_txns("pool_worker_build_node_state_works_for_new_format", &txns_src);
 
         let merkle_tree = super::create("pool_worker_build_node_state_works_for_new_format").unwrap();
-        let node_state = super::build_node_state(&merkle_tree).unwrap();
+        let node_state = super::build_node_state("pool_worker_build_node_state_works_for_new_format", &merkle_tree).unwrap();
 
         assert_eq!(2, ProtocolVersion::get());
 
@@ -397,9 +673,117 @@ mod tests {
         _write_genesis_txns("pool_worker_build_node_state_works_for_old_txns_format_and_2_protocol_version", &txns_src);
 
         let merkle_tree = super::create("pool_worker_build_node_state_works_for_old_txns_format_and_2_protocol_version").unwrap();
-        let res = super::build_node_state(&merkle_tree);
+        let res = super::build_node_state("pool_worker_build_node_state_works_for_old_txns_format_and_2_protocol_version", &merkle_tree);
         assert_kind!(IndyErrorKind::PoolIncompatibleProtocolVersion, res);
 
         test::cleanup_storage("pool_worker_build_node_state_works_for_old_txns_format_and_2_protocol_version");
     }
-}
\ No newline at end of file
+
+    fn _write_genesis_and_cache(pool_name: &str) {
+        _set_protocol_version(TEST_PROTOCOL_VERSION);
+
+        let node_txns = test::gen_txns();
+        _write_genesis_txns(pool_name, &node_txns[0..2].join("\n"));
+
+        let new_txns: Vec<Vec<u8>> = node_txns[2..4].iter()
+            .map(|txn| _parse_txn_from_json(txn).unwrap())
+            .collect();
+        dump_new_txns(pool_name, &new_txns).unwrap();
+    }
+
+    #[test]
+    fn pool_worker_restore_merkle_tree_works_from_snapshot() {
+        let pool_name = "pool_worker_restore_merkle_tree_works_from_snapshot";
+        test::cleanup_storage(pool_name);
+
+        _write_genesis_and_cache(pool_name);
+
+        let merkle_tree = super::create(pool_name).unwrap();
+        assert_eq!(4, merkle_tree.count());
+        assert!(get_pool_snapshot_path(pool_name).exists());
+
+        let p_stored = get_pool_stored_path(pool_name, false);
+        let restored = _from_snapshot(pool_name, &p_stored).unwrap();
+        assert_eq!(merkle_tree.count(), restored.count());
+        assert_eq!(merkle_tree.root_hash(), restored.root_hash());
+        assert_eq!(0, restored.iter().map(|txn| txn.len()).sum::<usize>());
+        assert_eq!(build_node_state(pool_name, &merkle_tree).unwrap(), build_node_state(pool_name, &restored).unwrap());
+
+        test::cleanup_storage(pool_name);
+    }
+
+    #[test]
+    fn pool_worker_restore_merkle_tree_works_for_corrupted_snapshot() {
+        let pool_name = "pool_worker_restore_merkle_tree_works_for_corrupted_snapshot";
+        test::cleanup_storage(pool_name);
+
+        _write_genesis_and_cache(pool_name);
+
+        let merkle_tree = super::create(pool_name).unwrap();
+
+        let mut f = fs::OpenOptions::new().append(true).open(get_pool_snapshot_path(pool_name)).unwrap();
+        f.write_all(&[0]).unwrap();
+
+        let p_stored = get_pool_stored_path(pool_name, false);
+        assert_kind!(IndyErrorKind::InvalidState, _from_snapshot(pool_name, &p_stored));
+        assert!(!get_pool_snapshot_path(pool_name).exists());
+
+        let restored = super::create(pool_name).unwrap();
+        assert_eq!(merkle_tree.root_hash(), restored.root_hash());
+        assert!(get_pool_snapshot_path(pool_name).exists());
+
+        test::cleanup_storage(pool_name);
+    }
+
+    #[test]
+    fn pool_worker_restore_merkle_tree_works_for_stale_snapshot() {
+        let pool_name = "pool_worker_restore_merkle_tree_works_for_stale_snapshot";
+        test::cleanup_storage(pool_name);
+
+        _write_genesis_and_cache(pool_name);
+        super::create(pool_name).unwrap();
+
+        let new_txn = _parse_txn_from_json(&test::gen_txns()[0].replace("Node1", "Node5")).unwrap();
+        dump_new_txns(pool_name, &[new_txn]).unwrap();
+
+        let p_stored = get_pool_stored_path(pool_name, false);
+        assert_kind!(IndyErrorKind::InvalidState, _from_snapshot(pool_name, &p_stored));
+
+        let merkle_tree = super::create(pool_name).unwrap();
+        assert_eq!(5, merkle_tree.count());
+
+        test::cleanup_storage(pool_name);
+    }
+
+    #[test]
+    fn pool_worker_restore_merkle_tree_works_for_snapshot_of_other_genesis() {
+        let pool_name = "pool_worker_restore_merkle_tree_works_for_snapshot_of_other_genesis";
+        test::cleanup_storage(pool_name);
+
+        _write_genesis_and_cache(pool_name);
+        super::create(pool_name).unwrap();
+
+        let node_txns = test::gen_txns();
+        _write_genesis_txns(pool_name, &node_txns[1..3].join("\n"));
+
+        let p_stored = get_pool_stored_path(pool_name, false);
+        assert_kind!(IndyErrorKind::InvalidState, _from_snapshot(pool_name, &p_stored));
+
+        test::cleanup_storage(pool_name);
+    }
+
+    #[test]
+    fn pool_worker_drop_cache_works_for_snapshot() {
+        let pool_name = "pool_worker_drop_cache_works_for_snapshot";
+        test::cleanup_storage(pool_name);
+
+        _write_genesis_and_cache(pool_name);
+        super::create(pool_name).unwrap();
+        assert!(get_pool_snapshot_path(pool_name).exists());
+
+        drop_cache(pool_name).unwrap();
+        assert!(!get_pool_snapshot_path(pool_name).exists());
+
+        test::cleanup_storage(pool_name);
+    }
+}
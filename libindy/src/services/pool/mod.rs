@@ -10,7 +10,7 @@ use self::zmq::Socket;
 
 use std::{fs, io};
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::Write;
 use std::sync::Mutex;
 
@@ -19,29 +19,38 @@ use serde::de::DeserializeOwned;
 
 use crate::api::ledger::{CustomFree, CustomTransactionParser};
 use crate::domain::{
-    pool::{PoolConfig, PoolOpenConfig},
+    pool::{PoolConfig, PoolOpenConfig, RequestOptions},
     ledger::response::{
         Message,
         Reply,
+        ReplyVerification,
         ResponseMetadata
     }
 };
 use indy_api_types::errors::*;
+use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::pool::{Pool, ZMQPool};
+use crate::services::pool::request_handler::DEFAULT_GENERATOR;
 use crate::utils::environment;
-use crate::services::pool::events::{COMMAND_EXIT, COMMAND_CONNECT, COMMAND_REFRESH};
+use crate::services::pool::events::{COMMAND_EXIT, COMMAND_CONNECT, COMMAND_REFRESH, COMMAND_STATUS};
 use indy_api_types::{CommandHandle, PoolHandle};
 use indy_utils::{next_command_handle, next_pool_handle};
-use ursa::bls::VerKey;
+use indy_utils::crypto::ed25519_sign;
+use indy_api_types::validation::Validatable;
+use rust_base58::{FromBase58, ToBase58};
+use ursa::bls::{Generator, VerKey};
 
 mod catchup;
 mod commander;
 mod events;
+#[cfg(feature = "in_process_pool")]
+mod in_process_networker;
 mod merkle_tree_factory;
 mod networker;
 mod pool;
 mod request_handler;
 mod state_proof;
+mod stats;
 mod types;
 
 lazy_static! {
@@ -74,38 +83,66 @@ impl PoolService {
             return Err(err_msg(IndyErrorKind::PoolConfigAlreadyExists, format!("Pool ledger config file with name \"{}\" already exists", name)));
         }
 
-        // check that we can build MerkeleTree from genesis transaction file
+        pool_config.validate()
+            .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+
+        // check that we can build MerkeleTree from genesis transactions
         //TODO: move parse to correct place
-        let mt = merkle_tree_factory::from_file(&pool_config.genesis_txn)?;
+        let mt = match (&pool_config.genesis_txn, &pool_config.genesis_txns) {
+            (Some(genesis_txn), _) => merkle_tree_factory::from_file(genesis_txn)?,
+            (None, Some(genesis_txns)) => merkle_tree_factory::from_txns(genesis_txns)?,
+            (None, None) => unreachable!()
+        };
 
         if mt.count() == 0 {
             return Err(err_msg(IndyErrorKind::InvalidStructure, "Empty genesis transaction file"));
         }
 
+        let genesis_hash = PoolService::_verify_genesis(&mt, &pool_config)?;
+
         fs::create_dir_all(path.as_path())
             .to_indy(IndyErrorKind::IOError, "Can't create pool config directory")?;
 
         path.push(name);
         path.set_extension("txn");
 
-        {
+        let mut gt_fout = fs::File::create(path.as_path())
+            .to_indy(IndyErrorKind::IOError,
+                     format!("Can't create genesis txn file {:?}", path.as_path()))?;
+
+        if let Some(ref genesis_txn) = pool_config.genesis_txn {
             // fs::copy also copies attributes of the file
             // and copying permissions can be problem for some cases
 
-            let mut gt_fin = fs::File::open(&pool_config.genesis_txn)
-                .to_indy(IndyErrorKind::IOError,
-                         format!("Can't open genesis txn file {:?}", &pool_config.genesis_txn))?;
-
-            let mut gt_fout = fs::File::create(path.as_path())
+            let mut gt_fin = fs::File::open(genesis_txn)
                 .to_indy(IndyErrorKind::IOError,
-                         format!("Can't create genesis txn file {:?}", path.as_path()))?;
+                         format!("Can't open genesis txn file {:?}", genesis_txn))?;
 
             io::copy(&mut gt_fin, &mut gt_fout)
                 .to_indy(IndyErrorKind::IOError,
                          format!("Can't copy genesis txn file from {:?} to {:?}",
-                                 &pool_config.genesis_txn, path.as_path()))?;
+                                 genesis_txn, path.as_path()))?;
+        } else if let Some(ref genesis_txns) = pool_config.genesis_txns {
+            for txn in genesis_txns {
+                writeln!(gt_fout, "{}", txn)
+                    .to_indy(IndyErrorKind::IOError,
+                             format!("Can't write genesis txn file {:?}", path.as_path()))?;
+            }
         }
 
+        gt_fout
+            .flush()
+            .to_indy(IndyErrorKind::IOError, format!("Can't write genesis txn file {:?}", path.as_path()))?;
+
+        // inline transactions are already stored in the genesis txn file of the pool,
+        // and verified genesis is pinned by its hash to be checked on every opening
+        let pool_config = PoolConfig {
+            genesis_txn: pool_config.genesis_txn,
+            genesis_txns: None,
+            genesis_hash,
+            genesis_signature: pool_config.genesis_signature,
+        };
+
         path.pop();
         path.push("config");
         path.set_extension("json");
@@ -130,6 +167,35 @@ impl PoolService {
         Ok(())
     }
 
+    /// Checks genesis transactions against the expected hash and steward signature from pool config.
+    ///
+    /// Returns base58 encoded merkle root of genesis transactions if any check was requested.
+    fn _verify_genesis(mt: &MerkleTree, pool_config: &PoolConfig) -> IndyResult<Option<String>> {
+        if pool_config.genesis_hash.is_none() && pool_config.genesis_signature.is_none() {
+            return Ok(None);
+        }
+
+        let genesis_hash = mt.root_hash().to_base58();
+
+        if let Some(ref expected_hash) = pool_config.genesis_hash {
+            if expected_hash != &genesis_hash {
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Genesis transactions don't match expected hash: {} != {}", genesis_hash, expected_hash)));
+            }
+        }
+
+        if let Some(ref genesis_signature) = pool_config.genesis_signature {
+            let verkey = ed25519_sign::PublicKey::from_slice(&genesis_signature.verkey.from_base58()?)?;
+            let signature = ed25519_sign::Signature::from_slice(&genesis_signature.signature.from_base58()?)?;
+
+            if !ed25519_sign::verify(&verkey, mt.root_hash(), &signature)? {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Genesis transactions signature is invalid"));
+            }
+        }
+
+        Ok(Some(genesis_hash))
+    }
+
     pub fn delete(&self, name: &str) -> IndyResult<()> {
         for ref pool in self.open_pools.try_borrow()?.values() {
             if pool.pool.get_name().eq(name) {
@@ -159,7 +225,7 @@ impl PoolService {
         let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets(&format!("pool_{}", name));
 
         new_pool.work(recv_cmd_sock);
-        self._send_msg(pool_handle, COMMAND_CONNECT, &send_cmd_sock, None, None)?;
+        self._send_msg(pool_handle, COMMAND_CONNECT, &send_cmd_sock, None, None, None)?;
 
         self.pending_pools.try_borrow_mut()?
             .insert(new_pool.get_id(), ZMQPool::new(new_pool, send_cmd_sock));
@@ -181,18 +247,46 @@ impl PoolService {
         self.send_action(handle, msg, None, None)
     }
 
+    pub fn send_tx_with_options(&self, handle: PoolHandle, msg: &str, options: &RequestOptions) -> IndyResult<CommandHandle> {
+        self.send_action_with_options(handle, msg, None, None, options)
+    }
+
     pub fn send_action(&self, handle: PoolHandle, msg: &str, nodes: Option<&str>, timeout: Option<i32>) -> IndyResult<CommandHandle> {
+        self._send_request(handle, msg, nodes, timeout, None)
+    }
+
+    pub fn send_action_with_options(&self, handle: PoolHandle, msg: &str, nodes: Option<&str>, timeout: Option<i32>, options: &RequestOptions) -> IndyResult<CommandHandle> {
+        let options = serde_json::to_string(options)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize RequestOptions")?;
+
+        self._send_request(handle, msg, nodes, timeout, Some(&options))
+    }
+
+    fn _send_request(&self, handle: PoolHandle, msg: &str, nodes: Option<&str>, timeout: Option<i32>, options: Option<&str>) -> IndyResult<CommandHandle> {
         let pools = self.open_pools.try_borrow()?;
 
         if let Some(ref pool) = pools.get(&handle) {
             let cmd_id: CommandHandle = next_command_handle();
-            self._send_msg(cmd_id, msg, &pool.cmd_socket, nodes, timeout)?;
+            self._send_msg(cmd_id, msg, &pool.cmd_socket, nodes, timeout, options)?;
             Ok(cmd_id)
         } else {
             Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {:?}", handle)))
         }
     }
 
+    /// Catches up the ledger other than the pool one starting from the local ledger of `ledger_size` txns with `root_hash`.
+    /// The reply contains transactions committed after the local ledger and the ledger root agreed by f + 1 nodes.
+    pub fn catchup_ledger(&self, handle: PoolHandle, ledger_id: u8, ledger_size: usize, root_hash: &[u8]) -> IndyResult<CommandHandle> {
+        if ledger_id == events::POOL_LEDGER_ID {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "The pool ledger is caught up by the pool itself"));
+        }
+
+        let ledger_status = serde_json::to_string(&types::Message::LedgerStatus(catchup::build_ledger_status_for_root(ledger_id, ledger_size, root_hash)))
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize LedgerStatus")?;
+
+        self.send_tx(handle, &ledger_status)
+    }
+
     pub fn register_sp_parser(txn_type: &str,
                               parser: CustomTransactionParser, free: CustomFree) -> IndyResult<()> {
         if events::REQUESTS_FOR_STATE_PROOFS.contains(&txn_type) {
@@ -220,7 +314,7 @@ impl PoolService {
         let mut pools = self.open_pools.try_borrow_mut()?;
 
         match pools.remove(&handle) {
-            Some(ref pool) => self._send_msg(cmd_id, COMMAND_EXIT, &pool.cmd_socket, None, None)?,
+            Some(ref pool) => self._send_msg(cmd_id, COMMAND_EXIT, &pool.cmd_socket, None, None, None)?,
             None => return Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))
         }
 
@@ -231,19 +325,79 @@ impl PoolService {
         self.send_action(handle, COMMAND_REFRESH, None, None)
     }
 
-    fn _send_msg(&self, cmd_id: CommandHandle, msg: &str, socket: &Socket, nodes: Option<&str>, timeout: Option<i32>) -> IndyResult<()> {
+    pub fn get_status(&self, handle: PoolHandle) -> IndyResult<CommandHandle> {
+        self.send_action(handle, COMMAND_STATUS, None, None)
+    }
+
+    fn _send_msg(&self, cmd_id: CommandHandle, msg: &str, socket: &Socket, nodes: Option<&str>, timeout: Option<i32>, options: Option<&str>) -> IndyResult<()> {
         let mut buf = [0u8; 4];
         let mut buf_to = [0u8; 4];
         LittleEndian::write_i32(&mut buf, cmd_id);
         let timeout = timeout.unwrap_or(-1);
         LittleEndian::write_i32(&mut buf_to, timeout);
-        if let Some(nodes) = nodes {
+        if let Some(options) = options {
+            // empty nodes frame means no nodes are specified
+            Ok(socket.send_multipart(&[msg.as_bytes(), &buf, &buf_to, nodes.unwrap_or("").as_bytes(), options.as_bytes()], zmq::DONTWAIT)?)
+        } else if let Some(nodes) = nodes {
             Ok(socket.send_multipart(&[msg.as_bytes(), &buf, &buf_to, nodes.as_bytes()], zmq::DONTWAIT)?)
         } else {
             Ok(socket.send_multipart(&[msg.as_bytes(), &buf, &buf_to], zmq::DONTWAIT)?)
         }
     }
 
+    /// BLS keys of the validator nodes from the locally stored pool ledger by node alias.
+    pub fn get_bls_keys(&self, name: &str) -> IndyResult<BTreeMap<String, Option<String>>> {
+        let merkle_tree = merkle_tree_factory::create(name)?;
+        let nodes = merkle_tree_factory::build_node_state(name, &merkle_tree)?;
+
+        let bls_keys = nodes.values()
+            .filter(|txn| txn.txn.data.data.services.as_ref()
+                .map(|services| services.iter().any(|service| service == "VALIDATOR"))
+                .unwrap_or(false))
+            .map(|txn| (txn.txn.data.data.alias.clone(), txn.txn.data.data.blskey.clone()))
+            .collect();
+
+        Ok(bls_keys)
+    }
+
+    /// BLS keys of the validator nodes of the opened pool by node alias.
+    pub fn get_open_pool_bls_keys(&self, handle: PoolHandle) -> IndyResult<HashMap<String, Option<String>>> {
+        let name = self.open_pools.try_borrow()?
+            .get(&handle)
+            .map(|pool| pool.pool.get_name().to_string())
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {:?}", handle)))?;
+
+        Ok(self.get_bls_keys(&name)?.into_iter().collect())
+    }
+
+    /// Verifies the reply received earlier against the given BLS keys of the pool nodes without contacting the pool.
+    pub fn verify_reply_offline(&self, reply: &str, bls_keys: &HashMap<String, Option<String>>) -> IndyResult<ReplyVerification> {
+        if bls_keys.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "BLS keys of pool nodes are empty"));
+        }
+
+        let nodes = bls_keys.iter()
+            .map(|(alias, bls_key)| -> IndyResult<(String, Option<VerKey>)> {
+                let bls_key = match *bls_key {
+                    Some(ref bls_key) => Some(bls_key.from_base58().ok()
+                        .and_then(|bls_key| VerKey::from_bytes(&bls_key).ok())
+                        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid BLS key of node {}", alias)))?),
+                    None => None
+                };
+                Ok((alias.clone(), bls_key))
+            })
+            .collect::<IndyResult<Nodes>>()?;
+
+        let gen = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+
+        let verified = state_proof::verify_saved_reply(reply, &nodes, pool::_get_f(nodes.len()), &gen)?;
+
+        Ok(ReplyVerification {
+            verified,
+            timestamp: if verified { get_last_signed_time(reply) } else { None },
+        })
+    }
+
     pub fn list(&self) -> IndyResult<Vec<serde_json::Value>> {
         let mut pool = Vec::new();
         let pool_home_path = environment::pool_home_path();
@@ -407,6 +561,21 @@ mod tests {
             assert_eq!(cmd_id, LittleEndian::read_i32(recv[1].as_slice()));
         }
 
+        #[test]
+        fn pool_service_get_status_works() {
+            test::cleanup_storage("pool_service_get_status_works");
+
+            let ps = PoolService::new();
+            let pool_id = next_pool_handle();
+            let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("pool_service_get_status_works");
+            ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(Pool::new("", pool_id, PoolOpenConfig::default()), send_cmd_sock));
+            let cmd_id = ps.get_status(pool_id).unwrap();
+            let recv = recv_cmd_sock.recv_multipart(zmq::DONTWAIT).unwrap();
+            assert_eq!(recv.len(), 3);
+            assert_eq!(COMMAND_STATUS, String::from_utf8(recv[0].clone()).unwrap());
+            assert_eq!(cmd_id, LittleEndian::read_i32(recv[1].as_slice()));
+        }
+
         #[test]
         fn pool_service_delete_works() {
             test::cleanup_storage("pool_service_delete_works");
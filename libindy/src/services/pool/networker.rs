@@ -56,7 +56,7 @@ impl Networker for ZMQNetworker {
 
     fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent> {
         match pe.clone() {
-            Some(NetworkerEvent::SendAllRequest(_, req_id, _, _)) | Some(NetworkerEvent::SendOneRequest(_, req_id, _)) | Some(NetworkerEvent::Resend(req_id, _)) => {
+            Some(NetworkerEvent::SendAllRequest(_, req_id, _, _)) | Some(NetworkerEvent::SendOneRequest(_, req_id, _, _)) | Some(NetworkerEvent::Resend(req_id, _)) => {
                 let num = self.req_id_mappings.get(&req_id).copied().or_else(|| {
                     trace!("sending new request");
                     self.pool_connections.iter().next_back().and_then(|(pc_idx, pc)| {
@@ -76,7 +76,9 @@ impl Networker for ZMQNetworker {
                         trace!("send request in existing conn");
 
                         match self.pool_connections.get_mut(&idx) {
-                            Some(pc) => pc.send_request(pe).expect("FIXME"),
+                            Some(pc) => if let Err(err) = pc.send_request(pe) {
+                                error!("Pool Connection can't send request: {:?}", err)
+                            },
                             None => error!("Pool Connection not found")
                         }
                         self.req_id_mappings.insert(req_id.clone(), idx);
@@ -85,7 +87,9 @@ impl Networker for ZMQNetworker {
                         trace!("send request in new conn");
                         let pc_id = sequence::get_next_id();
                         let mut pc = PoolConnection::new(self.nodes.clone(), self.active_timeout, self.preordered_nodes.clone());
-                        pc.send_request(pe).expect("FIXME");
+                        if let Err(err) = pc.send_request(pe) {
+                            error!("Pool Connection can't send request: {:?}", err)
+                        }
                         self.pool_connections.insert(pc_id, pc);
                         self.req_id_mappings.insert(req_id.clone(), pc_id);
                     }
@@ -170,7 +174,7 @@ pub struct PoolConnection {
     sockets: Vec<Option<ZSocket>>,
     ctx: zmq::Context,
     key_pair: zmq::CurveKeyPair,
-    resend: RefCell<HashMap<String, (usize, String)>>,
+    resend: RefCell<HashMap<String, (usize, String, Vec<usize>)>>,
     timeouts: RefCell<HashMap<(String, String), Tm>>,
     time_created: time::Tm,
     req_cnt: usize,
@@ -256,10 +260,11 @@ impl PoolConnection {
     fn send_request(&mut self, pe: Option<NetworkerEvent>) -> IndyResult<()> {
         trace!("send_request >> pe: {:?}", pe);
         match pe {
-            Some(NetworkerEvent::SendOneRequest(msg, req_id, timeout)) => {
+            Some(NetworkerEvent::SendOneRequest(msg, req_id, timeout, preference)) => {
                 self.req_cnt += 1;
-                self._send_msg_to_one_node(0, req_id.clone(), msg.clone(), timeout)?;
-                self.resend.borrow_mut().insert(req_id, (0, msg));
+                let order = NodesPreference::order(preference.as_ref(), &self.nodes)?;
+                self._send_msg_to_one_node(order[0], req_id.clone(), msg.clone(), timeout)?;
+                self.resend.borrow_mut().insert(req_id, (0, msg, order));
             }
             Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, nodes_to_send)) => {
                 self.req_cnt += 1;
@@ -270,10 +275,10 @@ impl PoolConnection {
                 }
             }
             Some(NetworkerEvent::Resend(req_id, timeout)) => {
-                let resend = if let Some(&mut (ref mut cnt, ref req)) = self.resend.borrow_mut().get_mut(&req_id) {
+                let resend = if let Some(&mut (ref mut cnt, ref req, ref order)) = self.resend.borrow_mut().get_mut(&req_id) {
                     *cnt += 1;
                     //TODO: FIXME: We can collect consensus just walking through if we are not collecting node aliases on the upper layer.
-                    Some((*cnt % self.nodes.len(), req.clone()))
+                    Some((order[*cnt % order.len()], req.clone()))
                 } else {
                     error!("Unknown req_id for resending {}", req_id); //FIXME handle at RH level
                     None
@@ -453,7 +458,7 @@ pub mod networker_tests {
             assert!(networker.pool_connections.is_empty());
             assert!(networker.req_id_mappings.is_empty());
 
-            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
 
             assert_eq!(1, networker.pool_connections.len());
             assert_eq!(1, networker.req_id_mappings.len());
@@ -547,11 +552,11 @@ pub mod networker_tests {
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
             for i in 0..5 {
-                networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), i.to_string(), POOL_ACK_TIMEOUT)));
+                networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), i.to_string(), POOL_ACK_TIMEOUT, None)));
                 assert_eq!(1, networker.pool_connections.len());
             }
 
-            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), "6".to_string(), POOL_ACK_TIMEOUT)));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), "6".to_string(), POOL_ACK_TIMEOUT, None)));
             assert_eq!(2, networker.pool_connections.len());
 
             let mut pc_iter = networker.pool_connections.values();
@@ -570,7 +575,7 @@ pub mod networker_tests {
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
             for i in 0..5 {
-                networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), i.to_string(), POOL_ACK_TIMEOUT)));
+                networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), i.to_string(), POOL_ACK_TIMEOUT, None)));
             }
             assert_eq!(1, networker.pool_connections.len());
 
@@ -579,7 +584,7 @@ pub mod networker_tests {
             }
             assert_eq!(1, networker.pool_connections.len());
 
-            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), "6".to_string(), POOL_ACK_TIMEOUT)));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), "6".to_string(), POOL_ACK_TIMEOUT, None)));
             assert_eq!(2, networker.pool_connections.len());
         }
 
@@ -591,7 +596,7 @@ pub mod networker_tests {
             let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
-            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
 
             thread::sleep(std::time::Duration::from_secs(1));
 
@@ -636,7 +641,7 @@ pub mod networker_tests {
 
             let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
-            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
 
             _roll_back_timeout(&mut networker);
 
@@ -653,12 +658,12 @@ pub mod networker_tests {
             let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
-            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
             networker.process_event(Some(NetworkerEvent::CleanTimeout(REQ_ID.to_string(), None)));
 
             assert_eq!(1, networker.pool_connections.len());
 
-            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), "2".to_string(), POOL_ACK_TIMEOUT)));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), "2".to_string(), POOL_ACK_TIMEOUT, None)));
 
             assert_eq!(1, networker.pool_connections.len());
         }
@@ -671,13 +676,13 @@ pub mod networker_tests {
             let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
-            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
 
             assert_eq!(1, networker.pool_connections.len());
 
             _roll_back_timeout(&mut networker);
 
-            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), "2".to_string(), POOL_ACK_TIMEOUT)));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), "2".to_string(), POOL_ACK_TIMEOUT, None)));
 
             assert_eq!(2, networker.pool_connections.len());
         }
@@ -695,7 +700,7 @@ pub mod networker_tests {
 
             assert_eq!(::std::i64::MAX, timeout);
 
-            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
 
             let (_, timeout) = networker.get_timeout();
 
@@ -811,7 +816,7 @@ pub mod networker_tests {
 
             assert!(!conn.has_active_requests());
 
-            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
+            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None))).unwrap();
 
             assert!(conn.has_active_requests());
         }
@@ -829,7 +834,7 @@ pub mod networker_tests {
             assert!(POOL_CON_ACTIVE_TO * 1000 - 10 <= timeout);
             assert!(POOL_CON_ACTIVE_TO * 1000 >= timeout);
 
-            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
+            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None))).unwrap();
 
             let (id, timeout) = conn.get_timeout();
             assert_eq!((REQ_ID.to_string(), NODE_NAME.to_string()), id);
@@ -844,7 +849,7 @@ pub mod networker_tests {
 
             let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
 
-            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
+            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None))).unwrap();
 
             thread::sleep(std::time::Duration::from_secs(1));
 
@@ -864,7 +869,7 @@ pub mod networker_tests {
 
             let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
 
-            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
+            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None))).unwrap();
 
             assert!(conn.has_active_requests());
 
@@ -903,8 +908,8 @@ pub mod networker_tests {
 
             let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
 
-            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
-            conn.send_request(Some(NetworkerEvent::SendOneRequest("msg2".to_string(), "12".to_string(), POOL_ACK_TIMEOUT))).unwrap();
+            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None))).unwrap();
+            conn.send_request(Some(NetworkerEvent::SendOneRequest("msg2".to_string(), "12".to_string(), POOL_ACK_TIMEOUT, None))).unwrap();
 
             assert_eq!(MESSAGE.to_string(), nodes_emulator::next(&handle).unwrap());
             assert_eq!("msg2".to_string(), nodes_emulator::next(&handle).unwrap());
@@ -923,7 +928,7 @@ pub mod networker_tests {
 
             let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec!["n1".to_string(), "n2".to_string()]);
 
-            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
+            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None))).unwrap();
 
             assert_eq!(MESSAGE.to_string(), nodes_emulator::next(&handle_1).unwrap());
             assert!(nodes_emulator::next(&handle_1).is_none());
@@ -959,7 +964,7 @@ pub mod networker_tests {
 
             let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
 
-            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
+            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None))).unwrap();
 
             conn.send_request(Some(NetworkerEvent::Resend(REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
 
@@ -980,7 +985,7 @@ pub mod networker_tests {
 
             let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec![]);
 
-            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
+            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None))).unwrap();
 
             conn.send_request(Some(NetworkerEvent::Resend(REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
 
@@ -990,6 +995,52 @@ pub mod networker_tests {
             }
         }
 
+        #[test]
+        fn pool_connection_resend_works_for_nodes_preference() {
+            let mut txn_1 = nodes_emulator::node();
+            let handle_1 = nodes_emulator::start(&mut txn_1);
+            let rn_1 = _remote_node(&txn_1);
+
+            let mut txn_2 = nodes_emulator::node_2();
+            let handle_2 = nodes_emulator::start(&mut txn_2);
+            let rn_2 = _remote_node(&txn_2);
+
+            let preference = NodesPreference {
+                preferred: vec![],
+                nodes_to_send: Some(vec![rn_2.name.clone()]),
+            };
+
+            let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec![]);
+
+            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, Some(preference)))).unwrap();
+
+            conn.send_request(Some(NetworkerEvent::Resend(REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
+
+            assert!(nodes_emulator::next(&handle_1).is_none());
+            assert_eq!(MESSAGE.to_string(), nodes_emulator::next(&handle_2).unwrap());
+            assert_eq!(MESSAGE.to_string(), nodes_emulator::next(&handle_2).unwrap());
+            assert!(nodes_emulator::next(&handle_2).is_none());
+        }
+
+        #[test]
+        fn pool_connection_send_works_for_all_nodes_excluded() {
+            let mut txn = nodes_emulator::node();
+            let handle = nodes_emulator::start(&mut txn);
+            let rn = _remote_node(&txn);
+
+            let preference = NodesPreference {
+                preferred: vec![],
+                nodes_to_send: Some(vec!["unknown".to_string()]),
+            };
+
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+
+            let res = conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, Some(preference))));
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+
+            assert!(nodes_emulator::next(&handle).is_none());
+        }
+
         #[test]
         fn pool_connection_send_works_for_invalid_node() {
             let txn = nodes_emulator::node();
@@ -998,7 +1049,7 @@ pub mod networker_tests {
 
             let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
 
-            let res = conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+            let res = conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
             assert_kind!(IndyErrorKind::IOError, res);
         }
     }
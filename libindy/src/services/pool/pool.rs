@@ -12,17 +12,20 @@ use crate::commands::Command;
 use crate::commands::CommandExecutor;
 use crate::commands::ledger::LedgerCommand;
 use crate::commands::pool::PoolCommand;
-use crate::domain::ledger::request::ProtocolVersion;
-use crate::domain::pool::PoolOpenConfig;
+use crate::domain::pool::{PoolOpenConfig, PoolTransport, ReadConsensusPolicy};
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
+use crate::services::pool::catchup::build_ledger_status;
 use crate::services::pool::commander::Commander;
 use crate::services::pool::events::*;
+#[cfg(feature = "in_process_pool")]
+use crate::services::pool::in_process_networker::InProcessNetworker;
 use crate::services::pool::{merkle_tree_factory, Nodes};
 use crate::services::pool::networker::{Networker, ZMQNetworker};
 use crate::services::pool::request_handler::{RequestHandler, RequestHandlerImpl};
-use rust_base58::{FromBase58, ToBase58};
-use crate::services::pool::types::{LedgerStatus, RemoteNode};
+use crate::services::pool::stats::PoolStats;
+use rust_base58::FromBase58;
+use crate::services::pool::types::RemoteNode;
 use indy_utils::crypto::ed25519_sign;
 
 use super::ursa::bls::VerKey;
@@ -35,6 +38,7 @@ struct PoolSM<T: Networker, R: RequestHandler<T>> {
     timeout: i64,
     extended_timeout: i64,
     number_read_nodes: u8,
+    read_consensus: ReadConsensusPolicy,
     state: PoolState<T, R>,
 }
 
@@ -69,6 +73,8 @@ struct ActiveState<T: Networker, R: RequestHandler<T>> {
     networker: Rc<RefCell<T>>,
     request_handlers: HashMap<String, R>,
     nodes: Nodes,
+    // catchup running in background after the pool membership is detected as outdated
+    refresh_handler: Option<R>,
 }
 
 struct SyncCatchupState<T: Networker, R: RequestHandler<T>> {
@@ -84,22 +90,75 @@ struct TerminatedState<T: Networker> {
 
 struct ClosedState {}
 
+impl<T: Networker, R: RequestHandler<T>> ActiveState<T, R> {
+    fn start_background_refresh(&mut self, pool_name: &str, timeout: i64, extended_timeout: i64, number_read_nodes: u8) {
+        if self.refresh_handler.is_some() {
+            trace!("PoolSM: background refresh is already in progress");
+            return;
+        }
+
+        info!("PoolSM: pool membership seems to be outdated, starting background refresh");
+        match _get_request_handler_with_ledger_status_sent(self.networker.clone(), pool_name, timeout, extended_timeout, number_read_nodes) {
+            Ok(request_handler) => self.refresh_handler = Some(request_handler),
+            Err(err) => warn!("PoolSM: can't start background refresh: {:?}", err)
+        }
+    }
+
+    fn handle_background_refresh_event(&mut self, pe: PoolEvent, pool_name: &str, timeout: i64, extended_timeout: i64, number_read_nodes: u8) {
+        match pe {
+            PoolEvent::CatchupRestart(merkle_tree) => {
+                self.refresh_handler = _get_nodes_and_remotes(pool_name, &merkle_tree).map(|(nodes, _)| {
+                    let mut request_handler = R::new(self.networker.clone(), _get_f(nodes.len()), &[], &nodes, pool_name, timeout, extended_timeout, number_read_nodes);
+                    let ls = build_ledger_status(POOL_LEDGER_ID, &merkle_tree);
+                    request_handler.process_event(Some(RequestEvent::LedgerStatus(ls, None, Some(merkle_tree))));
+                    request_handler
+                }).map_err(|err| warn!("PoolSM: background refresh failed: {:?}", err)).ok();
+            }
+            PoolEvent::CatchupTargetFound(target_mt_root, target_mt_size, merkle_tree) => {
+                self.refresh_handler = _get_nodes_and_remotes(pool_name, &merkle_tree).map(|(nodes, _)| {
+                    let mut request_handler = R::new(self.networker.clone(), _get_f(nodes.len()), &[], &nodes, pool_name, timeout, extended_timeout, number_read_nodes);
+                    request_handler.process_event(Some(RequestEvent::CatchupReq(merkle_tree, target_mt_size, target_mt_root)));
+                    request_handler
+                }).map_err(|err| warn!("PoolSM: background refresh failed: {:?}", err)).ok();
+            }
+            PoolEvent::Synced(merkle) => {
+                self.refresh_handler = None;
+                match _get_nodes_and_remotes(pool_name, &merkle) {
+                    Ok((nodes, remotes)) => {
+                        info!("PoolSM: background refresh finished, pool nodes: {:?}", nodes.keys());
+                        // requests in progress keep using connections to the previous set of nodes
+                        self.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
+                        self.nodes = nodes;
+                    }
+                    Err(err) => warn!("PoolSM: background refresh failed: {:?}", err)
+                }
+            }
+            PoolEvent::CatchupTargetNotFound(err) => {
+                warn!("PoolSM: background refresh failed: {:?}", err);
+                self.refresh_handler = None;
+            }
+            _ => {}
+        }
+    }
+}
+
 impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
-    pub fn new(networker: Rc<RefCell<T>>, pname: &str, id: PoolHandle, timeout: i64, extended_timeout: i64, number_read_nodes: u8) -> PoolSM<T, R> {
+    pub fn new(networker: Rc<RefCell<T>>, pname: &str, id: PoolHandle, timeout: i64, extended_timeout: i64, number_read_nodes: u8, read_consensus: ReadConsensusPolicy) -> PoolSM<T, R> {
         PoolSM {
             pool_name: pname.to_string(),
             id,
             timeout,
             extended_timeout,
             number_read_nodes,
+            read_consensus,
             state: PoolState::Initialization(InitializationState {
                 networker
             }),
         }
     }
 
-    pub fn step(pool_name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, number_read_nodes: u8, state: PoolState<T, R>) -> Self {
-        PoolSM { pool_name, id, timeout, extended_timeout, number_read_nodes, state }
+    pub fn step(pool_name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, number_read_nodes: u8, read_consensus: ReadConsensusPolicy, state: PoolState<T, R>) -> Self {
+        PoolSM { pool_name, id, timeout, extended_timeout, number_read_nodes, read_consensus, state }
     }
 }
 
@@ -139,6 +198,7 @@ impl<T: Networker, R: RequestHandler<T>> From<(InitializationState<T>, Nodes)> f
             networker: state.networker,
             request_handlers: HashMap::new(),
             nodes,
+            refresh_handler: None,
         }
     }
 }
@@ -163,6 +223,7 @@ impl<T: Networker, R: RequestHandler<T>> From<(GettingCatchupTargetState<T, R>,
             networker: state.networker,
             request_handlers: HashMap::new(),
             nodes,
+            refresh_handler: None,
         }
     }
 }
@@ -212,6 +273,10 @@ impl<T: Networker, R: RequestHandler<T>> From<ActiveState<T, R>> for ClosedState
             trace!("Termintating ongoing request");
             p.process_event(Some(RequestEvent::Terminate));
         });
+        if let Some(ref mut refresh_handler) = state.refresh_handler {
+            trace!("Termintating background refresh");
+            refresh_handler.process_event(Some(RequestEvent::Terminate));
+        }
         trace!("PoolSM: from active to closed");
         ClosedState {}
     }
@@ -226,6 +291,7 @@ impl<T: Networker, R: RequestHandler<T>> From<(SyncCatchupState<T, R>, Nodes)> f
             networker: state.networker,
             request_handlers: HashMap::new(),
             nodes,
+            refresh_handler: None,
         }
     }
 }
@@ -268,7 +334,7 @@ impl<T: Networker> From<TerminatedState<T>> for ClosedState {
 
 impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
     pub fn handle_event(self, pe: PoolEvent) -> Self {
-        let PoolSM { pool_name, id, state, timeout, extended_timeout, number_read_nodes } = self;
+        let PoolSM { pool_name, id, state, timeout, extended_timeout, number_read_nodes, read_consensus } = self;
         let state = match state {
             PoolState::Initialization(state) => match pe {
                 PoolEvent::CheckCache(cmd_id) => {
@@ -308,10 +374,10 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         PoolState::Terminated(state.into())
                     }
                     PoolEvent::CatchupRestart(merkle_tree) => {
-                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree) {
+                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&pool_name, &merkle_tree) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             state.request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
-                            let ls = _ledger_status(&merkle_tree);
+                            let ls = build_ledger_status(POOL_LEDGER_ID, &merkle_tree);
                             state.request_handler.process_event(Some(RequestEvent::LedgerStatus(ls, None, Some(merkle_tree))));
                             PoolState::GettingCatchupTarget(state)
                         } else {
@@ -319,7 +385,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         }
                     }
                     PoolEvent::CatchupTargetFound(target_mt_root, target_mt_size, merkle_tree) => {
-                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree) {
+                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&pool_name, &merkle_tree) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             let mut request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
                             request_handler.process_event(Some(RequestEvent::CatchupReq(merkle_tree, target_mt_size, target_mt_root)));
@@ -329,7 +395,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         }
                     }
                     PoolEvent::Synced(merkle) => {
-                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle) {
+                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&pool_name, &merkle) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh, Ok(()));
                             PoolState::Active((state, nodes).into())
@@ -367,7 +433,10 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
             PoolState::Closed(state) => PoolState::Closed(state),
             PoolState::Active(mut state) => {
                 match pe.clone() {
-                    PoolEvent::PoolOutdated => PoolState::Terminated(state.into()),
+                    PoolEvent::PoolOutdated => {
+                        state.start_background_refresh(&pool_name, timeout, extended_timeout, number_read_nodes);
+                        PoolState::Active(state)
+                    }
                     PoolEvent::Close(cmd_id) => {
                         _close_pool_ack(cmd_id);
                         PoolState::Closed(state.into())
@@ -379,10 +448,15 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                             PoolState::Terminated(state.into())
                         }
                     }
-                    PoolEvent::SendRequest(cmd_id, _, _, _) => {
+                    PoolEvent::SendRequest(cmd_id, _, _, _, _) => {
                         trace!("received request to send");
                         let re: Option<RequestEvent> = pe.into();
+                        let re = re.map(|re| re.or_read_consensus(read_consensus));
                         match re.as_ref().map(|r| r.get_req_id()) {
+                            Some(ref req_id) if state.request_handlers.get(req_id).map_or(false, |rh| !rh.is_terminal()) => {
+                                let res = Err(err_msg(IndyErrorKind::InvalidState, format!("Request with id {} is already in progress", req_id)));
+                                _send_submit_ack(cmd_id, res)
+                            }
                             Some(req_id) => {
                                 let mut request_handler = R::new(state.networker.clone(), _get_f(state.nodes.len()), &[cmd_id], &state.nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
                                 request_handler.process_event(re);
@@ -398,17 +472,28 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::NodeReply(reply, node) => {
                         trace!("received reply from node {:?}: {:?}", node, reply);
                         let re: Option<RequestEvent> = pe.into();
-                        match re.as_ref().map(|r| r.get_req_id()) {
-                            Some(req_id) => {
-                                let remove = if let Some(rh) = state.request_handlers.get_mut(&req_id) {
-                                    rh.process_event(re);
-                                    rh.is_terminal()
+                        match re {
+                            Some(RequestEvent::LedgerStatus(..)) |
+                            Some(RequestEvent::ConsistencyProof(..)) |
+                            Some(RequestEvent::CatchupRep(..)) if re.as_ref().map_or(false, |re| re.get_req_id().is_empty()) => {
+                                if let Some(pe) = state.refresh_handler.as_mut().and_then(|rh| rh.process_event(re)) {
+                                    state.handle_background_refresh_event(pe, &pool_name, timeout, extended_timeout, number_read_nodes);
+                                }
+                            }
+                            Some(re) => {
+                                let req_id = re.get_req_id();
+                                let (remove, pe) = if let Some(rh) = state.request_handlers.get_mut(&req_id) {
+                                    let pe = rh.process_event(Some(re));
+                                    (rh.is_terminal(), pe)
                                 } else {
-                                    false
+                                    (false, None)
                                 };
                                 if remove {
                                     state.request_handlers.remove(&req_id);
                                 }
+                                if let Some(PoolEvent::PoolOutdated) = pe {
+                                    state.start_background_refresh(&pool_name, timeout, extended_timeout, number_read_nodes);
+                                }
                             }
                             None => warn!("Request id not found in Reply: {:?}", reply)
                         };
@@ -417,7 +502,13 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     }
                     PoolEvent::Timeout(req_id, node_alias) => {
                         if let Some(rh) = state.request_handlers.get_mut(&req_id) {
-                            rh.process_event(pe.into());
+                            if let Some(PoolEvent::PoolOutdated) = rh.process_event(pe.into()) {
+                                state.start_background_refresh(&pool_name, timeout, extended_timeout, number_read_nodes);
+                            }
+                        } else if state.refresh_handler.is_some() && !"".eq(&req_id) {
+                            if let Some(pe) = state.refresh_handler.as_mut().and_then(|rh| rh.process_event(pe.into())) {
+                                state.handle_background_refresh_event(pe, &pool_name, timeout, extended_timeout, number_read_nodes);
+                            }
                         } else if "".eq(&req_id) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::Timeout));
                         } else {
@@ -437,7 +528,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     }
                     PoolEvent::NodesBlacklisted => PoolState::Terminated(state.into()),
                     PoolEvent::Synced(merkle) => {
-                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle).map_err(map_err_err!()) {
+                        if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&pool_name, &merkle).map_err(map_err_err!()) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh, Ok(()));
                             PoolState::Active((state, nodes).into())
@@ -449,7 +540,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                 }
             }
         };
-        PoolSM::step(pool_name, id, timeout, extended_timeout, number_read_nodes, state)
+        PoolSM::step(pool_name, id, timeout, extended_timeout, number_read_nodes, read_consensus, state)
     }
 
     pub fn is_terminal(&self) -> bool {
@@ -462,6 +553,17 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
             PoolState::Closed(_) => true,
         }
     }
+
+    pub fn get_state_name(&self) -> &'static str {
+        match self.state {
+            PoolState::Initialization(_) => "initialization",
+            PoolState::GettingCatchupTarget(_) => "getting_catchup_target",
+            PoolState::Active(_) => "active",
+            PoolState::SyncCatchup(_) => "sync_catchup",
+            PoolState::Terminated(_) => "terminated",
+            PoolState::Closed(_) => "closed",
+        }
+    }
 }
 
 pub struct Pool<S: Networker, R: RequestHandler<S>> {
@@ -475,6 +577,8 @@ pub struct Pool<S: Networker, R: RequestHandler<S>> {
     conn_limit: usize,
     preordered_nodes: Vec<String>,
     number_read_nodes: u8,
+    read_consensus: ReadConsensusPolicy,
+    transport: PoolTransport,
 }
 
 impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
@@ -491,6 +595,8 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
             conn_limit: config.conn_limit,
             preordered_nodes: config.preordered_nodes,
             number_read_nodes: config.number_read_nodes,
+            read_consensus: config.read_consensus,
+            transport: config.transport,
         }
     }
 
@@ -503,13 +609,31 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
         let conn_limit = self.conn_limit;
         let preordered_nodes = self.preordered_nodes.clone();
         let number_read_nodes = self.number_read_nodes;
+        let read_consensus = self.read_consensus;
+        let transport = self.transport.clone();
         self.worker = Some(thread::spawn(move || {
-            let mut pool_thread: PoolThread<S, R> = PoolThread::new(cmd_socket, name, id,
-                                                                    timeout, extended_timeout,
-                                                                    active_timeout, conn_limit,
-                                                                    preordered_nodes,
-                                                                    number_read_nodes);
-            pool_thread.work();
+            match transport {
+                PoolTransport::Zmq => {
+                    let networker = S::new(active_timeout, conn_limit, preordered_nodes);
+                    let mut pool_thread: PoolThread<S, R> = PoolThread::new(cmd_socket, name, id,
+                                                                            timeout, extended_timeout,
+                                                                            networker,
+                                                                            number_read_nodes,
+                                                                            read_consensus);
+                    pool_thread.work();
+                }
+                #[cfg(feature = "in_process_pool")]
+                PoolTransport::InProcess(ledger) => {
+                    let mut networker = InProcessNetworker::new(active_timeout, conn_limit, preordered_nodes);
+                    networker.set_ledger(ledger);
+                    let mut pool_thread: PoolThread<InProcessNetworker, R::InProcess> = PoolThread::new(cmd_socket, name, id,
+                                                                                                        timeout, extended_timeout,
+                                                                                                        networker,
+                                                                                                        number_read_nodes,
+                                                                                                        read_consensus);
+                    pool_thread.work();
+                }
+            }
         }));
     }
 
@@ -527,16 +651,18 @@ struct PoolThread<S: Networker, R: RequestHandler<S>> {
     events: VecDeque<PoolEvent>,
     commander: Commander,
     networker: Rc<RefCell<S>>,
+    stats: PoolStats,
 }
 
 impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
-    pub fn new(cmd_socket: zmq::Socket, name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, number_read_nodes: u8) -> Self {
-        let networker = Rc::new(RefCell::new(S::new(active_timeout, conn_limit, preordered_nodes)));
+    pub fn new(cmd_socket: zmq::Socket, name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, networker: S, number_read_nodes: u8, read_consensus: ReadConsensusPolicy) -> Self {
+        let networker = Rc::new(RefCell::new(networker));
         PoolThread {
-            pool_sm: Some(PoolSM::new(networker.clone(), &name, id, timeout, extended_timeout, number_read_nodes)),
+            pool_sm: Some(PoolSM::new(networker.clone(), &name, id, timeout, extended_timeout, number_read_nodes, read_consensus)),
             events: VecDeque::new(),
             commander: Commander::new(cmd_socket),
             networker,
+            stats: PoolStats::new(timeout, extended_timeout),
         }
     }
 
@@ -555,7 +681,14 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
             let pe = self.events.pop_front();
             trace!("received pool event: {:?}", pe);
             match pe {
+                Some(PoolEvent::GetStatus(cmd_id)) => {
+                    let state = self.pool_sm.as_ref().map(|w| w.get_state_name()).unwrap_or("closed");
+                    let res = serde_json::to_string(&self.stats.get_status(state))
+                        .to_indy(IndyErrorKind::InvalidState, "Can't serialize pool status");
+                    _send_status_ack(cmd_id, res);
+                }
                 Some(pe) => {
+                    self.stats.process_event(&pe);
                     self.pool_sm = self.pool_sm.take().map(|w| w.handle_event(pe));
                 }
                 _ => ()
@@ -598,7 +731,7 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
     }
 }
 
-fn _get_f(cnt: usize) -> usize {
+pub(super) fn _get_f(cnt: usize) -> usize {
     if cnt < 4 {
         return 0;
     }
@@ -614,13 +747,13 @@ fn _get_request_handler_with_ledger_status_sent<T: Networker, R: RequestHandler<
 {
     let mut merkle = merkle_tree_factory::create(pool_name)?;
 
-    let (nodes, remotes) = match _get_nodes_and_remotes(&merkle) {
+    let (nodes, remotes) = match _get_nodes_and_remotes(pool_name, &merkle) {
         Ok(n) => n,
         Err(err) => {
             match merkle_tree_factory::drop_cache(pool_name) {
                 Ok(_) => {
                     merkle = merkle_tree_factory::create(pool_name)?;
-                    _get_nodes_and_remotes(&merkle)?
+                    _get_nodes_and_remotes(pool_name, &merkle)?
                 }
                 Err(_) => { return Err(err); }
             }
@@ -628,26 +761,13 @@ fn _get_request_handler_with_ledger_status_sent<T: Networker, R: RequestHandler<
     };
     networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
     let mut request_handler = R::new(networker.clone(), _get_f(nodes.len()), &[], &nodes, pool_name, timeout, extended_timeout, number_read_nodes);
-    let ls = _ledger_status(&merkle);
+    let ls = build_ledger_status(POOL_LEDGER_ID, &merkle);
     request_handler.process_event(Some(RequestEvent::LedgerStatus(ls, None, Some(merkle))));
     Ok(request_handler)
 }
 
-fn _ledger_status(merkle: &MerkleTree) -> LedgerStatus {
-    let protocol_version = ProtocolVersion::get();
-
-    LedgerStatus {
-        txnSeqNo: merkle.count(),
-        merkleRoot: merkle.root_hash().as_slice().to_base58(),
-        ledgerId: 0,
-        ppSeqNo: None,
-        viewNo: None,
-        protocolVersion: if protocol_version > 1 { Some(protocol_version) } else { None },
-    }
-}
-
-fn _get_nodes_and_remotes(merkle: &MerkleTree) -> IndyResult<(Nodes, Vec<RemoteNode>)> {
-    let nodes = merkle_tree_factory::build_node_state(merkle)?;
+fn _get_nodes_and_remotes(pool_name: &str, merkle: &MerkleTree) -> IndyResult<(Nodes, Vec<RemoteNode>)> {
+    let nodes = merkle_tree_factory::build_node_state(pool_name, merkle)?;
 
     Ok(nodes.iter().map(|(_, txn)| {
         let node_alias = txn.txn.data.data.alias.clone();
@@ -720,6 +840,11 @@ fn _send_submit_ack(cmd_id: CommandHandle, res: IndyResult<String>) {
     CommandExecutor::instance().send(Command::Ledger(lc)).unwrap();
 }
 
+fn _send_status_ack(cmd_id: CommandHandle, res: IndyResult<String>) {
+    let pc = PoolCommand::GetStatusAck(cmd_id, res);
+    CommandExecutor::instance().send(Command::Pool(pc)).unwrap();
+}
+
 fn _send_open_refresh_ack(cmd_id: CommandHandle, id: PoolHandle, is_refresh: bool, res: IndyResult<()>) {
     trace!("PoolSM: from getting catchup target to active");
     let pc = if is_refresh {
@@ -763,6 +888,7 @@ impl Drop for ZMQPool {
 
 #[cfg(test)]
 mod tests {
+    use crate::domain::ledger::request::ProtocolVersion;
     use crate::services::pool::networker::MockNetworker;
     use crate::services::pool::request_handler::tests::MockRequestHandler;
     use crate::services::pool::types::{Message, Reply, ReplyResultV1, ReplyTxnV1, ReplyV1, ResponseMetadata};
@@ -809,7 +935,7 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_new_initialization_works() {
-            let _p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "name", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let _p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "name", next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
         }
 
         #[test]
@@ -819,7 +945,7 @@ mod tests {
             ProtocolVersion::set(2);
             _write_genesis_txns("pool_wrapper_check_cache_works");
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_check_cache_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_check_cache_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             assert_match!(PoolState::GettingCatchupTarget(_), p.state);
@@ -831,7 +957,7 @@ mod tests {
         pub fn pool_wrapper_check_cache_works_for_no_pool_created() {
             let p: PoolSM<MockNetworker, MockRequestHandler> =
                 PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))),
-                            "pool_wrapper_check_cache_works_for_no_pool_created", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+                            "pool_wrapper_check_cache_works_for_no_pool_created", next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             assert_match!(PoolState::Terminated(_), p.state);
@@ -839,7 +965,7 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_terminated_close_works() {
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_terminated_close_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_terminated_close_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let cmd_id: CommandHandle = next_command_handle();
@@ -850,7 +976,7 @@ mod tests {
         #[test]
         pub fn pool_wrapper_terminated_refresh_works() {
             test::cleanup_pool("pool_wrapper_terminated_refresh_works");
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_terminated_refresh_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_terminated_refresh_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
 
@@ -874,6 +1000,7 @@ mod tests {
                 timeout: 0,
                 extended_timeout: 0,
                 number_read_nodes: NUMBER_READ_NODES,
+                read_consensus: ReadConsensusPolicy::default(),
             };
 
             let p = p.handle_event(PoolEvent::Timeout("".to_string(), "".to_string()));
@@ -890,7 +1017,7 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_cloe_works_from_initialization() {
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_cloe_works_from_initialization", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_cloe_works_from_initialization", next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::Close(cmd_id));
             assert_match!(PoolState::Closed(_), p.state);
@@ -904,7 +1031,7 @@ mod tests {
             _write_genesis_txns("pool_wrapper_close_works_from_getting_catchup_target");
 
             let p: PoolSM<MockNetworker, MockRequestHandler> =
-                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_close_works_from_getting_catchup_target", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_close_works_from_getting_catchup_target", next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let cmd_id: CommandHandle = next_command_handle();
@@ -922,7 +1049,7 @@ mod tests {
             _write_genesis_txns("pool_wrapper_catchup_target_not_found_works");
 
             let p: PoolSM<MockNetworker, MockRequestHandler> =
-                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_catchup_target_not_found_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_catchup_target_not_found_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::CatchupTargetNotFound(err_msg(IndyErrorKind::PoolTimeout, "Pool timeout")));
@@ -939,7 +1066,7 @@ mod tests {
             _write_genesis_txns("pool_wrapper_getting_catchup_target_synced_works");
 
             let p: PoolSM<MockNetworker, MockRequestHandler> =
-                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_getting_catchup_target_synced_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_getting_catchup_target_synced_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
@@ -962,7 +1089,7 @@ mod tests {
                 "pool_wrapper_getting_catchup_target_synced_works_for_node_state_error",
                 next_pool_handle(),
                 0,
-                0, NUMBER_READ_NODES);
+                0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             ProtocolVersion::set(1);
@@ -989,7 +1116,7 @@ mod tests {
                 "pool_wrapper_getting_catchup_target_catchup_target_found_works",
                 next_pool_handle(),
                 0,
-                0, NUMBER_READ_NODES);
+                0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
@@ -1013,7 +1140,7 @@ mod tests {
                             "pool_wrapper_getting_catchup_target_catchup_target_found_works_for_node_state_error",
                             next_pool_handle(),
                             0,
-                            0, NUMBER_READ_NODES);
+                            0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             ProtocolVersion::set(1);
@@ -1041,7 +1168,7 @@ mod tests {
                             "pool_wrapper_sync_catchup_close_works",
                             next_pool_handle(),
                             0,
-                            0, NUMBER_READ_NODES);
+                            0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
@@ -1069,7 +1196,7 @@ mod tests {
                 "pool_wrapper_sync_catchup_synced_works",
                 next_pool_handle(),
                 0,
-                0, NUMBER_READ_NODES);
+                0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
@@ -1096,7 +1223,7 @@ mod tests {
                 "pool_wrapper_sync_catchup_synced_works_for_node_state_error",
                 next_pool_handle(),
                 0,
-                0, NUMBER_READ_NODES);
+                0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
@@ -1128,12 +1255,12 @@ mod tests {
                                                                            "pool_wrapper_active_send_request_works",
                                                                            next_pool_handle(),
                                                                            0,
-                                                                           0, NUMBER_READ_NODES);
+                                                                           0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let cmd_id: CommandHandle = next_command_handle();
-            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None));
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
                 PoolState::Active(state) => {
@@ -1146,6 +1273,47 @@ mod tests {
             test::cleanup_storage("pool_wrapper_active_send_request_works");
         }
 
+        #[test]
+        pub fn pool_wrapper_active_send_request_works_for_ledger_catchup() {
+            test::cleanup_storage("pool_wrapper_active_send_request_works_for_ledger_catchup");
+
+            ProtocolVersion::set(2);
+            _write_genesis_txns("pool_wrapper_active_send_request_works_for_ledger_catchup");
+
+            let req = json!({
+                "op": "LEDGER_STATUS",
+                "txnSeqNo": 0,
+                "merkleRoot": "",
+                "ledgerId": 1,
+                "ppSeqNo": null,
+                "viewNo": null,
+            }).to_string();
+
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(
+                RefCell::new(MockNetworker::new(0,
+                                                0,
+                                                vec![]))),
+                                                                           "pool_wrapper_active_send_request_works_for_ledger_catchup",
+                                                                           next_pool_handle(),
+                                                                           0,
+                                                                           0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
+            let cmd_id: CommandHandle = next_command_handle();
+            let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
+            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
+            let cmd_id: CommandHandle = next_command_handle();
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
+            assert_match!(PoolState::Active(_), p.state);
+            match p.state {
+                PoolState::Active(state) => {
+                    assert_eq!(state.request_handlers.len(), 1);
+                    assert!(state.request_handlers.contains_key(&ledger_catchup_req_id(1)));
+                }
+                _ => assert!(false)
+            };
+
+            test::cleanup_storage("pool_wrapper_active_send_request_works_for_ledger_catchup");
+        }
+
         #[test]
         pub fn pool_wrapper_active_send_request_works_for_no_req_id() {
             test::cleanup_storage("pool_wrapper_active_send_request_works_for_no_req_id");
@@ -1168,12 +1336,12 @@ mod tests {
                             "pool_wrapper_active_send_request_works_for_no_req_id",
                             next_pool_handle(),
                             0,
-                            0, NUMBER_READ_NODES);
+                            0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let cmd_id: CommandHandle = next_command_handle();
-            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None));
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
                 PoolState::Active(state) => {
@@ -1221,12 +1389,12 @@ mod tests {
                 "pool_wrapper_active_node_reply_works",
                 next_pool_handle(),
                 0,
-                0, NUMBER_READ_NODES);
+                0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let cmd_id: CommandHandle = next_command_handle();
-            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None));
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
             let p = p.handle_event(PoolEvent::NodeReply(rep, "node".to_string()));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
@@ -1259,12 +1427,12 @@ mod tests {
                                        0,
                                        vec![]))),
                             "pool_wrapper_sends_requests_to_two_nodes",
-                            next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+                            next_pool_handle(), 0, 0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let cmd_id: CommandHandle = next_command_handle();
-            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None));
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
                 PoolState::Active(state) => {
@@ -1311,12 +1479,12 @@ mod tests {
                                                                            "pool_wrapper_active_node_reply_works_for_no_request",
                                                                            next_pool_handle(),
                                                                            0,
-                                                                           0, NUMBER_READ_NODES);
+                                                                           0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let cmd_id: CommandHandle = next_command_handle();
-            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None));
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
             let p = p.handle_event(PoolEvent::NodeReply(rep, "node".to_string()));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
@@ -1330,6 +1498,45 @@ mod tests {
             test::cleanup_storage("pool_wrapper_active_node_reply_works_for_no_request");
         }
 
+        #[test]
+        pub fn pool_wrapper_active_pool_outdated_works() {
+            test::cleanup_storage("pool_wrapper_active_pool_outdated_works");
+
+            ProtocolVersion::set(2);
+            _write_genesis_txns("pool_wrapper_active_pool_outdated_works");
+
+            let req = json!({
+                "reqId": 1,
+                "operation": {
+                    "type": "1"
+                }
+            }).to_string();
+
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(
+                RefCell::new(MockNetworker::new(0,
+                                                0,
+                                                vec![]))),
+                                                                           "pool_wrapper_active_pool_outdated_works",
+                                                                           next_pool_handle(),
+                                                                           0,
+                                                                           0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
+            let cmd_id: CommandHandle = next_command_handle();
+            let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
+            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
+            let cmd_id: CommandHandle = next_command_handle();
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
+            let p = p.handle_event(PoolEvent::PoolOutdated);
+            match p.state {
+                PoolState::Active(state) => {
+                    assert!(state.refresh_handler.is_some());
+                    assert!(state.request_handlers.contains_key("1"));
+                }
+                _ => assert!(false)
+            };
+
+            test::cleanup_storage("pool_wrapper_active_pool_outdated_works");
+        }
+
         #[test]
         pub fn pool_wrapper_active_node_reply_works_for_invalid_reply() {
             test::cleanup_storage("pool_wrapper_active_node_reply_works_for_invalid_reply");
@@ -1354,12 +1561,12 @@ mod tests {
                             "pool_wrapper_active_node_reply_works_for_invalid_reply",
                             next_pool_handle(),
                             0,
-                            0, NUMBER_READ_NODES);
+                            0, NUMBER_READ_NODES, ReadConsensusPolicy::default());
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let cmd_id: CommandHandle = next_command_handle();
-            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None));
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
             let p = p.handle_event(PoolEvent::NodeReply(rep.to_string(), "node".to_string()));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
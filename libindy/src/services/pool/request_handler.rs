@@ -14,18 +14,25 @@ use self::super::THRESHOLD;
 use crate::commands::Command;
 use crate::commands::CommandExecutor;
 use crate::commands::ledger::LedgerCommand;
+use crate::domain::pool::{ReadConsensusPolicy, RequestOptions};
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
-use crate::services::pool::catchup::{build_catchup_req, CatchupProgress, check_cons_proofs, check_nodes_responses_on_status};
+use crate::services::pool::catchup::{build_catchup_req, build_ledger_catchup_req, CatchupProgress, check_cons_proofs, check_nodes_responses_on_status};
 use crate::services::pool::events::NetworkerEvent;
+use crate::services::pool::events::NodesPreference;
 use crate::services::pool::events::PoolEvent;
 use crate::services::pool::events::RequestEvent;
+use crate::services::pool::events::ledger_catchup_req_id;
 use crate::services::pool::{get_last_signed_time, Nodes};
 use crate::services::pool::merkle_tree_factory;
 use crate::services::pool::networker::Networker;
+#[cfg(feature = "in_process_pool")]
+use crate::services::pool::in_process_networker::InProcessNetworker;
 use crate::services::pool::state_proof;
 use crate::services::pool::types::CatchupRep;
 use crate::services::pool::types::HashableValue;
+use crate::services::pool::types::LedgerStatus;
+use crate::services::pool::types::ResendableRequest;
 
 use super::ursa::bls::Generator;
 
@@ -43,16 +50,20 @@ struct RequestSM<T: Networker> {
     timeout: i64,
     extended_timeout: i64,
     number_read_nodes: u8,
+    nodes_to_send: Option<Vec<String>> /* nodes left after applying the request options, all nodes if not set */,
     state: RequestState<T>,
 }
 
 /// Transitions of request state
-/// Start -> Start, Single, Consensus, CatchupSingle, CatchupConsensus, Full, Finish
+/// Start -> Start, Single, Consensus, CatchupSingle, CatchupConsensus, LedgerCatchupConsensus, Full, All, Finish
 /// Single -> Single, Finish
 /// Consensus -> Consensus, Finish
 /// CatchupSingle -> CatchupSingle, Finish
 /// CatchupConsensus -> CatchupConsensus, Finish
+/// LedgerCatchupConsensus -> LedgerCatchupConsensus, LedgerCatchupSingle, Finish
+/// LedgerCatchupSingle -> LedgerCatchupSingle, Finish
 /// Full -> Full, Finish
+/// All -> All, Finish
 /// Finish -> Finish
 enum RequestState<T: Networker> {
     Start(StartState<T>),
@@ -60,7 +71,10 @@ enum RequestState<T: Networker> {
     Consensus(ConsensusState<T>),
     CatchupSingle(CatchupSingleState<T>),
     CatchupConsensus(CatchupConsensusState<T>),
+    LedgerCatchupConsensus(LedgerCatchupConsensusState<T>),
+    LedgerCatchupSingle(LedgerCatchupSingleState<T>),
     Full(FullState<T>),
+    All(AllState<T>),
     Finish(FinishState),
 }
 
@@ -86,6 +100,7 @@ impl<T: Networker> RequestSM<T> {
             timeout,
             extended_timeout,
             number_read_nodes,
+            nodes_to_send: None,
             state: RequestState::Start(StartState {
                 networker
             }),
@@ -100,6 +115,7 @@ impl<T: Networker> RequestSM<T> {
                 timeout: i64,
                 extended_timeout: i64,
                 number_read_nodes: u8,
+                nodes_to_send: Option<Vec<String>>,
                 state: RequestState<T>) -> Self {
         RequestSM {
             f,
@@ -110,6 +126,7 @@ impl<T: Networker> RequestSM<T> {
             timeout,
             extended_timeout,
             number_read_nodes,
+            nodes_to_send,
             state,
         }
     }
@@ -124,6 +141,7 @@ struct ConsensusState<T: Networker> {
     replies: HashMap<HashableValue, HashSet<String>>,
     timeout_nodes: HashSet<String>,
     networker: Rc<RefCell<T>>,
+    resendable_request: Option<ResendableRequest>,
 }
 
 struct CatchupConsensusState<T: Networker> {
@@ -140,6 +158,21 @@ struct CatchupSingleState<T: Networker> {
     req_id: String,
 }
 
+struct LedgerCatchupConsensusState<T: Networker> {
+    ledger_status: LedgerStatus /* local status of the ledger */,
+    replies: HashMap<(String, usize), HashSet<String>> /* (merkle root, size) of the ledger -> node aliases */,
+    timeout_nodes: HashSet<String>,
+    networker: Rc<RefCell<T>>,
+}
+
+struct LedgerCatchupSingleState<T: Networker> {
+    ledger_status: LedgerStatus /* local status of the ledger */,
+    target_mt_root: String,
+    target_mt_size: usize,
+    failed_nodes: HashSet<String>,
+    networker: Rc<RefCell<T>>,
+}
+
 struct SingleState<T: Networker> {
     denied_nodes: HashSet<String> /* FIXME should be map, may be merged with replies */,
     replies: HashMap<HashableValue, HashSet<NodeResponse>>,
@@ -147,6 +180,7 @@ struct SingleState<T: Networker> {
     networker: Rc<RefCell<T>>,
     sp_key: Option<Vec<u8>>,
     timestamps: (Option<u64>, Option<u64>),
+    state_proof_only: bool,
 }
 
 struct FullState<T: Networker> {
@@ -155,10 +189,15 @@ struct FullState<T: Networker> {
     networker: Rc<RefCell<T>>,
 }
 
+struct AllState<T: Networker> {
+    replies: HashMap<String, Option<String>> /* node alias -> raw reply, None on timeout */,
+    networker: Rc<RefCell<T>>,
+}
+
 struct FinishState {}
 
-impl<T: Networker> From<(StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u64>))> for SingleState<T> {
-    fn from((state, sp_key, timestamps): (StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u64>))) -> Self {
+impl<T: Networker> From<(StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u64>), bool)> for SingleState<T> {
+    fn from((state, sp_key, timestamps, state_proof_only): (StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u64>), bool)) -> Self {
         SingleState {
             denied_nodes: HashSet::new(),
             replies: HashMap::new(),
@@ -166,6 +205,7 @@ impl<T: Networker> From<(StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u6
             networker: state.networker.clone(),
             sp_key,
             timestamps,
+            state_proof_only,
         }
     }
 }
@@ -177,6 +217,19 @@ impl<T: Networker> From<StartState<T>> for ConsensusState<T> {
             replies: HashMap::new(),
             timeout_nodes: HashSet::new(),
             networker: state.networker.clone(),
+            resendable_request: None,
+        }
+    }
+}
+
+impl<T: Networker> From<(StartState<T>, Option<ResendableRequest>)> for ConsensusState<T> {
+    fn from((state, resendable_request): (StartState<T>, Option<ResendableRequest>)) -> Self {
+        ConsensusState {
+            denied_nodes: HashSet::new(),
+            replies: HashMap::new(),
+            timeout_nodes: HashSet::new(),
+            networker: state.networker.clone(),
+            resendable_request,
         }
     }
 }
@@ -203,6 +256,29 @@ impl<T: Networker> From<(MerkleTree, StartState<T>, Vec<u8>, usize, String)> for
     }
 }
 
+impl<T: Networker> From<(LedgerStatus, StartState<T>)> for LedgerCatchupConsensusState<T> {
+    fn from((ledger_status, state): (LedgerStatus, StartState<T>)) -> Self {
+        LedgerCatchupConsensusState {
+            ledger_status,
+            replies: HashMap::new(),
+            timeout_nodes: HashSet::new(),
+            networker: state.networker.clone(),
+        }
+    }
+}
+
+impl<T: Networker> From<(LedgerCatchupConsensusState<T>, String, usize)> for LedgerCatchupSingleState<T> {
+    fn from((state, target_mt_root, target_mt_size): (LedgerCatchupConsensusState<T>, String, usize)) -> Self {
+        LedgerCatchupSingleState {
+            ledger_status: state.ledger_status,
+            target_mt_root,
+            target_mt_size,
+            failed_nodes: HashSet::new(),
+            networker: state.networker.clone(),
+        }
+    }
+}
+
 impl<T: Networker> From<StartState<T>> for FullState<T> {
     fn from(state: StartState<T>) -> Self {
         FullState {
@@ -223,6 +299,15 @@ impl<T: Networker> From<(Option<Vec<String>>, StartState<T>)> for FullState<T> {
     }
 }
 
+impl<T: Networker> From<StartState<T>> for AllState<T> {
+    fn from(state: StartState<T>) -> Self {
+        AllState {
+            replies: HashMap::new(),
+            networker: state.networker.clone(),
+        }
+    }
+}
+
 impl<T: Networker> RequestState<T> {
     fn finish() -> RequestState<T> {
         RequestState::Finish(FinishState {})
@@ -251,7 +336,8 @@ impl Hash for NodeResponse {
 
 impl<T: Networker> RequestSM<T> {
     fn handle_event(self, re: RequestEvent) -> (Self, Option<PoolEvent>) {
-        let RequestSM { state, f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, number_read_nodes } = self;
+        let RequestSM { state, f, cmd_ids, nodes, generator, pool_name, mut timeout, mut extended_timeout, number_read_nodes, mut nodes_to_send } = self;
+        let nodes_cnt = nodes_to_send.as_ref().map(Vec::len).unwrap_or_else(|| nodes.len());
         let (state, event) = match state {
             RequestState::Start(state) => {
                 match re {
@@ -266,7 +352,7 @@ impl<T: Networker> RequestSM<T> {
                     RequestEvent::CatchupReq(merkle, target_mt_size, target_mt_root) => {
                         match build_catchup_req(&merkle, target_mt_size) {
                             Ok(Some((req_id, req_json))) => {
-                                state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendOneRequest(req_json, req_id.clone(), timeout)));
+                                state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendOneRequest(req_json, req_id.clone(), timeout, None)));
                                 (RequestState::CatchupSingle((merkle, state, target_mt_root, target_mt_size, req_id).into()), None)
                             }
                             Ok(None) => {
@@ -279,45 +365,99 @@ impl<T: Networker> RequestSM<T> {
                             }
                         }
                     }
-                    RequestEvent::CustomSingleRequest(msg, req_id, sp_key, timestamps) => {
-                        state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendOneRequest(msg.clone(), req_id.clone(), timeout)));
-
-                        for _ in 0..number_read_nodes - 1 {
-                            state.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
+                    RequestEvent::LedgerCatchup(ls) => {
+                        let req_id = ledger_catchup_req_id(ls.ledgerId as usize);
+                        match serde_json::to_string(&super::types::Message::LedgerStatus(ls.clone()))
+                            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize LedgerStatus") {
+                            Ok(msg) => {
+                                state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id, extended_timeout, None)));
+                                (RequestState::LedgerCatchupConsensus((ls, state).into()), None)
+                            }
+                            Err(err) => {
+                                _send_replies(&cmd_ids, Err(err));
+                                (RequestState::finish(), None)
+                            }
                         }
-
-                        (RequestState::Single((state, sp_key, timestamps).into()), None)
                     }
-                    RequestEvent::CustomFullRequest(msg, req_id, local_timeout, nodes_to_send) => {
-                        let timeout = local_timeout.map(|to| to as i64).unwrap_or(extended_timeout);
-                        if let Some(nodes_to_send) = nodes_to_send {
-                            match serde_json::from_str::<Vec<String>>(&nodes_to_send) {
-                                Ok(nodes_to_send) => {
-                                    //TODO check empty list on API level?
-                                    let is_nodes_to_send_known = !nodes_to_send.is_empty() && nodes_to_send.iter().all(|node| nodes.contains_key(node));
-                                    if is_nodes_to_send_known {
-                                        state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, Some(nodes_to_send.clone()))));
-                                        (RequestState::Full((Some(nodes_to_send), state).into()), None)
-                                    } else {
-                                        _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::InvalidStructure,
-                                                                            format!("There is no known node in list to send {:?}, known nodes are {:?}",
-                                                                                    nodes_to_send, nodes.keys()))));
-                                        (RequestState::finish(), None)
+                    RequestEvent::CustomSingleRequest(msg, req_id, sp_key, timestamps, options) => {
+                        match _get_nodes_to_send(&nodes, &options) {
+                            Ok(selected_nodes) => {
+                                timeout = options.timeout.unwrap_or(timeout);
+                                extended_timeout = options.extended_timeout.unwrap_or(extended_timeout);
+                                nodes_to_send = selected_nodes;
+
+                                match options.read_consensus().unwrap_or_default() {
+                                    read_consensus @ ReadConsensusPolicy::Fast |
+                                    read_consensus @ ReadConsensusPolicy::StateProof => {
+                                        let preference = _get_nodes_preference(&options, &nodes_to_send);
+                                        state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendOneRequest(msg.clone(), req_id.clone(), timeout, preference)));
+
+                                        for _ in 0..number_read_nodes - 1 {
+                                            state.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
+                                        }
+
+                                        let state_proof_only = read_consensus == ReadConsensusPolicy::StateProof;
+                                        (RequestState::Single((state, sp_key, timestamps, state_proof_only).into()), None)
+                                    }
+                                    ReadConsensusPolicy::Consensus => {
+                                        state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, nodes_to_send.clone())));
+                                        (RequestState::Consensus(state.into()), None)
+                                    }
+                                    ReadConsensusPolicy::All => {
+                                        state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, nodes_to_send.clone())));
+                                        (RequestState::All(state.into()), None)
                                     }
-                                }
-                                Err(err) => {
-                                    _send_replies(&cmd_ids, Err(err.to_indy(IndyErrorKind::InvalidStructure, "Invalid list of nodes to send")));
-                                    (RequestState::finish(), None)
                                 }
                             }
-                        } else {
-                            state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, None)));
-                            (RequestState::Full((None, state).into()), None)
+                            Err(err) => {
+                                _send_replies(&cmd_ids, Err(err));
+                                (RequestState::finish(), None)
+                            }
                         }
                     }
-                    RequestEvent::CustomConsensusRequest(msg, req_id) => {
-                        state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, None)));
-                        (RequestState::Consensus(state.into()), None)
+                    RequestEvent::CustomFullRequest(msg, req_id, local_timeout, full_nodes_to_send, options) => {
+                        let timeout = local_timeout.map(|to| to as i64).or(options.timeout).unwrap_or(extended_timeout);
+
+                        let full_nodes_to_send = match full_nodes_to_send {
+                            Some(full_nodes_to_send) => serde_json::from_str::<Vec<String>>(&full_nodes_to_send)
+                                .to_indy(IndyErrorKind::InvalidStructure, "Invalid list of nodes to send")
+                                .map(Some),
+                            None => Ok(options.preferred_nodes.clone())
+                        };
+
+                        match full_nodes_to_send.and_then(|full_nodes_to_send| _get_full_nodes_to_send(full_nodes_to_send, &nodes, &options)) {
+                            Ok(full_nodes_to_send) => {
+                                state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, full_nodes_to_send.clone())));
+                                (RequestState::Full((full_nodes_to_send, state).into()), None)
+                            }
+                            Err(err) => {
+                                _send_replies(&cmd_ids, Err(err));
+                                (RequestState::finish(), None)
+                            }
+                        }
+                    }
+                    RequestEvent::CustomConsensusRequest(msg, req_id, options) => {
+                        let selected_nodes = if options.require_state_proof == Some(true) {
+                            Err(err_msg(IndyErrorKind::InvalidStructure, "State proof can't be required for the request without state proof support"))
+                        } else {
+                            _get_nodes_to_send(&nodes, &options)
+                        };
+
+                        match selected_nodes {
+                            Ok(selected_nodes) => {
+                                timeout = options.timeout.unwrap_or(timeout);
+                                extended_timeout = options.extended_timeout.unwrap_or(extended_timeout);
+                                nodes_to_send = selected_nodes;
+
+                                let resendable_request = ResendableRequest::new(msg.clone(), options.retries.unwrap_or(0));
+                                state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, nodes_to_send.clone())));
+                                (RequestState::Consensus((state, resendable_request).into()), None)
+                            }
+                            Err(err) => {
+                                _send_replies(&cmd_ids, Err(err));
+                                (RequestState::finish(), None)
+                            }
+                        }
                     }
                     _ => {
                         (RequestState::Start(state), None)
@@ -343,7 +483,7 @@ impl<T: Networker> RequestSM<T> {
                                 _send_ok_replies(&cmd_ids, &raw_msg);
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
                                 (RequestState::finish(), None)
-                            } else if state.is_consensus_reachable(f, nodes.len()) {
+                            } else if state.is_consensus_reachable(f, nodes_cnt) {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
                                 (RequestState::Consensus(state), None)
                             } else {
@@ -354,7 +494,7 @@ impl<T: Networker> RequestSM<T> {
                             }
                         } else {
                             state.denied_nodes.insert(node_alias.clone());
-                            if state.denied_nodes.len() + state.replies.len() == nodes.len() {
+                            if state.denied_nodes.len() + state.replies.len() == nodes_cnt {
                                 _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
                                 (RequestState::finish(), None)
                             } else {
@@ -368,14 +508,21 @@ impl<T: Networker> RequestSM<T> {
                     }
                     RequestEvent::Timeout(req_id, node_alias) => {
                         state.timeout_nodes.insert(node_alias.clone());
-                        if state.is_consensus_reachable(f, nodes.len()) {
+                        let pe = _check_timeouts_for_outdated_pool(&state.timeout_nodes, f);
+                        if state.is_consensus_reachable(f, nodes_cnt) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
-                            (RequestState::Consensus(state), None)
+                            (RequestState::Consensus(state), pe)
+                        } else if let Some(msg) = state.resendable_request.as_mut().and_then(ResendableRequest::next_attempt) {
+                            debug!("consensus wasn't reached because of timeouts, resending request {}", req_id);
+                            state.timeout_nodes.clear();
+                            state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id.clone(), None)));
+                            state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, nodes_to_send.clone())));
+                            (RequestState::Consensus(state), pe)
                         } else {
                             //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
                             _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
-                            (RequestState::finish(), None)
+                            (RequestState::finish(), pe)
                         }
                     }
                     RequestEvent::Terminate => {
@@ -393,6 +540,7 @@ impl<T: Networker> RequestSM<T> {
                         trace!("reply on single request");
                         state.timeout_nodes.remove(&node_alias);
                         if let Ok((result, result_without_proof)) = _get_msg_result_without_state_proof(&raw_msg) {
+                            let pe = _check_state_proof_for_outdated_pool(&result, &nodes);
                             let hashable = HashableValue { inner: result_without_proof };
 
                             let last_write_time = get_last_signed_time(&raw_msg).unwrap_or(0);
@@ -406,17 +554,19 @@ impl<T: Networker> RequestSM<T> {
                                 )
                             };
 
-                            if cnt > f
+                            let is_consensus_reached = cnt > f && !state.state_proof_only;
+
+                            if is_consensus_reached
                                 || _check_state_proof(&result, f, &generator, &nodes, &raw_msg, state.sp_key.as_ref().map(Vec::as_slice), state.timestamps, last_write_time) {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
-                                _send_ok_replies(&cmd_ids, if cnt > f { &soonest } else { &raw_msg });
-                                (RequestState::finish(), None)
+                                _send_ok_replies(&cmd_ids, if is_consensus_reached { &soonest } else { &raw_msg });
+                                (RequestState::finish(), pe)
                             } else {
-                                (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
+                                (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes_cnt, timeout), pe)
                             }
                         } else {
                             state.denied_nodes.insert(node_alias.clone());
-                            (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
+                            (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes_cnt, timeout), None)
                         }
                     }
                     RequestEvent::ReqACK(_, _, node_alias, req_id) => {
@@ -425,7 +575,8 @@ impl<T: Networker> RequestSM<T> {
                     }
                     RequestEvent::Timeout(req_id, node_alias) => {
                         state.timeout_nodes.insert(node_alias.clone());
-                        (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
+                        let pe = _check_timeouts_for_outdated_pool(&state.timeout_nodes, f);
+                        (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes_cnt, timeout), pe)
                     }
                     RequestEvent::Terminate => {
                         _finish_request(&cmd_ids);
@@ -468,6 +619,10 @@ impl<T: Networker> RequestSM<T> {
                         match _process_catchup_reply(&mut cr, &state.merkle_tree, &state.target_mt_root, state.target_mt_size, &pool_name) {
                             Ok(merkle) => {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(state.req_id.clone(), None)));
+                                // the snapshot is saved once the pool ledger is caught up to the target
+                                if let Err(err) = merkle_tree_factory::save_snapshot(&pool_name, &merkle) {
+                                    warn!("Can't save merkle tree snapshot: {:?}", err);
+                                }
                                 (RequestState::finish(), Some(PoolEvent::Synced(merkle)))
                             }
                             Err(_) => {
@@ -489,6 +644,55 @@ impl<T: Networker> RequestSM<T> {
                     _ => (RequestState::CatchupSingle(state), None)
                 }
             }
+            RequestState::LedgerCatchupConsensus(state) => {
+                match re {
+                    RequestEvent::LedgerStatus(ls, Some(node_alias), _) => {
+                        let req_id = ledger_catchup_req_id(ls.ledgerId as usize);
+                        (RequestSM::_ledger_catchup_target_handle_consensus_state(
+                            state, Some((ls.merkleRoot, ls.txnSeqNo)), node_alias, req_id, &cmd_ids, f, nodes_cnt, timeout), None)
+                    }
+                    RequestEvent::ConsistencyProof(cp, node_alias) => {
+                        let req_id = ledger_catchup_req_id(cp.ledgerId);
+                        (RequestSM::_ledger_catchup_target_handle_consensus_state(
+                            state, Some((cp.newMerkleRoot, cp.seqNoEnd)), node_alias, req_id, &cmd_ids, f, nodes_cnt, timeout), None)
+                    }
+                    RequestEvent::Timeout(req_id, node_alias) => {
+                        (RequestSM::_ledger_catchup_target_handle_consensus_state(
+                            state, None, node_alias, req_id, &cmd_ids, f, nodes_cnt, timeout), None)
+                    }
+                    RequestEvent::Terminate => {
+                        _finish_request(&cmd_ids);
+                        (RequestState::finish(), None)
+                    }
+                    _ => (RequestState::LedgerCatchupConsensus(state), None)
+                }
+            }
+            RequestState::LedgerCatchupSingle(state) => {
+                match re {
+                    RequestEvent::CatchupRep(rep, node_alias) => {
+                        let req_id = ledger_catchup_req_id(rep.ledgerId);
+                        match _check_ledger_catchup_reply(&rep, state.ledger_status.txnSeqNo + 1, state.target_mt_size) {
+                            Ok(()) => {
+                                state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
+                                _send_ok_replies(&cmd_ids, &_build_ledger_catchup_reply(rep, &state.target_mt_root, state.target_mt_size));
+                                (RequestState::finish(), None)
+                            }
+                            Err(err) => {
+                                warn!("Invalid catchup reply from node {}: {:?}", node_alias, err);
+                                (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes_cnt, timeout), None)
+                            }
+                        }
+                    }
+                    RequestEvent::Timeout(req_id, node_alias) => {
+                        (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes_cnt, timeout), None)
+                    }
+                    RequestEvent::Terminate => {
+                        _finish_request(&cmd_ids);
+                        (RequestState::finish(), None)
+                    }
+                    _ => (RequestState::LedgerCatchupSingle(state), None)
+                }
+            }
             RequestState::Full(state) => {
                 match re {
                     RequestEvent::Reply(_, raw_msg, node_alias, req_id) |
@@ -507,9 +711,24 @@ impl<T: Networker> RequestSM<T> {
                     _ => (RequestState::Full(state), None),
                 }
             }
+            RequestState::All(state) => {
+                match re {
+                    RequestEvent::Reply(_, raw_msg, node_alias, req_id) |
+                    RequestEvent::ReqNACK(_, raw_msg, node_alias, req_id) |
+                    RequestEvent::Reject(_, raw_msg, node_alias, req_id) =>
+                        (RequestSM::_all_nodes_handle_reply(state, req_id, node_alias, Some(raw_msg), &cmd_ids, nodes_cnt), None),
+                    RequestEvent::Timeout(req_id, node_alias) =>
+                        (RequestSM::_all_nodes_handle_reply(state, req_id, node_alias, None, &cmd_ids, nodes_cnt), None),
+                    RequestEvent::Terminate => {
+                        _finish_request(&cmd_ids);
+                        (RequestState::finish(), None)
+                    }
+                    _ => (RequestState::All(state), None),
+                }
+            }
             RequestState::Finish(state) => (RequestState::Finish(state), None)
         };
-        (RequestSM::step(f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, number_read_nodes, state), event)
+        (RequestSM::step(f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, number_read_nodes, nodes_to_send, state), event)
     }
 
     fn is_terminal(&self) -> bool {
@@ -519,7 +738,10 @@ impl<T: Networker> RequestSM<T> {
             RequestState::Single(_) |
             RequestState::CatchupSingle(_) |
             RequestState::CatchupConsensus(_) |
-            RequestState::Full(_) => false,
+            RequestState::LedgerCatchupConsensus(_) |
+            RequestState::LedgerCatchupSingle(_) |
+            RequestState::Full(_) |
+            RequestState::All(_) => false,
             RequestState::Finish(_) => true
         }
     }
@@ -555,6 +777,22 @@ impl<T: Networker> RequestSM<T> {
         }
     }
 
+    fn _all_nodes_handle_reply(mut state: AllState<T>,
+                               req_id: String, node_alias: String, raw_msg: Option<String>,
+                               cmd_ids: &[CommandHandle],
+                               nodes_cnt: usize) -> RequestState<T> {
+        state.replies.insert(node_alias.clone(), raw_msg);
+
+        if state.replies.len() == nodes_cnt {
+            state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
+            _send_ok_replies(&cmd_ids, &_build_all_nodes_reply(&state.replies));
+            RequestState::Finish(FinishState {})
+        } else {
+            state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
+            RequestState::All(state)
+        }
+    }
+
     fn _catchup_target_handle_consensus_state(mut state: CatchupConsensusState<T>,
                                               mt_root: String, sz: usize, cons_proof: Option<Vec<String>>,
                                               node_alias: String, req_id: String,
@@ -579,6 +817,38 @@ impl<T: Networker> RequestSM<T> {
         }
     }
 
+    fn _ledger_catchup_target_handle_consensus_state(mut state: LedgerCatchupConsensusState<T>,
+                                                     target: Option<(String, usize)> /* None on timeout */,
+                                                     node_alias: String, req_id: String,
+                                                     cmd_ids: &[CommandHandle],
+                                                     f: usize, nodes_cnt: usize, timeout: i64) -> RequestState<T> {
+        match target {
+            Some(target) => { state.replies.entry(target).or_insert_with(HashSet::new).insert(node_alias.clone()); }
+            None => { state.timeout_nodes.insert(node_alias.clone()); }
+        }
+
+        let agreed_target = state.replies.iter()
+            .find(|(_, node_aliases)| node_aliases.len() > f)
+            .map(|(target, _)| target.clone());
+
+        match agreed_target {
+            Some((target_mt_root, target_mt_size)) => {
+                state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id.clone(), None)));
+                state.catch_up(target_mt_root, target_mt_size, req_id, cmd_ids, timeout)
+            }
+            None if state.is_consensus_reachable(f, nodes_cnt) => {
+                state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
+                RequestState::LedgerCatchupConsensus(state)
+            }
+            None => {
+                //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
+                _send_replies(cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "No consensus possible")));
+                state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
+                RequestState::finish()
+            }
+        }
+    }
+
     fn _process_catchup_target(merkle_root: String,
                                txn_seq_no: usize,
                                hashes: Option<Vec<String>>,
@@ -612,6 +882,10 @@ impl<T: Networker> RequestSM<T> {
 }
 
 pub trait RequestHandler<T: Networker> {
+    /// The same handler working over the in-process transport
+    #[cfg(feature = "in_process_pool")]
+    type InProcess: RequestHandler<InProcessNetworker>;
+
     fn new(networker: Rc<RefCell<T>>, f: usize, cmd_ids: &[CommandHandle], nodes: &Nodes, pool_name: &str, timeout: i64, extended_timeout: i64, number_read_nodes: u8) -> Self;
     fn process_event(&mut self, ore: Option<RequestEvent>) -> Option<PoolEvent>;
     fn is_terminal(&self) -> bool;
@@ -622,6 +896,9 @@ pub struct RequestHandlerImpl<T: Networker> {
 }
 
 impl<T: Networker> RequestHandler<T> for RequestHandlerImpl<T> {
+    #[cfg(feature = "in_process_pool")]
+    type InProcess = RequestHandlerImpl<InProcessNetworker>;
+
     fn new(networker: Rc<RefCell<T>>, f: usize, cmd_ids: &[CommandHandle], nodes: &Nodes, pool_name: &str, timeout: i64, extended_timeout: i64, number_read_nodes: u8) -> Self {
         RequestHandlerImpl {
             request_wrapper: Some(RequestSM::new(networker, f, cmd_ids, nodes, pool_name, timeout, extended_timeout, number_read_nodes)),
@@ -677,6 +954,133 @@ impl<T: Networker> ConsensusState<T> {
     }
 }
 
+impl<T: Networker> LedgerCatchupConsensusState<T> {
+    fn is_consensus_reachable(&self, f: usize, total_nodes_cnt: usize) -> bool {
+        let rep_no: usize = self.replies.values().map(HashSet::len).sum();
+        let max_no = self.replies.values().map(HashSet::len).max().unwrap_or(0);
+        (max_no + total_nodes_cnt).saturating_sub(rep_no + self.timeout_nodes.len()) > f
+    }
+
+    fn catch_up(self, target_mt_root: String, target_mt_size: usize, req_id: String, cmd_ids: &[CommandHandle], timeout: i64) -> RequestState<T> {
+        let ledger_id = self.ledger_status.ledgerId as usize;
+        let cur_mt_size = self.ledger_status.txnSeqNo;
+
+        if target_mt_size == cur_mt_size && target_mt_root == self.ledger_status.merkleRoot {
+            let rep = CatchupRep { ledgerId: ledger_id, consProof: Vec::new(), txns: HashMap::new() };
+            _send_ok_replies(cmd_ids, &_build_ledger_catchup_reply(rep, &target_mt_root, target_mt_size));
+            return RequestState::finish();
+        }
+
+        if target_mt_size <= cur_mt_size {
+            _send_replies(cmd_ids, Err(err_msg(IndyErrorKind::InvalidState, "Local merkle tree is not acceptable for the ledger")));
+            return RequestState::finish();
+        }
+
+        match build_ledger_catchup_req(ledger_id, cur_mt_size + 1, target_mt_size) {
+            Ok(req_json) => {
+                self.networker.borrow_mut().process_event(Some(NetworkerEvent::SendOneRequest(req_json, req_id, timeout, None)));
+                RequestState::LedgerCatchupSingle((self, target_mt_root, target_mt_size).into())
+            }
+            Err(err) => {
+                _send_replies(cmd_ids, Err(err));
+                RequestState::finish()
+            }
+        }
+    }
+}
+
+impl<T: Networker> LedgerCatchupSingleState<T> {
+    fn try_to_continue(mut self, req_id: String, node_alias: String, cmd_ids: &[CommandHandle], nodes_cnt: usize, timeout: i64) -> RequestState<T> {
+        self.failed_nodes.insert(node_alias.clone());
+
+        if self.failed_nodes.len() < nodes_cnt {
+            self.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
+            self.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
+            RequestState::LedgerCatchupSingle(self)
+        } else {
+            _send_replies(cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "No node replied with the requested transactions of the ledger")));
+            self.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
+            RequestState::finish()
+        }
+    }
+}
+
+// Nodes left after applying `excluded_nodes` of the request options, None means all nodes.
+fn _get_nodes_to_send(nodes: &Nodes, options: &RequestOptions) -> IndyResult<Option<Vec<String>>> {
+    match options.excluded_nodes {
+        Some(ref excluded_nodes) if !excluded_nodes.is_empty() => {
+            let nodes_to_send: Vec<String> = nodes.keys()
+                .filter(|node| !excluded_nodes.contains(node))
+                .cloned()
+                .collect();
+
+            if nodes_to_send.is_empty() {
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("All known nodes {:?} are excluded", nodes.keys())));
+            }
+
+            Ok(Some(nodes_to_send))
+        }
+        _ => Ok(None)
+    }
+}
+
+// Nodes to send the action to: the explicit list (or preferred nodes) without excluded ones.
+fn _get_full_nodes_to_send(nodes_to_send: Option<Vec<String>>, nodes: &Nodes, options: &RequestOptions) -> IndyResult<Option<Vec<String>>> {
+    match nodes_to_send {
+        Some(nodes_to_send) => {
+            //TODO check empty list on API level?
+            let is_nodes_to_send_known = !nodes_to_send.is_empty() && nodes_to_send.iter().all(|node| nodes.contains_key(node));
+            if !is_nodes_to_send_known {
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("There is no known node in list to send {:?}, known nodes are {:?}",
+                                           nodes_to_send, nodes.keys())));
+            }
+
+            let excluded_nodes = options.excluded_nodes.clone().unwrap_or_default();
+            let nodes_to_send: Vec<String> = nodes_to_send.into_iter()
+                .filter(|node| !excluded_nodes.contains(node))
+                .collect();
+
+            if nodes_to_send.is_empty() {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "All nodes in list to send are excluded"));
+            }
+
+            Ok(Some(nodes_to_send))
+        }
+        None => _get_nodes_to_send(nodes, options)
+    }
+}
+
+fn _get_nodes_preference(options: &RequestOptions, nodes_to_send: &Option<Vec<String>>) -> Option<NodesPreference> {
+    if options.preferred_nodes.is_none() && nodes_to_send.is_none() {
+        return None;
+    }
+
+    Some(NodesPreference {
+        preferred: options.preferred_nodes.clone().unwrap_or_default(),
+        nodes_to_send: nodes_to_send.clone(),
+    })
+}
+
+// More than f nodes didn't reply in time: probably they left the pool and the local copy of pool ledger is outdated.
+// The event is emitted only once per request, when the threshold is crossed.
+fn _check_timeouts_for_outdated_pool(timeout_nodes: &HashSet<String>, f: usize) -> Option<PoolEvent> {
+    if timeout_nodes.len() == f + 1 {
+        Some(PoolEvent::PoolOutdated)
+    } else {
+        None
+    }
+}
+
+fn _check_state_proof_for_outdated_pool(msg_result: &SJsonValue, nodes: &Nodes) -> Option<PoolEvent> {
+    if state_proof::is_signed_by_unknown_nodes(msg_result, nodes) {
+        Some(PoolEvent::PoolOutdated)
+    } else {
+        None
+    }
+}
+
 fn _parse_nack(denied_nodes: &mut HashSet<String>, f: usize, raw_msg: &str, cmd_ids: &[CommandHandle], node_alias: &str) -> bool {
     if denied_nodes.len() == f {
         _send_ok_replies(cmd_ids, raw_msg);
@@ -704,9 +1108,35 @@ fn _process_catchup_reply(rep: &mut CatchupRep, merkle: &MerkleTree, target_mt_r
 
     check_cons_proofs(&merkle, &rep.consProof, target_mt_root, target_mt_size)?;
     merkle_tree_factory::dump_new_txns(pool_name, &txns_to_drop)?;
+
     Ok(merkle)
 }
 
+// The reply must contain exactly the requested transactions, they are verified against the ledger root
+// by the client holding the merkle tree of the ledger.
+fn _check_ledger_catchup_reply(rep: &CatchupRep, seq_no_start: usize, seq_no_end: usize) -> IndyResult<()> {
+    let seq_nos = rep.txns.keys()
+        .map(|seq_no| seq_no.parse::<usize>())
+        .collect::<Result<HashSet<usize>, _>>()
+        .to_indy(IndyErrorKind::InvalidStructure, "Invalid key in catchup reply")?;
+
+    if rep.txns.len() != seq_nos.len() || seq_nos != (seq_no_start..=seq_no_end).collect::<HashSet<usize>>() {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           format!("Catchup reply doesn't contain transactions from {} to {}", seq_no_start, seq_no_end)));
+    }
+
+    Ok(())
+}
+
+fn _build_ledger_catchup_reply(rep: CatchupRep, target_mt_root: &str, target_mt_size: usize) -> String {
+    json!({
+        "ledgerId": rep.ledgerId,
+        "txnSeqNo": target_mt_size,
+        "merkleRoot": target_mt_root,
+        "txns": rep.txns,
+    }).to_string()
+}
+
 fn _send_ok_replies(cmd_ids: &[CommandHandle], msg: &str) {
     _send_replies(cmd_ids, Ok(msg.to_string()))
 }
@@ -724,6 +1154,54 @@ fn _send_replies(cmd_ids: &[CommandHandle], msg: IndyResult<String>) {
     });
 }
 
+/// Returns the reply itself if all nodes replied the same result (state proofs aside).
+/// Otherwise returns the diff: groups of nodes with the same reply and the list of nodes which didn't reply.
+fn _build_all_nodes_reply(replies: &HashMap<String, Option<String>>) -> String {
+    let mut groups: HashMap<HashableValue, (Vec<String>, SJsonValue)> = HashMap::new();
+    let mut timeout_nodes: Vec<String> = Vec::new();
+
+    for (node_alias, raw_msg) in replies {
+        match raw_msg {
+            Some(raw_msg) => {
+                let (key, reply) = match (_get_msg_result_without_state_proof(raw_msg), serde_json::from_str::<SJsonValue>(raw_msg)) {
+                    (Ok((_, result_without_proof)), Ok(reply)) => (result_without_proof, reply),
+                    _ => (SJsonValue::from(raw_msg.as_str()), SJsonValue::from(raw_msg.as_str()))
+                };
+
+                groups.entry(HashableValue { inner: key })
+                    .or_insert_with(|| (Vec::new(), reply))
+                    .0.push(node_alias.clone());
+            }
+            None => timeout_nodes.push(node_alias.clone())
+        }
+    }
+
+    if timeout_nodes.is_empty() && groups.len() == 1 {
+        let unanimous_reply = groups.values().next()
+            .and_then(|&(ref nodes, _)| nodes.first())
+            .and_then(|node_alias| replies.get(node_alias));
+
+        if let Some(Some(raw_msg)) = unanimous_reply {
+            return raw_msg.clone();
+        }
+    }
+
+    let mut groups: Vec<(Vec<String>, SJsonValue)> = groups.into_iter()
+        .map(|(_, (mut nodes, reply))| {
+            nodes.sort();
+            (nodes, reply)
+        })
+        .collect();
+    groups.sort_by(|&(ref nodes1, _), &(ref nodes2, _)| nodes2.len().cmp(&nodes1.len()).then_with(|| nodes1.cmp(nodes2)));
+    timeout_nodes.sort();
+
+    json!({
+        "op": "REPLY_DIFF",
+        "replies": groups.into_iter().map(|(nodes, reply)| json!({"nodes": nodes, "reply": reply})).collect::<Vec<SJsonValue>>(),
+        "timeout_nodes": timeout_nodes,
+    }).to_string()
+}
+
 fn _get_msg_result_without_state_proof(msg: &str) -> IndyResult<(SJsonValue, SJsonValue)> {
     let msg = serde_json::from_str::<SJsonValue>(msg)
         .to_indy(IndyErrorKind::InvalidStructure, "Response is malformed json")?;
@@ -862,6 +1340,9 @@ pub mod tests {
     pub struct MockRequestHandler {}
 
     impl<T: Networker> RequestHandler<T> for MockRequestHandler {
+        #[cfg(feature = "in_process_pool")]
+        type InProcess = MockRequestHandler;
+
         fn new(_networker: Rc<RefCell<T>>, _f: usize, _cmd_ids: &[CommandHandle], _nodes: &Nodes, _pool_name: &str, _timeout: i64, _extended_timeout: i64, _number_read_nodes: u8) -> Self {
             MockRequestHandler {}
         }
@@ -946,6 +1427,14 @@ pub mod tests {
                                 NUMBER_READ_NODES)
     }
 
+    fn _domain_ledger_status() -> LedgerStatus {
+        LedgerStatus { ledgerId: 1, ..LedgerStatus::default() }
+    }
+
+    fn _domain_consistency_proof() -> ConsistencyProof {
+        ConsistencyProof { ledgerId: 1, seqNoEnd: 2, newMerkleRoot: "root".to_string(), ..ConsistencyProof::default() }
+    }
+
     // required because of dumping txns to cache
     fn _create_pool(pool_name: &str, content: Option<String>) {
         let mut file = test_pool_create_poolfile(pool_name);
@@ -974,6 +1463,13 @@ pub mod tests {
             assert_match!(RequestState::CatchupConsensus(_), request_handler.request_wrapper.unwrap().state);
         }
 
+        #[test]
+        fn request_handler_process_ledger_catchup_event_from_start_works() {
+            let mut request_handler = _request_handler("request_handler_process_ledger_catchup_event_from_start_works", 0, 1);
+            request_handler.process_event(Some(RequestEvent::LedgerCatchup(_domain_ledger_status())));
+            assert_match!(RequestState::LedgerCatchupConsensus(_), request_handler.request_wrapper.unwrap().state);
+        }
+
         #[test]
         fn request_handler_process_catchup_req_event_from_start_works() {
             let mut request_handler = _request_handler("request_handler_process_catchup_req_event_from_start_works", 0, 1);
@@ -991,52 +1487,101 @@ pub mod tests {
         #[test]
         fn request_handler_process_custom_single_req_event_from_start_works() {
             let mut request_handler = _request_handler("request_handler_process_custom_single_req_event_from_start_works", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
 
+        #[test]
+        fn request_handler_process_custom_single_req_event_from_start_works_for_consensus_policy() {
+            let mut request_handler = _request_handler("request_handler_process_custom_single_req_event_from_start_works_for_consensus_policy", 0, 1);
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions { read_consensus: Some(ReadConsensusPolicy::Consensus), ..RequestOptions::default() })));
+            assert_match!(RequestState::Consensus(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_custom_single_req_event_from_start_works_for_all_policy() {
+            let mut request_handler = _request_handler("request_handler_process_custom_single_req_event_from_start_works_for_all_policy", 0, 1);
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions { read_consensus: Some(ReadConsensusPolicy::All), ..RequestOptions::default() })));
+            assert_match!(RequestState::All(_), request_handler.request_wrapper.unwrap().state);
+        }
+
         #[test]
         fn request_handler_process_consensus_full_req_event_from_start_works() {
             let mut request_handler = _request_handler("request_handler_process_consensus_full_req_event_from_start_works", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, None)));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, None, RequestOptions::default())));
             assert_match!(RequestState::Full(_), request_handler.request_wrapper.unwrap().state);
         }
 
         #[test]
         fn request_handler_process_consensus_full_req_event_from_start_works_for_list_nodes() {
             let mut request_handler = _request_handler("request_handler_process_consensus_full_req_event_from_start_works_for_list_nodes", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, Some(format!(r#"["{}"]"#, NODE)))));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, Some(format!(r#"["{}"]"#, NODE)), RequestOptions::default())));
             assert_match!(RequestState::Full(_), request_handler.request_wrapper.unwrap().state);
         }
 
         #[test]
         fn request_handler_process_consensus_full_req_event_from_start_works_for_empty_list_nodes() {
             let mut request_handler = _request_handler("request_handler_process_consensus_full_req_event_from_start_works_for_empty_list_nodes", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, Some("[ ]".to_string()))));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, Some("[ ]".to_string()), RequestOptions::default())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
 
         #[test]
         fn request_handler_process_consensus_full_req_event_from_start_works_for_list_nodes_contains_unknown_node() {
             let mut request_handler = _request_handler("request_handler_process_consensus_full_req_event_from_start_works_for_list_nodes_contains_unknown_node", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, Some("[Unknown Node]".to_string()))));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, Some("[Unknown Node]".to_string()), RequestOptions::default())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
 
         #[test]
         fn request_handler_process_consensus_full_req_event_from_start_works_for_invalid_list_nodes_format() {
             let mut request_handler = _request_handler("request_handler_process_consensus_full_req_event_from_start_works_for_invalid_list_nodes_format", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, Some(format!(r#""{}""#, NODE)))));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, Some(format!(r#""{}""#, NODE)), RequestOptions::default())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
 
         #[test]
         fn request_handler_process_custom_consensus_req_event_from_start_works() {
             let mut request_handler = _request_handler("request_handler_process_custom_consensus_req_event_from_start_works", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             assert_match!(RequestState::Consensus(_), request_handler.request_wrapper.unwrap().state);
         }
 
+        #[test]
+        fn request_handler_process_custom_consensus_req_event_from_start_works_for_all_nodes_excluded() {
+            let mut request_handler = _request_handler("request_handler_process_custom_consensus_req_event_from_start_works_for_all_nodes_excluded", 0, 1);
+            let options = RequestOptions { excluded_nodes: Some(vec![NODE.to_string()]), ..RequestOptions::default() };
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), options)));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_custom_consensus_req_event_from_start_works_for_require_state_proof() {
+            let mut request_handler = _request_handler("request_handler_process_custom_consensus_req_event_from_start_works_for_require_state_proof", 0, 1);
+            let options = RequestOptions { require_state_proof: Some(true), ..RequestOptions::default() };
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), options)));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_custom_single_req_event_from_start_works_for_options() {
+            let mut request_handler = _request_handler("request_handler_process_custom_single_req_event_from_start_works_for_options", 0, 2);
+            let options = RequestOptions {
+                timeout: Some(5),
+                extended_timeout: Some(7),
+                preferred_nodes: Some(vec![NODE_2.to_string()]),
+                excluded_nodes: Some(vec![NODE.to_string()]),
+                ..RequestOptions::default()
+            };
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), options)));
+
+            let request_wrapper = request_handler.request_wrapper.unwrap();
+            assert_eq!(5, request_wrapper.timeout);
+            assert_eq!(7, request_wrapper.extended_timeout);
+            assert_eq!(Some(vec![NODE_2.to_string()]), request_wrapper.nodes_to_send);
+            assert_match!(RequestState::Single(_), request_wrapper.state);
+        }
+
         #[test]
         fn request_handler_process_other_event_from_start_works() {
             let mut request_handler = _request_handler("request_handler_process_other_event_from_start_works", 0, 1);
@@ -1051,7 +1596,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_consensus_state_works_for_consensus_reached() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_consensus_state_works_for_consensus_reached", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), SIMPLE_REPLY.to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1062,7 +1607,7 @@ pub mod tests {
             // some nodes accept, some reject and some nack.  the end result is consensus should not be reached
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_consensus_state_works_for_consensus_reached_with_mixed_msgs", 1, 4);
 
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), SIMPLE_REPLY.to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), REJECT_REPLY.to_string(), NODE_2.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), NACK_REPLY.to_string(), NODE_3.to_string(), REQ_ID.to_string())));
@@ -1085,7 +1630,7 @@ pub mod tests {
             // some nodes accept, some reject and some nack.  the end result is consensus should not be reached
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_consensus_state_works_for_consensus_reached_with_0_concensus", 1, 4);
 
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), SIMPLE_REPLY.to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), "".to_string(), NODE_2.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), "".to_string(), NODE_3.to_string(), REQ_ID.to_string())));
@@ -1103,7 +1648,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_consensus_state_works_for_consensus_reachable() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_consensus_state_works_for_consensus_reachable", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), SIMPLE_REPLY.to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Consensus(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1111,7 +1656,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_consensus_state_works_for_consensus_not_reachable() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_consensus_state_works_for_consensus_not_reachable", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), r#"{"result":{}}"#.to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), r#"{}"#.to_string(), NODE_2.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
@@ -1120,7 +1665,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_consensus_state_works_for_invalid_message() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_consensus_state_works_for_invalid_message", 1, 4);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Consensus(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1128,7 +1673,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reqack_event_from_consensus_state_works() {
             let mut request_handler = _request_handler("request_handler_process_reqack_event_from_consensus_state_works", 1, 4);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::ReqACK(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Consensus(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1136,7 +1681,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reqnack_event_from_consensus_state_works_for_consensus_reached() {
             let mut request_handler = _request_handler("request_handler_process_reqnack_event_from_consensus_state_works_for_consensus_reached", 1, 1);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1144,7 +1689,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reqnack_event_from_consensus_state_works_for_consensus_reachable() {
             let mut request_handler = _request_handler("request_handler_process_reqnack_event_from_consensus_state_works_for_consensus_reachable", 1, 3);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Consensus(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1152,7 +1697,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reqnack_event_from_consensus_state_works_for_consensus_not_reachable() {
             let mut request_handler = _request_handler("request_handler_process_reqnack_event_from_consensus_state_works_for_consensus_not_reachable", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), r#"{"result":{}}"#.to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
@@ -1161,7 +1706,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reject_event_from_consensus_state_works_for_consensus_reached() {
             let mut request_handler = _request_handler("request_handler_process_reject_event_from_consensus_state_works_for_consensus_reached", 1, 1);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1169,7 +1714,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reject_event_from_consensus_state_works_for_consensus_reachable() {
             let mut request_handler = _request_handler("request_handler_process_reject_event_from_consensus_state_works_for_consensus_reachable", 1, 3);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Consensus(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1177,7 +1722,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reject_event_from_consensus_state_works_for_consensus_not_reachable() {
             let mut request_handler = _request_handler("request_handler_process_reject_event_from_consensus_state_works_for_consensus_not_reachable", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), r#"{"result":{}}"#.to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
@@ -1186,23 +1731,51 @@ pub mod tests {
         #[test]
         fn request_handler_process_timeout_event_from_consensus_state_works_for_consensus_reachable() {
             let mut request_handler = _request_handler("request_handler_process_timeout_event_from_consensus_state_works_for_consensus_reachable", 1, 3);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Timeout(NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Consensus(_), request_handler.request_wrapper.unwrap().state);
         }
 
+        #[test]
+        fn request_handler_process_timeout_event_from_consensus_state_works_for_excluded_nodes() {
+            let mut request_handler = _request_handler("request_handler_process_timeout_event_from_consensus_state_works_for_excluded_nodes", 1, 3);
+            let options = RequestOptions { excluded_nodes: Some(vec!["n3".to_string()]), ..RequestOptions::default() };
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), options)));
+            request_handler.process_event(Some(RequestEvent::Timeout(NODE.to_string(), REQ_ID.to_string())));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
         #[test]
         fn request_handler_process_timeout_event_from_consensus_state_works_for_consensus_not_reachable() {
             let mut request_handler = _request_handler("request_handler_process_timeout_event_from_consensus_state_works_for_consensus_not_reachable", 1, 1);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Timeout(NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
 
+        #[test]
+        fn request_handler_process_timeout_event_from_consensus_state_works_for_retries() {
+            let mut request_handler = _request_handler("request_handler_process_timeout_event_from_consensus_state_works_for_retries", 1, 1);
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions { retries: Some(1), ..RequestOptions::default() })));
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_reply_event_from_consensus_state_works_after_retry() {
+            let mut request_handler = _request_handler("request_handler_process_reply_event_from_consensus_state_works_after_retry", 0, 1);
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions { retries: Some(1), ..RequestOptions::default() })));
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
+            assert_match!(RequestState::Consensus(_), request_handler.request_wrapper.as_ref().unwrap().state);
+            request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
         #[test]
         fn request_handler_process_terminate_event_from_consensus_state_works_for_consensus_not_reachable() {
             let mut request_handler = _request_handler("request_handler_process_terminate_event_from_consensus_state_works_for_consensus_not_reachable", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Terminate));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1210,7 +1783,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_other_event_from_consensus_state_works() {
             let mut request_handler = _request_handler("request_handler_process_other_event_from_consensus_state_works", 1, 4);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Ping));
             assert_match!(RequestState::Consensus(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1223,12 +1796,40 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_single_state_works_for_consensus_reached() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_consensus_reached", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "{}".to_string(), NODE_2.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
 
+        #[test]
+        fn request_handler_process_reply_event_from_single_state_works_for_state_proof_policy() {
+            let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_state_proof_policy", 1, 4);
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions { read_consensus: Some(ReadConsensusPolicy::StateProof), ..RequestOptions::default() })));
+            request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "{}".to_string(), NODE_2.to_string(), REQ_ID.to_string())));
+            assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_reply_event_from_single_state_works_for_unknown_participants() {
+            let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_unknown_participants", 1, 4);
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
+            let reply = json!({
+                "result": {
+                    "state_proof": {
+                        "multi_signature": {
+                            "participants": [NODE, "unknown"]
+                        }
+                    }
+                },
+                "op": "REPLY",
+            }).to_string();
+            let pe = request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), reply, NODE.to_string(), REQ_ID.to_string())));
+            assert_match!(Some(PoolEvent::PoolOutdated), pe);
+            assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
+        }
+
         fn correct_state_proof_reply(timestamp: u64) -> String {
             json!({
                 "result": {
@@ -1251,7 +1852,7 @@ pub mod tests {
             set_freshness_threshold(600);
             add_state_proof_parser();
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_state_proof", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(
                 RequestEvent::Reply(Reply::default(), correct_state_proof_reply(_get_cur_time() - 300), NODE.to_string(), REQ_ID.to_string()))
             );
@@ -1263,7 +1864,7 @@ pub mod tests {
             set_freshness_threshold(600);
             add_state_proof_parser();
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_state_proof_from_future", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(
                 Some(RequestEvent::Reply(Reply::default(), correct_state_proof_reply(_get_cur_time() + 300), NODE.to_string(), REQ_ID.to_string()))
             );
@@ -1294,7 +1895,7 @@ pub mod tests {
             set_freshness_threshold(600);
             add_state_proof_parser();
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_freshness_filtering", 2, 4);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             //
             request_handler.process_event(Some(RequestEvent::Reply(
                 Reply::default(),
@@ -1321,7 +1922,7 @@ pub mod tests {
             add_state_proof_parser();
 
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_state_proof_from_past", 2, 4);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, Some(_get_cur_time() - 400)))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, Some(_get_cur_time() - 400)), RequestOptions::default())));
 
             {
                 request_handler.process_event(
@@ -1348,7 +1949,7 @@ pub mod tests {
             add_state_proof_parser();
 
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_freshness_filtering_from_env_variable", 2, 4);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             //
             request_handler.process_event(Some(RequestEvent::Reply(
                 Reply::default(),
@@ -1373,7 +1974,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_single_state_works_for_not_completed() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_not_completed", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1381,7 +1982,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_single_state_works_for_cannot_be_completed() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_cannot_be_completed", 1, 1);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1389,7 +1990,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_single_state_works_for_invalid_message() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_invalid_message", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1397,7 +1998,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reqack_event_from_single_state_works() {
             let mut request_handler = _request_handler("request_handler_process_reqack_event_from_single_state_works", 1, 1);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::ReqACK(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1405,7 +2006,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reqnack_event_from_single_state_works_for_completed() {
             let mut request_handler = _request_handler("request_handler_process_reqnack_event_from_single_state_works_for_completed", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), "{}".to_string(), NODE_2.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
@@ -1414,7 +2015,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reqnack_event_from_single_state_works_for_not_completed() {
             let mut request_handler = _request_handler("request_handler_process_reqnack_event_from_single_state_works_for_not_completed", 1, 3);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1422,7 +2023,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reject_event_from_single_state_works_for_completed() {
             let mut request_handler = _request_handler("request_handler_process_reject_event_from_single_state_works_for_completed", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), "{}".to_string(), NODE_2.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
@@ -1431,7 +2032,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reject_event_from_single_state_works_for_not_completed() {
             let mut request_handler = _request_handler("request_handler_process_reject_event_from_single_state_works_for_not_completed", 1, 3);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1439,7 +2040,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_timeout_event_from_single_state_works() {
             let mut request_handler = _request_handler("request_handler_process_timeout_event_from_single_state_works", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1447,15 +2048,26 @@ pub mod tests {
         #[test]
         fn request_handler_process_timeout_event_from_single_state_works_for_cannot_be_completed() {
             let mut request_handler = _request_handler("request_handler_process_timeout_event_from_single_state_works_for_cannot_be_completed", 1, 1);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
 
+        #[test]
+        fn request_handler_process_timeout_event_from_single_state_works_for_outdated_pool() {
+            let mut request_handler = _request_handler("request_handler_process_timeout_event_from_single_state_works_for_outdated_pool", 1, 4);
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
+            let pe = request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
+            assert_match!(None, pe);
+            let pe = request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE_2.to_string())));
+            assert_match!(Some(PoolEvent::PoolOutdated), pe);
+            assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
+        }
+
         #[test]
         fn request_handler_process_terminate_event_from_single_state_works() {
             let mut request_handler = _request_handler("request_handler_process_terminate_event_from_single_state_works", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Terminate));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1463,7 +2075,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_other_event_from_single_state_works() {
             let mut request_handler = _request_handler("request_handler_process_other_event_from_single_state_works", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Pong));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1474,7 +2086,7 @@ pub mod tests {
             // some nodes accept, some reject and some nack.  the end result is consensus should not be reached
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_consensus_reached_with_mixed_msgs", 1, 4);
 
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), SIMPLE_REPLY.to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), REJECT_REPLY.to_string(), NODE_2.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), NACK_REPLY.to_string(), NODE_3.to_string(), REQ_ID.to_string())));
@@ -1497,7 +2109,7 @@ pub mod tests {
             // some nodes accept, some reject and some nack.  the end result is consensus should not be reached
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_consensus_reached_with_0_concensus", 1, 4);
 
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), SIMPLE_REPLY.to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), "".to_string(), NODE_2.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), "".to_string(), NODE_3.to_string(), REQ_ID.to_string())));
@@ -1664,13 +2276,138 @@ pub mod tests {
         }
     }
 
+    mod ledger_catchup_consensus {
+        use super::*;
+
+        #[test]
+        fn request_handler_process_ledger_status_event_from_ledger_catchup_consensus_state_works_for_up_to_date_ledger() {
+            let mut request_handler = _request_handler("request_handler_process_ledger_status_event_from_ledger_catchup_consensus_state_works_for_up_to_date_ledger", 0, 1);
+            request_handler.process_event(Some(RequestEvent::LedgerCatchup(_domain_ledger_status())));
+            request_handler.process_event(Some(RequestEvent::LedgerStatus(_domain_ledger_status(), Some(NODE.to_string()), None)));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_ledger_status_event_from_ledger_catchup_consensus_state_works_for_not_completed() {
+            let mut request_handler = _request_handler("request_handler_process_ledger_status_event_from_ledger_catchup_consensus_state_works_for_not_completed", 1, 4);
+            request_handler.process_event(Some(RequestEvent::LedgerCatchup(_domain_ledger_status())));
+            request_handler.process_event(Some(RequestEvent::LedgerStatus(_domain_ledger_status(), Some(NODE.to_string()), None)));
+            assert_match!(RequestState::LedgerCatchupConsensus(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_consistency_proof_event_from_ledger_catchup_consensus_state_works() {
+            let mut request_handler = _request_handler("request_handler_process_consistency_proof_event_from_ledger_catchup_consensus_state_works", 0, 1);
+            request_handler.process_event(Some(RequestEvent::LedgerCatchup(_domain_ledger_status())));
+            request_handler.process_event(Some(RequestEvent::ConsistencyProof(_domain_consistency_proof(), NODE.to_string())));
+            assert_match!(RequestState::LedgerCatchupSingle(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_consistency_proof_event_from_ledger_catchup_consensus_state_works_for_different_targets() {
+            let mut request_handler = _request_handler("request_handler_process_consistency_proof_event_from_ledger_catchup_consensus_state_works_for_different_targets", 1, 4);
+            request_handler.process_event(Some(RequestEvent::LedgerCatchup(_domain_ledger_status())));
+            request_handler.process_event(Some(RequestEvent::ConsistencyProof(_domain_consistency_proof(), NODE.to_string())));
+            request_handler.process_event(Some(RequestEvent::LedgerStatus(_domain_ledger_status(), Some(NODE_2.to_string()), None)));
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE_3.to_string())));
+            assert_match!(&RequestState::LedgerCatchupConsensus(_), &request_handler.request_wrapper.as_ref().unwrap().state);
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE_4.to_string())));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_ledger_status_event_from_ledger_catchup_consensus_state_works_for_behind_ledger() {
+            let mut request_handler = _request_handler("request_handler_process_ledger_status_event_from_ledger_catchup_consensus_state_works_for_behind_ledger", 0, 1);
+            request_handler.process_event(Some(RequestEvent::LedgerCatchup(LedgerStatus { txnSeqNo: 3, ledgerId: 1, ..LedgerStatus::default() })));
+            request_handler.process_event(Some(RequestEvent::LedgerStatus(_domain_ledger_status(), Some(NODE.to_string()), None)));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_timeout_event_from_ledger_catchup_consensus_state_works_for_all_timeouts() {
+            let mut request_handler = _request_handler("request_handler_process_timeout_event_from_ledger_catchup_consensus_state_works_for_all_timeouts", 0, 1);
+            request_handler.process_event(Some(RequestEvent::LedgerCatchup(_domain_ledger_status())));
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_terminate_event_from_ledger_catchup_consensus_state_works() {
+            let mut request_handler = _request_handler("request_handler_process_terminate_event_from_ledger_catchup_consensus_state_works", 0, 1);
+            request_handler.process_event(Some(RequestEvent::LedgerCatchup(_domain_ledger_status())));
+            request_handler.process_event(Some(RequestEvent::Terminate));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_other_event_from_ledger_catchup_consensus_state_works() {
+            let mut request_handler = _request_handler("request_handler_process_other_event_from_ledger_catchup_consensus_state_works", 0, 1);
+            request_handler.process_event(Some(RequestEvent::LedgerCatchup(_domain_ledger_status())));
+            request_handler.process_event(Some(RequestEvent::Pong));
+            assert_match!(RequestState::LedgerCatchupConsensus(_), request_handler.request_wrapper.unwrap().state);
+        }
+    }
+
+    mod ledger_catchup_single {
+        use super::*;
+
+        fn _ledger_catchup_single_request_handler(pool_name: &str, nodes_cnt: usize) -> RequestHandlerImpl<MockNetworker> {
+            let mut request_handler = _request_handler(pool_name, 0, nodes_cnt);
+            request_handler.process_event(Some(RequestEvent::LedgerCatchup(_domain_ledger_status())));
+            request_handler.process_event(Some(RequestEvent::ConsistencyProof(_domain_consistency_proof(), NODE.to_string())));
+            request_handler
+        }
+
+        fn _domain_catchup_rep(seq_nos: &[usize]) -> CatchupRep {
+            let txns = seq_nos.iter()
+                .map(|seq_no| (seq_no.to_string(), json!({"txn": {"type": "1"}, "txnMetadata": {"seqNo": seq_no}})))
+                .collect();
+            CatchupRep { ledgerId: 1, consProof: Vec::new(), txns }
+        }
+
+        #[test]
+        fn request_handler_process_catchup_reply_event_from_ledger_catchup_single_state_works() {
+            let mut request_handler = _ledger_catchup_single_request_handler("request_handler_process_catchup_reply_event_from_ledger_catchup_single_state_works", 1);
+            request_handler.process_event(Some(RequestEvent::CatchupRep(_domain_catchup_rep(&[1, 2]), NODE.to_string())));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_catchup_reply_event_from_ledger_catchup_single_state_works_for_missed_txn() {
+            let mut request_handler = _ledger_catchup_single_request_handler("request_handler_process_catchup_reply_event_from_ledger_catchup_single_state_works_for_missed_txn", 2);
+            request_handler.process_event(Some(RequestEvent::CatchupRep(_domain_catchup_rep(&[2]), NODE.to_string())));
+            assert_match!(RequestState::LedgerCatchupSingle(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_catchup_reply_event_from_ledger_catchup_single_state_works_for_all_nodes_failed() {
+            let mut request_handler = _ledger_catchup_single_request_handler("request_handler_process_catchup_reply_event_from_ledger_catchup_single_state_works_for_all_nodes_failed", 1);
+            request_handler.process_event(Some(RequestEvent::CatchupRep(_domain_catchup_rep(&[1, 2, 3]), NODE.to_string())));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_timeout_event_from_ledger_catchup_single_state_works() {
+            let mut request_handler = _ledger_catchup_single_request_handler("request_handler_process_timeout_event_from_ledger_catchup_single_state_works", 2);
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
+            assert_match!(RequestState::LedgerCatchupSingle(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_terminate_event_from_ledger_catchup_single_state_works() {
+            let mut request_handler = _ledger_catchup_single_request_handler("request_handler_process_terminate_event_from_ledger_catchup_single_state_works", 1);
+            request_handler.process_event(Some(RequestEvent::Terminate));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+    }
+
     mod full {
         use super::*;
 
         #[test]
         fn request_handler_process_reply_event_from_full_state_works_for_completed() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_full_state_works_for_completed", 1, 1);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None)));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None, RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), r#"{"result":""}"#.to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1678,7 +2415,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_full_state_works_for_not_completed() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_full_state_works_for_not_completed", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None)));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None, RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), r#"{"result":""}"#.to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Full(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1686,7 +2423,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_full_state_works_for_different_replies() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_full_state_works_for_different_replies", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None)));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None, RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), r#"{"result":"11"}"#.to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), r#"{"result":"22"}"#.to_string(), "n2".to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
@@ -1695,7 +2432,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reqnack_event_from_full_state_works_for_completed() {
             let mut request_handler = _request_handler("request_handler_process_reqnack_event_from_full_state_works_for_completed", 1, 1);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None)));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None, RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), r#"{"result":""}"#.to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1703,7 +2440,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reqnack_event_from_full_state_works_for_not_completed() {
             let mut request_handler = _request_handler("request_handler_process_reqnack_event_from_full_state_works_for_not_completed", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None)));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None, RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), r#"{"result":""}"#.to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Full(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1711,7 +2448,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reject_event_from_full_state_works_for_completed() {
             let mut request_handler = _request_handler("request_handler_process_reject_event_from_full_state_works_for_completed", 1, 1);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None)));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None, RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), r#"{"result":""}"#.to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1719,7 +2456,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reject_event_from_full_state_works_for_not_completed() {
             let mut request_handler = _request_handler("request_handler_process_reject_event_from_full_state_works_for_not_completed", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None)));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None, RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), r#"{"result":""}"#.to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Full(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1727,7 +2464,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_timeout_event_from_full_state_works_for_completed() {
             let mut request_handler = _request_handler("request_handler_process_timeout_event_from_full_state_works_for_completed", 1, 1);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None)));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None, RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1735,7 +2472,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_timeout_event_from_full_state_works_for_not_completed() {
             let mut request_handler = _request_handler("request_handler_process_timeout_event_from_full_state_works_for_not_completed", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None)));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None, RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
             assert_match!(RequestState::Full(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1743,7 +2480,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reqack_event_from_full_state_works() {
             let mut request_handler = _request_handler("request_handler_process_reqack_event_from_full_state_works", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None)));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None, RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::ReqACK(Response::default(), r#"{"result":""}"#.to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Full(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1751,7 +2488,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_terminate_event_from_full_state_works() {
             let mut request_handler = _request_handler("request_handler_process_terminate_event_from_full_state_works", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None)));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None, RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Terminate));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1759,19 +2496,72 @@ pub mod tests {
         #[test]
         fn request_handler_process_other_event_from_full_state_works() {
             let mut request_handler = _request_handler("request_handler_process_other_event_from_full_state_works", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None)));
+            request_handler.process_event(Some(RequestEvent::CustomFullRequest(r#"{"result":""}"#.to_string(), REQ_ID.to_string(), None, None, RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Pong));
             assert_match!(RequestState::Full(_), request_handler.request_wrapper.unwrap().state);
         }
     }
 
+    mod all_state {
+        use super::*;
+
+        #[test]
+        fn request_handler_process_reply_event_from_all_state_works() {
+            let mut request_handler = _request_handler("request_handler_process_reply_event_from_all_state_works", 0, 2);
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), RequestOptions { read_consensus: Some(ReadConsensusPolicy::All), ..RequestOptions::default() })));
+            request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), SIMPLE_REPLY.to_string(), NODE.to_string(), REQ_ID.to_string())));
+            {
+                let request_handler_ref = request_handler.request_wrapper.as_ref().unwrap();
+                assert_match!(RequestState::All(_), request_handler_ref.state);
+            }
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE_2.to_string())));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn build_all_nodes_reply_works_for_same_replies() {
+            let reply = json!({"op": "REPLY", "result": {"data": "1", "state_proof": {"root_hash": "1"}}}).to_string();
+            let reply_2 = json!({"op": "REPLY", "result": {"data": "1", "state_proof": {"root_hash": "2"}}}).to_string();
+
+            let mut replies = HashMap::new();
+            replies.insert(NODE.to_string(), Some(reply.clone()));
+            replies.insert(NODE_2.to_string(), Some(reply_2.clone()));
+
+            let res = _build_all_nodes_reply(&replies);
+            assert!(res == reply || res == reply_2);
+        }
+
+        #[test]
+        fn build_all_nodes_reply_works_for_diff() {
+            let reply = json!({"op": "REPLY", "result": {"data": "1"}});
+            let reply_2 = json!({"op": "REPLY", "result": {"data": "2"}});
+
+            let mut replies = HashMap::new();
+            replies.insert(NODE.to_string(), Some(reply.to_string()));
+            replies.insert(NODE_2.to_string(), Some(reply.to_string()));
+            replies.insert(NODE_3.to_string(), Some(reply_2.to_string()));
+            replies.insert(NODE_4.to_string(), None);
+
+            let res: SJsonValue = serde_json::from_str(&_build_all_nodes_reply(&replies)).unwrap();
+            let expected = json!({
+                "op": "REPLY_DIFF",
+                "replies": [
+                    {"nodes": [NODE, NODE_2], "reply": reply},
+                    {"nodes": [NODE_3], "reply": reply_2},
+                ],
+                "timeout_nodes": [NODE_4],
+            });
+            assert_eq!(expected, res);
+        }
+    }
+
     mod finish {
         use super::*;
 
         #[test]
         fn request_handler_process_event_from_finish_state_works() {
             let mut request_handler = _request_handler("request_handler_process_event_from_finish_state_works", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string())));
+            request_handler.process_event(Some(RequestEvent::CustomConsensusRequest(MESSAGE.to_string(), REQ_ID.to_string(), RequestOptions::default())));
             request_handler.process_event(Some(RequestEvent::Terminate));
             request_handler.process_event(Some(RequestEvent::Ping));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
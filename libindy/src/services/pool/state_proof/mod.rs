@@ -14,6 +14,7 @@ use serde_json::Value as SJsonValue;
 use indy_api_types::ErrorCode;
 use crate::domain::ledger::{constants, request::ProtocolVersion};
 use indy_api_types::errors::prelude::*;
+use crate::services::ledger::txn_leaf_value;
 use crate::services::pool::events::{REQUESTS_FOR_STATE_PROOFS, REQUESTS_FOR_MULTI_STATE_PROOFS};
 use indy_utils::crypto::hash::hash as openssl_hash;
 
@@ -75,6 +76,16 @@ pub fn parse_generic_reply_for_proof_checking(json_msg: &SJsonValue, raw_msg: &s
     }
 }
 
+/// Checks if the multi signature of the reply state proof contains participants which are not in the known pool nodes.
+/// It means the pool membership was changed and the local copy of pool ledger is outdated.
+pub fn is_signed_by_unknown_nodes(msg_result: &SJsonValue, nodes: &Nodes) -> bool {
+    msg_result["state_proof"]["multi_signature"]["participants"].as_array()
+        .map(|participants| participants.iter()
+            .filter_map(SJsonValue::as_str)
+            .any(|participant| !nodes.contains_key(participant)))
+        .unwrap_or(false)
+}
+
 pub fn verify_parsed_sp(parsed_sps: Vec<ParsedSP>,
                         nodes: &Nodes,
                         f: usize,
@@ -145,6 +156,37 @@ pub fn verify_parsed_sp(parsed_sps: Vec<ParsedSP>,
     true
 }
 
+/// Verifies the state proof of the reply received earlier against the pinned BLS keys of the pool nodes.
+///
+/// The key of the proven state is built from the request fields the nodes echo in the reply result.
+/// Freshness of the reply isn't checked as the reply is expected to be old.
+pub fn verify_saved_reply(raw_msg: &str, bls_keys: &Nodes, f: usize, gen: &Generator) -> IndyResult<bool> {
+    let msg: SJsonValue = serde_json::from_str(raw_msg)
+        .to_indy(IndyErrorKind::InvalidStructure, "Reply is invalid json")?;
+
+    if msg["op"] != json!("REPLY") {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Only REPLY can be verified, got: {:?}", msg["op"])));
+    }
+
+    let result = &msg["result"];
+
+    let mut operation = result.clone();
+    if operation["type"].as_str() == Some(constants::GET_TXN) {
+        // GET_TXN result contains the transaction in `data` instead of the requested seq_no
+        operation["data"] = result["seqNo"].clone();
+    }
+
+    let sp_key = parse_key_from_request_for_builtin_sp(&json!({ "operation": operation }));
+
+    let res = match parse_generic_reply_for_proof_checking(result, raw_msg, sp_key.as_ref().map(Vec::as_slice)) {
+        Some(ref parsed_sps) if parsed_sps.is_empty() => false,
+        Some(parsed_sps) => verify_parsed_sp(parsed_sps, bls_keys, f, gen),
+        None => false
+    };
+
+    Ok(res)
+}
+
 #[logfn(Trace)]
 pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Vec<u8>> {
     let type_ = json_msg["operation"]["type"].as_str()?;
@@ -242,6 +284,7 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
             }
         }
         constants::GET_TXN_AUTHR_AGRMT => {
+            // Latest and by timestamp requests are proved by the same key, the timestamp just selects the state root
             match (json_msg["version"].as_str(), json_msg["digest"].as_str(), json_msg["timestamp"].as_u64()) {
                 (None, None, _ts) => "2:latest".to_owned(),
                 (None, Some(digest), None) => format!("2:d:{}", digest),
@@ -253,10 +296,33 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
             }
         }
         constants::GET_TXN_AUTHR_AGRMT_AML => {
-            if let Some(version) = json_msg["version"].as_str() {
-                format!("3:v:{}", version)
+            match (json_msg["version"].as_str(), json_msg["timestamp"].as_u64()) {
+                (Some(version), None) => format!("3:v:{}", version),
+                (None, _ts) => "3:latest".to_owned(),
+                _ => {
+                    error!("parse_key_from_request_for_builtin_sp: <<< GET_TXN_AUTHR_AGRMT_AML Unexpected combination of request parameters, skip StateProof logic");
+                    return None;
+                }
+            }
+        }
+        constants::GET_RICH_SCHEMA_OBJECT_BY_ID => {
+            if let Some(id) = json_msg["id"].as_str() {
+                trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_RICH_SCHEMA_OBJECT_BY_ID id {:?}", id);
+                id.to_string()
             } else {
-                "3:latest".to_owned()
+                trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< GET_RICH_SCHEMA_OBJECT_BY_ID No key suffix");
+                return None;
+            }
+        }
+        constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA => {
+            if let (Some(rs_type), Some(rs_name), Some(rs_version)) = (json_msg["rsType"].as_str(),
+                                                                       json_msg["rsName"].as_str(),
+                                                                       json_msg["rsVersion"].as_str()) {
+                trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_RICH_SCHEMA_OBJECT_BY_METADATA rs_type {:?}, rs_name {:?}, rs_version {:?}", rs_type, rs_name, rs_version);
+                _rich_schema_object_metadata_key(rs_type, rs_name, rs_version)
+            } else {
+                trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< GET_RICH_SCHEMA_OBJECT_BY_METADATA No key suffix");
+                return None;
             }
         }
         constants::GET_TXN => {
@@ -283,7 +349,8 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
                 return None;
             }
         }
-        constants::GET_REVOC_REG | constants::GET_REVOC_REG_DELTA | constants::GET_TXN_AUTHR_AGRMT | constants::GET_TXN_AUTHR_AGRMT_AML | constants::GET_AUTH_RULE => {
+        constants::GET_REVOC_REG | constants::GET_REVOC_REG_DELTA | constants::GET_TXN_AUTHR_AGRMT | constants::GET_TXN_AUTHR_AGRMT_AML | constants::GET_AUTH_RULE |
+        constants::GET_RICH_SCHEMA_OBJECT_BY_ID | constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA => {
             Vec::new()
         }
         constants::GET_REVOC_REG_DEF => {
@@ -351,6 +418,21 @@ fn _parse_reply_for_builtin_sp(json_msg: &SJsonValue, type_: &str, key: &[u8]) -
 
     trace!("TransactionHandler::parse_reply_for_builtin_sp: data: {:?}, parsed_data: {:?}", data, parsed_data);
 
+    // Nodes resolve the object found by metadata to its id and prove the object stored by id,
+    // so the requested metadata is checked against the proven object instead.
+    let key = if type_ == constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA && !parsed_data.is_null() {
+        match _rich_schema_object_key_by_metadata(&parsed_data, key) {
+            Ok(key) => key,
+            Err(err) => {
+                trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< {:?}", err);
+                return None;
+            }
+        }
+    } else {
+        key.to_vec()
+    };
+    let key = key.as_slice();
+
     let mut state_proofs = vec![];
 
     match _parse_reply_for_sp(json_msg, data.as_ref().map(String::as_str), &parsed_data, type_, key) {
@@ -768,45 +850,12 @@ fn _parse_reply_for_proof_value(json_msg: &SJsonValue, data: Option<&str>, parse
         }
 
         match xtype {
-            //TODO constants::GET_DDO => support DDO
+            // GET_DDO has no state proof, DID documents are resolved from verified GET_NYM and GET_ATTR (see indy_resolve_did)
             constants::GET_TXN => {
-                value = json!({});
-                if parsed_data["txn"].is_null() && parsed_data["txnMetadata"].is_null() &&
-                    parsed_data["ver"].is_null() && parsed_data["reqSignature"].is_null() {
-                    return Ok(None);
-                }
-                if !parsed_data["txn"].is_null() {
-                    value["txn"] = parsed_data["txn"].clone();
-                }
-                if !parsed_data["txnMetadata"].is_null() {
-                    value["txnMetadata"] = parsed_data["txnMetadata"].clone();
-                }
-                if !parsed_data["ver"].is_null() {
-                    value["ver"] = parsed_data["ver"].clone();
-                }
-                if !parsed_data["reqSignature"].is_null() {
-                    value["reqSignature"] = parsed_data["reqSignature"].clone();
-                }
-
-                // Adjust attrib transaction to match stored state
-                if value["txn"]["type"].as_str() == Some(constants::ATTRIB) {
-                    if let Some(raw) = value["txn"]["data"]["raw"].as_str() {
-                        if raw.is_empty() {
-                            value["txn"]["data"]["raw"] = SJsonValue::from("");
-                        } else {
-
-                            value["txn"]["data"]["raw"] =
-                                SJsonValue::from(hex::encode(openssl_hash(raw.as_bytes()).map_err(|err| err.to_string())?));
-                        }
-                    } else if let Some(enc) = value["txn"]["data"]["enc"].as_str() {
-                        if enc.is_empty() {
-                            value["txn"]["data"]["enc"] = SJsonValue::from("");
-                        } else {
-                            value["txn"]["data"]["enc"] =
-                                SJsonValue::from(hex::encode(openssl_hash(enc.as_bytes()).map_err(|err| err.to_string())?));
-                        }
-                    }
-                }
+                value = match txn_leaf_value(parsed_data).map_err(|err| err.to_string())? {
+                    Some(value) => value,
+                    None => return Ok(None)
+                };
             }
             constants::GET_NYM => {
                 value["identifier"] = parsed_data["identifier"].clone();
@@ -816,7 +865,8 @@ fn _parse_reply_for_proof_value(json_msg: &SJsonValue, data: Option<&str>, parse
             constants::GET_ATTR => {
                 value["val"] = SJsonValue::String(hex::encode(openssl_hash(data.as_bytes()).map_err(|err| err.to_string())?));
             }
-            constants::GET_CRED_DEF | constants::GET_REVOC_REG_DEF | constants::GET_REVOC_REG | constants::GET_TXN_AUTHR_AGRMT_AML => {
+            constants::GET_CRED_DEF | constants::GET_REVOC_REG_DEF | constants::GET_REVOC_REG | constants::GET_TXN_AUTHR_AGRMT_AML |
+            constants::GET_RICH_SCHEMA_OBJECT_BY_ID | constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA => {
                 value["val"] = parsed_data.clone();
             }
             constants::GET_AUTH_RULE => {
@@ -854,9 +904,18 @@ fn _parse_reply_for_proof_value(json_msg: &SJsonValue, data: Option<&str>, parse
                 if _is_full_taa_state_value_expected(sp_key) {
                     value["val"] = parsed_data.clone();
                 } else {
-                    value = SJsonValue::String(hex::encode(_calculate_taa_digest(parsed_data["text"].as_str().unwrap_or(""),
-                                                                                 parsed_data["version"].as_str().unwrap_or(""))
-                        .map_err(|err| format!("Can't calculate expected TAA digest to verify StateProof on the request ({})", err))?));
+                    // Only the digest is stored by version and latest keys, so text and version are proved through it
+                    let digest = hex::encode(_calculate_taa_digest(parsed_data["text"].as_str().unwrap_or(""),
+                                                                   parsed_data["version"].as_str().unwrap_or(""))
+                        .map_err(|err| format!("Can't calculate expected TAA digest to verify StateProof on the request ({})", err))?);
+
+                    if let Some(returned_digest) = parsed_data["digest"].as_str() {
+                        if returned_digest != digest {
+                            return Err(format!("Returned TAA digest {} doesn't match calculated one {}", returned_digest, digest));
+                        }
+                    }
+
+                    value = SJsonValue::String(digest);
                 }
             }
             _ => {
@@ -881,6 +940,26 @@ fn _calculate_taa_digest(text: &str, version: &str) -> IndyResult<Vec<u8>> {
     openssl_hash(content.as_bytes())
 }
 
+fn _rich_schema_object_metadata_key(rs_type: &str, rs_name: &str, rs_version: &str) -> String {
+    format!("{}:{}:{}", rs_type, rs_name, rs_version)
+}
+
+fn _rich_schema_object_key_by_metadata(parsed_data: &SJsonValue, metadata_key: &[u8]) -> Result<Vec<u8>, String> {
+    let (id, rs_type, rs_name, rs_version) = match (parsed_data["id"].as_str(),
+                                                    parsed_data["rsType"].as_str(),
+                                                    parsed_data["rsName"].as_str(),
+                                                    parsed_data["rsVersion"].as_str()) {
+        (Some(id), Some(rs_type), Some(rs_name), Some(rs_version)) => (id, rs_type, rs_name, rs_version),
+        _ => return Err("Invalid data for GET_RICH_SCHEMA_OBJECT_BY_METADATA".to_string())
+    };
+
+    if _rich_schema_object_metadata_key(rs_type, rs_name, rs_version).as_bytes() != metadata_key {
+        return Err("Returned Rich Schema object doesn't match requested metadata".to_string());
+    }
+
+    Ok(id.as_bytes().to_vec())
+}
+
 fn _is_full_taa_state_value_expected(expected_state_key: &[u8]) -> bool {
     expected_state_key.starts_with(b"2:d:")
 }
@@ -895,6 +974,38 @@ mod tests {
 
     use hex::FromHex;
     use libc::c_char;
+    use ursa::bls::SignKey;
+
+    use crate::services::pool::request_handler::DEFAULT_GENERATOR;
+
+    fn _gen() -> Generator {
+        Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap()
+    }
+
+    fn _sign_keys() -> Vec<(String, SignKey)> {
+        (1..=4).map(|i| (format!("Node{}", i), SignKey::new(None).unwrap())).collect()
+    }
+
+    fn _nodes(sign_keys: &[(String, SignKey)]) -> Nodes {
+        sign_keys.iter()
+            .map(|(alias, sign_key)| (alias.clone(), Some(VerKey::new(&_gen(), sign_key).unwrap())))
+            .collect()
+    }
+
+    fn _multi_signature(sign_keys: &[(String, SignKey)], value: SJsonValue) -> SJsonValue {
+        let message = rmp_serde::to_vec_named(&value).unwrap();
+
+        let signatures = sign_keys.iter()
+            .map(|(_, sign_key)| Bls::sign(&message, sign_key).unwrap())
+            .collect::<Vec<_>>();
+        let signature = MultiSignature::new(&signatures.iter().collect::<Vec<_>>()).unwrap();
+
+        json!({
+            "value": value,
+            "participants": sign_keys.iter().map(|(alias, _)| alias.clone()).collect::<Vec<String>>(),
+            "signature": signature.as_bytes().to_base58()
+        })
+    }
 
     /// For audit proofs tree looks like this
     ///         12345
@@ -1734,4 +1845,338 @@ mod tests {
                            }),
                    }));
     }
+
+    #[test]
+    fn is_signed_by_unknown_nodes_works() {
+        let mut nodes: Nodes = HashMap::new();
+        nodes.insert("Node1".to_string(), None);
+        nodes.insert("Node2".to_string(), None);
+
+        let known = json!({"state_proof": {"multi_signature": {"participants": ["Node1", "Node2"]}}});
+        assert!(!is_signed_by_unknown_nodes(&known, &nodes));
+
+        let unknown = json!({"state_proof": {"multi_signature": {"participants": ["Node1", "Node5"]}}});
+        assert!(is_signed_by_unknown_nodes(&unknown, &nodes));
+
+        assert!(!is_signed_by_unknown_nodes(&json!({"data": null}), &nodes));
+    }
+
+    mod txn_author_agreement {
+        use super::*;
+
+        const TEXT: &str = "some agreement text";
+        const VERSION: &str = "1.0.0";
+
+        fn _key(operation: SJsonValue) -> Option<String> {
+            parse_key_from_request_for_builtin_sp(&json!({"operation": operation}))
+                .map(|key| String::from_utf8(key).unwrap())
+        }
+
+        fn _digest() -> String {
+            hex::encode(_calculate_taa_digest(TEXT, VERSION).unwrap())
+        }
+
+        fn _taa() -> SJsonValue {
+            json!({"text": TEXT, "version": VERSION, "digest": _digest(), "ratification_ts": 12345})
+        }
+
+        fn _value(sp_key: &str, data: &SJsonValue) -> Result<Option<String>, String> {
+            let json_msg = json!({"seqNo": 10, "txnTime": 1000, "data": data});
+            _parse_reply_for_proof_value(&json_msg, Some(&data.to_string()), data, constants::GET_TXN_AUTHR_AGRMT, sp_key.as_bytes())
+        }
+
+        #[test]
+        fn parse_key_works_for_all_taa_request_shapes() {
+            assert_eq!(Some("2:latest".to_string()), _key(json!({"type": constants::GET_TXN_AUTHR_AGRMT})));
+            assert_eq!(Some("2:latest".to_string()), _key(json!({"type": constants::GET_TXN_AUTHR_AGRMT, "timestamp": 1000})));
+            assert_eq!(Some("2:d:abc".to_string()), _key(json!({"type": constants::GET_TXN_AUTHR_AGRMT, "digest": "abc"})));
+            assert_eq!(Some("2:v:1.0".to_string()), _key(json!({"type": constants::GET_TXN_AUTHR_AGRMT, "version": "1.0"})));
+            assert_eq!(None, _key(json!({"type": constants::GET_TXN_AUTHR_AGRMT, "version": "1.0", "timestamp": 1000})));
+        }
+
+        #[test]
+        fn parse_key_works_for_all_aml_request_shapes() {
+            assert_eq!(Some("3:latest".to_string()), _key(json!({"type": constants::GET_TXN_AUTHR_AGRMT_AML})));
+            assert_eq!(Some("3:latest".to_string()), _key(json!({"type": constants::GET_TXN_AUTHR_AGRMT_AML, "timestamp": 1000})));
+            assert_eq!(Some("3:v:1.0".to_string()), _key(json!({"type": constants::GET_TXN_AUTHR_AGRMT_AML, "version": "1.0"})));
+            assert_eq!(None, _key(json!({"type": constants::GET_TXN_AUTHR_AGRMT_AML, "version": "1.0", "timestamp": 1000})));
+        }
+
+        #[test]
+        fn parse_value_works_for_taa_by_digest() {
+            let value = _value(&format!("2:d:{}", _digest()), &_taa()).unwrap().unwrap();
+            let value: SJsonValue = serde_json::from_str(&value).unwrap();
+
+            assert_eq!(json!({"lsn": 10, "lut": 1000, "val": _taa()}), value);
+        }
+
+        #[test]
+        fn parse_value_works_for_taa_by_version_latest_and_timestamp() {
+            assert_eq!(Some(_digest()), _value(&format!("2:v:{}", VERSION), &_taa()).unwrap());
+            assert_eq!(Some(_digest()), _value("2:latest", &_taa()).unwrap());
+        }
+
+        #[test]
+        fn parse_value_works_for_taa_with_mismatched_digest() {
+            let mut taa = _taa();
+            taa["digest"] = json!("other");
+
+            assert!(_value("2:latest", &taa).is_err());
+        }
+
+        #[test]
+        fn parse_value_works_for_aml() {
+            let aml = json!({"version": VERSION, "aml": {"click": "agree"}, "amlContext": "context"});
+            let json_msg = json!({"seqNo": 10, "txnTime": 1000, "data": aml});
+
+            let value = _parse_reply_for_proof_value(&json_msg, Some(&aml.to_string()), &aml, constants::GET_TXN_AUTHR_AGRMT_AML, b"3:latest").unwrap().unwrap();
+            let value: SJsonValue = serde_json::from_str(&value).unwrap();
+
+            assert_eq!(json!({"lsn": 10, "lut": 1000, "val": aml}), value);
+        }
+
+        // State trie with the single `key` -> `value` pair, returns base64 encoded proof nodes and base58 encoded root hash
+        fn _state_proof(key: &str, value: &str) -> (String, String) {
+            // hex-prefix encoded path of the leaf with even number of nibbles
+            let mut path = vec![0x20];
+            path.extend_from_slice(key.as_bytes());
+
+            let mut leaf = rlp::RlpStream::new_list(2);
+            leaf.append(&path);
+            leaf.append(&rlp::encode_list::<Vec<u8>, Vec<u8>>(&[value.as_bytes().to_vec()]).to_vec());
+
+            let leaf: Node = UntrustedRlp::new(&leaf.out()).as_val().unwrap();
+
+            (base64::encode(&rlp::encode_list::<Node, &Node>(&[&leaf])), leaf.get_hash().to_base58())
+        }
+
+        // Reply of a single node to GET_TXN_AUTHR_AGRMT `operation` proving `state_value` stored by `state_key`
+        fn _taa_reply(operation: &SJsonValue, state_key: &str, state_value: &str, taa: &SJsonValue, sign_keys: &[(String, SignKey)]) -> SJsonValue {
+            let (proof_nodes, root_hash) = _state_proof(state_key, state_value);
+
+            let value = json!({"ledger_id": 2, "pool_state_root_hash": "", "state_root_hash": root_hash, "timestamp": 1000, "txn_root_hash": ""});
+
+            let mut result = operation.clone();
+            result["identifier"] = json!("L5AD5g65TDQr1PPHHRoiGf");
+            result["reqId"] = json!(1);
+            result["seqNo"] = json!(10);
+            result["txnTime"] = json!(1000);
+            result["data"] = taa.clone();
+            result["state_proof"] = json!({
+                "proof_nodes": proof_nodes,
+                "root_hash": root_hash,
+                "multi_signature": _multi_signature(sign_keys, value)
+            });
+            result
+        }
+
+        // The same checks as the request handler applies to the reply of a single node
+        fn _check_reply(operation: &SJsonValue, result: &SJsonValue, nodes: &Nodes) -> bool {
+            let sp_key = parse_key_from_request_for_builtin_sp(&json!({"operation": operation}));
+            let raw_msg = json!({"op": "REPLY", "result": result}).to_string();
+
+            match parse_generic_reply_for_proof_checking(result, &raw_msg, sp_key.as_ref().map(Vec::as_slice)) {
+                Some(parsed_sps) => verify_parsed_sp(parsed_sps, nodes, 1, &_gen()),
+                None => false
+            }
+        }
+
+        #[test]
+        fn single_node_reply_is_verified_for_all_taa_request_shapes() {
+            let sign_keys = _sign_keys();
+            let nodes = _nodes(&sign_keys);
+
+            let cases = vec![
+                (json!({"type": constants::GET_TXN_AUTHR_AGRMT}), "2:latest".to_string(), _digest()),
+                (json!({"type": constants::GET_TXN_AUTHR_AGRMT, "timestamp": 1000}), "2:latest".to_string(), _digest()),
+                (json!({"type": constants::GET_TXN_AUTHR_AGRMT, "version": VERSION}), format!("2:v:{}", VERSION), _digest()),
+                (json!({"type": constants::GET_TXN_AUTHR_AGRMT, "digest": _digest()}), format!("2:d:{}", _digest()),
+                 json!({"lsn": 10, "lut": 1000, "val": _taa()}).to_string()),
+            ];
+
+            for (operation, state_key, state_value) in cases {
+                let result = _taa_reply(&operation, &state_key, &state_value, &_taa(), &sign_keys);
+                assert!(_check_reply(&operation, &result, &nodes), "operation: {:?}", operation);
+            }
+        }
+
+        #[test]
+        fn single_node_reply_is_not_verified_for_other_taa_text() {
+            let sign_keys = _sign_keys();
+            let nodes = _nodes(&sign_keys);
+
+            let operation = json!({"type": constants::GET_TXN_AUTHR_AGRMT, "version": VERSION});
+
+            let mut taa = _taa();
+            taa["text"] = json!("other text");
+            taa["digest"] = json!(hex::encode(_calculate_taa_digest("other text", VERSION).unwrap()));
+
+            let result = _taa_reply(&operation, &format!("2:v:{}", VERSION), &_digest(), &taa, &sign_keys);
+            assert!(!_check_reply(&operation, &result, &nodes));
+        }
+
+        #[test]
+        fn single_node_reply_is_not_verified_for_other_pool_keys() {
+            let operation = json!({"type": constants::GET_TXN_AUTHR_AGRMT});
+
+            let result = _taa_reply(&operation, "2:latest", &_digest(), &_taa(), &_sign_keys());
+            assert!(!_check_reply(&operation, &result, &_nodes(&_sign_keys())));
+        }
+    }
+
+    mod rich_schema_object {
+        use super::*;
+
+        const ID: &str = "did:sov:8a9Bpb6Vs8r7W4oBSZ4aeGRAvqBNE3MRpbFaNDYXv2Ps";
+
+        fn _key(operation: SJsonValue) -> Option<String> {
+            parse_key_from_request_for_builtin_sp(&json!({"operation": operation}))
+                .map(|key| String::from_utf8(key).unwrap())
+        }
+
+        fn _object() -> SJsonValue {
+            json!({
+                "id": ID,
+                "content": "{\"@id\":\"did:sov:8a9Bpb6Vs8r7W4oBSZ4aeGRAvqBNE3MRpbFaNDYXv2Ps\",\"@type\":\"rdfs:Class\"}",
+                "rsName": "SimpleRichSchema",
+                "rsVersion": "1.0",
+                "rsType": "sch",
+                "ver": "1",
+                "from": "V4SGRU86Z58d6TV7PBUe6f"
+            })
+        }
+
+        #[test]
+        fn parse_key_works_for_rich_schema_object_requests() {
+            assert_eq!(Some(ID.to_string()), _key(json!({"type": constants::GET_RICH_SCHEMA_OBJECT_BY_ID, "id": ID})));
+            assert_eq!(Some("sch:SimpleRichSchema:1.0".to_string()),
+                       _key(json!({"type": constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA, "rsType": "sch", "rsName": "SimpleRichSchema", "rsVersion": "1.0"})));
+            assert_eq!(None, _key(json!({"type": constants::GET_RICH_SCHEMA_OBJECT_BY_ID})));
+            assert_eq!(None, _key(json!({"type": constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA, "rsType": "sch", "rsName": "SimpleRichSchema"})));
+        }
+
+        #[test]
+        fn key_by_metadata_works_for_found_object() {
+            let key = _rich_schema_object_key_by_metadata(&_object(), b"sch:SimpleRichSchema:1.0").unwrap();
+            assert_eq!(ID.as_bytes().to_vec(), key);
+        }
+
+        #[test]
+        fn key_by_metadata_works_for_object_with_other_metadata() {
+            assert!(_rich_schema_object_key_by_metadata(&_object(), b"sch:SimpleRichSchema:2.0").is_err());
+            assert!(_rich_schema_object_key_by_metadata(&_object(), b"ctx:SimpleRichSchema:1.0").is_err());
+            assert!(_rich_schema_object_key_by_metadata(&json!({"id": ID}), b"sch:SimpleRichSchema:1.0").is_err());
+        }
+
+        #[test]
+        fn parse_value_works_for_rich_schema_object() {
+            let object = _object();
+            let json_msg = json!({"seqNo": 10, "txnTime": 1000, "data": object});
+
+            let value = _parse_reply_for_proof_value(&json_msg, Some(&object.to_string()), &object, constants::GET_RICH_SCHEMA_OBJECT_BY_ID, ID.as_bytes()).unwrap().unwrap();
+            let value: SJsonValue = serde_json::from_str(&value).unwrap();
+
+            assert_eq!(json!({"lsn": 10, "lut": 1000, "val": object}), value);
+        }
+
+        #[test]
+        fn parse_reply_works_for_object_with_other_metadata() {
+            let json_msg = json!({
+                "type": constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+                "seqNo": 10,
+                "txnTime": 1000,
+                "data": _object(),
+                "state_proof": {"proof_nodes": "", "root_hash": "", "multi_signature": {}}
+            });
+
+            assert!(_parse_reply_for_builtin_sp(&json_msg, constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA, b"sch:SimpleRichSchema:2.0").is_none());
+        }
+    }
+
+    mod verify_saved_reply {
+        use crate::services::ledger::merkletree::merkletree::MerkleTree;
+
+        use super::*;
+
+        const LEDGER_SIZE: u64 = 5;
+        const SEQ_NO: u64 = 3;
+        const TIMESTAMP: u64 = 1_500_000_000;
+
+        fn _txn(seq_no: u64) -> SJsonValue {
+            json!({"txn": {"type": "1", "data": {"dest": format!("dest{}", seq_no)}}, "txnMetadata": {"seqNo": seq_no}, "ver": "1"})
+        }
+
+        fn _get_txn_reply(sign_keys: &[(String, SignKey)]) -> SJsonValue {
+            let txns = (1..=LEDGER_SIZE).map(_txn).collect::<Vec<SJsonValue>>();
+            let mt = MerkleTree::from_vec(txns.iter().map(|txn| rmp_serde::to_vec(txn).unwrap()).collect()).unwrap();
+            let root_hash = mt.root_hash().to_base58();
+
+            let mut data = txns[SEQ_NO as usize - 1].clone();
+            data["auditPath"] = json!(mt.gen_audit_path(SEQ_NO as usize - 1).unwrap().iter().map(|hash| hash.to_base58()).collect::<Vec<String>>());
+            data["rootHash"] = json!(root_hash);
+            data["ledgerSize"] = json!(LEDGER_SIZE);
+
+            let value = json!({"ledger_id": 1, "pool_state_root_hash": "", "state_root_hash": "", "timestamp": TIMESTAMP, "txn_root_hash": root_hash});
+
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_TXN,
+                    "seqNo": SEQ_NO,
+                    "data": data,
+                    "state_proof": {
+                        "multi_signature": _multi_signature(sign_keys, value)
+                    }
+                }
+            })
+        }
+
+        #[test]
+        fn verify_saved_reply_works_for_get_txn() {
+            let sign_keys = _sign_keys();
+            let reply = _get_txn_reply(&sign_keys);
+
+            assert!(verify_saved_reply(&reply.to_string(), &_nodes(&sign_keys), 1, &_gen()).unwrap());
+        }
+
+        #[test]
+        fn verify_saved_reply_works_for_changed_txn() {
+            let sign_keys = _sign_keys();
+            let mut reply = _get_txn_reply(&sign_keys);
+            reply["result"]["data"]["txn"]["data"]["dest"] = json!("other");
+
+            assert!(!verify_saved_reply(&reply.to_string(), &_nodes(&sign_keys), 1, &_gen()).unwrap());
+        }
+
+        #[test]
+        fn verify_saved_reply_works_for_other_pool_keys() {
+            let sign_keys = _sign_keys();
+            let reply = _get_txn_reply(&sign_keys);
+
+            assert!(!verify_saved_reply(&reply.to_string(), &_nodes(&_sign_keys()), 1, &_gen()).unwrap());
+        }
+
+        #[test]
+        fn verify_saved_reply_works_for_not_enough_participants() {
+            let sign_keys = _sign_keys();
+            let reply = _get_txn_reply(&sign_keys[0..2]);
+
+            assert!(!verify_saved_reply(&reply.to_string(), &_nodes(&sign_keys), 1, &_gen()).unwrap());
+        }
+
+        #[test]
+        fn verify_saved_reply_works_for_reply_without_state_proof() {
+            let reply = json!({"op": "REPLY", "result": {"type": constants::GET_NYM, "dest": "dest", "seqNo": 1, "data": null}});
+
+            assert!(!verify_saved_reply(&reply.to_string(), &_nodes(&_sign_keys()), 1, &_gen()).unwrap());
+        }
+
+        #[test]
+        fn verify_saved_reply_works_for_not_reply() {
+            let reply = json!({"op": "REQNACK", "reason": "reason"});
+
+            let res = verify_saved_reply(&reply.to_string(), &_nodes(&_sign_keys()), 1, &_gen());
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
 }
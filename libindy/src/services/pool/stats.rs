@@ -0,0 +1,191 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json;
+use time::Tm;
+
+use crate::services::pool::events::{PoolEvent, RequestEvent};
+
+use super::time::Duration;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PoolStatus {
+    pub state: String,
+    pub nodes: Vec<NodeStatus>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct NodeStatus {
+    pub alias: String,
+    pub last_seen: Option<i64>,
+    pub avg_latency_ms: Option<i64>,
+    pub replies: u64,
+    pub nacks: u64,
+    pub timeouts: u64,
+    pub invalid_replies: u64,
+    pub ledger_size: Option<usize>,
+    #[serde(skip)]
+    latency_sum_ms: i64,
+    #[serde(skip)]
+    latency_cnt: i64,
+}
+
+impl NodeStatus {
+    fn new(alias: &str) -> NodeStatus {
+        NodeStatus {
+            alias: alias.to_string(),
+            ..NodeStatus::default()
+        }
+    }
+
+    fn add_latency(&mut self, latency: Duration) {
+        self.latency_sum_ms += latency.num_milliseconds();
+        self.latency_cnt += 1;
+        self.avg_latency_ms = Some(self.latency_sum_ms / self.latency_cnt);
+    }
+}
+
+/// Collects per node statistics from the events processed by the pool worker thread.
+///
+/// Latency of a node is measured from the moment the request was sent to the pool
+/// (or resent after a timeout of another node) to the moment the node replied to it.
+pub struct PoolStats {
+    nodes: BTreeMap<String, NodeStatus>,
+    requests: HashMap<String, Tm>,
+    request_ttl: Duration,
+}
+
+impl PoolStats {
+    pub fn new(timeout: i64, extended_timeout: i64) -> PoolStats {
+        PoolStats {
+            nodes: BTreeMap::new(),
+            requests: HashMap::new(),
+            request_ttl: Duration::seconds(timeout + extended_timeout),
+        }
+    }
+
+    pub fn process_event(&mut self, pe: &PoolEvent) {
+        match *pe {
+            PoolEvent::SendRequest(_, ref msg, _, _, _) => {
+                let now = time::now();
+                let request_ttl = self.request_ttl;
+                self.requests.retain(|_, sent| now - *sent < request_ttl);
+
+                if let Some(req_id) = _parse_req_id(msg) {
+                    self.requests.insert(req_id, now);
+                }
+            }
+            PoolEvent::NodeReply(_, ref node_alias) => {
+                let now = time::now();
+                let re: Option<RequestEvent> = pe.clone().into();
+                let requests = &self.requests;
+                let node = self.nodes.entry(node_alias.to_string()).or_insert_with(|| NodeStatus::new(node_alias));
+
+                node.last_seen = Some(now.to_timespec().sec);
+
+                match re {
+                    Some(RequestEvent::LedgerStatus(ls, _, _)) => node.ledger_size = Some(ls.txnSeqNo),
+                    Some(RequestEvent::ConsistencyProof(cp, _)) => node.ledger_size = Some(cp.seqNoEnd),
+                    Some(RequestEvent::Reply(_, _, _, req_id)) => {
+                        node.replies += 1;
+                        if let Some(sent) = requests.get(&req_id) {
+                            node.add_latency(now - *sent);
+                        }
+                    }
+                    Some(RequestEvent::ReqNACK(_, _, _, req_id)) | Some(RequestEvent::Reject(_, _, _, req_id)) => {
+                        node.nacks += 1;
+                        if let Some(sent) = requests.get(&req_id) {
+                            node.add_latency(now - *sent);
+                        }
+                    }
+                    Some(_) => {}
+                    None => node.invalid_replies += 1,
+                }
+            }
+            PoolEvent::Timeout(ref req_id, ref node_alias) if !node_alias.is_empty() => {
+                self.nodes.entry(node_alias.to_string()).or_insert_with(|| NodeStatus::new(node_alias)).timeouts += 1;
+
+                // the request is resent to the next node right after the timeout
+                if let Some(sent) = self.requests.get_mut(req_id) {
+                    *sent = time::now();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn get_status(&self, state: &str) -> PoolStatus {
+        PoolStatus {
+            state: state.to_string(),
+            nodes: self.nodes.values().cloned().collect(),
+        }
+    }
+}
+
+fn _parse_req_id(msg: &str) -> Option<String> {
+    serde_json::from_str::<serde_json::Value>(msg).ok()
+        .and_then(|req| req["reqId"].as_u64())
+        .map(|req_id| req_id.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NODE: &str = "n1";
+
+    fn _send_request(stats: &mut PoolStats) {
+        stats.process_event(&PoolEvent::SendRequest(1, r#"{"reqId":1,"operation":{"type":"105"}}"#.to_string(), None, None, None));
+    }
+
+    #[test]
+    fn pool_stats_process_reply_works() {
+        let mut stats = PoolStats::new(20, 60);
+        _send_request(&mut stats);
+        stats.process_event(&PoolEvent::NodeReply(r#"{"op":"REPLY","result":{"reqId":1}}"#.to_string(), NODE.to_string()));
+
+        let status = stats.get_status("active");
+        assert_eq!("active", status.state);
+        assert_eq!(1, status.nodes.len());
+        assert_eq!(NODE, status.nodes[0].alias);
+        assert_eq!(1, status.nodes[0].replies);
+        assert!(status.nodes[0].last_seen.is_some());
+        assert!(status.nodes[0].avg_latency_ms.is_some());
+    }
+
+    #[test]
+    fn pool_stats_process_nack_and_invalid_reply_works() {
+        let mut stats = PoolStats::new(20, 60);
+        _send_request(&mut stats);
+        stats.process_event(&PoolEvent::NodeReply(r#"{"op":"REQNACK","reqId":1,"reason":"reason"}"#.to_string(), NODE.to_string()));
+        stats.process_event(&PoolEvent::NodeReply("not a json".to_string(), NODE.to_string()));
+
+        let node = &stats.get_status("active").nodes[0];
+        assert_eq!(0, node.replies);
+        assert_eq!(1, node.nacks);
+        assert_eq!(1, node.invalid_replies);
+    }
+
+    #[test]
+    fn pool_stats_process_ledger_status_works() {
+        let mut stats = PoolStats::new(20, 60);
+        stats.process_event(&PoolEvent::NodeReply(
+            r#"{"op":"LEDGER_STATUS","txnSeqNo":4,"merkleRoot":"root","ledgerId":0,"ppSeqNo":null,"viewNo":null}"#.to_string(), NODE.to_string()));
+
+        let node = &stats.get_status("active").nodes[0];
+        assert_eq!(Some(4), node.ledger_size);
+        assert_eq!(None, node.avg_latency_ms);
+    }
+
+    #[test]
+    fn pool_stats_process_timeout_works() {
+        let mut stats = PoolStats::new(20, 60);
+        _send_request(&mut stats);
+        stats.process_event(&PoolEvent::Timeout("1".to_string(), NODE.to_string()));
+        stats.process_event(&PoolEvent::Timeout("".to_string(), "".to_string()));
+
+        let status = stats.get_status("active");
+        assert_eq!(1, status.nodes.len());
+        assert_eq!(1, status.nodes[0].timeouts);
+        assert_eq!(None, status.nodes[0].last_seen);
+    }
+}
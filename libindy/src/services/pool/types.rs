@@ -525,12 +525,31 @@ impl PartialEq for HashableValue {
 }
 
 
+/// Request which is resent to all nodes as is if consensus wasn't reached because of timeouts.
+/// Nodes reply with the stored result to the request which is already ordered, so resending is idempotent.
 #[derive(Debug, PartialEq, Eq)]
 pub struct ResendableRequest {
     pub request: String,
-    pub start_node: usize,
-    pub next_node: usize,
-    pub next_try_send_time: Option<time::Tm>,
+    pub retries_left: usize,
+}
+
+impl ResendableRequest {
+    pub fn new(request: String, retries: usize) -> Option<ResendableRequest> {
+        if retries > 0 {
+            Some(ResendableRequest { request, retries_left: retries })
+        } else {
+            None
+        }
+    }
+
+    pub fn next_attempt(&mut self) -> Option<String> {
+        if self.retries_left > 0 {
+            self.retries_left -= 1;
+            Some(self.request.clone())
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
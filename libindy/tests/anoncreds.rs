@@ -3344,6 +3344,147 @@ mod high_cases {
         assert!(!valid);
     }
 
+    mod verifier_verify_proof_verbose {
+        use super::*;
+
+        #[test]
+        fn verifier_verify_proof_verbose_works_for_correct_proof() {
+            let report = anoncreds::verifier_verify_proof_verbose(&anoncreds::proof_request_attr(),
+                                                                  &anoncreds::proof_json(),
+                                                                  &anoncreds::schemas_for_proof(),
+                                                                  &anoncreds::cred_defs_for_proof(),
+                                                                  "{}",
+                                                                  "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(json!(true), report["valid"]);
+            assert_eq!(serde_json::Value::Null, report["error"]);
+            assert_eq!(json!({"status": "passed"}), report["proof"]);
+
+            let attr_report = &report["requested_attributes"]["attr1_referent"];
+            assert_eq!(json!(0), attr_report["sub_proof_index"]);
+            assert_eq!(json!(false), attr_report["self_attested"]);
+            assert_eq!(json!([]), attr_report["restrictions"]);
+            assert_eq!(json!({"status": "passed"}), attr_report["encoding"]);
+            assert_eq!(json!({"status": "skipped"}), attr_report["non_revoked"]);
+        }
+
+        #[test]
+        fn verifier_verify_proof_verbose_works_for_wrong_encoded() {
+            let proof_json = anoncreds::proof_json().replace(r#"encoded":"1139481716457488690172217916278103335"#, r#"encoded":"1111111111111111111111111111111111111"#);
+
+            let report = anoncreds::verifier_verify_proof_verbose(&anoncreds::proof_request_attr(),
+                                                                  &proof_json,
+                                                                  &anoncreds::schemas_for_proof(),
+                                                                  &anoncreds::cred_defs_for_proof(),
+                                                                  "{}",
+                                                                  "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(json!(false), report["valid"]);
+            assert!(report["error"].as_str().unwrap().contains("Encoded Values for \"name\" are different"));
+
+            let encoding = &report["requested_attributes"]["attr1_referent"]["encoding"];
+            assert_eq!(json!("failed"), encoding["status"]);
+            assert!(encoding["reason"].as_str().unwrap().contains("Encoded Values for \"name\" are different"));
+        }
+
+        #[test]
+        fn verifier_verify_proof_verbose_works_for_restriction_alternatives() {
+            let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": {
+                       "attr1_referent": {
+                           "name":"name",
+                           "restrictions": [{ "issuer_did": "NO DID" }, { "issuer_did": ISSUER_DID }]
+                       }
+                   },
+                   "requested_predicates": {},
+                }).to_string();
+
+            let report = anoncreds::verifier_verify_proof_verbose(&proof_req,
+                                                                  &anoncreds::proof_json(),
+                                                                  &anoncreds::schemas_for_proof(),
+                                                                  &anoncreds::cred_defs_for_proof(),
+                                                                  "{}",
+                                                                  "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(json!(true), report["valid"]);
+
+            let restrictions = report["requested_attributes"]["attr1_referent"]["restrictions"].as_array().unwrap();
+            assert_eq!(2, restrictions.len());
+
+            assert_eq!(json!({"issuer_did": "NO DID"}), restrictions[0]["restriction"]);
+            assert_eq!(json!("failed"), restrictions[0]["result"]["status"]);
+            assert!(restrictions[0]["result"]["reason"].as_str().unwrap().contains("\"issuer_did\" values are different"));
+
+            assert_eq!(json!({"issuer_did": ISSUER_DID}), restrictions[1]["restriction"]);
+            assert_eq!(json!({"status": "passed"}), restrictions[1]["result"]);
+        }
+
+        #[test]
+        fn verifier_verify_proof_verbose_works_for_missed_predicate() {
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": {
+                   "attr1_referent": { "name":"name" }
+               },
+               "requested_predicates": {
+                    "predicate1_referent": { "name":"age", "p_type":">=", "p_value":18 }
+               },
+            }).to_string();
+
+            let report = anoncreds::verifier_verify_proof_verbose(&proof_req,
+                                                                  &anoncreds::proof_json(),
+                                                                  &anoncreds::schemas_for_proof(),
+                                                                  &anoncreds::cred_defs_for_proof(),
+                                                                  "{}",
+                                                                  "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(json!(false), report["valid"]);
+            assert!(report["error"].as_str().unwrap().contains("do not correspond to received"));
+
+            let predicate_report = &report["requested_predicates"]["predicate1_referent"];
+            assert_eq!(serde_json::Value::Null, predicate_report["sub_proof_index"]);
+            assert_eq!(json!([]), predicate_report["restrictions"]);
+            assert_eq!(json!("failed"), predicate_report["predicate"]["status"]);
+        }
+
+        #[test]
+        fn verifier_verify_proof_verbose_works_for_missed_referent() {
+            let other_proof_req_json = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": {
+                   "attr1_referent": { "name":"name" },
+                   "attr2_referent": { "name":"sex" }
+               },
+               "requested_predicates": {},
+            }).to_string();
+
+            let report = anoncreds::verifier_verify_proof_verbose(&other_proof_req_json,
+                                                                  &anoncreds::proof_json(),
+                                                                  &anoncreds::schemas_for_proof(),
+                                                                  &anoncreds::cred_defs_for_proof(),
+                                                                  "{}",
+                                                                  "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(json!(false), report["valid"]);
+            assert!(report["error"].as_str().unwrap().contains("do not correspond to received"));
+            assert_eq!(json!({"status": "passed"}), report["requested_attributes"]["attr1_referent"]["encoding"]);
+            assert_eq!(json!("failed"), report["requested_attributes"]["attr2_referent"]["encoding"]["status"]);
+            assert_eq!(serde_json::Value::Null, report["requested_attributes"]["attr2_referent"]["sub_proof_index"]);
+        }
+    }
+
     mod verifier_verify_proof_with_proof_req_restrictions {
         use super::*;
 
@@ -10,7 +10,7 @@ extern crate indyrs as api;
 mod demos {
     use super::*;
 
-    use crate::utils::{wallet, anoncreds};
+    use crate::utils::{wallet, anoncreds, pool, cache};
     use crate::utils::anoncreds::{COMMON_MASTER_SECRET, CREDENTIAL1_ID, CREDENTIAL2_ID, CREDENTIAL3_ID};
 
     use indy::ErrorCode;
@@ -864,6 +864,185 @@ mod demos {
         wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
     }
 
+    #[cfg(feature = "revocation_tests")]
+    #[cfg(feature = "in_process_pool")]
+    #[test]
+    fn anoncreds_works_for_revocation_proof_with_prover_create_revocation_states() {
+        let setup = Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_proof_with_prover_create_revocation_states").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_proof_with_prover_create_revocation_states").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (schema_id, schema_json,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuance Credential
+        let (_, revoc_reg_delta_json) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+        let revoc_reg_delta_json = revoc_reg_delta_json.unwrap();
+        let revoc_reg_delta: serde_json::Value = serde_json::from_str(&revoc_reg_delta_json).unwrap();
+
+        //6. Ledger contains Revocation Registry Definition and Entry written at 95
+        let timestamp = 95;
+        let pool_handle = pool::create_and_open_in_process_pool_ledger(&setup.name, json!({
+            "replies": {
+                "115": {
+                    "type": "115",
+                    "id": rev_reg_id,
+                    "seqNo": 5,
+                    "txnTime": 90,
+                    "data": serde_json::from_str::<serde_json::Value>(&revoc_reg_def_json).unwrap()
+                },
+                "117": {
+                    "type": "117",
+                    "revocRegDefId": rev_reg_id,
+                    "seqNo": 6,
+                    "txnTime": timestamp,
+                    "data": {
+                        "revocDefType": "CL_ACCUM",
+                        "revocRegDefId": rev_reg_id,
+                        "value": {
+                            "accum_to": {"value": {"accum": revoc_reg_delta["value"]["accum"]}, "txnTime": timestamp},
+                            "issued": revoc_reg_delta["value"]["issued"],
+                            "revoked": []
+                        }
+                    }
+                }
+            }
+        })).unwrap();
+
+        //7. Prover gets Credentials for Proof Request
+        let proof_request = json!({
+           "nonce":"123432421212",
+           "name":"proof_req_1",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({
+                   "name":"name",
+                   "non_revoked": json!({ "to":100 })
+               })
+           }),
+           "requested_predicates": json!({
+               "predicate1_referent": json!({ "name":"age", "p_type":">=", "p_value":18 })
+           }),
+           "non_revoked": json!({ "from":80, "to":100 })
+        }).to_string();
+
+        let credentials_json = anoncreds::prover_get_credentials_for_proof_req(prover_wallet_handle, &proof_request).unwrap();
+        let credential = anoncreds::get_credential_for_attr_referent(&credentials_json, "attr1_referent");
+
+        //8. Prover creates RevocationStates for the Proof without timestamps set
+        let requested_credentials_json = json!({
+             "self_attested_attributes": json!({}),
+             "requested_attributes": json!({
+                "attr1_referent": json!({ "cred_id": credential.referent, "revealed":true })
+             }),
+             "requested_predicates": json!({
+                "predicate1_referent": json!({ "cred_id": credential.referent })
+             })
+        }).to_string();
+
+        let (rev_states_json, requested_credentials_json) = anoncreds::prover_create_revocation_states(prover_wallet_handle,
+                                                                                                      pool_handle,
+                                                                                                      None,
+                                                                                                      blob_storage_reader_handle,
+                                                                                                      &proof_request,
+                                                                                                      &requested_credentials_json).unwrap();
+
+        let requested_credentials: serde_json::Value = serde_json::from_str(&requested_credentials_json).unwrap();
+        assert_eq!(json!(timestamp), requested_credentials["requested_attributes"]["attr1_referent"]["timestamp"]);
+        assert_eq!(json!(timestamp), requested_credentials["requested_predicates"]["predicate1_referent"]["timestamp"]);
+
+        let rev_states: serde_json::Value = serde_json::from_str(&rev_states_json).unwrap();
+        assert_eq!(1, rev_states[&rev_reg_id].as_object().unwrap().len());
+        assert_eq!(json!(timestamp), rev_states[&rev_reg_id][timestamp.to_string()]["timestamp"]);
+
+        //9. Prover reuses the cached RevocationState
+        let (cached_rev_states_json, _) = anoncreds::prover_create_revocation_states(prover_wallet_handle,
+                                                                                     pool_handle,
+                                                                                     None,
+                                                                                     blob_storage_reader_handle,
+                                                                                     &proof_request,
+                                                                                     &requested_credentials_json).unwrap();
+        let cached_rev_states: serde_json::Value = serde_json::from_str(&cached_rev_states_json).unwrap();
+        assert_eq!(rev_states, cached_rev_states);
+
+        //10. Prover purges the cache and builds the same RevocationState again
+        cache::purge_rev_state_cache(prover_wallet_handle, "{}").unwrap();
+
+        let (rebuilt_rev_states_json, _) = anoncreds::prover_create_revocation_states(prover_wallet_handle,
+                                                                                      pool_handle,
+                                                                                      None,
+                                                                                      blob_storage_reader_handle,
+                                                                                      &proof_request,
+                                                                                      &requested_credentials_json).unwrap();
+        let rebuilt_rev_states: serde_json::Value = serde_json::from_str(&rebuilt_rev_states_json).unwrap();
+        assert_eq!(rev_states, rebuilt_rev_states);
+
+        //11. Prover creates Proof
+        let schemas_json = json!({
+            schema_id.clone(): serde_json::from_str::<Schema>(&schema_json).unwrap()
+        }).to_string();
+
+        let credential_defs_json = json!({
+            cred_def_id.clone(): serde_json::from_str::<CredentialDefinition>(&cred_def_json).unwrap()
+        }).to_string();
+
+        let proof_json = anoncreds::prover_create_proof(prover_wallet_handle,
+                                                        &proof_request,
+                                                        &requested_credentials_json,
+                                                        COMMON_MASTER_SECRET,
+                                                        &schemas_json,
+                                                        &credential_defs_json,
+                                                        &rev_states_json).unwrap();
+
+        //12. Verifier verifies proof
+        let rev_reg_defs_json = json!({
+            rev_reg_id.clone(): serde_json::from_str::<RevocationRegistryDefinition>(&revoc_reg_def_json).unwrap()
+        }).to_string();
+
+        let rev_regs_json = json!({
+            rev_reg_id.clone(): json!({
+                timestamp.to_string(): serde_json::from_str::<RevocationRegistry>(&revoc_reg_delta_json).unwrap()
+            })
+        }).to_string();
+
+        let valid = anoncreds::verifier_verify_proof(&proof_request,
+                                                     &proof_json,
+                                                     &schemas_json,
+                                                     &credential_defs_json,
+                                                     &rev_reg_defs_json,
+                                                     &rev_regs_json).unwrap();
+        assert!(valid);
+
+        pool::close(pool_handle).unwrap();
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
     #[cfg(feature = "revocation_tests")]
     #[test]
     fn anoncreds_works_for_revocation_proof_issuance_by_default() {
@@ -2326,6 +2505,404 @@ mod demos {
         wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
     }
 
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_issuer_build_revoc_reg_entry_request() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_issuer_build_revoc_reg_entry_request").unwrap();
+
+        //2 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            _, _,
+            rev_reg_id, _, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_BY_DEFAULT"}"#);
+
+        //3. Issuer revokes several Credentials at once
+        let (request, rev_reg_delta_json) = anoncreds::issuer_build_revoc_reg_entry_request(issuer_wallet_handle, blob_storage_reader_handle,
+                                                                                            ISSUER_DID, &rev_reg_id, "[1, 2, 3]", "[]").unwrap();
+        let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+        assert_eq!("114", request["operation"]["type"].as_str().unwrap());
+        assert_eq!(rev_reg_id, request["operation"]["revocRegDefId"].as_str().unwrap());
+        let mut revoked: Vec<u32> = serde_json::from_value(request["operation"]["value"]["value"]["revoked"].clone()).unwrap();
+        revoked.sort();
+        assert_eq!(vec![1, 2, 3], revoked);
+
+        let rev_reg_delta: serde_json::Value = serde_json::from_str(&rev_reg_delta_json).unwrap();
+        assert_eq!(request["operation"]["value"], rev_reg_delta);
+
+        //4. Issuer revokes and unrevokes Credentials in one request
+        let (request, _) = anoncreds::issuer_build_revoc_reg_entry_request(issuer_wallet_handle, blob_storage_reader_handle,
+                                                                           ISSUER_DID, &rev_reg_id, "[4]", "[2]").unwrap();
+        let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+        assert_eq!(json!([4]), request["operation"]["value"]["value"]["revoked"]);
+        assert_eq!(json!([2]), request["operation"]["value"]["value"]["issued"]);
+        assert_eq!(rev_reg_delta["value"]["accum"], request["operation"]["value"]["value"]["prevAccum"]);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_issuer_create_credential_with_rollover() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_issuer_create_credential_with_rollover").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_issuer_create_credential_with_rollover").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, _, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":2, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        let tails_writer_handle = utils::blob_storage::open_writer("default", &anoncreds::tails_writer_config()).unwrap();
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        let issue_credential = || {
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(issuer_wallet_handle, &cred_def_id).unwrap();
+
+            let (cred_req_json, _) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                             DID_MY1,
+                                                                             &cred_offer_json,
+                                                                             &cred_def_json,
+                                                                             COMMON_MASTER_SECRET).unwrap();
+
+            let (cred_json, issuance_json) = anoncreds::issuer_create_credential_with_rollover(issuer_wallet_handle,
+                                                                                               &cred_offer_json,
+                                                                                               &cred_req_json,
+                                                                                               &anoncreds::gvt_credential_values_json(),
+                                                                                               &rev_reg_id,
+                                                                                               blob_storage_reader_handle,
+                                                                                               tails_writer_handle,
+                                                                                               r#"{"threshold":1.0, "max_cred_num":3}"#).unwrap();
+
+            let cred: serde_json::Value = serde_json::from_str(&cred_json).unwrap();
+            let issuance: serde_json::Value = serde_json::from_str(&issuance_json).unwrap();
+            assert_eq!(cred["rev_reg_id"], issuance["rev_reg_id"]);
+            issuance
+        };
+
+        //5. Issuer issues the first Credential from the Registry below the threshold
+        let issuance = issue_credential();
+        assert_eq!(json!(rev_reg_id), issuance["rev_reg_id"]);
+        assert_eq!(json!("1"), issuance["cred_rev_id"]);
+        assert_eq!(json!([1]), issuance["rev_reg_delta"]["value"]["issued"]);
+        assert_eq!(serde_json::Value::Null, issuance["rollover"]);
+
+        //6. Issuer issues the second Credential filling up the Registry and gets the next Registry to publish
+        let issuance = issue_credential();
+        assert_eq!(json!(rev_reg_id), issuance["rev_reg_id"]);
+        assert_eq!(json!("2"), issuance["cred_rev_id"]);
+
+        let rollover = &issuance["rollover"];
+        let next_rev_reg_id = rollover["rev_reg_id"].as_str().unwrap().to_string();
+        assert_eq!(format!("{}-1", rev_reg_id), next_rev_reg_id);
+
+        assert_eq!(json!("113"), rollover["rev_reg_def_request"]["operation"]["type"]);
+        assert_eq!(json!(next_rev_reg_id), rollover["rev_reg_def_request"]["operation"]["id"]);
+        assert_eq!(json!(3), rollover["rev_reg_def_request"]["operation"]["value"]["maxCredNum"]);
+        assert_eq!(json!(ISSUER_DID), rollover["rev_reg_def_request"]["identifier"]);
+
+        assert_eq!(json!("114"), rollover["rev_reg_entry_request"]["operation"]["type"]);
+        assert_eq!(json!(next_rev_reg_id), rollover["rev_reg_entry_request"]["operation"]["revocRegDefId"]);
+
+        //7. Issuer issues the third Credential from the next Registry using the id of the original one
+        let issuance = issue_credential();
+        assert_eq!(json!(next_rev_reg_id), issuance["rev_reg_id"]);
+        assert_eq!(json!("1"), issuance["cred_rev_id"]);
+        assert_eq!(serde_json::Value::Null, issuance["rollover"]);
+
+        //8. Issuer revokes the Credential issued from the next Registry
+        anoncreds::issuer_revoke_credential(issuer_wallet_handle, blob_storage_reader_handle, &next_rev_reg_id, "1").unwrap();
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_issuer_create_credential_with_rollover_after_failed_rollover() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_issuer_create_credential_with_rollover_after_failed_rollover").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_issuer_create_credential_with_rollover_after_failed_rollover").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, _, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":2, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        let tails_writer_handle = utils::blob_storage::open_writer("default", &anoncreds::tails_writer_config()).unwrap();
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        let issue_credential = |tails_writer_handle: i32| {
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(issuer_wallet_handle, &cred_def_id).unwrap();
+
+            let (cred_req_json, _) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                             DID_MY1,
+                                                                             &cred_offer_json,
+                                                                             &cred_def_json,
+                                                                             COMMON_MASTER_SECRET).unwrap();
+
+            anoncreds::issuer_create_credential_with_rollover(issuer_wallet_handle,
+                                                              &cred_offer_json,
+                                                              &cred_req_json,
+                                                              &anoncreds::gvt_credential_values_json(),
+                                                              &rev_reg_id,
+                                                              blob_storage_reader_handle,
+                                                              tails_writer_handle,
+                                                              r#"{"threshold":1.0}"#)
+                .map(|(_, issuance_json)| serde_json::from_str::<serde_json::Value>(&issuance_json).unwrap())
+        };
+
+        //5. Issuer issues the first Credential from the Registry below the threshold
+        let issuance = issue_credential(tails_writer_handle).unwrap();
+        assert_eq!(json!("1"), issuance["cred_rev_id"]);
+
+        //6. Issuer fails to issue the second Credential as the tails of the next Registry can't be written
+        let res = issue_credential(tails_writer_handle + 100);
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        //7. Issuer retries and gets the Credential with the not burned index along with the next Registry to publish
+        let issuance = issue_credential(tails_writer_handle).unwrap();
+        assert_eq!(json!(rev_reg_id), issuance["rev_reg_id"]);
+        assert_eq!(json!("2"), issuance["cred_rev_id"]);
+        assert_eq!(json!(format!("{}-1", rev_reg_id)), issuance["rollover"]["rev_reg_id"]);
+        assert_eq!(json!("113"), issuance["rollover"]["rev_reg_def_request"]["operation"]["type"]);
+
+        //8. Issuer issues the third Credential from the next Registry
+        let issuance = issue_credential(tails_writer_handle).unwrap();
+        assert_eq!(json!(format!("{}-1", rev_reg_id)), issuance["rev_reg_id"]);
+        assert_eq!(json!("1"), issuance["cred_rev_id"]);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_issuer_issued_credentials_registry() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_issuer_issued_credentials_registry").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_issuer_issued_credentials_registry").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuer issues two Credentials
+        let (cred_rev_id_1, _) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+
+        let (cred_rev_id_2, _) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL2_ID,
+            &anoncreds::gvt2_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+
+        //6. Issuer tags the Credentials by their holders
+        anoncreds::issuer_set_issued_credential_tags(issuer_wallet_handle, &rev_reg_id, &cred_rev_id_1, r#"{"holder":"alice"}"#).unwrap();
+        anoncreds::issuer_set_issued_credential_tags(issuer_wallet_handle, &rev_reg_id, &cred_rev_id_2, r#"{"holder":"bob"}"#).unwrap();
+
+        //7. Issuer gets all issued Credentials by batches
+        let (search_handle, total_count) = anoncreds::issuer_search_issued_credentials(issuer_wallet_handle, None).unwrap();
+        assert_eq!(2, total_count);
+
+        for _ in 0..2 {
+            let issued_credentials = anoncreds::issuer_fetch_issued_credentials(search_handle, 1).unwrap();
+            let issued_credentials: Vec<serde_json::Value> = serde_json::from_str(&issued_credentials).unwrap();
+            assert_eq!(1, issued_credentials.len());
+        }
+
+        assert_eq!("[]", anoncreds::issuer_fetch_issued_credentials(search_handle, 1).unwrap());
+        anoncreds::issuer_close_issued_credentials_search(search_handle).unwrap();
+
+        let issued_credentials = anoncreds::issuer_fetch_all_issued_credentials(issuer_wallet_handle, Some(r#"{"~issuance_time":{"$gt":"0"}}"#)).unwrap();
+        let issued_credentials: Vec<serde_json::Value> = serde_json::from_str(&issued_credentials).unwrap();
+        assert_eq!(2, issued_credentials.len());
+
+        //8. Issuer revokes the Credential of the holder found by tag
+        let issued_credentials = anoncreds::issuer_fetch_all_issued_credentials(issuer_wallet_handle, Some(r#"{"holder":"bob"}"#)).unwrap();
+        let issued_credentials: Vec<serde_json::Value> = serde_json::from_str(&issued_credentials).unwrap();
+        assert_eq!(1, issued_credentials.len());
+
+        let issued_credential = &issued_credentials[0];
+        assert_eq!(json!(cred_def_id), issued_credential["cred_def_id"]);
+        assert_eq!(json!(rev_reg_id), issued_credential["rev_reg_id"]);
+        assert_eq!(json!(cred_rev_id_2), issued_credential["cred_rev_id"]);
+        assert_eq!(json!(false), issued_credential["revoked"]);
+        assert!(issued_credential["issuance_time"].as_u64().unwrap() > 0);
+        assert_eq!(json!({"holder": "bob"}), issued_credential["tags"]);
+
+        anoncreds::issuer_revoke_credential(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, issued_credential["cred_rev_id"].as_str().unwrap()).unwrap();
+
+        //9. Issuer gets revoked Credentials
+        let revoked_credentials = anoncreds::issuer_fetch_all_issued_credentials(issuer_wallet_handle, Some(r#"{"~revoked":"true"}"#)).unwrap();
+        let revoked_credentials: Vec<serde_json::Value> = serde_json::from_str(&revoked_credentials).unwrap();
+        assert_eq!(1, revoked_credentials.len());
+        assert_eq!(json!(cred_rev_id_2), revoked_credentials[0]["cred_rev_id"]);
+        assert_eq!(json!({"holder": "bob"}), revoked_credentials[0]["tags"]);
+
+        //10. Issuer unrevokes the Credential with REVOC_REG_ENTRY request
+        anoncreds::issuer_build_revoc_reg_entry_request(issuer_wallet_handle, blob_storage_reader_handle,
+                                                        ISSUER_DID, &rev_reg_id, "[]", &format!("[{}]", cred_rev_id_2)).unwrap();
+
+        let revoked_credentials = anoncreds::issuer_fetch_all_issued_credentials(issuer_wallet_handle, Some(r#"{"~revoked":"true"}"#)).unwrap();
+        assert_eq!("[]", revoked_credentials);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_issuer_set_issued_credential_tags_for_invalid_tags() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_issuer_set_issued_credential_tags_for_invalid_tags").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_issuer_set_issued_credential_tags_for_invalid_tags").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        //4. Issuer issues Credential
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        let (cred_rev_id, _) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+
+        //5. Issuer overrides reserved tag
+        let res = anoncreds::issuer_set_issued_credential_tags(issuer_wallet_handle, &rev_reg_id, &cred_rev_id, r#"{"revoked":"true"}"#);
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        //6. Issuer tags not issued Credential
+        let res = anoncreds::issuer_set_issued_credential_tags(issuer_wallet_handle, &rev_reg_id, "5", r#"{"holder":"alice"}"#);
+        assert_code!(ErrorCode::WalletItemNotFound, res);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_issuer_build_revoc_reg_entry_request_for_invalid_indices() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_issuer_build_revoc_reg_entry_request_for_invalid_indices").unwrap();
+
+        //2 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            _, _,
+            rev_reg_id, _, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_BY_DEFAULT"}"#);
+
+        //3. Issuer passes the same index to revoke and unrevoke
+        let res = anoncreds::issuer_build_revoc_reg_entry_request(issuer_wallet_handle, blob_storage_reader_handle,
+                                                                  ISSUER_DID, &rev_reg_id, "[1]", "[1]");
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        //4. Issuer passes no indices
+        let res = anoncreds::issuer_build_revoc_reg_entry_request(issuer_wallet_handle, blob_storage_reader_handle,
+                                                                  ISSUER_DID, &rev_reg_id, "[]", "[]");
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        //5. Issuer passes index out of the registry
+        let res = anoncreds::issuer_build_revoc_reg_entry_request(issuer_wallet_handle, blob_storage_reader_handle,
+                                                                  ISSUER_DID, &rev_reg_id, "[1, 10]", "[]");
+        assert_code!(ErrorCode::AnoncredsInvalidUserRevocId, res);
+
+        //6. Failed call doesn't change the registry so the first index can still be revoked
+        anoncreds::issuer_build_revoc_reg_entry_request(issuer_wallet_handle, blob_storage_reader_handle,
+                                                        ISSUER_DID, &rev_reg_id, "[1]", "[]").unwrap();
+
+        //7. Issuer unrevokes not revoked Credential
+        let res = anoncreds::issuer_build_revoc_reg_entry_request(issuer_wallet_handle, blob_storage_reader_handle,
+                                                                  ISSUER_DID, &rev_reg_id, "[]", "[2]");
+        assert_code!(ErrorCode::AnoncredsInvalidUserRevocId, res);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+    }
+
 
     #[test]
     fn anoncreds_works_for_multiple_requested_predicates_from_one_credential() {
@@ -426,4 +426,33 @@ mod high_cases {
             purge_cred_def_cache(setup.wallet_handle, &json!({"minFresh": 1000}).to_string()).unwrap();
         }
     }
+
+    mod rev_state_cache {
+        use super::*;
+
+        #[test]
+        fn indy_purge_rev_state_cache_no_options() {
+            let setup = Setup::wallet();
+            purge_rev_state_cache(setup.wallet_handle, "{}").unwrap();
+        }
+
+        #[test]
+        fn indy_purge_rev_state_cache_all_data() {
+            let setup = Setup::wallet();
+            purge_rev_state_cache(setup.wallet_handle, &json!({"maxAge": -1}).to_string()).unwrap();
+        }
+
+        #[test]
+        fn indy_purge_rev_state_cache_older_than_1000_seconds() {
+            let setup = Setup::wallet();
+            purge_rev_state_cache(setup.wallet_handle, &json!({"maxAge": 1000}).to_string()).unwrap();
+        }
+
+        #[test]
+        fn indy_purge_rev_state_cache_works_for_invalid_options() {
+            let setup = Setup::wallet();
+            let res = purge_rev_state_cache(setup.wallet_handle, &json!({"maxAge": "old"}).to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 }
\ No newline at end of file
@@ -169,6 +169,447 @@ mod high_cases {
         }
     }
 
+    #[cfg(feature = "in_process_pool")]
+    mod submit_write_request {
+        use super::*;
+
+        fn _open_in_process_pool(setup: &Setup, replies: serde_json::Value) -> PoolHandle {
+            pool::create_and_open_in_process_pool_ledger(&setup.name, json!({"replies": replies})).unwrap()
+        }
+
+        #[test]
+        fn indy_submit_write_request_works_for_committed() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, json!({
+                "1": {"txn": {"type": "1", "data": {"dest": DID_MY1}}, "txnMetadata": {"seqNo": 5}}
+            }));
+
+            let nym_request = ledger::build_nym_request(DID_TRUSTEE, DID_MY1, None, None, None).unwrap();
+            let outcome = ledger::submit_write_request(pool_handle, &nym_request, "{}").unwrap();
+            let outcome: serde_json::Value = serde_json::from_str(&outcome).unwrap();
+
+            assert_eq!("committed", outcome["status"].as_str().unwrap());
+            assert_eq!("REPLY", outcome["reply"]["op"].as_str().unwrap());
+            assert_eq!(5, outcome["reply"]["result"]["txnMetadata"]["seqNo"].as_u64().unwrap());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_submit_write_request_works_for_rejected() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, json!({}));
+
+            let nym_request = ledger::build_nym_request(DID_TRUSTEE, DID_MY1, None, None, None).unwrap();
+            let outcome = ledger::submit_write_request(pool_handle, &nym_request, r#"{"retries": 0}"#).unwrap();
+            let outcome: serde_json::Value = serde_json::from_str(&outcome).unwrap();
+
+            assert_eq!("rejected", outcome["status"].as_str().unwrap());
+            assert_eq!("REQNACK", outcome["reply"]["op"].as_str().unwrap());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        // Node1 and Node2 reply differently to the write and the other nodes are silent, so the write times out,
+        // while the follow-up reads are answered by Node1 and Node2 with the same replies
+        fn _open_in_process_pool_with_write_timeout(setup: &Setup, get_nym_seq_no: Option<u64>, written_req_id: u64, written_payload_digest: &str) -> PoolHandle {
+            pool::create_and_open_in_process_pool_ledger(&setup.name, json!({
+                "replies": {
+                    "105": {"type": "105", "dest": DID_MY1, "seqNo": get_nym_seq_no, "data": null}
+                },
+                "node_replies": {
+                    "Node1": {"1": {"txn": {"type": "1", "data": {"dest": DID_MY1}}, "txnMetadata": {"seqNo": 2}}},
+                    "Node2": {"1": {"txn": {"type": "1", "data": {"dest": DID_MY1}}, "txnMetadata": {"seqNo": 3}}}
+                },
+                "silent_nodes": ["Node3", "Node4"],
+                "txns": {
+                    "1": [
+                        {"txn": {"type": "1", "data": {"dest": DID_TRUSTEE}, "metadata": {"from": DID_TRUSTEE, "reqId": 1}}, "txnMetadata": {"seqNo": 1}, "ver": "1"},
+                        {"txn": {"type": "1", "data": {"dest": DID_MY1}, "metadata": {"from": DID_TRUSTEE, "reqId": written_req_id, "payloadDigest": written_payload_digest}}, "txnMetadata": {"seqNo": 2}, "ver": "1"}
+                    ]
+                }
+            })).unwrap()
+        }
+
+        fn _req_id(request: &str) -> u64 {
+            serde_json::from_str::<serde_json::Value>(request).unwrap()["reqId"].as_u64().unwrap()
+        }
+
+        const WRITE_TIMEOUT_OPTIONS: &str = r#"{"retries": 0, "timeout": 1, "preferred_nodes": ["Node1", "Node2"]}"#;
+
+        #[test]
+        fn indy_submit_write_request_works_for_timeout_and_written() {
+            let setup = Setup::empty();
+
+            let nym_request = ledger::build_nym_request(DID_TRUSTEE, DID_MY1, None, None, None).unwrap();
+            let pool_handle = _open_in_process_pool_with_write_timeout(&setup, Some(2), _req_id(&nym_request), &ledger::payload_digest(&nym_request));
+
+            let outcome = ledger::submit_write_request(pool_handle, &nym_request, WRITE_TIMEOUT_OPTIONS).unwrap();
+            let outcome: serde_json::Value = serde_json::from_str(&outcome).unwrap();
+
+            assert_eq!("committed", outcome["status"].as_str().unwrap());
+            assert_eq!(2, outcome["reply"]["result"]["data"]["txnMetadata"]["seqNo"].as_u64().unwrap());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_submit_write_request_works_for_timeout_and_object_not_found() {
+            let setup = Setup::empty();
+
+            let nym_request = ledger::build_nym_request(DID_TRUSTEE, DID_MY1, None, None, None).unwrap();
+            let pool_handle = _open_in_process_pool_with_write_timeout(&setup, None, _req_id(&nym_request), &ledger::payload_digest(&nym_request));
+
+            let outcome = ledger::submit_write_request(pool_handle, &nym_request, WRITE_TIMEOUT_OPTIONS).unwrap();
+            let outcome: serde_json::Value = serde_json::from_str(&outcome).unwrap();
+
+            assert_eq!("unknown", outcome["status"].as_str().unwrap());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_submit_write_request_works_for_timeout_and_txn_of_other_request() {
+            let setup = Setup::empty();
+
+            let nym_request = ledger::build_nym_request(DID_TRUSTEE, DID_MY1, None, None, None).unwrap();
+            let pool_handle = _open_in_process_pool_with_write_timeout(&setup, Some(2), _req_id(&nym_request) + 1, &ledger::payload_digest(&nym_request));
+
+            let outcome = ledger::submit_write_request(pool_handle, &nym_request, WRITE_TIMEOUT_OPTIONS).unwrap();
+            let outcome: serde_json::Value = serde_json::from_str(&outcome).unwrap();
+
+            assert_eq!("not_written", outcome["status"].as_str().unwrap());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_submit_write_request_works_for_timeout_and_txn_of_other_payload() {
+            let setup = Setup::empty();
+
+            let nym_request = ledger::build_nym_request(DID_TRUSTEE, DID_MY1, None, None, None).unwrap();
+            let other_nym_request = ledger::build_nym_request(DID_TRUSTEE, DID_MY1, None, None, Some("TRUSTEE")).unwrap();
+            let pool_handle = _open_in_process_pool_with_write_timeout(&setup, Some(2), _req_id(&nym_request), &ledger::payload_digest(&other_nym_request));
+
+            let outcome = ledger::submit_write_request(pool_handle, &nym_request, WRITE_TIMEOUT_OPTIONS).unwrap();
+            let outcome: serde_json::Value = serde_json::from_str(&outcome).unwrap();
+
+            assert_eq!("not_written", outcome["status"].as_str().unwrap());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_submit_write_request_works_for_invalid_options() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, json!({}));
+
+            let nym_request = ledger::build_nym_request(DID_TRUSTEE, DID_MY1, None, None, None).unwrap();
+            let res = ledger::submit_write_request(pool_handle, &nym_request, r#"{"retries": "many"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+    }
+
+    #[cfg(feature = "in_process_pool")]
+    mod submit_request_with_options {
+        use super::*;
+
+        fn _open_in_process_pool(setup: &Setup) -> PoolHandle {
+            pool::create_and_open_in_process_pool_ledger(&setup.name, json!({
+                "replies": {
+                    "105": {"type": "105", "dest": DID_TRUSTEE, "seqNo": 1, "data": null},
+                    "119": {"type": "119", "data": "info"}
+                },
+                "node_replies": {
+                    "Node4": {
+                        "105": {"type": "105", "dest": DID_TRUSTEE, "seqNo": 2, "data": null}
+                    }
+                }
+            })).unwrap()
+        }
+
+        #[test]
+        fn indy_submit_request_with_options_works_for_excluded_nodes() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup);
+
+            let get_nym_request = ledger::build_get_nym_request(Some(DID_TRUSTEE), DID_TRUSTEE).unwrap();
+            let options = json!({"read_consensus": "all", "excluded_nodes": ["Node4"], "timeout": 5}).to_string();
+            let response = ledger::submit_request_with_options(pool_handle, &get_nym_request, &options).unwrap();
+
+            let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+            assert_eq!("REPLY", response["op"].as_str().unwrap());
+            assert_eq!(1, response["result"]["seqNo"].as_u64().unwrap());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_submit_request_with_options_works_for_all_nodes_excluded() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup);
+
+            let get_nym_request = ledger::build_get_nym_request(Some(DID_TRUSTEE), DID_TRUSTEE).unwrap();
+            let options = json!({"excluded_nodes": ["Node1", "Node2", "Node3", "Node4"]}).to_string();
+            let res = ledger::submit_request_with_options(pool_handle, &get_nym_request, &options);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_submit_request_with_options_works_for_invalid_timeout() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup);
+
+            let get_nym_request = ledger::build_get_nym_request(Some(DID_TRUSTEE), DID_TRUSTEE).unwrap();
+            let res = ledger::submit_request_with_options(pool_handle, &get_nym_request, r#"{"timeout": 0}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_submit_request_with_options_works_for_conflicting_state_proof_options() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup);
+
+            let get_nym_request = ledger::build_get_nym_request(Some(DID_TRUSTEE), DID_TRUSTEE).unwrap();
+            let options = json!({"read_consensus": "consensus", "require_state_proof": true}).to_string();
+            let res = ledger::submit_request_with_options(pool_handle, &get_nym_request, &options);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_sign_and_submit_request_with_options_works() {
+            let setup = Setup::wallet();
+            let pool_handle = _open_in_process_pool(&setup);
+
+            let (did, _) = did::create_and_store_my_did(setup.wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let get_nym_request = ledger::build_get_nym_request(Some(&did), DID_TRUSTEE).unwrap();
+            let options = json!({"read_consensus": "consensus", "preferred_nodes": ["Node1"], "extended_timeout": 10}).to_string();
+            let response = ledger::sign_and_submit_request_with_options(pool_handle, setup.wallet_handle, &did, &get_nym_request, &options).unwrap();
+
+            let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+            assert_eq!("REPLY", response["op"].as_str().unwrap());
+            assert_eq!(1, response["result"]["seqNo"].as_u64().unwrap());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_submit_action_with_options_works() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup);
+
+            let get_validator_info_request = ledger::build_get_validator_info_request(DID_TRUSTEE).unwrap();
+            let options = json!({"preferred_nodes": ["Node1", "Node2"], "excluded_nodes": ["Node3"], "timeout": 5}).to_string();
+            let response = ledger::submit_action_with_options(pool_handle, &get_validator_info_request, &options).unwrap();
+
+            let response: HashMap<String, String> = serde_json::from_str(&response).unwrap();
+            assert_eq!(2, response.len());
+            assert!(response.contains_key("Node1"));
+            assert!(response.contains_key("Node2"));
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_submit_action_with_options_works_for_not_action() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup);
+
+            let get_nym_request = ledger::build_get_nym_request(Some(DID_TRUSTEE), DID_TRUSTEE).unwrap();
+            let res = ledger::submit_action_with_options(pool_handle, &get_nym_request, "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+    }
+
+    #[cfg(feature = "in_process_pool")]
+    mod resolve_did {
+        use super::*;
+
+        const ABBREVIATED_VERKEY_MY1: &str = "~HYwqs2vrTc8Tn4uBV7NBTe";
+        const ENDPOINT: &str = "https://agent.example.com";
+        const CONSENSUS_OPTIONS: &str = r#"{"read_consensus": "consensus"}"#;
+
+        fn _open_in_process_pool(setup: &Setup, nym_data: serde_json::Value, attr_data: serde_json::Value) -> PoolHandle {
+            pool::create_and_open_in_process_pool_ledger(&setup.name, json!({
+                "replies": {
+                    "105": {"type": "105", "dest": DID_MY1, "seqNo": 1, "data": nym_data},
+                    "104": {"type": "104", "dest": DID_MY1, "raw": "endpoint", "seqNo": 2, "data": attr_data}
+                }
+            })).unwrap()
+        }
+
+        fn _nym_data() -> serde_json::Value {
+            json!(json!({"dest": DID_MY1, "identifier": DID_TRUSTEE, "role": null, "verkey": ABBREVIATED_VERKEY_MY1}).to_string())
+        }
+
+        fn _attr_data() -> serde_json::Value {
+            json!(json!({"endpoint": {"endpoint": ENDPOINT}}).to_string())
+        }
+
+        #[test]
+        fn indy_resolve_did_works() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, _nym_data(), _attr_data());
+
+            let did_doc = ledger::resolve_did(pool_handle, DID_MY1, Some(CONSENSUS_OPTIONS)).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
+
+            assert_eq!(DID_MY1_V1, did_doc["id"].as_str().unwrap());
+            assert_eq!(VERKEY_MY1, did_doc["verificationMethod"][0]["publicKeyBase58"].as_str().unwrap());
+            assert_eq!(json!([format!("{}#key-1", DID_MY1_V1)]), did_doc["authentication"]);
+            assert_eq!(ENDPOINT, did_doc["service"][0]["serviceEndpoint"].as_str().unwrap());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_fully_qualified_did() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, _nym_data(), _attr_data());
+
+            let did_doc = ledger::resolve_did(pool_handle, DID_MY1_V1, Some(CONSENSUS_OPTIONS)).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
+
+            assert_eq!(DID_MY1_V1, did_doc["id"].as_str().unwrap());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_no_endpoint() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, _nym_data(), serde_json::Value::Null);
+
+            let did_doc = ledger::resolve_did(pool_handle, DID_MY1, Some(CONSENSUS_OPTIONS)).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
+
+            assert_eq!(VERKEY_MY1, did_doc["verificationMethod"][0]["publicKeyBase58"].as_str().unwrap());
+            assert!(did_doc["service"].is_null());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_unknown_did() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, serde_json::Value::Null, serde_json::Value::Null);
+
+            let res = ledger::resolve_did(pool_handle, DID_MY1, Some(CONSENSUS_OPTIONS));
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_replies_without_state_proof() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, _nym_data(), _attr_data());
+
+            let res = ledger::resolve_did(pool_handle, DID_MY1, None);
+            assert_code!(ErrorCode::PoolLedgerTimeout, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_other_did_method() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, _nym_data(), _attr_data());
+
+            let res = ledger::resolve_did(pool_handle, "did:peer:VsKV7grR1BUE29mG2Fm2kX", Some(CONSENSUS_OPTIONS));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+    }
+
+    #[cfg(feature = "in_process_pool")]
+    mod get_ledger_objects {
+        use super::*;
+        use crate::utils::domain::anoncreds::schema::SchemaId;
+
+        const CONSENSUS_OPTIONS: &str = r#"{"read_consensus": "consensus"}"#;
+
+        fn _schema_id(name: &str) -> String {
+            SchemaId::new(&DidValue(DID_TRUSTEE.to_string()), name, SCHEMA_VERSION).0
+        }
+
+        fn _open_in_process_pool(setup: &Setup) -> PoolHandle {
+            pool::create_and_open_in_process_pool_ledger(&setup.name, json!({
+                "replies": {
+                    "107": {
+                        "type": "107",
+                        "dest": DID_TRUSTEE,
+                        "seqNo": 1,
+                        "data": {"name": GVT_SCHEMA_NAME, "version": SCHEMA_VERSION, "attr_names": ["name", "age"]}
+                    }
+                }
+            })).unwrap()
+        }
+
+        #[test]
+        fn indy_get_ledger_objects_works() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup);
+
+            let schema_id = _schema_id(GVT_SCHEMA_NAME);
+            let ids = json!({"schemas": [schema_id, schema_id]}).to_string();
+
+            let objects = ledger::get_ledger_objects(pool_handle, None, &ids, Some(CONSENSUS_OPTIONS)).unwrap();
+            let objects: serde_json::Value = serde_json::from_str(&objects).unwrap();
+
+            let schema: SchemaV1 = serde_json::from_value(objects["schemas"][&schema_id]["object"].clone()).unwrap();
+            assert_eq!(schema_id, schema.id.0);
+            assert_eq!(GVT_SCHEMA_NAME, schema.name);
+            assert_eq!(1, objects["schemas"].as_object().unwrap().len());
+            assert_eq!(json!({}), objects["cred_defs"]);
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_get_ledger_objects_works_for_per_item_errors() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup);
+
+            let schema_id = _schema_id(GVT_SCHEMA_NAME);
+            let other_schema_id = _schema_id(XYZ_SCHEMA_NAME);
+            let cred_def_id = format!("{}:3:CL:1:{}", DID_TRUSTEE, TAG_1);
+            let ids = json!({"schemas": [schema_id, other_schema_id], "cred_defs": [cred_def_id]}).to_string();
+
+            let objects = ledger::get_ledger_objects(pool_handle, None, &ids, Some(CONSENSUS_OPTIONS)).unwrap();
+            let objects: serde_json::Value = serde_json::from_str(&objects).unwrap();
+
+            assert!(objects["schemas"][&schema_id]["object"].is_object());
+            assert_eq!(json!(ErrorCode::LedgerInvalidTransaction as i32), objects["schemas"][&other_schema_id]["error"]["code"]);
+            assert!(objects["cred_defs"][&cred_def_id]["error"]["message"].is_string());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_get_ledger_objects_works_for_empty_ids() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup);
+
+            let res = ledger::get_ledger_objects(pool_handle, None, "{}", Some(CONSENSUS_OPTIONS));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+    }
+
     mod sign_request {
         use super::*;
 
@@ -887,6 +1328,289 @@ mod high_cases {
         }
     }
 
+    #[cfg(feature = "in_process_pool")]
+    mod get_txn_range {
+        use super::*;
+
+        fn _txns(count: u64) -> Vec<serde_json::Value> {
+            (1..=count)
+                .map(|seq_no| json!({
+                    "txn": {"type": constants::NYM, "data": {"dest": DID_MY1, "alias": format!("alias{}", seq_no)}},
+                    "txnMetadata": {"seqNo": seq_no},
+                    "ver": "1"
+                }))
+                .collect()
+        }
+
+        fn _bls_seeds() -> serde_json::Value {
+            // seeds of BLS keys of the genesis nodes of the test pool
+            json!({
+                "Node1": "000000000000000000000000000Node1",
+                "Node2": "000000000000000000000000000Node2",
+                "Node3": "000000000000000000000000000Node3",
+                "Node4": "000000000000000000000000000Node4",
+            })
+        }
+
+        fn _open_in_process_pool(setup: &Setup, ledger_id: &str, txns: Vec<serde_json::Value>) -> PoolHandle {
+            pool::create_and_open_in_process_pool_ledger(&setup.name, json!({"txns": {ledger_id: txns}, "bls_seeds": _bls_seeds()})).unwrap()
+        }
+
+        #[test]
+        fn indy_get_txn_range_works() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, "1", _txns(3));
+
+            let page = ledger::get_txn_range(pool_handle, Some(DID_TRUSTEE), None, 1, None).unwrap();
+            let page: serde_json::Value = serde_json::from_str(&page).unwrap();
+
+            let txns = page["txns"].as_array().unwrap();
+            assert_eq!(vec![1, 2, 3], txns.iter().map(|txn| txn["txnMetadata"]["seqNo"].as_u64().unwrap()).collect::<Vec<u64>>());
+            assert_eq!(3, page["ledger_size"].as_u64().unwrap());
+            assert!(page["root_hash"].is_string());
+            assert!(page["next"].is_null());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_get_txn_range_works_for_part_of_ledger() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, "1", _txns(3));
+
+            let page = ledger::get_txn_range(pool_handle, None, None, 2, Some(2)).unwrap();
+            let page: serde_json::Value = serde_json::from_str(&page).unwrap();
+
+            let txns = page["txns"].as_array().unwrap();
+            assert_eq!(1, txns.len());
+            assert_eq!("alias2", txns[0]["txn"]["data"]["alias"].as_str().unwrap());
+            assert!(page["next"].is_null());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_get_txn_range_works_for_paging() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, "1", _txns(101));
+
+            let page = ledger::get_txn_range(pool_handle, None, None, 1, None).unwrap();
+            let page: serde_json::Value = serde_json::from_str(&page).unwrap();
+            assert_eq!(100, page["txns"].as_array().unwrap().len());
+            assert_eq!(101, page["next"].as_i64().unwrap());
+
+            let page = ledger::get_txn_range(pool_handle, None, None, 101, None).unwrap();
+            let page: serde_json::Value = serde_json::from_str(&page).unwrap();
+            assert_eq!(1, page["txns"].as_array().unwrap().len());
+            assert!(page["next"].is_null());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_get_txn_range_works_for_audit_ledger() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, "3", _txns(2));
+
+            let page = ledger::get_txn_range(pool_handle, None, Some("AUDIT"), 1, Some(10)).unwrap();
+            let page: serde_json::Value = serde_json::from_str(&page).unwrap();
+            assert_eq!(2, page["txns"].as_array().unwrap().len());
+            assert!(page["next"].is_null());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_get_txn_range_works_for_from_beyond_ledger() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, "1", _txns(2));
+
+            let page = ledger::get_txn_range(pool_handle, None, None, 5, None).unwrap();
+            let page: serde_json::Value = serde_json::from_str(&page).unwrap();
+            assert!(page["txns"].as_array().unwrap().is_empty());
+            assert!(page["ledger_size"].is_null());
+            assert!(page["next"].is_null());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_get_txn_range_works_for_invalid_range() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, "1", _txns(2));
+
+            let res = ledger::get_txn_range(pool_handle, None, None, 0, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let res = ledger::get_txn_range(pool_handle, None, None, 2, Some(1));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_get_txn_range_works_for_unsigned_reply() {
+            let setup = Setup::empty();
+            let pool_handle = pool::create_and_open_in_process_pool_ledger(&setup.name, json!({"txns": {"1": _txns(2)}})).unwrap();
+
+            let res = ledger::get_txn_range(pool_handle, None, None, 1, None);
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_get_txn_range_works_for_signature_of_other_keys() {
+            let setup = Setup::empty();
+            let bls_seeds = json!({
+                "Node1": "000000000000000000000000000Node5",
+                "Node2": "000000000000000000000000000Node6",
+                "Node3": "000000000000000000000000000Node7",
+            });
+            let pool_handle = pool::create_and_open_in_process_pool_ledger(&setup.name, json!({"txns": {"1": _txns(2)}, "bls_seeds": bls_seeds})).unwrap();
+
+            let res = ledger::get_txn_range(pool_handle, None, None, 1, None);
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_get_txn_range_works_for_invalid_pool_handle() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, "1", _txns(2));
+
+            let res = ledger::get_txn_range(pool_handle + 1, None, None, 1, None);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+    }
+
+    #[cfg(feature = "in_process_pool")]
+    mod subscribe_ledger {
+        use super::*;
+        use futures::Stream;
+
+        fn _open_in_process_pool(setup: &Setup, txns_count: u64) -> PoolHandle {
+            let txns: Vec<serde_json::Value> = (1..=txns_count)
+                .map(|seq_no| json!({
+                    "txn": {"type": constants::NYM, "data": {"dest": DID_MY1, "alias": format!("alias{}", seq_no)}},
+                    "txnMetadata": {"seqNo": seq_no},
+                    "ver": "1"
+                }))
+                .collect();
+
+            // the subscription is seeded by GET_TXN reply verified by BLS multi-signature of the pool
+            let bls_seeds = json!({
+                "Node1": "000000000000000000000000000Node1",
+                "Node2": "000000000000000000000000000Node2",
+                "Node3": "000000000000000000000000000Node3",
+                "Node4": "000000000000000000000000000Node4",
+            });
+
+            pool::create_and_open_in_process_pool_ledger(&setup.name, json!({"txns": {"1": txns}, "bls_seeds": bls_seeds})).unwrap()
+        }
+
+        #[test]
+        fn indy_subscribe_ledger_works() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, 3);
+
+            let (subscription_handle, txns) = ledger::subscribe_ledger(pool_handle, None, Some(2), Some(r#"{"interval":1}"#)).unwrap();
+
+            let txns: Vec<serde_json::Value> = txns.wait()
+                .take(2)
+                .map(|txn| serde_json::from_str(&txn.unwrap().unwrap()).unwrap())
+                .collect();
+            assert_eq!(vec![2, 3], txns.iter().map(|txn| txn["txnMetadata"]["seqNo"].as_u64().unwrap()).collect::<Vec<u64>>());
+
+            ledger::unsubscribe_ledger(subscription_handle).unwrap();
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_subscribe_ledger_works_from_first_txn() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, 3);
+
+            let (subscription_handle, txns) = ledger::subscribe_ledger(pool_handle, None, Some(1), Some(r#"{"interval":1}"#)).unwrap();
+
+            let txns: Vec<serde_json::Value> = txns.wait()
+                .take(3)
+                .map(|txn| serde_json::from_str(&txn.unwrap().unwrap()).unwrap())
+                .collect();
+            assert_eq!(vec![1, 2, 3], txns.iter().map(|txn| txn["txnMetadata"]["seqNo"].as_u64().unwrap()).collect::<Vec<u64>>());
+
+            ledger::unsubscribe_ledger(subscription_handle).unwrap();
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_subscribe_ledger_works_for_new_txns_only() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, 3);
+
+            let (_, txns) = ledger::subscribe_ledger(pool_handle, None, None, Some(r#"{"interval":1}"#)).unwrap();
+
+            // committed transactions aren't reported, so the first event is the failed sync of the closed pool
+            pool::close(pool_handle).unwrap();
+
+            let res = txns.wait().next().unwrap().unwrap();
+            assert!(res.is_err());
+        }
+
+        #[test]
+        fn indy_subscribe_ledger_works_for_invalid_options() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, 1);
+
+            let res = ledger::subscribe_ledger(pool_handle, None, None, Some(r#"{"interval":0}"#));
+            assert_code!(ErrorCode::CommonInvalidParam5, res);
+
+            let res = ledger::subscribe_ledger(pool_handle, None, Some(0), None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_subscribe_ledger_works_for_pool_ledger() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, 1);
+
+            let res = ledger::subscribe_ledger(pool_handle, Some("POOL"), None, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_subscribe_ledger_works_for_invalid_pool_handle() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, 1);
+
+            let res = ledger::subscribe_ledger(pool_handle + 1, None, None, None);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_unsubscribe_ledger_works_for_unknown_handle() {
+            let setup = Setup::empty();
+            let pool_handle = _open_in_process_pool(&setup, 1);
+
+            let (subscription_handle, _txns) = ledger::subscribe_ledger(pool_handle, None, None, None).unwrap();
+            ledger::unsubscribe_ledger(subscription_handle).unwrap();
+
+            let res = ledger::unsubscribe_ledger(subscription_handle);
+            assert_code!(ErrorCode::CommonInvalidParam2, res);
+
+            pool::close(pool_handle).unwrap();
+        }
+    }
+
     mod pool_config {
         use super::*;
 
@@ -1115,6 +1839,474 @@ mod high_cases {
         }
     }
 
+    mod ledgers_freeze {
+        use super::*;
+
+        #[test]
+        fn indy_build_ledgers_freeze_request_works() {
+            let expected_result = json!({
+                "type": constants::LEDGERS_FREEZE,
+                "ledgers_ids": [0, 1, 28, 345]
+            });
+
+            let request = ledger::build_ledgers_freeze_request(DID_TRUSTEE, "[0, 1, 28, 345]").unwrap();
+            check_request_operation(&request, expected_result);
+        }
+
+        #[test]
+        fn indy_build_ledgers_freeze_request_works_for_invalid_ledgers_ids() {
+            let res = ledger::build_ledgers_freeze_request(DID_TRUSTEE, r#"["1", "2"]"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_ledgers_freeze_request_works_for_empty_ledgers_ids() {
+            let res = ledger::build_ledgers_freeze_request(DID_TRUSTEE, "[]");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_get_frozen_ledgers_request_works() {
+            let expected_result = json!({
+                "type": constants::GET_FROZEN_LEDGERS
+            });
+
+            let request = ledger::build_get_frozen_ledgers_request(Some(DID_TRUSTEE)).unwrap();
+            check_request_operation(&request, expected_result);
+        }
+    }
+
+    mod upgrade_status {
+        use super::*;
+
+        #[test]
+        fn indy_parse_upgrade_status_works() {
+            let txns = json!([
+                {
+                    "txn": {
+                        "type": constants::POOL_UPGRADE,
+                        "data": {"name": "upgrade-libindy", "version": "2.0.0", "action": "start", "sha256": "f284b", "schedule": {}},
+                        "metadata": {"from": DID_TRUSTEE}
+                    },
+                    "txnMetadata": {"seqNo": 1, "txnTime": 1000},
+                    "ver": "1"
+                },
+                {
+                    "txn": {
+                        "type": constants::NODE_UPGRADE,
+                        "data": {"data": {"action": "complete", "version": "2.0.0"}},
+                        "metadata": {"from": "Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv"}
+                    },
+                    "txnMetadata": {"seqNo": 2, "txnTime": 2000},
+                    "ver": "1"
+                }
+            ]).to_string();
+
+            let status = ledger::parse_upgrade_status(&txns).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            assert_eq!("2.0.0", status["pool_upgrade"]["version"].as_str().unwrap());
+            assert_eq!(json!({"Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv": {"action": "complete", "version": "2.0.0", "seq_no": 2, "txn_time": 2000}}),
+                       status["nodes"]);
+        }
+
+        #[test]
+        fn indy_parse_upgrade_status_works_for_invalid_txns() {
+            let res = ledger::parse_upgrade_status(r#"{"txn": {}}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod rich_schema_requests {
+        use super::*;
+
+        const RS_ID: &str = "did:sov:8a9Bpb6Vs8r7W4oBSZ4aeGRAvqBNE3MRpbFaNDYXv2Ps";
+        const RS_NAME: &str = "SimpleRichSchema";
+        const RS_VERSION: &str = "1.0";
+
+        fn rich_schema_content() -> String {
+            json!({"@id": RS_ID, "@type": "rdfs:Class", "name": "string"}).to_string()
+        }
+
+        fn rich_schema() -> serde_json::Value {
+            json!({
+                "id": RS_ID,
+                "content": rich_schema_content(),
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION,
+                "rsType": "sch"
+            })
+        }
+
+        #[test]
+        fn indy_build_rich_schema_object_request_works() {
+            let expected_result = json!({
+                "type": constants::RICH_SCHEMA,
+                "id": RS_ID,
+                "content": rich_schema_content(),
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION,
+                "rsType": "sch",
+                "ver": "1"
+            });
+
+            let request = ledger::build_rich_schema_object_request(DID_TRUSTEE, &rich_schema().to_string()).unwrap();
+            check_request_operation(&request, expected_result);
+        }
+
+        #[test]
+        fn indy_build_rich_schema_object_request_works_for_context() {
+            let mut context = rich_schema();
+            context["rsType"] = json!("ctx");
+            context["content"] = json!(json!({"@context": {"name": "http://schema.org/name"}}).to_string());
+
+            let request = ledger::build_rich_schema_object_request(DID_TRUSTEE, &context.to_string()).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            assert_eq!(json!(constants::JSON_LD_CONTEXT), request["operation"]["type"]);
+        }
+
+        #[test]
+        fn indy_build_rich_schema_object_request_works_for_invalid_content() {
+            let mut object = rich_schema();
+            object["content"] = json!(json!({"@id": "other", "@type": "rdfs:Class"}).to_string());
+
+            let res = ledger::build_rich_schema_object_request(DID_TRUSTEE, &object.to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_rich_schema_object_request_works_for_unknown_type() {
+            let mut object = rich_schema();
+            object["rsType"] = json!("unknown");
+
+            let res = ledger::build_rich_schema_object_request(DID_TRUSTEE, &object.to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_get_rich_schema_object_by_id_request_works() {
+            let expected_result = json!({
+                "type": constants::GET_RICH_SCHEMA_OBJECT_BY_ID,
+                "id": RS_ID
+            });
+
+            let request = ledger::build_get_rich_schema_object_by_id_request(Some(DID_TRUSTEE), RS_ID).unwrap();
+            check_request_operation(&request, expected_result);
+        }
+
+        #[test]
+        fn indy_build_get_rich_schema_object_by_metadata_request_works() {
+            let expected_result = json!({
+                "type": constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+                "rsType": "sch",
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION
+            });
+
+            let request = ledger::build_get_rich_schema_object_by_metadata_request(None, "sch", RS_NAME, RS_VERSION).unwrap();
+            check_request_operation(&request, expected_result);
+        }
+
+        #[test]
+        fn indy_parse_get_rich_schema_object_response_works() {
+            let mut data = rich_schema();
+            data["ver"] = json!("1");
+            data["from"] = json!(DID_TRUSTEE);
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_RICH_SCHEMA_OBJECT_BY_ID,
+                    "id": RS_ID,
+                    "data": data,
+                    "seqNo": 10,
+                    "txnTime": 1_600_000_000
+                }
+            }).to_string();
+
+            let (id, object) = ledger::parse_get_rich_schema_object_response(&response).unwrap();
+            assert_eq!(RS_ID, id);
+
+            let object: serde_json::Value = serde_json::from_str(&object).unwrap();
+            assert_eq!(json!(rich_schema_content()), object["content"]);
+            assert_eq!(json!("sch"), object["rsType"]);
+        }
+
+        #[test]
+        fn indy_parse_get_rich_schema_object_response_works_for_not_found() {
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_RICH_SCHEMA_OBJECT_BY_ID,
+                    "id": RS_ID,
+                    "data": null,
+                    "seqNo": null,
+                    "txnTime": null
+                }
+            }).to_string();
+
+            let res = ledger::parse_get_rich_schema_object_response(&response);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+    }
+
+    mod endorsement {
+        use super::*;
+
+        fn get_auth_rule_response() -> String {
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_AUTH_RULE,
+                    "reqId": 1,
+                    "identifier": DID_TRUSTEE,
+                    "data": [{
+                        "auth_type": constants::NYM,
+                        "auth_action": "ADD",
+                        "field": "role",
+                        "old_value": null,
+                        "new_value": "101",
+                        "constraint": {
+                            "constraint_id": "AND",
+                            "auth_constraints": [
+                                {"constraint_id": "ROLE", "role": "0", "sig_count": 1, "need_to_be_owner": false, "metadata": {}},
+                                {"constraint_id": "ROLE", "role": "101", "sig_count": 1, "need_to_be_owner": false, "metadata": {}}
+                            ]
+                        }
+                    }]
+                }
+            }).to_string()
+        }
+
+        fn prepare_endorsement(submitter_did: &str) -> String {
+            let request = ledger::build_nym_request(submitter_did, DID_MY2, None, None, Some("ENDORSER")).unwrap();
+            ledger::prepare_endorsement(&request, &get_auth_rule_response(), None).unwrap()
+        }
+
+        fn get_nym_response(did: &str, verkey: &str, role: Option<&str>) -> serde_json::Value {
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_NYM,
+                    "reqId": 1,
+                    "identifier": DID_TRUSTEE,
+                    "dest": did,
+                    "data": json!({"dest": did, "identifier": DID_TRUSTEE, "verkey": verkey, "role": role}).to_string()
+                }
+            })
+        }
+
+        fn status(endorsement: &str, get_nym_responses: &serde_json::Value) -> serde_json::Value {
+            serde_json::from_str(&ledger::endorsement_status(endorsement, &get_nym_responses.to_string()).unwrap()).unwrap()
+        }
+
+        #[test]
+        fn indy_prepare_endorsement_works() {
+            let endorsement = prepare_endorsement(DID_TRUSTEE);
+            let endorsement: serde_json::Value = serde_json::from_str(&endorsement).unwrap();
+
+            assert_eq!(json!(constants::NYM), endorsement["request"]["operation"]["type"]);
+            assert_eq!(1, endorsement["required_signers"].as_array().unwrap().len());
+            assert_eq!(2, endorsement["required_signers"][0].as_array().unwrap().len());
+        }
+
+        #[test]
+        fn indy_prepare_endorsement_works_for_other_request_type() {
+            let request = ledger::build_attrib_request(DID_TRUSTEE, DID_TRUSTEE, None, Some(ATTRIB_RAW_DATA), None).unwrap();
+
+            let res = ledger::prepare_endorsement(&request, &get_auth_rule_response(), None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_endorsement_works() {
+            let setup = Setup::wallet();
+
+            let (trustee_did, trustee_verkey) = did::create_and_store_my_did(setup.wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (endorser_did, endorser_verkey) = did::create_and_store_my_did(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let get_nym_responses = json!([
+                get_nym_response(&trustee_did, &trustee_verkey, Some("0")),
+                get_nym_response(&endorser_did, &endorser_verkey, Some("101"))
+            ]);
+
+            let endorsement = prepare_endorsement(&trustee_did);
+
+            let status_ = status(&endorsement, &get_nym_responses);
+            assert_eq!(json!(false), status_["ready"]);
+            assert_eq!(2, status_["unmet"].as_array().unwrap().len());
+
+            let endorsement = ledger::endorsement_add_signature(setup.wallet_handle, &endorsement, &trustee_did).unwrap();
+
+            let status_ = status(&endorsement, &get_nym_responses);
+            assert_eq!(json!(false), status_["ready"]);
+            assert_eq!(json!([{"constraint": {"constraint_id": "ROLE", "role": "101", "sig_count": 1, "need_to_be_owner": false, "metadata": {}}, "signatures": 0}]),
+                       status_["unmet"]);
+
+            let endorsement = ledger::endorsement_add_signature(setup.wallet_handle, &endorsement, &endorser_did).unwrap();
+
+            let status_ = status(&endorsement, &get_nym_responses);
+            assert_eq!(json!(true), status_["ready"]);
+            assert_eq!(json!([]), status_["unmet"]);
+            assert_eq!(json!([]), status_["unresolved"]);
+
+            let signatures: serde_json::Value = serde_json::from_str(&ledger::endorsement_check_signatures(&endorsement).unwrap()).unwrap();
+            assert_eq!(json!({"valid": [trustee_did, endorser_did], "invalid": [], "unknown": [], "missing": []}), signatures);
+        }
+
+        #[test]
+        fn indy_endorsement_works_for_changed_request() {
+            let setup = Setup::wallet();
+
+            let (trustee_did, trustee_verkey) = did::create_and_store_my_did(setup.wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let endorsement = prepare_endorsement(&trustee_did);
+            let endorsement = ledger::endorsement_add_signature(setup.wallet_handle, &endorsement, &trustee_did).unwrap();
+
+            let mut endorsement: serde_json::Value = serde_json::from_str(&endorsement).unwrap();
+            endorsement["request"]["operation"]["role"] = json!("0");
+
+            let signatures: serde_json::Value = serde_json::from_str(&ledger::endorsement_check_signatures(&endorsement.to_string()).unwrap()).unwrap();
+            assert_eq!(json!([trustee_did]), signatures["invalid"]);
+
+            let get_nym_responses = json!([get_nym_response(&trustee_did, &trustee_verkey, Some("0"))]);
+            assert_eq!(json!(false), status(&endorsement.to_string(), &get_nym_responses)["ready"]);
+        }
+
+        #[test]
+        fn indy_endorsement_status_works_for_signer_without_nym() {
+            let setup = Setup::wallet();
+
+            let (trustee_did, trustee_verkey) = did::create_and_store_my_did(setup.wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (endorser_did, _) = did::create_and_store_my_did(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let endorsement = prepare_endorsement(&trustee_did);
+            let endorsement = ledger::endorsement_add_signature(setup.wallet_handle, &endorsement, &trustee_did).unwrap();
+            let endorsement = ledger::endorsement_add_signature(setup.wallet_handle, &endorsement, &endorser_did).unwrap();
+
+            let status_ = status(&endorsement, &json!([get_nym_response(&trustee_did, &trustee_verkey, Some("0"))]));
+            assert_eq!(json!(false), status_["ready"]);
+            assert_eq!(json!([endorser_did]), status_["unresolved"]);
+        }
+
+        #[test]
+        fn indy_endorsement_status_works_for_signer_with_other_role_on_ledger() {
+            let setup = Setup::wallet();
+
+            let (trustee_did, trustee_verkey) = did::create_and_store_my_did(setup.wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (endorser_did, endorser_verkey) = did::create_and_store_my_did(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let endorsement = prepare_endorsement(&trustee_did);
+            let endorsement = ledger::endorsement_add_signature(setup.wallet_handle, &endorsement, &trustee_did).unwrap();
+            let endorsement = ledger::endorsement_add_signature(setup.wallet_handle, &endorsement, &endorser_did).unwrap();
+
+            let get_nym_responses = json!([
+                get_nym_response(&trustee_did, &trustee_verkey, Some("0")),
+                get_nym_response(&endorser_did, &endorser_verkey, None)
+            ]);
+
+            let status_ = status(&endorsement, &get_nym_responses);
+            assert_eq!(json!(false), status_["ready"]);
+            assert_eq!(json!([]), status_["unresolved"]);
+            assert_eq!(1, status_["unmet"].as_array().unwrap().len());
+        }
+
+        #[test]
+        fn indy_endorsement_status_works_for_invalid_get_nym_responses() {
+            let endorsement = prepare_endorsement(DID_TRUSTEE);
+
+            let res = ledger::endorsement_status(&endorsement, "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_submit_endorsement_works_for_not_ready_request() {
+            let endorsement = prepare_endorsement(DID_TRUSTEE);
+
+            let res = ledger::submit_endorsement(INVALID_POOL_HANDLE, &endorsement, "[]");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod evaluate_auth_rule {
+        use super::*;
+
+        fn get_auth_rule_response() -> String {
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_AUTH_RULE,
+                    "reqId": 1,
+                    "identifier": DID_TRUSTEE,
+                    "data": [{
+                        "auth_type": constants::NYM,
+                        "auth_action": "ADD",
+                        "field": "role",
+                        "old_value": null,
+                        "new_value": "101",
+                        "constraint": {
+                            "constraint_id": "OR",
+                            "auth_constraints": [
+                                {"constraint_id": "ROLE", "role": "0", "sig_count": 1, "need_to_be_owner": false, "metadata": {"fees": "trustee_fee"}},
+                                {"constraint_id": "ROLE", "role": "2", "sig_count": 1, "need_to_be_owner": false, "metadata": {"fees": "steward_fee"}}
+                            ]
+                        }
+                    }]
+                }
+            }).to_string()
+        }
+
+        fn evaluate(signers: serde_json::Value) -> serde_json::Value {
+            let request = ledger::build_nym_request(DID_TRUSTEE, DID_MY2, None, None, Some("ENDORSER")).unwrap();
+            let evaluation = ledger::evaluate_auth_rule(&request, "ADD", None, &get_auth_rule_response(), &signers.to_string()).unwrap();
+            serde_json::from_str(&evaluation).unwrap()
+        }
+
+        #[test]
+        fn indy_evaluate_auth_rule_works() {
+            let evaluation = evaluate(json!([{"role": "TRUSTEE"}]));
+
+            assert_eq!(json!(true), evaluation["accepted"]);
+            assert_eq!(json!(["trustee_fee"]), evaluation["rules"][0]["fees"]);
+        }
+
+        #[test]
+        fn indy_evaluate_auth_rule_works_for_not_enough_signers() {
+            let evaluation = evaluate(json!([{"role": null}, {"role": "ENDORSER"}]));
+
+            assert_eq!(json!(false), evaluation["accepted"]);
+            assert_eq!(json!(false), evaluation["rules"][0]["satisfied"]);
+            assert_eq!(1, evaluation["rules"][0]["unmet"].as_array().unwrap().len());
+        }
+
+        #[test]
+        fn indy_evaluate_auth_rule_works_for_invalid_signers() {
+            let request = ledger::build_nym_request(DID_TRUSTEE, DID_MY2, None, None, Some("ENDORSER")).unwrap();
+
+            let res = ledger::evaluate_auth_rule(&request, "ADD", None, &get_auth_rule_response(), r#"{"role": "TRUSTEE"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_evaluate_auth_rule_works_for_other_action() {
+            let request = ledger::build_nym_request(DID_TRUSTEE, DID_MY2, None, None, Some("ENDORSER")).unwrap();
+
+            let evaluation = ledger::evaluate_auth_rule(&request, "EDIT", Some(""), &get_auth_rule_response(), r#"[{"role": "TRUSTEE"}]"#).unwrap();
+            let evaluation: serde_json::Value = serde_json::from_str(&evaluation).unwrap();
+
+            assert_eq!(json!(false), evaluation["accepted"]);
+            assert_eq!(json!([]), evaluation["rules"]);
+        }
+
+        #[test]
+        fn indy_evaluate_auth_rule_works_for_invalid_action() {
+            let request = ledger::build_nym_request(DID_TRUSTEE, DID_MY2, None, None, Some("ENDORSER")).unwrap();
+
+            let res = ledger::evaluate_auth_rule(&request, "REMOVE", None, &get_auth_rule_response(), r#"[{"role": "TRUSTEE"}]"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod revoc_reg_def_requests {
         use super::*;
 
@@ -6,10 +6,13 @@ inject_indy_dependencies!();
 extern crate indyrs as indy;
 extern crate indyrs as api;
 
+use std::collections::HashMap;
+
 use self::indy::ErrorCode;
 
-use crate::utils::{environment, pool};
+use crate::utils::{crypto, environment, ledger, pool, test};
 use crate::utils::constants::*;
+use rust_base58::ToBase58;
 use crate::utils::Setup;
 
 mod high_cases {
@@ -41,6 +44,52 @@ mod high_cases {
 
             let _ = fs::remove_file(txn_file_path);
         }
+
+        #[test]
+        fn create_pool_ledger_config_works_for_inline_genesis_txns() {
+            let setup = Setup::empty();
+
+            let genesis_txns: Vec<serde_json::Value> = test::gen_txns().iter()
+                .map(|txn| serde_json::from_str(txn).unwrap())
+                .collect();
+
+            let pool_config = json!({"genesis_txns": genesis_txns}).to_string();
+
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+        }
+
+        #[test]
+        fn create_pool_ledger_config_works_for_genesis_hash() {
+            let setup = Setup::empty();
+
+            let genesis_txn = test::gen_txns()[0].clone();
+            let genesis_hash = pool::genesis_txn_hash(&genesis_txn).to_base58();
+
+            let pool_config = json!({
+                "genesis_txns": [serde_json::from_str::<serde_json::Value>(&genesis_txn).unwrap()],
+                "genesis_hash": genesis_hash,
+            }).to_string();
+
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+        }
+
+        #[test]
+        fn create_pool_ledger_config_works_for_genesis_signature() {
+            let setup = Setup::wallet();
+
+            let genesis_txn = test::gen_txns()[0].clone();
+            let txn_file_path = pool::create_genesis_txn_file(&setup.name, &genesis_txn, None);
+
+            let verkey = crypto::create_key(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+            let signature = crypto::sign(setup.wallet_handle, &verkey, &pool::genesis_txn_hash(&genesis_txn)).unwrap();
+
+            let pool_config = json!({
+                "genesis_txn": txn_file_path.to_string_lossy(),
+                "genesis_signature": {"verkey": verkey, "signature": signature.to_base58()},
+            }).to_string();
+
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+        }
     }
 
     mod open {
@@ -108,6 +157,78 @@ mod high_cases {
 
             pool::open_pool_ledger(&setup.name, None).unwrap();
         }
+
+        #[test]
+        fn open_pool_ledger_works_for_in_process_transport() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let config = json!({
+                "transport": {
+                    "type": "in_process",
+                    "replies": {
+                        "105": {"type": "105", "dest": DID_TRUSTEE, "seqNo": 1, "data": "{\"dest\":\"V4SGRU86Z58d6TV7PBUe6f\"}"}
+                    }
+                }
+            }).to_string();
+
+            let pool_handle = pool::open_pool_ledger(&setup.name, Some(&config)).unwrap();
+
+            let get_nym_request = ledger::build_get_nym_request(Some(DID_TRUSTEE), DID_TRUSTEE).unwrap();
+            let response = ledger::submit_request(pool_handle, &get_nym_request).unwrap();
+
+            let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+            assert_eq!("REPLY", response["op"].as_str().unwrap());
+            assert_eq!(DID_TRUSTEE, response["result"]["dest"].as_str().unwrap());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        fn open_pool_ledger_works_for_all_read_consensus() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let config = json!({
+                "read_consensus": "all",
+                "transport": {
+                    "type": "in_process",
+                    "replies": {
+                        "105": {"type": "105", "dest": DID_TRUSTEE, "seqNo": 1, "data": "{\"dest\":\"V4SGRU86Z58d6TV7PBUe6f\"}"}
+                    },
+                    "node_replies": {
+                        "Node4": {
+                            "105": {"type": "105", "dest": DID_TRUSTEE, "seqNo": 2, "data": null}
+                        }
+                    }
+                }
+            }).to_string();
+
+            let pool_handle = pool::open_pool_ledger(&setup.name, Some(&config)).unwrap();
+
+            let get_nym_request = ledger::build_get_nym_request(Some(DID_TRUSTEE), DID_TRUSTEE).unwrap();
+            let response = ledger::submit_request(pool_handle, &get_nym_request).unwrap();
+
+            let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+            assert_eq!("REPLY_DIFF", response["op"].as_str().unwrap());
+            assert_eq!(json!(["Node1", "Node2", "Node3"]), response["replies"][0]["nodes"]);
+            assert_eq!(json!(["Node4"]), response["replies"][1]["nodes"]);
+            assert_eq!(json!([]), response["timeout_nodes"]);
+
+            let response = ledger::submit_request_with_options(pool_handle, &get_nym_request, r#"{"read_consensus":"consensus"}"#).unwrap();
+
+            let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+            assert_eq!("REPLY", response["op"].as_str().unwrap());
+            assert_eq!(1, response["result"]["seqNo"].as_u64().unwrap());
+
+            pool::close(pool_handle).unwrap();
+        }
     }
 
     mod refresh {
@@ -121,6 +242,88 @@ mod high_cases {
         }
     }
 
+    mod get_status {
+        use super::*;
+
+        #[test]
+        fn indy_get_pool_status_works() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let config = json!({
+                "transport": {
+                    "type": "in_process",
+                    "replies": {
+                        "105": {"type": "105", "dest": DID_TRUSTEE, "seqNo": 1, "data": null}
+                    }
+                }
+            }).to_string();
+
+            let pool_handle = pool::open_pool_ledger(&setup.name, Some(&config)).unwrap();
+
+            let get_nym_request = ledger::build_get_nym_request(Some(DID_TRUSTEE), DID_TRUSTEE).unwrap();
+            ledger::submit_request(pool_handle, &get_nym_request).unwrap();
+
+            let status = pool::get_status(pool_handle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            assert_eq!("active", status["state"].as_str().unwrap());
+
+            let nodes = status["nodes"].as_array().unwrap();
+            assert_eq!(vec!["Node1", "Node2", "Node3", "Node4"], nodes.iter().map(|node| node["alias"].as_str().unwrap()).collect::<Vec<&str>>());
+            assert!(nodes.iter().all(|node| node["ledger_size"].as_u64() == Some(4)));
+            assert!(nodes.iter().all(|node| node["last_seen"].is_i64()));
+            assert!(nodes.iter().any(|node| node["replies"].as_u64().unwrap() > 0 && node["avg_latency_ms"].is_i64()));
+
+            pool::close(pool_handle).unwrap();
+        }
+    }
+
+    mod get_bls_keys {
+        use super::*;
+
+        #[test]
+        fn indy_get_pool_bls_keys_works() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let bls_keys = pool::get_pool_bls_keys(&setup.name).unwrap();
+            let bls_keys: HashMap<String, Option<String>> = serde_json::from_str(&bls_keys).unwrap();
+
+            assert_eq!(4, bls_keys.len());
+            assert!(bls_keys.values().all(Option::is_some));
+            assert_eq!(Some("4N8aUNHSgjQVgkpm8nhNEfDf6txHznoYREg9kirmJrkivgL4oSEimFF6nsQ6M41QvhM2Z33nves5vfSn9n1UwNFJBYtWVnHYMATn76vLuL3zU88KyeAYcHfsih3He6UHcXDxcaecHVz6jhCYz1P2UZn2bDVruL5wXpehgBfBaLKm3Ba"),
+                       bls_keys["Node1"].as_ref().map(String::as_str));
+        }
+
+        #[test]
+        fn indy_verify_reply_offline_works_for_reply_without_state_proof() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let bls_keys = pool::get_pool_bls_keys(&setup.name).unwrap();
+
+            let reply = json!({
+                "op": "REPLY",
+                "result": {"type": "105", "dest": DID_TRUSTEE, "seqNo": 1, "data": null}
+            }).to_string();
+
+            let verification = ledger::verify_reply_offline(&reply, &bls_keys).unwrap();
+            let verification: serde_json::Value = serde_json::from_str(&verification).unwrap();
+
+            assert_eq!(json!({"verified": false, "timestamp": null}), verification);
+        }
+    }
+
     mod close {
         use super::*;
 
@@ -240,11 +443,80 @@ mod medium_cases {
             let pool_config = pool::pool_config_json(txn_file_path.as_path());
             pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
         }
+
+        #[test]
+        fn create_pool_ledger_config_works_for_both_genesis_sources() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = json!({
+                "genesis_txn": txn_file_path.to_string_lossy(),
+                "genesis_txns": [serde_json::from_str::<serde_json::Value>(&test::gen_txns()[0]).unwrap()],
+            }).to_string();
+
+            let res = pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str()));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn create_pool_ledger_config_works_for_wrong_genesis_hash() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = json!({
+                "genesis_txn": txn_file_path.to_string_lossy(),
+                "genesis_hash": pool::genesis_txn_hash(&test::gen_txns()[0]).to_base58(),
+            }).to_string();
+
+            let res = pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str()));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn create_pool_ledger_config_works_for_invalid_genesis_signature() {
+            let setup = Setup::wallet();
+
+            let genesis_txns = test::gen_txns();
+            let txn_file_path = pool::create_genesis_txn_file(&setup.name, &genesis_txns[0], None);
+
+            let verkey = crypto::create_key(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+            let signature = crypto::sign(setup.wallet_handle, &verkey, &pool::genesis_txn_hash(&genesis_txns[1])).unwrap();
+
+            let pool_config = json!({
+                "genesis_txn": txn_file_path.to_string_lossy(),
+                "genesis_signature": {"verkey": verkey, "signature": signature.to_base58()},
+            }).to_string();
+
+            let res = pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str()));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
     }
 
     mod open {
         use super::*;
 
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_ledger_works_for_tampered_genesis_txns() {
+            let setup = Setup::empty();
+
+            let genesis_txns = test::gen_txns();
+            let pool_config = json!({
+                "genesis_txns": [serde_json::from_str::<serde_json::Value>(&genesis_txns[0]).unwrap()],
+                "genesis_hash": pool::genesis_txn_hash(&genesis_txns[0]).to_base58(),
+            }).to_string();
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let mut txn_file_path = environment::pool_path(&setup.name);
+            txn_file_path.push(&setup.name);
+            txn_file_path.set_extension("txn");
+            pool::create_genesis_txn_file(&setup.name, &genesis_txns[1], Some(txn_file_path.as_path()));
+
+            let config = json!({"transport": {"type": "in_process"}}).to_string();
+            let res = pool::open_pool_ledger(&setup.name, Some(&config));
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn open_pool_ledger_works_for_twice() {
@@ -477,6 +749,55 @@ mod medium_cases {
         }
     }
 
+    mod get_status {
+        use super::*;
+
+        #[test]
+        fn indy_get_pool_status_works_for_invalid_handle() {
+            Setup::empty();
+
+            let res = pool::get_status(0);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+    }
+
+    mod get_bls_keys {
+        use super::*;
+
+        #[test]
+        fn indy_get_pool_bls_keys_works_for_not_created_pool() {
+            let setup = Setup::empty();
+
+            let res = pool::get_pool_bls_keys(&setup.name);
+            assert_code!(ErrorCode::PoolLedgerNotCreatedError, res);
+        }
+
+        #[test]
+        fn indy_verify_reply_offline_works_for_invalid_reply() {
+            Setup::empty();
+
+            let bls_keys = json!({"Node1": null}).to_string();
+
+            let res = ledger::verify_reply_offline("not a json", &bls_keys);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let reply = json!({"op": "REQNACK", "reason": "some reason"}).to_string();
+            let res = ledger::verify_reply_offline(&reply, &bls_keys);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_verify_reply_offline_works_for_invalid_bls_key() {
+            Setup::empty();
+
+            let bls_keys = json!({"Node1": "invalid"}).to_string();
+            let reply = json!({"op": "REPLY", "result": {"type": "105", "dest": DID_TRUSTEE}}).to_string();
+
+            let res = ledger::verify_reply_offline(&reply, &bls_keys);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod set_protocol_version {
         use super::*;
 
@@ -21,7 +21,7 @@ use crate::utils::domain::anoncreds::credential::{AttributeValues, CredentialInf
 use crate::utils::domain::anoncreds::credential_for_proof_request::CredentialsForProofRequest;
 use crate::utils::domain::crypto::did::DidValue;
 
-use indy::WalletHandle;
+use indy::{WalletHandle, PoolHandle};
 
 pub static mut CREDENTIAL_DEF_JSON: &'static str = "";
 pub static mut CREDENTIAL_OFFER_JSON: &'static str = "";
@@ -80,10 +80,45 @@ pub fn issuer_create_credential(wallet_handle: WalletHandle, cred_offer_json: &s
     anoncreds::issuer_create_credential(wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle.unwrap_or(-1)).wait() // TODO OPTIONAL blob_storage_reader_handle
 }
 
+pub fn issuer_create_credential_with_rollover(wallet_handle: WalletHandle, cred_offer_json: &str, cred_req_json: &str, cred_values_json: &str,
+                                              rev_reg_id: &str, blob_storage_reader_handle: i32, tails_writer_handle: i32,
+                                              rollover_config_json: &str) -> Result<(String, String), IndyError> {
+    anoncreds::issuer_create_credential_with_rollover(wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id,
+                                                      blob_storage_reader_handle, tails_writer_handle, rollover_config_json).wait()
+}
+
 pub fn issuer_revoke_credential(wallet_handle: WalletHandle, blob_storage_reader_handle: i32, rev_reg_id: &str, cred_revoc_id: &str) -> Result<String, IndyError> {
     anoncreds::issuer_revoke_credential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id).wait()
 }
 
+pub fn issuer_build_revoc_reg_entry_request(wallet_handle: WalletHandle, blob_storage_reader_handle: i32, submitter_did: &str, rev_reg_id: &str,
+                                            revoked_json: &str, unrevoked_json: &str) -> Result<(String, String), IndyError> {
+    anoncreds::issuer_build_revoc_reg_entry_request(wallet_handle, blob_storage_reader_handle, submitter_did, rev_reg_id, revoked_json, unrevoked_json).wait()
+}
+
+pub fn issuer_set_issued_credential_tags(wallet_handle: WalletHandle, rev_reg_id: &str, cred_revoc_id: &str, tags_json: &str) -> Result<(), IndyError> {
+    anoncreds::issuer_set_issued_credential_tags(wallet_handle, rev_reg_id, cred_revoc_id, tags_json).wait()
+}
+
+pub fn issuer_search_issued_credentials(wallet_handle: WalletHandle, query_json: Option<&str>) -> Result<(i32, usize), IndyError> {
+    anoncreds::issuer_search_issued_credentials(wallet_handle, query_json).wait()
+}
+
+pub fn issuer_fetch_issued_credentials(search_handle: i32, count: usize) -> Result<String, IndyError> {
+    anoncreds::issuer_fetch_issued_credentials(search_handle, count).wait()
+}
+
+pub fn issuer_close_issued_credentials_search(search_handle: i32) -> Result<(), IndyError> {
+    anoncreds::issuer_close_issued_credentials_search(search_handle).wait()
+}
+
+pub fn issuer_fetch_all_issued_credentials(wallet_handle: WalletHandle, query_json: Option<&str>) -> Result<String, IndyError> {
+    let (search_handle, total_count) = issuer_search_issued_credentials(wallet_handle, query_json)?;
+    let issued_credentials = issuer_fetch_issued_credentials(search_handle, total_count)?;
+    issuer_close_issued_credentials_search(search_handle)?;
+    Ok(issued_credentials)
+}
+
 pub fn issuer_merge_revocation_registry_deltas(rev_reg_delta: &str, other_rev_reg_delta: &str) -> Result<String, IndyError> {
     anoncreds::issuer_merge_revocation_registry_deltas(rev_reg_delta, other_rev_reg_delta).wait()
 }
@@ -165,6 +200,11 @@ pub fn verifier_verify_proof(proof_request_json: &str, proof_json: &str, schemas
     anoncreds::verifier_verify_proof(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
 }
 
+pub fn verifier_verify_proof_verbose(proof_request_json: &str, proof_json: &str, schemas_json: &str,
+                                     cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Result<String, IndyError> {
+    anoncreds::verifier_verify_proof_verbose(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
+}
+
 pub fn create_revocation_state(blob_storage_reader_handle: i32, rev_reg_def_json: &str,
                                rev_reg_delta_json: &str, timestamp: u64, cred_rev_id: &str) -> Result<String, IndyError> {
     anoncreds::create_revocation_state(blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
@@ -175,6 +215,11 @@ pub fn update_revocation_state(tails_reader_handle: i32, rev_state_json: &str, r
     anoncreds::update_revocation_state(tails_reader_handle, rev_state_json, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
 }
 
+pub fn prover_create_revocation_states(wallet_handle: WalletHandle, pool_handle: PoolHandle, submitter_did: Option<&str>, tails_reader_handle: i32,
+                                       proof_request_json: &str, requested_credentials_json: &str) -> Result<(String, String), IndyError> {
+    anoncreds::prover_create_revocation_states(wallet_handle, pool_handle, submitter_did, tails_reader_handle, proof_request_json, requested_credentials_json).wait()
+}
+
 pub fn generate_nonce() -> Result<String, IndyError> {
     anoncreds::generate_nonce().wait()
 }
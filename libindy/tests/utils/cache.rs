@@ -21,4 +21,8 @@ pub fn purge_schema_cache(wallet_handle: WalletHandle, options_json: &str) -> Re
 
 pub fn purge_cred_def_cache(wallet_handle: WalletHandle, options_json: &str) -> Result<(), IndyError> {
     cache::purge_cred_def_cache(wallet_handle, options_json).wait()
+}
+
+pub fn purge_rev_state_cache(wallet_handle: WalletHandle, options_json: &str) -> Result<(), IndyError> {
+    cache::purge_rev_state_cache(wallet_handle, options_json).wait()
 }
\ No newline at end of file
@@ -5,6 +5,7 @@ use indy::{IndyError, ErrorCode};
 use indy::ledger;
 use indy_utils::crypto::hash::hash;
 use self::futures::Future;
+use self::futures::sync::mpsc;
 use self::indy_sys::ledger::{CustomTransactionParser, CustomFree, indy_register_transaction_parser_for_sp};
 
 use crate::utils::{timeout, anoncreds, blob_storage, did, wallet, pool, callback};
@@ -14,7 +15,7 @@ use std::sync::{Once};
 use std::mem;
 use std::ffi::CString;
 
-use indy::{WalletHandle, PoolHandle};
+use indy::{CommandHandle, WalletHandle, PoolHandle};
 
 pub static mut SCHEMA_ID: &'static str = "";
 pub static mut SCHEMA_ID_V2: &'static str = "";
@@ -29,6 +30,10 @@ pub fn sign_and_submit_request(pool_handle: PoolHandle, wallet_handle: WalletHan
     ledger::sign_and_submit_request(pool_handle, wallet_handle, submitter_did, request_json).wait()
 }
 
+pub fn sign_and_submit_request_with_options(pool_handle: PoolHandle, wallet_handle: WalletHandle, submitter_did: &str, request_json: &str, options_json: &str) -> Result<String, IndyError> {
+    ledger::sign_and_submit_request_with_options(pool_handle, wallet_handle, submitter_did, request_json, options_json).wait()
+}
+
 pub fn submit_request_with_retries(pool_handle: PoolHandle, request_json: &str, previous_response: &str) -> Result<String, IndyError> {
     _submit_retry(extract_seq_no_from_reply(previous_response).unwrap(), || {
         submit_request(pool_handle, request_json)
@@ -39,10 +44,22 @@ pub fn submit_request(pool_handle: PoolHandle, request_json: &str) -> Result<Str
     ledger::submit_request(pool_handle, request_json).wait()
 }
 
+pub fn submit_request_with_options(pool_handle: PoolHandle, request_json: &str, options_json: &str) -> Result<String, IndyError> {
+    ledger::submit_request_with_options(pool_handle, request_json, options_json).wait()
+}
+
+pub fn submit_write_request(pool_handle: PoolHandle, request_json: &str, options_json: &str) -> Result<String, IndyError> {
+    ledger::submit_write_request(pool_handle, request_json, options_json).wait()
+}
+
 pub fn submit_action(pool_handle: PoolHandle, request_json: &str, nodes: Option<&str>, timeout: Option<i32>) -> Result<String, IndyError> {
     ledger::submit_action(pool_handle, request_json, nodes, timeout).wait()
 }
 
+pub fn submit_action_with_options(pool_handle: PoolHandle, request_json: &str, options_json: &str) -> Result<String, IndyError> {
+    ledger::submit_action_with_options(pool_handle, request_json, options_json).wait()
+}
+
 pub fn sign_request(wallet_handle: WalletHandle, submitter_did: &str, request_json: &str) -> Result<String, IndyError> {
     ledger::sign_request(wallet_handle, submitter_did, request_json).wait()
 }
@@ -85,10 +102,44 @@ pub fn calculate_hash(text: &str, version: &str) -> String {
     hex::encode(digest)
 }
 
+// Digest of the request without signatures which the pool stores as payloadDigest of the transaction.
+// Attribute values aren't hashed before the serialization, so it doesn't fit ATTRIB requests.
+pub fn payload_digest(request_json: &str) -> String {
+    fn _serialize(value: &serde_json::Value, is_top_level: bool) -> String {
+        match value {
+            serde_json::Value::Bool(value) => if *value { "True".to_string() } else { "False".to_string() },
+            serde_json::Value::Number(value) => value.to_string(),
+            serde_json::Value::String(value) => value.clone(),
+            serde_json::Value::Array(array) => array.iter().map(|element| _serialize(element, false)).collect::<Vec<String>>().join(","),
+            serde_json::Value::Object(map) => map.iter()
+                .filter(|(key, _)| !is_top_level || !["signature", "signatures", "fees"].contains(&key.as_str()))
+                .map(|(key, value)| format!("{}:{}", key, _serialize(value, false)))
+                .collect::<Vec<String>>()
+                .join("|"),
+            serde_json::Value::Null => String::new()
+        }
+    }
+
+    let request: serde_json::Value = serde_json::from_str(request_json).unwrap();
+    hex::encode(hash(_serialize(&request, true).as_bytes()).unwrap())
+}
+
 pub fn build_get_ddo_request(submitter_did: Option<&str>, target_did: &str) -> Result<String, IndyError> {
     ledger::build_get_ddo_request(submitter_did, target_did).wait()
 }
 
+pub fn resolve_did(pool_handle: PoolHandle, did: &str, options_json: Option<&str>) -> Result<String, IndyError> {
+    ledger::resolve_did(pool_handle, did, options_json).wait()
+}
+
+pub fn get_ledger_objects(pool_handle: PoolHandle, submitter_did: Option<&str>, ids_json: &str, options_json: Option<&str>) -> Result<String, IndyError> {
+    ledger::get_ledger_objects(pool_handle, submitter_did, ids_json, options_json).wait()
+}
+
+pub fn verify_reply_offline(reply_json: &str, bls_keys_json: &str) -> Result<String, IndyError> {
+    ledger::verify_reply_offline(reply_json, bls_keys_json).wait()
+}
+
 pub fn build_nym_request(submitter_did: &str, target_did: &str, verkey: Option<&str>,
                          alias: Option<&str>, role: Option<&str>) -> Result<String, IndyError> {
     ledger::build_nym_request(submitter_did, target_did, verkey, alias, role).wait()
@@ -138,6 +189,19 @@ pub fn build_get_txn_request(submitter_did: Option<&str>, data: i32, ledger_type
     ledger::build_get_txn_request(submitter_did, ledger_type, data).wait()
 }
 
+pub fn get_txn_range(pool_handle: PoolHandle, submitter_did: Option<&str>, ledger_type: Option<&str>, from: i32, to: Option<i32>) -> Result<String, IndyError> {
+    ledger::get_txn_range(pool_handle, submitter_did, ledger_type, from, to).wait()
+}
+
+pub fn subscribe_ledger(pool_handle: PoolHandle, ledger_type: Option<&str>, from: Option<i32>, options_json: Option<&str>)
+                        -> Result<(CommandHandle, mpsc::UnboundedReceiver<Result<String, IndyError>>), IndyError> {
+    ledger::subscribe_ledger(pool_handle, ledger_type, from, options_json).wait()
+}
+
+pub fn unsubscribe_ledger(subscription_handle: CommandHandle) -> Result<(), IndyError> {
+    ledger::unsubscribe_ledger(subscription_handle).wait()
+}
+
 pub fn build_pool_config_request(submitter_did: &str, writes: bool, force: bool) -> Result<String, IndyError> {
     ledger::build_pool_config_request(submitter_did, writes, force).wait()
 }
@@ -154,6 +218,18 @@ pub fn build_pool_upgrade_request(submitter_did: &str, name: &str, version: &str
                                        timeout, schedule, justification, reinstall, force, package).wait()
 }
 
+pub fn build_ledgers_freeze_request(submitter_did: &str, ledgers_ids: &str) -> Result<String, IndyError> {
+    ledger::build_ledgers_freeze_request(submitter_did, ledgers_ids).wait()
+}
+
+pub fn build_get_frozen_ledgers_request(submitter_did: Option<&str>) -> Result<String, IndyError> {
+    ledger::build_get_frozen_ledgers_request(submitter_did).wait()
+}
+
+pub fn parse_upgrade_status(txns_json: &str) -> Result<String, IndyError> {
+    ledger::parse_upgrade_status(txns_json).wait()
+}
+
 pub fn build_revoc_reg_def_request(submitter_did: &str, data: &str) -> Result<String, IndyError> {
     ledger::build_revoc_reg_def_request(submitter_did, data).wait()
 }
@@ -178,6 +254,22 @@ pub fn parse_get_schema_response(get_schema_response: &str) -> Result<(String, S
     ledger::parse_get_schema_response(get_schema_response).wait()
 }
 
+pub fn build_rich_schema_object_request(submitter_did: &str, rich_schema_object_json: &str) -> Result<String, IndyError> {
+    ledger::build_rich_schema_object_request(submitter_did, rich_schema_object_json).wait()
+}
+
+pub fn build_get_rich_schema_object_by_id_request(submitter_did: Option<&str>, id: &str) -> Result<String, IndyError> {
+    ledger::build_get_rich_schema_object_by_id_request(submitter_did, id).wait()
+}
+
+pub fn build_get_rich_schema_object_by_metadata_request(submitter_did: Option<&str>, rs_type: &str, rs_name: &str, rs_version: &str) -> Result<String, IndyError> {
+    ledger::build_get_rich_schema_object_by_metadata_request(submitter_did, rs_type, rs_name, rs_version).wait()
+}
+
+pub fn parse_get_rich_schema_object_response(get_rich_schema_object_response: &str) -> Result<(String, String), IndyError> {
+    ledger::parse_get_rich_schema_object_response(get_rich_schema_object_response).wait()
+}
+
 pub fn parse_get_cred_def_response(get_cred_def_response: &str) -> Result<(String, String), IndyError> {
     ledger::parse_get_cred_def_response(get_cred_def_response).wait()
 }
@@ -283,6 +375,30 @@ pub fn append_request_endorser(request_json: &str,
     ledger::append_request_endorser(request_json, endorser_did).wait()
 }
 
+pub fn prepare_endorsement(request_json: &str, get_auth_rule_response_json: &str, owner_did: Option<&str>) -> Result<String, IndyError> {
+    ledger::prepare_endorsement(request_json, get_auth_rule_response_json, owner_did).wait()
+}
+
+pub fn endorsement_add_signature(wallet_handle: WalletHandle, endorsement_json: &str, signer_did: &str) -> Result<String, IndyError> {
+    ledger::endorsement_add_signature(wallet_handle, endorsement_json, signer_did).wait()
+}
+
+pub fn endorsement_check_signatures(endorsement_json: &str) -> Result<String, IndyError> {
+    ledger::endorsement_check_signatures(endorsement_json).wait()
+}
+
+pub fn endorsement_status(endorsement_json: &str, get_nym_responses_json: &str) -> Result<String, IndyError> {
+    ledger::endorsement_status(endorsement_json, get_nym_responses_json).wait()
+}
+
+pub fn submit_endorsement(pool_handle: PoolHandle, endorsement_json: &str, get_nym_responses_json: &str) -> Result<String, IndyError> {
+    ledger::submit_endorsement(pool_handle, endorsement_json, get_nym_responses_json).wait()
+}
+
+pub fn evaluate_auth_rule(request_json: &str, auth_action: &str, old_value: Option<&str>, get_auth_rule_response_json: &str, signers_json: &str) -> Result<String, IndyError> {
+    ledger::evaluate_auth_rule(request_json, auth_action, old_value, get_auth_rule_response_json, signers_json).wait()
+}
+
 pub fn post_entities() -> (&'static str, &'static str, &'static str) {
     lazy_static! {
                     static ref COMMON_ENTITIES_INIT: Once = Once::new();
@@ -1,5 +1,6 @@
 extern crate byteorder;
 extern crate futures;
+extern crate openssl;
 extern crate rmp_serde;
 
 use self::byteorder::{LittleEndian, WriteBytesExt};
@@ -110,6 +111,16 @@ pub fn pool_config_json(txn_file_path: &Path) -> String {
     serde_json::to_string(&config).unwrap()
 }
 
+// Merkle root hash of genesis that consists of the single transaction
+pub fn genesis_txn_hash(txn: &str) -> Vec<u8> {
+    let txn_json = serde_json::from_str::<serde_json::Value>(txn).unwrap();
+
+    let mut leaf = vec![0x00];
+    leaf.extend(rmp_serde::to_vec_named(&txn_json).unwrap());
+
+    openssl::sha::sha256(&leaf).to_vec()
+}
+
 pub fn create_pool_ledger_config(pool_name: &str, pool_config: Option<&str>) -> Result<(), IndyError> {
     pool::create_pool_ledger_config(pool_name, pool_config).wait()
 }
@@ -170,10 +181,29 @@ pub fn create_and_open_pool_ledger(pool_name: &str) -> Result<PoolHandle, IndyEr
     open_pool_ledger(pool_name, None)
 }
 
+#[cfg(feature = "in_process_pool")]
+pub fn create_and_open_in_process_pool_ledger(pool_name: &str, mut transport: serde_json::Value) -> Result<PoolHandle, IndyError> {
+    let txn_file_path = create_genesis_txn_file_for_test_pool(pool_name, None, None);
+    let pool_config = pool_config_json(txn_file_path.as_path());
+    create_pool_ledger_config(pool_name, Some(pool_config.as_str()))?;
+
+    transport["type"] = json!("in_process");
+    let config = json!({"transport": transport}).to_string();
+    open_pool_ledger(pool_name, Some(&config))
+}
+
 pub fn refresh(pool_handle: PoolHandle) -> Result<(), IndyError> {
     pool::refresh_pool_ledger(pool_handle).wait()
 }
 
+pub fn get_status(pool_handle: PoolHandle) -> Result<String, IndyError> {
+    pool::get_pool_status(pool_handle).wait()
+}
+
+pub fn get_pool_bls_keys(pool_name: &str) -> Result<String, IndyError> {
+    pool::get_pool_bls_keys(pool_name).wait()
+}
+
 pub fn close(pool_handle: PoolHandle) -> Result<(), IndyError> {
     pool::close_pool_ledger(pool_handle).wait()
 }
@@ -63,6 +63,18 @@ extern {
                                          blob_storage_reader_handle: BlobStorageReaderHandle,
                                          cb: Option<ResponseStringStringStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_issuer_create_credential_with_rollover(command_handle: CommandHandle,
+                                                       wallet_handle: WalletHandle,
+                                                       cred_offer_json: CString,
+                                                       cred_req_json: CString,
+                                                       cred_values_json: CString,
+                                                       rev_reg_id: CString,
+                                                       blob_storage_reader_handle: BlobStorageReaderHandle,
+                                                       tails_writer_handle: TailsWriterHandle,
+                                                       rollover_config_json: CString,
+                                                       cb: Option<ResponseStringStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_issuer_revoke_credential(command_handle: CommandHandle,
                                          wallet_handle: WalletHandle,
@@ -71,6 +83,41 @@ extern {
                                          cred_revoc_id: CString,
                                          cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_issuer_build_revoc_reg_entry_request(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle,
+                                                     submitter_did: CString,
+                                                     rev_reg_id: CString,
+                                                     revoked_json: CString,
+                                                     unrevoked_json: CString,
+                                                     cb: Option<ResponseStringStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_issuer_set_issued_credential_tags(command_handle: CommandHandle,
+                                                  wallet_handle: WalletHandle,
+                                                  rev_reg_id: CString,
+                                                  cred_revoc_id: CString,
+                                                  tags_json: CString,
+                                                  cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_issuer_search_issued_credentials(command_handle: CommandHandle,
+                                                 wallet_handle: WalletHandle,
+                                                 query_json: CString,
+                                                 cb: Option<ResponseI32UsizeCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_issuer_fetch_issued_credentials(command_handle: CommandHandle,
+                                                search_handle: SearchHandle,
+                                                count: usize,
+                                                cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_issuer_close_issued_credentials_search(command_handle: CommandHandle,
+                                                       search_handle: SearchHandle,
+                                                       cb: Option<ResponseEmptyCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_issuer_merge_revocation_registry_deltas(command_handle: CommandHandle,
                                                         rev_reg_delta_json: CString,
@@ -197,6 +244,16 @@ extern {
                                       rev_regs_json: CString,
                                       cb: Option<ResponseBoolCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_verifier_verify_proof_verbose(command_handle: CommandHandle,
+                                              proof_request_json: CString,
+                                              proof_json: CString,
+                                              schemas_json: CString,
+                                              credential_defs_json: CString,
+                                              rev_reg_defs_json: CString,
+                                              rev_regs_json: CString,
+                                              cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_create_revocation_state(command_handle: CommandHandle,
                                         blob_storage_reader_handle: BlobStorageReaderHandle,
@@ -216,6 +273,16 @@ extern {
                                         cred_rev_id: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_prover_create_revocation_states(command_handle: CommandHandle,
+                                                wallet_handle: WalletHandle,
+                                                pool_handle: PoolHandle,
+                                                submitter_did: CString,
+                                                blob_storage_reader_handle: BlobStorageReaderHandle,
+                                                proof_request_json: CString,
+                                                requested_credentials_json: CString,
+                                                cb: Option<ResponseStringStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_generate_nonce(command_handle: CommandHandle,
                                cb: Option<ResponseStringCB>) -> Error;
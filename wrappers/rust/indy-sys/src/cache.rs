@@ -33,4 +33,10 @@ extern {
                                      wallet_handle: WalletHandle,
                                      options_json: CString,
                                      cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_purge_rev_state_cache(command_handle: CommandHandle,
+                                      wallet_handle: WalletHandle,
+                                      options_json: CString,
+                                      cb: Option<ResponseEmptyCB>) -> Error;
 }
@@ -11,12 +11,35 @@ extern {
                                         request_json: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_sign_and_submit_request_with_options(command_handle: CommandHandle,
+                                                     pool_handle: PoolHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     submitter_did: CString,
+                                                     request_json: CString,
+                                                     options_json: CString,
+                                                     cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_submit_request(command_handle: CommandHandle,
                                pool_handle: PoolHandle,
                                request_json: CString,
                                cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_submit_request_with_options(command_handle: CommandHandle,
+                                            pool_handle: PoolHandle,
+                                            request_json: CString,
+                                            options_json: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_submit_write_request(command_handle: CommandHandle,
+                                     pool_handle: PoolHandle,
+                                     request_json: CString,
+                                     options_json: CString,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_submit_action(command_handle: CommandHandle,
                               pool_handle: PoolHandle,
@@ -25,6 +48,13 @@ extern {
                               timeout: Timeout,
                               cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_submit_action_with_options(command_handle: CommandHandle,
+                                           pool_handle: PoolHandle,
+                                           request_json: CString,
+                                           options_json: CString,
+                                           cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_sign_request(command_handle: CommandHandle,
                              wallet_handle: WalletHandle,
@@ -45,6 +75,27 @@ extern {
                                       target_did: CString,
                                       cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_resolve_did(command_handle: CommandHandle,
+                            pool_handle: PoolHandle,
+                            did: CString,
+                            options_json: CString,
+                            cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_get_ledger_objects(command_handle: CommandHandle,
+                                   pool_handle: PoolHandle,
+                                   submitter_did: CString,
+                                   ids_json: CString,
+                                   options_json: CString,
+                                   cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_verify_reply_offline(command_handle: CommandHandle,
+                                     reply_json: CString,
+                                     bls_keys_json: CString,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_build_nym_request(command_handle: CommandHandle,
                                   submitter_did: CString,
@@ -136,6 +187,29 @@ extern {
                                       seq_no: i32,
                                       cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_get_txn_range(command_handle: CommandHandle,
+                              pool_handle: PoolHandle,
+                              submitter_did: CString,
+                              ledger_type: CString,
+                              from: i32,
+                              to: i32,
+                              cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_subscribe_ledger(command_handle: CommandHandle,
+                                 pool_handle: PoolHandle,
+                                 ledger_type: CString,
+                                 from: i32,
+                                 options_json: CString,
+                                 cb: Option<ResponseI32CB>,
+                                 txn_cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_unsubscribe_ledger(command_handle: CommandHandle,
+                                   subscription_handle: CommandHandle,
+                                   cb: Option<ResponseEmptyCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_build_pool_config_request(command_handle: CommandHandle,
                                           submitter_did: CString,
@@ -165,6 +239,22 @@ extern {
                                            package: CString,
                                            cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_build_ledgers_freeze_request(command_handle: CommandHandle,
+                                             submitter_did: CString,
+                                             ledgers_ids: CString,
+                                             cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_build_get_frozen_ledgers_request(command_handle: CommandHandle,
+                                                 submitter_did: CString,
+                                                 cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_parse_upgrade_status(command_handle: CommandHandle,
+                                     txns_json: CString,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_build_revoc_reg_def_request(command_handle: CommandHandle,
                                             submitter_did: CString,
@@ -215,6 +305,31 @@ extern {
                                                    get_revoc_reg_delta_response: CString,
                                                    cb: Option<ResponseStringStringU64CB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_build_rich_schema_object_request(command_handle: CommandHandle,
+                                                 submitter_did: CString,
+                                                 rich_schema_object_json: CString,
+                                                 cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_build_get_rich_schema_object_by_id_request(command_handle: CommandHandle,
+                                                           submitter_did: CString,
+                                                           id: CString,
+                                                           cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_build_get_rich_schema_object_by_metadata_request(command_handle: CommandHandle,
+                                                                 submitter_did: CString,
+                                                                 rs_type: CString,
+                                                                 rs_name: CString,
+                                                                 rs_version: CString,
+                                                                 cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_parse_get_rich_schema_object_response(command_handle: CommandHandle,
+                                                      get_rich_schema_object_response: CString,
+                                                      cb: Option<ResponseStringStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_register_transaction_parser_for_sp(command_handle: CommandHandle,
                                                    txn_type: CString,
@@ -305,6 +420,47 @@ extern {
                                         request_json: CString,
                                         endorser_did: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_prepare_endorsement(command_handle: CommandHandle,
+                                    request_json: CString,
+                                    get_auth_rule_response_json: CString,
+                                    owner_did: CString,
+                                    cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_endorsement_add_signature(command_handle: CommandHandle,
+                                          wallet_handle: WalletHandle,
+                                          endorsement_json: CString,
+                                          signer_did: CString,
+                                          cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_endorsement_check_signatures(command_handle: CommandHandle,
+                                             endorsement_json: CString,
+                                             cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_endorsement_status(command_handle: CommandHandle,
+                                   endorsement_json: CString,
+                                   get_nym_responses_json: CString,
+                                   cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_submit_endorsement(command_handle: CommandHandle,
+                                   pool_handle: PoolHandle,
+                                   endorsement_json: CString,
+                                   get_nym_responses_json: CString,
+                                   cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_evaluate_auth_rule(command_handle: CommandHandle,
+                                   request_json: CString,
+                                   auth_action: CString,
+                                   old_value: CString,
+                                   get_auth_rule_response_json: CString,
+                                   signers_json: CString,
+                                   cb: Option<ResponseStringCB>) -> Error;
 }
 
 pub type CustomTransactionParser = extern fn(reply_from_node: CString, parsed_sp: *mut CString) -> Error;
@@ -21,6 +21,16 @@ extern {
                                     handle: PoolHandle,
                                     cb: Option<ResponseEmptyCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_get_pool_status(command_handle: CommandHandle,
+                                handle: PoolHandle,
+                                cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_get_pool_bls_keys(command_handle: CommandHandle,
+                                  config_name: CString,
+                                  cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_list_pools(command_handle: CommandHandle,
                            cb: Option<ResponseStringCB>) -> Error;
@@ -15,7 +15,7 @@ use ffi::{ResponseStringStringCB,
           ResponseI32CB,
           ResponseEmptyCB,
           ResponseBoolCB};
-use {CommandHandle, WalletHandle, SearchHandle, BlobStorageReaderHandle, TailsWriterHandle};
+use {CommandHandle, WalletHandle, PoolHandle, SearchHandle, BlobStorageReaderHandle, TailsWriterHandle};
 use ffi::BlobStorageReaderCfgHandle;
 
 /*
@@ -406,6 +406,82 @@ fn _issuer_create_credential(
     })
 }
 
+/// Issue Credential for the given Cred Request as `issuer_create_credential` does
+/// and roll the revocation registry over once it fills up.
+///
+/// When issuing the credential makes the utilization of the registry cross the threshold the next registry is created
+/// and stored into the wallet before the credential is issued. It's linked to the current one along with the issuance,
+/// so the failed call issues nothing and can be retried. Subsequent calls with the id of the original registry
+/// transparently issue credentials from the next registry once the current one is full.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `cred_offer_json`: a cred offer created by create_credential_offer
+/// * `cred_req_json`: a credential request created by store_credential
+/// * `cred_values_json`: a credential containing attribute values for each of requested attribute names.
+/// * `rev_reg_id`: id of revocation registry stored in the wallet
+/// * `blob_storage_reader_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+/// * `tails_writer_handle`: handle of blob storage to store tails of the next registry
+/// * `rollover_config_json`: rollover configuration json.
+///     {
+///         "threshold": float, - (optional, 0.9 by default) share of the used registry indices (0, 1] triggering the rollover
+///         "max_cred_num": int, - (optional, the same as the current registry by default) maximum number of credentials the next registry can process
+///         "tag": string, - (optional) tag of the next registry
+///     }
+///
+/// # Returns
+/// * `cred_json`: Credential json containing signed credential values
+/// * `issuance_json`: Issuance details
+///     {
+///         "rev_reg_id": string, - id of the revocation registry the credential has been issued from
+///         "cred_rev_id": string, - local id for revocation info
+///         "rev_reg_delta": <revoc_reg_delta_json>, - Revocation registry delta json with a newly issued credential
+///         "rollover": { - (optional) the registry created by this call
+///             "rev_reg_id": string,
+///             "rev_reg_def_request": <request_json>, - REVOC_REG_DEF request to publish the next registry
+///             "rev_reg_entry_request": <request_json>, - REVOC_REG_ENTRY request with the initial state of the next registry
+///         }
+///     }
+pub fn issuer_create_credential_with_rollover(wallet_handle: WalletHandle,
+                                              cred_offer_json: &str,
+                                              cred_req_json: &str,
+                                              cred_values_json: &str,
+                                              rev_reg_id: &str,
+                                              blob_storage_reader_handle: BlobStorageReaderHandle,
+                                              tails_writer_handle: TailsWriterHandle,
+                                              rollover_config_json: &str) -> Box<dyn Future<Item=(String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string();
+
+    let err = _issuer_create_credential_with_rollover(command_handle, wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id,
+                                                      blob_storage_reader_handle, tails_writer_handle, rollover_config_json, cb);
+
+    ResultHandler::str_str(command_handle, err, receiver)
+}
+
+fn _issuer_create_credential_with_rollover(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    cred_offer_json: &str,
+    cred_req_json: &str,
+    cred_values_json: &str,
+    rev_reg_id: &str,
+    blob_storage_reader_handle: BlobStorageReaderHandle,
+    tails_writer_handle: TailsWriterHandle,
+    rollover_config_json: &str,
+    cb: Option<ResponseStringStringCB>
+) -> ErrorCode {
+    let cred_offer_json = c_str!(cred_offer_json);
+    let cred_req_json = c_str!(cred_req_json);
+    let cred_values_json = c_str!(cred_values_json);
+    let rev_reg_id = c_str!(rev_reg_id);
+    let rollover_config_json = c_str!(rollover_config_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_create_credential_with_rollover(command_handle, wallet_handle, cred_offer_json.as_ptr(), cred_req_json.as_ptr(), cred_values_json.as_ptr(),
+                                                               rev_reg_id.as_ptr(), blob_storage_reader_handle, tails_writer_handle, rollover_config_json.as_ptr(), cb)
+    })
+}
+
 /// Revoke a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
 ///
 /// The corresponding credential definition and revocation registry must be already
@@ -444,6 +520,153 @@ fn _issuer_revoke_credential(command_handle: CommandHandle,
     })
 }
 
+/// Revoke and unrevoke a set of credentials identified by their cred_revoc_ids (returned by indy_issuer_create_credential)
+/// and build REVOC_REG_ENTRY transaction publishing all the changes at once.
+///
+/// The revocation registry stored in the wallet is updated only if all the indices are processed successfully.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `blob_storage_reader_cfg_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+/// * `submitter_did`: DID of the submitter stored in secured Wallet.
+/// * `rev_reg_id`: id of revocation registry stored in wallet
+/// * `revoked_json`: array of local ids of the credentials to revoke
+/// * `unrevoked_json`: array of local ids of the credentials to unrevoke
+///
+/// # Returns
+/// * `rev_reg_entry_request_json`: REVOC_REG_ENTRY request ready to be signed
+/// * `revoc_reg_delta_json`: Revocation registry delta json with all the changes applied
+pub fn issuer_build_revoc_reg_entry_request(wallet_handle: WalletHandle, blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle, submitter_did: &str, rev_reg_id: &str, revoked_json: &str, unrevoked_json: &str) -> Box<dyn Future<Item=(String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string();
+
+    let err = _issuer_build_revoc_reg_entry_request(command_handle, wallet_handle, blob_storage_reader_cfg_handle, submitter_did, rev_reg_id, revoked_json, unrevoked_json, cb);
+
+    ResultHandler::str_str(command_handle, err, receiver)
+}
+
+fn _issuer_build_revoc_reg_entry_request(command_handle: CommandHandle,
+                                         wallet_handle: WalletHandle,
+                                         blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle,
+                                         submitter_did: &str,
+                                         rev_reg_id: &str,
+                                         revoked_json: &str,
+                                         unrevoked_json: &str,
+                                         cb: Option<ResponseStringStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let rev_reg_id = c_str!(rev_reg_id);
+    let revoked_json = c_str!(revoked_json);
+    let unrevoked_json = c_str!(unrevoked_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_build_revoc_reg_entry_request(command_handle, wallet_handle, blob_storage_reader_cfg_handle, submitter_did.as_ptr(), rev_reg_id.as_ptr(), revoked_json.as_ptr(), unrevoked_json.as_ptr(), cb)
+    })
+}
+
+/// Set tags of the credential issued from the revocation registry.
+///
+/// Issued credentials are recorded with `cred_def_id`, `rev_reg_id`, `cred_rev_id`, `~issuance_time` and `~revoked` tags.
+/// This call replaces the tags set by the Issuer keeping the ones listed above.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `rev_reg_id`: id of revocation registry stored in wallet
+/// * `cred_revoc_id`: local id for revocation info (returned by issuer_create_credential)
+/// * `tags_json`: tags to set to the issued credential record
+///     {
+///         "tagName1": <str>,
+///         ...
+///     }
+pub fn issuer_set_issued_credential_tags(wallet_handle: WalletHandle, rev_reg_id: &str, cred_revoc_id: &str, tags_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _issuer_set_issued_credential_tags(command_handle, wallet_handle, rev_reg_id, cred_revoc_id, tags_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _issuer_set_issued_credential_tags(command_handle: CommandHandle, wallet_handle: WalletHandle, rev_reg_id: &str, cred_revoc_id: &str, tags_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let rev_reg_id = c_str!(rev_reg_id);
+    let cred_revoc_id = c_str!(cred_revoc_id);
+    let tags_json = c_str!(tags_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_set_issued_credential_tags(command_handle, wallet_handle, rev_reg_id.as_ptr(), cred_revoc_id.as_ptr(), tags_json.as_ptr(), cb)
+    })
+}
+
+/// Search for the credentials issued from the revocation registries of the Issuer.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `query_json`: (Optional) Wql query filter for the issued credentials by their tags
+///
+/// # Returns
+/// * `search_handle`: Search handle that can be used later to fetch records by small batches (with issuer_fetch_issued_credentials)
+/// * `total_count`: Total count of records
+pub fn issuer_search_issued_credentials(wallet_handle: WalletHandle, query_json: Option<&str>) -> Box<dyn Future<Item=(SearchHandle, usize), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_handle_usize();
+
+    let err = _issuer_search_issued_credentials(command_handle, wallet_handle, query_json, cb);
+
+    ResultHandler::handle_usize(command_handle, err, receiver)
+}
+
+fn _issuer_search_issued_credentials(command_handle: CommandHandle, wallet_handle: WalletHandle, query_json: Option<&str>, cb: Option<ResponseI32UsizeCB>) -> ErrorCode {
+    let query_json_str = opt_c_str!(query_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_search_issued_credentials(command_handle, wallet_handle, opt_c_ptr!(query_json, query_json_str), cb)
+    })
+}
+
+/// Fetch next issued credentials for search.
+///
+/// # Arguments
+/// * `search_handle`: Search handle (created by issuer_search_issued_credentials)
+/// * `count`: Count of issued credentials to fetch
+///
+/// # Returns
+/// * `issued_credentials_json` - list of the issued credentials
+///     [{
+///         "cred_def_id": string,
+///         "rev_reg_id": string,
+///         "cred_rev_id": string,
+///         "issuance_time": number - seconds since Unix Epoch,
+///         "revoked": bool,
+///         "tags": {tag name: tag value} - tags set by the Issuer
+///     }]
+pub fn issuer_fetch_issued_credentials(search_handle: SearchHandle, count: usize) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_fetch_issued_credentials(command_handle, search_handle, count, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_fetch_issued_credentials(command_handle: CommandHandle, search_handle: SearchHandle, count: usize, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_fetch_issued_credentials(command_handle, search_handle, count, cb)
+    })
+}
+
+/// Close issued credentials search (make search handle invalid)
+///
+/// # Arguments
+/// * `search_handle`: Search handle (created by issuer_search_issued_credentials)
+pub fn issuer_close_issued_credentials_search(search_handle: SearchHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _issuer_close_issued_credentials_search(command_handle, search_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _issuer_close_issued_credentials_search(command_handle: CommandHandle, search_handle: SearchHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_close_issued_credentials_search(command_handle, search_handle, cb)
+    })
+}
+
 /// Merge two revocation registry deltas (returned by create_credential or revoke_credential) to accumulate common delta.
 /// Send common delta to ledger to reduce the load.
 ///
@@ -1345,6 +1568,62 @@ fn _verifier_verify_proof(command_handle: CommandHandle, proof_request_json: &st
     })
 }
 
+/// Verifies a proof as `verifier_verify_proof` does and explains the result per referent of the proof request.
+///
+/// # Arguments
+/// * `proof_request_json`: proof request json (see `verifier_verify_proof`)
+/// * `proof_json`: created for request proof json
+/// * `schemas_json`: all schemas participating in the proof
+/// * `credential_defs_json`: all credential definitions participating in the proof
+/// * `rev_reg_defs_json`: all revocation registry definitions participating in the proof
+/// * `rev_regs_json`: all revocation registries participating in the proof
+///
+/// # Returns
+/// * `report_json`: verification report
+///     {
+///         "valid": bool, - the same result as `verifier_verify_proof` returns
+///         "error": Optional<string>, - the error failing the verification of the requested proof before the cryptographic one
+///         "proof": <check_result>, - result of the cryptographic proof verification
+///         "requested_attributes": {
+///             "<attr_referent>": {
+///                 "sub_proof_index": Optional<int>,
+///                 "self_attested": bool,
+///                 "restrictions": [{"restriction": <wql query>, "result": <check_result>}, ...],
+///                 "encoding": <check_result>,
+///                 "non_revoked": <check_result>,
+///             },
+///         },
+///         "requested_predicates": {
+///             "<predicate_referent>": {
+///                 "sub_proof_index": Optional<int>,
+///                 "restrictions": [{"restriction": <wql query>, "result": <check_result>}, ...],
+///                 "non_revoked": <check_result>,
+///                 "predicate": <check_result>,
+///             },
+///         },
+///     }
+/// where check_result: {"status": "passed" | "failed" | "skipped", "reason": Optional<string>}
+pub fn verifier_verify_proof_verbose(proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verifier_verify_proof_verbose(command_handle, proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verifier_verify_proof_verbose(command_handle: CommandHandle, proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+    let proof_json = c_str!(proof_json);
+    let schemas_json = c_str!(schemas_json);
+    let credential_defs_json = c_str!(credential_defs_json);
+    let rev_reg_defs_json = c_str!(rev_reg_defs_json);
+    let rev_regs_json = c_str!(rev_regs_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_verify_proof_verbose(command_handle, proof_request_json.as_ptr(), proof_json.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_reg_defs_json.as_ptr(), rev_regs_json.as_ptr(), cb)
+    })
+}
+
 
 /// Create revocation state for a credential that corresponds to a particular time.
 ///
@@ -1431,6 +1710,41 @@ fn _update_revocation_state(command_handle: CommandHandle, blob_storage_reader_h
     })
 }
 
+/// Create revocation states required to build the proof for the proof request.
+///
+/// The states are built from the revocation registry definitions and deltas read from the ledger
+/// and cached in the wallet; the latest cached state is updated with the newer delta instead of being recalculated.
+/// Use `cache::purge_rev_state_cache` to clean the cache.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `pool_handle`: pool handle (created by Pool::open_pool_ledger).
+/// * `submitter_did`: (Optional) DID of the read request sender.
+/// * `blob_storage_reader_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+/// * `proof_request_json`: proof request json
+/// * `requested_credentials_json`: requested credentials json, `timestamp` fields can be omitted
+///
+/// # Returns
+/// * `rev_states_json`: revocation states to pass to `prover_create_proof`
+/// * `requested_credentials_json`: requested credentials json with `timestamp` of the revocation states set
+pub fn prover_create_revocation_states(wallet_handle: WalletHandle, pool_handle: PoolHandle, submitter_did: Option<&str>, blob_storage_reader_handle: BlobStorageReaderHandle, proof_request_json: &str, requested_credentials_json: &str) -> Box<dyn Future<Item=(String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string();
+
+    let err = _prover_create_revocation_states(command_handle, wallet_handle, pool_handle, submitter_did, blob_storage_reader_handle, proof_request_json, requested_credentials_json, cb);
+
+    ResultHandler::str_str(command_handle, err, receiver)
+}
+
+fn _prover_create_revocation_states(command_handle: CommandHandle, wallet_handle: WalletHandle, pool_handle: PoolHandle, submitter_did: Option<&str>, blob_storage_reader_handle: BlobStorageReaderHandle, proof_request_json: &str, requested_credentials_json: &str, cb: Option<ResponseStringStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let proof_request_json = c_str!(proof_request_json);
+    let requested_credentials_json = c_str!(requested_credentials_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_create_revocation_states(command_handle, wallet_handle, pool_handle, opt_c_ptr!(submitter_did, submitter_did_str), blob_storage_reader_handle, proof_request_json.as_ptr(), requested_credentials_json.as_ptr(), cb)
+    })
+}
+
 /// Generates 80-bit numbers that can be used as a nonce for proof request.
 ///
 /// # Arguments
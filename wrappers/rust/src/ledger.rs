@@ -4,15 +4,20 @@ use std::ffi::CString;
 use std::ptr::null;
 
 use futures::Future;
+use futures::sync::mpsc;
 
 use ffi::ledger;
-use ffi::{ResponseStringCB,
+use ffi::{ResponseEmptyCB,
+          ResponseI32CB,
+          ResponseStringCB,
           ResponseStringStringCB,
           ResponseStringStringU64CB};
 
 use utils::callbacks::{ClosureHandler, ResultHandler};
 use {WalletHandle, CommandHandle, PoolHandle};
 
+pub mod response;
+
 /// Signs and submits request message to validator pool.
 ///
 /// Adds submitter information to passed request json, signs it with submitter
@@ -49,6 +54,45 @@ fn _sign_and_submit_request(command_handle: CommandHandle, pool_handle: PoolHand
     })
 }
 
+/// Signs and submits request message to validator pool with options overriding the pool defaults for this request.
+///
+/// Adds submitter information to passed request json, signs it with submitter
+/// sign key (see Crypto::sign), and sends signed request message
+/// to validator pool (see submit_request_with_options).
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `wallet_handle` - wallet handle (created by Wallet::open).
+/// * `submitter_did` - Id of Identity stored in secured Wallet.
+/// * `request_json` - Request data json.
+/// * `options_json` - Request options json (see submit_request_with_options).
+///
+/// # Returns
+/// Request result as json.
+pub fn sign_and_submit_request_with_options(pool_handle: PoolHandle, wallet_handle: WalletHandle, submitter_did: &str, request_json: &str, options_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _sign_and_submit_request_with_options(command_handle, pool_handle, wallet_handle, submitter_did, request_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _sign_and_submit_request_with_options(command_handle: CommandHandle, pool_handle: PoolHandle, wallet_handle: WalletHandle, submitter_did: &str, request_json: &str, options_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let request_json = c_str!(request_json);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_sign_and_submit_request_with_options(command_handle,
+                                                          pool_handle,
+                                                          wallet_handle,
+                                                          submitter_did.as_ptr(),
+                                                          request_json.as_ptr(),
+                                                          options_json.as_ptr(),
+                                                          cb)
+    })
+}
+
 /// Publishes request message to validator pool (no signing, unlike sign_and_submit_request).
 ///
 /// The request is sent to the validator pool as is. It's assumed that it's already prepared.
@@ -73,6 +117,80 @@ fn _submit_request(command_handle: CommandHandle, pool_handle: PoolHandle, reque
     ErrorCode::from(unsafe { ledger::indy_submit_request(command_handle, pool_handle, request_json.as_ptr(), cb) })
 }
 
+/// Publishes request message to validator pool with options overriding the pool defaults for this request.
+///
+/// The request is sent to the validator pool as is. It's assumed that it's already prepared.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `request_json` - Request data json.
+/// * `options_json` - Request options json.
+/// {
+///     "read_consensus": string (optional) - consensus policy for read requests (pool default is used if not set):
+///         "fast" - first reply with verified state proof or f+1 matching replies
+///         "state_proof" - only reply with verified state proof is accepted
+///         "consensus" - f+1 matching replies are required
+///         "all" - request is sent to all nodes, returns the reply if all nodes replied the same or the diff otherwise:
+///             {"op": "REPLY_DIFF", "replies": [{"nodes": [<node alias>], "reply": <reply json>}], "timeout_nodes": [<node alias>]}
+///     "require_state_proof": bool (optional) - accept only replies with verified state proof (same as "state_proof" read consensus)
+///     "timeout": int (optional) - time to wait for the node reply or REQACK (in sec), pool "timeout" if not set
+///     "extended_timeout": int (optional) - time to wait for the node reply after REQACK (in sec), pool "extended_timeout" if not set
+///     "preferred_nodes": [<node alias>] (optional) - nodes asked first by the requests sent to one node at a time
+///     "excluded_nodes": [<node alias>] (optional) - nodes the request is never sent to
+///     "retries": int (optional, 0 by default) - how many times the write request is resent if consensus wasn't reached because of timeouts
+/// }
+///
+/// # Returns
+/// Request result as json.
+pub fn submit_request_with_options(pool_handle: PoolHandle, request_json: &str, options_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_request_with_options(command_handle, pool_handle, request_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_request_with_options(command_handle: CommandHandle, pool_handle: PoolHandle, request_json: &str, options_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe { ledger::indy_submit_request_with_options(command_handle, pool_handle, request_json.as_ptr(), options_json.as_ptr(), cb) })
+}
+
+/// Publishes write request message to validator pool and reports whether it was written.
+///
+/// The request is sent to the validator pool as is. It's assumed that it's already prepared and signed.
+/// If the consensus wasn't reached because of timeouts the same request is resent to the pool.
+/// Concurrent submissions of the same request to the same pool are joined and get the same outcome.
+/// If the consensus still wasn't reached, NYM, SCHEMA and CRED_DEF requests are checked by reading
+/// the written object and its transaction from the ledger.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `request_json` - Request data json.
+/// * `options_json` - Request options json (see submit_request_with_options), "retries" is 1 by default.
+///
+/// # Returns
+/// Write outcome json:
+/// {
+///     "status": "committed" | "rejected" | "not_written" | "unknown",
+///     "reply": <reply json> or null - pool reply the status is based on
+/// }
+pub fn submit_write_request(pool_handle: PoolHandle, request_json: &str, options_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_write_request(command_handle, pool_handle, request_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_write_request(command_handle: CommandHandle, pool_handle: PoolHandle, request_json: &str, options_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe { ledger::indy_submit_write_request(command_handle, pool_handle, request_json.as_ptr(), options_json.as_ptr(), cb) })
+}
+
 pub fn submit_action(pool_handle: PoolHandle, request_json: &str, nodes: Option<&str>, wait_timeout: Option<i32>) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
@@ -90,6 +208,37 @@ fn _submit_action(command_handle: CommandHandle, pool_handle: PoolHandle, reques
     })
 }
 
+/// Send action to validator pool with options overriding the pool defaults for this request.
+///
+/// The list of requests can be send:
+///     POOL_RESTART
+///     GET_VALIDATOR_INFO
+///
+/// The request is sent to the nodes as is. It's assumed that it's already prepared.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `request_json` - Request data json.
+/// * `options_json` - Request options json (see submit_request_with_options).
+///     The action is sent to "preferred_nodes" only (all nodes if not set) except "excluded_nodes".
+///
+/// # Returns
+/// Request result as json.
+pub fn submit_action_with_options(pool_handle: PoolHandle, request_json: &str, options_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_action_with_options(command_handle, pool_handle, request_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_action_with_options(command_handle: CommandHandle, pool_handle: PoolHandle, request_json: &str, options_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe { ledger::indy_submit_action_with_options(command_handle, pool_handle, request_json.as_ptr(), options_json.as_ptr(), cb) })
+}
+
 /// Signs request message.
 ///
 /// Adds submitter information to passed request json, signs it with submitter
@@ -167,6 +316,110 @@ fn _build_get_ddo_request(command_handle: CommandHandle, submitter_did: Option<&
     ErrorCode::from(unsafe { ledger::indy_build_get_ddo_request(command_handle, opt_c_ptr!(submitter_did, submitter_did_str), target_did.as_ptr(), cb) })
 }
 
+/// Resolves `did:sov` DID into W3C DID document built from its NYM and raw `endpoint` attribute.
+///
+/// By default only the replies with verified state proof are accepted.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `did` - DID to resolve, either unqualified or qualified with `sov` method.
+/// * `options_json` - (Optional) Request options json (see submit_request_with_options).
+///     If neither "read_consensus" nor "require_state_proof" is set, "require_state_proof" is true.
+///
+/// # Returns
+/// DID document json:
+/// {
+///     "@context": "https://www.w3.org/ns/did/v1",
+///     "id": "did:sov:<did>",
+///     "verificationMethod": [{"id": "did:sov:<did>#key-1", "type": "Ed25519VerificationKey2018",
+///                             "controller": "did:sov:<did>", "publicKeyBase58": <full verkey>}],
+///     "authentication": ["did:sov:<did>#key-1"],
+///     "service": [{"id": "did:sov:<did>#endpoint", "type": "endpoint", "serviceEndpoint": <endpoint>,
+///                  "routingKeys": [<verkey>]}] // omitted if DID has no endpoint
+/// }
+pub fn resolve_did(pool_handle: PoolHandle, did: &str, options_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _resolve_did(command_handle, pool_handle, did, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _resolve_did(command_handle: CommandHandle, pool_handle: PoolHandle, did: &str, options_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe { ledger::indy_resolve_did(command_handle, pool_handle, did.as_ptr(), opt_c_ptr!(options_json, options_json_str), cb) })
+}
+
+/// Reads many schemas, credential definitions and revocation registry definitions from the ledger in one call.
+///
+/// The requests are processed concurrently, the error of the single read is reported for its id
+/// instead of failing the whole batch.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `submitter_did` - (Optional) DID of the read request sender.
+/// * `ids_json` - ids of the objects: {"schemas": [id], "cred_defs": [id], "rev_reg_defs": [id]}
+/// * `options_json` - (Optional) Request options json (see submit_request_with_options) applied to every request.
+///
+/// # Returns
+/// Ledger objects json: {"schemas": {id: {"object": json} or {"error": {"code": int, "message": string}}}, "cred_defs": {...}, "rev_reg_defs": {...}}
+pub fn get_ledger_objects(pool_handle: PoolHandle, submitter_did: Option<&str>, ids_json: &str, options_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_ledger_objects(command_handle, pool_handle, submitter_did, ids_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_ledger_objects(command_handle: CommandHandle, pool_handle: PoolHandle, submitter_did: Option<&str>, ids_json: &str, options_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let ids_json = c_str!(ids_json);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_get_ledger_objects(command_handle,
+                                        pool_handle,
+                                        opt_c_ptr!(submitter_did, submitter_did_str),
+                                        ids_json.as_ptr(),
+                                        opt_c_ptr!(options_json, options_json_str),
+                                        cb)
+    })
+}
+
+/// Verifies a saved ledger reply offline against a pinned set of pool nodes BLS keys.
+///
+/// Note: freshness of the reply isn't checked, use returned timestamp to decide on it.
+///
+/// # Arguments
+/// * `reply_json` - saved reply of a read request containing state proof.
+/// * `bls_keys_json` - BLS keys of all pool validator nodes (see pool::get_pool_bls_keys):
+///     {
+///         <node alias>: <base58 BLS key> or null
+///     }
+///
+/// # Returns
+/// Verification result json:
+/// {
+///     "verified": bool - whether the reply data is proved by the multi-signature
+///     "timestamp": int or null - unix time (in seconds) the multi-signature was made at, if verified
+/// }
+pub fn verify_reply_offline(reply_json: &str, bls_keys_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verify_reply_offline(command_handle, reply_json, bls_keys_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verify_reply_offline(command_handle: CommandHandle, reply_json: &str, bls_keys_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let reply_json = c_str!(reply_json);
+    let bls_keys_json = c_str!(bls_keys_json);
+
+    ErrorCode::from(unsafe { ledger::indy_verify_reply_offline(command_handle, reply_json.as_ptr(), bls_keys_json.as_ptr(), cb) })
+}
+
 /// Builds a NYM request. Request to create a new NYM record for a specific user.
 ///
 /// # Arguments
@@ -281,7 +534,8 @@ fn _parse_get_nym_response(command_handle: CommandHandle, get_nym_response: &str
 /// * `ledger_type` - (Optional) type of the ledger the requested transaction belongs to:
 ///     DOMAIN - used default,
 ///     POOL,
-///     CONFIG
+///     CONFIG,
+///     AUDIT
 /// * `seq_no` - seq_no of transaction in ledger.
 ///
 /// # Returns
@@ -609,6 +863,116 @@ fn _build_get_validator_info_request(command_handle: CommandHandle, submitter_di
     })
 }
 
+/// Fetches a range of transactions from the ledger by GET_TXN requests and verifies them.
+///
+/// Every transaction is checked against the root of the ledger by its audit path.
+/// All transactions of the page are verified against the same root, which must be signed by a valid
+/// BLS multi-signature of the pool nodes (their BLS keys are taken from the local pool ledger).
+/// Replies without the multi-signature are rejected as unverified.
+/// At most 100 transactions are returned at once, use `next` to fetch the rest of the range.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// * `ledger_type` - (Optional) type of the ledger the transactions belong to:
+///     DOMAIN - used default,
+///     POOL,
+///     CONFIG,
+///     AUDIT
+/// * `from` - seq_no of the first transaction of the range (starting from 1).
+/// * `to` - (Optional) seq_no of the last transaction of the range (inclusive), up to the end of the ledger if not set.
+///
+/// # Returns
+/// Page of the range as json:
+/// {
+///     "txns": [<GET_TXN reply data>],
+///     "ledger_size": int or null,
+///     "root_hash": string or null,
+///     "next": int or null - seq_no to pass as `from` to fetch the next page
+/// }
+pub fn get_txn_range(pool_handle: PoolHandle, submitter_did: Option<&str>, ledger_type: Option<&str>, from: i32, to: Option<i32>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_txn_range(command_handle, pool_handle, submitter_did, ledger_type, from, to, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_txn_range(command_handle: CommandHandle, pool_handle: PoolHandle, submitter_did: Option<&str>, ledger_type: Option<&str>, from: i32, to: Option<i32>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let ledger_type_str = opt_c_str!(ledger_type);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_get_txn_range(command_handle, pool_handle, opt_c_ptr!(submitter_did, submitter_did_str), opt_c_ptr!(ledger_type, ledger_type_str), from, to.unwrap_or(-1), cb)
+    })
+}
+
+/// Subscribes to transactions newly committed to the ledger.
+///
+/// The ledger is periodically caught up with the pool by LEDGER_STATUS and CATCHUP_REQ requests and
+/// every new transaction is verified against the root of the ledger agreed by f+1 nodes
+/// before it is sent to the stream.
+/// Errors of the sync are sent to the stream too, the sync is retried on the next interval.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `ledger_type` - (Optional) type of the ledger to subscribe to:
+///     DOMAIN - used default,
+///     CONFIG,
+///     AUDIT
+/// * `from` - (Optional) seq_no of the first transaction to report,
+///     only transactions committed after the subscription are reported if not set.
+/// * `options_json` - (Optional) options of the subscription:
+/// {
+///     "interval": int (optional) - interval between syncs with the ledger (in sec), 10 by default.
+/// }
+///
+/// # Returns
+/// Subscription handle (to be passed to `unsubscribe_ledger`) and the stream of newly committed
+/// transactions as they are stored on the ledger.
+pub fn subscribe_ledger(pool_handle: PoolHandle, ledger_type: Option<&str>, from: Option<i32>, options_json: Option<&str>)
+                        -> Box<dyn Future<Item=(CommandHandle, mpsc::UnboundedReceiver<Result<String, IndyError>>), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_handle();
+    let (txn_receiver, txn_cb) = ClosureHandler::cb_ec_string_stream(command_handle);
+
+    let err = _subscribe_ledger(command_handle, pool_handle, ledger_type, from, options_json, cb, txn_cb);
+
+    Box::new(ResultHandler::handle(command_handle, err, receiver)
+        .map(move |subscription_handle| (subscription_handle, txn_receiver))
+        .map_err(move |err| {
+            ClosureHandler::drop_stream(command_handle);
+            err
+        }))
+}
+
+fn _subscribe_ledger(command_handle: CommandHandle, pool_handle: PoolHandle, ledger_type: Option<&str>, from: Option<i32>, options_json: Option<&str>,
+                     cb: Option<ResponseI32CB>, txn_cb: Option<ResponseStringCB>) -> ErrorCode {
+    let ledger_type_str = opt_c_str!(ledger_type);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_subscribe_ledger(command_handle, pool_handle, opt_c_ptr!(ledger_type, ledger_type_str), from.unwrap_or(-1), opt_c_ptr!(options_json, options_json_str), cb, txn_cb)
+    })
+}
+
+/// Cancels the subscription to newly committed transactions of the ledger.
+///
+/// # Arguments
+/// * `subscription_handle` - subscription handle (returned by `subscribe_ledger`).
+pub fn unsubscribe_ledger(subscription_handle: CommandHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _unsubscribe_ledger(command_handle, subscription_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _unsubscribe_ledger(command_handle: CommandHandle, subscription_handle: CommandHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        ledger::indy_unsubscribe_ledger(command_handle, subscription_handle, cb)
+    })
+}
+
 /// Builds a POOL_CONFIG request. Request to change Pool's configuration.
 ///
 /// # Arguments
@@ -746,6 +1110,78 @@ fn _build_pool_upgrade_request(command_handle: CommandHandle,
     })
 }
 
+/// Builds a LEDGERS_FREEZE request. Request to freeze list of ledgers (sent by Trustee).
+///
+/// # Arguments
+/// * `submitter_did` - Identifier (DID) of the transaction author as base58-encoded string.
+/// * `ledgers_ids` - non-empty list of ids of the ledgers to freeze as json array of integers.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_ledgers_freeze_request(submitter_did: &str, ledgers_ids: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_ledgers_freeze_request(command_handle, submitter_did, ledgers_ids, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_ledgers_freeze_request(command_handle: CommandHandle, submitter_did: &str, ledgers_ids: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let ledgers_ids = c_str!(ledgers_ids);
+
+    ErrorCode::from(unsafe { ledger::indy_build_ledgers_freeze_request(command_handle, submitter_did.as_ptr(), ledgers_ids.as_ptr(), cb) })
+}
+
+/// Builds a GET_FROZEN_LEDGERS request. Request to get list of frozen ledgers.
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_frozen_ledgers_request(submitter_did: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_frozen_ledgers_request(command_handle, submitter_did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_frozen_ledgers_request(command_handle: CommandHandle, submitter_did: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+
+    ErrorCode::from(unsafe { ledger::indy_build_get_frozen_ledgers_request(command_handle, opt_c_ptr!(submitter_did, submitter_did_str), cb) })
+}
+
+/// Parses the status of the pool upgrade from the transactions of the config ledger.
+///
+/// # Arguments
+/// * `txns_json` - json array of config ledger transactions (as in GET_TXN reply data).
+///
+/// # Returns
+/// Upgrade status json:
+/// {
+///     "pool_upgrade": {"name": string, "version": string, "action": string, "schedule": {<node did>: <time>} or null,
+///                      "seq_no": int or null, "txn_time": int or null} or null,
+///     "nodes": {
+///         <node did>: {"action": string, "version": string, "seq_no": int or null, "txn_time": int or null}
+///     }
+/// }
+pub fn parse_upgrade_status(txns_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_upgrade_status(command_handle, txns_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_upgrade_status(command_handle: CommandHandle, txns_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let txns_json = c_str!(txns_json);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_upgrade_status(command_handle, txns_json.as_ptr(), cb) })
+}
+
 /// Builds a REVOC_REG_DEF request. Request to add the definition of revocation registry
 /// to an exists credential definition.
 ///
@@ -994,6 +1430,125 @@ fn _parse_get_revoc_reg_delta_response(command_handle: CommandHandle, get_revoc_
     ErrorCode::from(unsafe { ledger::indy_parse_get_revoc_reg_delta_response(command_handle, get_revoc_reg_delta_response.as_ptr(), cb) })
 }
 
+/// Builds a Rich Schema object request. Request to add one of the JSON-LD based Rich Schema objects:
+/// context, rich schema, encoding, mapping, credential definition or presentation definition.
+///
+/// # Arguments
+/// * `submitter_did` - Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `append_request_endorser`)
+/// * `rich_schema_object_json` - Rich Schema object json
+/// {
+///     id: string - identifier of the object (DID-like string),
+///     content: string - JSON-LD document of the object serialized to string,
+///     rsName: string - name of the object,
+///     rsVersion: string - version of the object,
+///     rsType: string - type of the object, one of: "ctx", "sch", "enc", "map", "cdf", "pdf",
+///     ver: (Optional) string - version of the object json, "1" by default.
+/// }
+///
+/// # Returns
+/// Request result as json.
+pub fn build_rich_schema_object_request(submitter_did: &str, rich_schema_object_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_rich_schema_object_request(command_handle, submitter_did, rich_schema_object_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_rich_schema_object_request(command_handle: CommandHandle, submitter_did: &str, rich_schema_object_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let rich_schema_object_json = c_str!(rich_schema_object_json);
+
+    ErrorCode::from(unsafe { ledger::indy_build_rich_schema_object_request(command_handle, submitter_did.as_ptr(), rich_schema_object_json.as_ptr(), cb) })
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_ID request. Request to get Rich Schema object of any type by its id.
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// * `id` - identifier of the Rich Schema object.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_rich_schema_object_by_id_request(submitter_did: Option<&str>, id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_rich_schema_object_by_id_request(command_handle, submitter_did, id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_rich_schema_object_by_id_request(command_handle: CommandHandle, submitter_did: Option<&str>, id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let id = c_str!(id);
+
+    ErrorCode::from(unsafe { ledger::indy_build_get_rich_schema_object_by_id_request(command_handle, opt_c_ptr!(submitter_did, submitter_did_str), id.as_ptr(), cb) })
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_METADATA request. Request to get Rich Schema object by its type, name and version.
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// * `rs_type` - type of the Rich Schema object, one of: "ctx", "sch", "enc", "map", "cdf", "pdf".
+/// * `rs_name` - name of the Rich Schema object.
+/// * `rs_version` - version of the Rich Schema object.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_rich_schema_object_by_metadata_request(submitter_did: Option<&str>, rs_type: &str, rs_name: &str, rs_version: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_rich_schema_object_by_metadata_request(command_handle, submitter_did, rs_type, rs_name, rs_version, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_rich_schema_object_by_metadata_request(command_handle: CommandHandle, submitter_did: Option<&str>, rs_type: &str, rs_name: &str, rs_version: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let rs_type = c_str!(rs_type);
+    let rs_name = c_str!(rs_name);
+    let rs_version = c_str!(rs_version);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_get_rich_schema_object_by_metadata_request(command_handle,
+                                                                      opt_c_ptr!(submitter_did, submitter_did_str),
+                                                                      rs_type.as_ptr(),
+                                                                      rs_name.as_ptr(),
+                                                                      rs_version.as_ptr(),
+                                                                      cb)
+    })
+}
+
+/// Parse a GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA response to get Rich Schema object.
+///
+/// # Arguments
+/// * `get_rich_schema_object_response` - response of GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+///
+/// # Returns
+/// Rich Schema object id and Rich Schema object json.
+/// {
+///     id: string - identifier of the object,
+///     content: string - JSON-LD document of the object serialized to string,
+///     rsName: string - name of the object,
+///     rsVersion: string - version of the object,
+///     rsType: string - type of the object,
+///     ver: string - version of the object json
+/// }
+pub fn parse_get_rich_schema_object_response(get_rich_schema_object_response: &str) -> Box<dyn Future<Item=(String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string();
+
+    let err = _parse_get_rich_schema_object_response(command_handle, get_rich_schema_object_response, cb);
+
+    ResultHandler::str_str(command_handle, err, receiver)
+}
+
+fn _parse_get_rich_schema_object_response(command_handle: CommandHandle, get_rich_schema_object_response: &str, cb: Option<ResponseStringStringCB>) -> ErrorCode {
+    let get_rich_schema_object_response = c_str!(get_rich_schema_object_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_rich_schema_object_response(command_handle, get_rich_schema_object_response.as_ptr(), cb) })
+}
+
 /// Parse transaction response to fetch metadata.
 /// The important use case for this method is validation of Node's response freshens.
 ///
@@ -1510,4 +2065,194 @@ fn _append_request_endorser(command_handle: CommandHandle,
                                              endorser_did.as_ptr(),
                                              cb)
     })
-}
\ No newline at end of file
+}
+
+/// Prepares an endorsement envelope for a request which needs signatures of several parties.
+///
+/// # Arguments
+/// * `request_json`: request to be signed.
+/// * `get_auth_rule_response_json`: response of GET_AUTH_RULE request for the action performed by the request.
+/// * `owner_did`: (Optional) DID of the owner of the ledger object affected by the request.
+///
+/// # Returns
+/// Endorsement envelope json.
+pub fn prepare_endorsement(request_json: &str,
+                           get_auth_rule_response_json: &str,
+                           owner_did: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prepare_endorsement(command_handle, request_json, get_auth_rule_response_json, owner_did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prepare_endorsement(command_handle: CommandHandle,
+                        request_json: &str,
+                        get_auth_rule_response_json: &str,
+                        owner_did: Option<&str>,
+                        cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let get_auth_rule_response_json = c_str!(get_auth_rule_response_json);
+    let owner_did_str = opt_c_str!(owner_did);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_prepare_endorsement(command_handle,
+                                         request_json.as_ptr(),
+                                         get_auth_rule_response_json.as_ptr(),
+                                         opt_c_ptr!(owner_did, owner_did_str),
+                                         cb)
+    })
+}
+
+/// Signs the request of an endorsement envelope by the DID from the wallet and records the signer.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `endorsement_json`: endorsement envelope (created by `prepare_endorsement`).
+/// * `signer_did`: DID of the signer stored in the wallet.
+///
+/// # Returns
+/// Endorsement envelope json with the signature and the signer added.
+pub fn endorsement_add_signature(wallet_handle: WalletHandle,
+                                 endorsement_json: &str,
+                                 signer_did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _endorsement_add_signature(command_handle, wallet_handle, endorsement_json, signer_did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _endorsement_add_signature(command_handle: CommandHandle,
+                              wallet_handle: WalletHandle,
+                              endorsement_json: &str,
+                              signer_did: &str,
+                              cb: Option<ResponseStringCB>) -> ErrorCode {
+    let endorsement_json = c_str!(endorsement_json);
+    let signer_did = c_str!(signer_did);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_endorsement_add_signature(command_handle,
+                                               wallet_handle,
+                                               endorsement_json.as_ptr(),
+                                               signer_did.as_ptr(),
+                                               cb)
+    })
+}
+
+/// Checks signatures of the request of an endorsement envelope against the keys of the recorded signers.
+///
+/// # Arguments
+/// * `endorsement_json`: endorsement envelope (created by `prepare_endorsement`).
+///
+/// # Returns
+/// Signatures check json: {"valid": [did], "invalid": [did], "unknown": [did], "missing": [did]}
+pub fn endorsement_check_signatures(endorsement_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _endorsement_check_signatures(command_handle, endorsement_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _endorsement_check_signatures(command_handle: CommandHandle, endorsement_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let endorsement_json = c_str!(endorsement_json);
+
+    ErrorCode::from(unsafe { ledger::indy_endorsement_check_signatures(command_handle, endorsement_json.as_ptr(), cb) })
+}
+
+/// Evaluates whether the request of an endorsement envelope is ready to be submitted.
+///
+/// Roles of the signers are taken from their NYMs in GET_NYM responses.
+///
+/// # Arguments
+/// * `endorsement_json`: endorsement envelope (created by `prepare_endorsement`).
+/// * `get_nym_responses_json`: list of GET_NYM responses for the signers received from the ledger.
+///
+/// # Returns
+/// Status json: {"ready": bool, "unmet": [{"constraint": json, "signatures": int}], "signatures": json, "unresolved": [did]}
+pub fn endorsement_status(endorsement_json: &str, get_nym_responses_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _endorsement_status(command_handle, endorsement_json, get_nym_responses_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _endorsement_status(command_handle: CommandHandle, endorsement_json: &str, get_nym_responses_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let endorsement_json = c_str!(endorsement_json);
+    let get_nym_responses_json = c_str!(get_nym_responses_json);
+
+    ErrorCode::from(unsafe { ledger::indy_endorsement_status(command_handle, endorsement_json.as_ptr(), get_nym_responses_json.as_ptr(), cb) })
+}
+
+/// Submits the request of an endorsement envelope to validator pool if it is ready to be submitted.
+///
+/// # Arguments
+/// * `pool_handle`: pool handle (created by Pool::open_ledger).
+/// * `endorsement_json`: endorsement envelope (created by `prepare_endorsement`).
+/// * `get_nym_responses_json`: list of GET_NYM responses for the signers (see `endorsement_status`).
+///
+/// # Returns
+/// Request result as json.
+pub fn submit_endorsement(pool_handle: PoolHandle, endorsement_json: &str, get_nym_responses_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_endorsement(command_handle, pool_handle, endorsement_json, get_nym_responses_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_endorsement(command_handle: CommandHandle, pool_handle: PoolHandle, endorsement_json: &str, get_nym_responses_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let endorsement_json = c_str!(endorsement_json);
+    let get_nym_responses_json = c_str!(get_nym_responses_json);
+
+    ErrorCode::from(unsafe { ledger::indy_submit_endorsement(command_handle, pool_handle, endorsement_json.as_ptr(), get_nym_responses_json.as_ptr(), cb) })
+}
+
+/// Evaluates locally whether the request will be accepted by the ledger according to the auth rules.
+///
+/// # Arguments
+/// * `request_json`: request to be evaluated.
+/// * `auth_action`: action the request performs on the ledger: ADD or EDIT.
+/// * `old_value`: (Optional) current value of the field on the ledger for EDIT action.
+/// * `get_auth_rule_response_json`: response of GET_AUTH_RULE request (either for the specific rule or for all rules).
+/// * `signers_json`: signers of the request: [{"role": string or null, "owner": bool}]
+///
+/// # Returns
+/// Evaluation json: {"accepted": bool, "rules": [{"auth_action", "field", "old_value", "new_value", "satisfied", "unmet", "fees"}]}
+pub fn evaluate_auth_rule(request_json: &str,
+                          auth_action: &str,
+                          old_value: Option<&str>,
+                          get_auth_rule_response_json: &str,
+                          signers_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _evaluate_auth_rule(command_handle, request_json, auth_action, old_value, get_auth_rule_response_json, signers_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _evaluate_auth_rule(command_handle: CommandHandle,
+                       request_json: &str,
+                       auth_action: &str,
+                       old_value: Option<&str>,
+                       get_auth_rule_response_json: &str,
+                       signers_json: &str,
+                       cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let auth_action = c_str!(auth_action);
+    let old_value_str = opt_c_str!(old_value);
+    let get_auth_rule_response_json = c_str!(get_auth_rule_response_json);
+    let signers_json = c_str!(signers_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_evaluate_auth_rule(command_handle,
+                                        request_json.as_ptr(),
+                                        auth_action.as_ptr(),
+                                        opt_c_ptr!(old_value, old_value_str),
+                                        get_auth_rule_response_json.as_ptr(),
+                                        signers_json.as_ptr(),
+                                        cb)
+    })
+}
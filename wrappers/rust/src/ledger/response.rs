@@ -0,0 +1,446 @@
+//! Typed replies of the ledger transactions.
+//!
+//! Ledger functions return the replies of the pool as is. The structures below describe
+//! the replies of the read (GET_*) transactions and of the NODE transaction so they can be
+//! decoded with `parse_*` functions instead of digging through `serde_json::Value`.
+
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde_json::{self, Value};
+
+use {ErrorCode, IndyError};
+
+/// Message sent by the pool in response to a request.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "op")]
+pub enum Message<T> {
+    #[serde(rename = "REQNACK")]
+    ReqNack(Nack),
+    #[serde(rename = "REJECT")]
+    Reject(Nack),
+    #[serde(rename = "REPLY")]
+    Reply(ReplyMessage<T>),
+}
+
+/// Reason of the request rejection (REQNACK or REJECT message).
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Nack {
+    pub identifier: Option<String>,
+    pub req_id: Option<u64>,
+    pub reason: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ReplyMessage<T> {
+    pub result: T,
+}
+
+/// Result of the read transaction.
+///
+/// Fields common to all read replies are kept here, the type specific ones are in `result`.
+/// `seq_no` and `txn_time` are None if the requested object isn't written on the ledger.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GetReply<T> {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub identifier: Option<String>,
+    pub req_id: Option<u64>,
+    pub seq_no: Option<u64>,
+    pub txn_time: Option<u64>,
+    #[serde(rename = "state_proof")]
+    pub state_proof: Option<Value>,
+    #[serde(flatten)]
+    pub result: T,
+}
+
+impl<T> GetReply<T> {
+    /// Whether the requested object is written on the ledger.
+    pub fn is_found(&self) -> bool {
+        self.seq_no.is_some()
+    }
+}
+
+/// Result of the write transaction.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteReply<T> {
+    pub txn: Txn<T>,
+    pub txn_metadata: TxnMetadata,
+    pub ver: Option<String>,
+    pub root_hash: Option<String>,
+    pub audit_path: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Txn<T> {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub data: T,
+    pub metadata: TxnAuthorMetadata,
+    pub protocol_version: Option<usize>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TxnAuthorMetadata {
+    pub from: Option<String>,
+    pub req_id: Option<u64>,
+    pub endorser: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TxnMetadata {
+    pub seq_no: Option<u64>,
+    pub txn_time: Option<u64>,
+    pub txn_id: Option<String>,
+}
+
+/// GET_NYM result. `data` is the json string with the NYM, it can be decoded with `GetNymResult::nym`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct GetNymResult {
+    pub dest: String,
+    pub data: Option<String>,
+}
+
+impl GetNymResult {
+    pub fn nym(&self) -> Result<Option<NymData>, IndyError> {
+        _parse_nested(self.data.as_ref())
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NymData {
+    pub dest: String,
+    pub identifier: Option<String>,
+    pub role: Option<String>,
+    pub verkey: Option<String>,
+    pub seq_no: Option<u64>,
+    pub txn_time: Option<u64>,
+}
+
+/// GET_ATTR result. Only one of `raw`, `hash` and `enc` is set: the name of the requested attribute.
+///
+/// `data` is the attribute value: the json string for `raw` attribute, it can be decoded with `GetAttribResult::raw_data`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct GetAttribResult {
+    pub dest: String,
+    pub raw: Option<String>,
+    pub hash: Option<String>,
+    pub enc: Option<String>,
+    pub data: Option<String>,
+}
+
+impl GetAttribResult {
+    pub fn raw_data(&self) -> Result<Option<Value>, IndyError> {
+        _parse_nested(self.data.as_ref())
+    }
+}
+
+/// GET_SCHEMA result. `attr_names` are None if the schema isn't found.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct GetSchemaResult {
+    pub dest: String,
+    pub data: SchemaData,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct SchemaData {
+    pub name: String,
+    pub version: String,
+    pub attr_names: Option<Vec<String>>,
+}
+
+/// GET_CLAIM_DEF result. `data` contains public keys of the credential definition.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct GetCredDefResult {
+    #[serde(rename = "ref")]
+    pub ref_: u64,
+    pub signature_type: String,
+    pub origin: String,
+    pub tag: Option<String>,
+    pub data: Option<Value>,
+}
+
+/// GET_REVOC_REG_DEF result.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct GetRevocRegDefResult {
+    pub id: String,
+    pub data: Option<Value>,
+}
+
+/// GET_REVOC_REG result. `data` contains the accumulator of the registry at `timestamp`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRevocRegResult {
+    pub revoc_reg_def_id: String,
+    pub timestamp: Option<u64>,
+    pub data: Option<Value>,
+}
+
+/// GET_REVOC_REG_DELTA result.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRevocRegDeltaResult {
+    pub revoc_reg_def_id: String,
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    pub data: Option<RevocRegDeltaData>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct RevocRegDeltaData {
+    pub value: RevocRegDeltaValue,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct RevocRegDeltaValue {
+    pub accum_from: Option<AccumulatorState>,
+    pub accum_to: AccumulatorState,
+    #[serde(default)]
+    pub issued: Vec<u32>,
+    #[serde(default)]
+    pub revoked: Vec<u32>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccumulatorState {
+    pub value: Value,
+    pub txn_time: u64,
+}
+
+/// GET_AUTH_RULE result.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct GetAuthRuleResult {
+    pub data: Vec<AuthRule>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct AuthRule {
+    pub auth_type: String,
+    pub auth_action: String,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub constraint: Constraint,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "constraint_id")]
+pub enum Constraint {
+    #[serde(rename = "OR")]
+    OrConstraint(CombinationConstraint),
+    #[serde(rename = "AND")]
+    AndConstraint(CombinationConstraint),
+    #[serde(rename = "ROLE")]
+    RoleConstraint(RoleConstraint),
+    #[serde(rename = "FORBIDDEN")]
+    ForbiddenConstraint(ForbiddenConstraint),
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct RoleConstraint {
+    pub sig_count: u32,
+    pub role: Option<String>,
+    pub metadata: Option<Value>,
+    #[serde(default)]
+    pub need_to_be_owner: bool,
+    #[serde(default)]
+    pub off_ledger_signature: bool,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct CombinationConstraint {
+    pub auth_constraints: Vec<Constraint>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ForbiddenConstraint {}
+
+/// GET_TXN_AUTHR_AGRMT result. `data` is None if no agreement is set.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct GetTxnAuthorAgreementResult {
+    pub data: Option<TxnAuthorAgreement>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TxnAuthorAgreement {
+    pub text: Option<String>,
+    pub version: String,
+    pub digest: Option<String>,
+    pub ratification_ts: Option<u64>,
+    pub retirement_ts: Option<u64>,
+}
+
+/// GET_TXN_AUTHR_AGRMT_AML result. `data` is None if no acceptance mechanisms are set.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct GetAcceptanceMechanismsResult {
+    pub data: Option<AcceptanceMechanisms>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptanceMechanisms {
+    pub aml: HashMap<String, Value>,
+    pub version: String,
+    pub aml_context: Option<String>,
+}
+
+/// Data of the NODE transaction.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct NodeTxnData {
+    pub dest: String,
+    pub data: NodeData,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct NodeData {
+    pub alias: String,
+    pub node_ip: Option<String>,
+    pub node_port: Option<u32>,
+    pub client_ip: Option<String>,
+    pub client_port: Option<u32>,
+    pub services: Option<Vec<String>>,
+    pub blskey: Option<String>,
+    pub blskey_pop: Option<String>,
+}
+
+/// VALIDATOR_INFO result of the single node.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ValidatorInfoResult {
+    pub data: Option<Value>,
+}
+
+/// Decodes the reply of the pool with the given result type.
+///
+/// REQNACK and REJECT messages are returned as `LedgerInvalidTransaction` error with the reason of the rejection.
+pub fn parse_reply<T: DeserializeOwned>(response: &str) -> Result<T, IndyError> {
+    let message: Message<T> = serde_json::from_str(response)
+        .map_err(|err| _error(ErrorCode::CommonInvalidStructure, format!("Cannot parse ledger reply: {}", err)))?;
+
+    match message {
+        Message::Reply(reply) => Ok(reply.result),
+        Message::ReqNack(nack) | Message::Reject(nack) =>
+            Err(_error(ErrorCode::LedgerInvalidTransaction, nack.reason))
+    }
+}
+
+/// Decodes the reply of the read transaction of the given type (`105` for GET_NYM for instance).
+pub fn parse_get_reply<T: DeserializeOwned>(response: &str, type_: &str) -> Result<GetReply<T>, IndyError> {
+    let reply: GetReply<T> = parse_reply(response)?;
+
+    if reply.type_ != type_ {
+        return Err(_error(ErrorCode::CommonInvalidStructure,
+                          format!("Ledger reply of type {} has been passed instead of {}", reply.type_, type_)));
+    }
+
+    Ok(reply)
+}
+
+pub fn parse_get_nym_reply(get_nym_response: &str) -> Result<GetReply<GetNymResult>, IndyError> {
+    parse_get_reply(get_nym_response, GET_NYM)
+}
+
+pub fn parse_get_attrib_reply(get_attrib_response: &str) -> Result<GetReply<GetAttribResult>, IndyError> {
+    parse_get_reply(get_attrib_response, GET_ATTR)
+}
+
+pub fn parse_get_schema_reply(get_schema_response: &str) -> Result<GetReply<GetSchemaResult>, IndyError> {
+    parse_get_reply(get_schema_response, GET_SCHEMA)
+}
+
+pub fn parse_get_cred_def_reply(get_cred_def_response: &str) -> Result<GetReply<GetCredDefResult>, IndyError> {
+    parse_get_reply(get_cred_def_response, GET_CRED_DEF)
+}
+
+pub fn parse_get_revoc_reg_def_reply(get_revoc_reg_def_response: &str) -> Result<GetReply<GetRevocRegDefResult>, IndyError> {
+    parse_get_reply(get_revoc_reg_def_response, GET_REVOC_REG_DEF)
+}
+
+pub fn parse_get_revoc_reg_reply(get_revoc_reg_response: &str) -> Result<GetReply<GetRevocRegResult>, IndyError> {
+    parse_get_reply(get_revoc_reg_response, GET_REVOC_REG)
+}
+
+pub fn parse_get_revoc_reg_delta_reply(get_revoc_reg_delta_response: &str) -> Result<GetReply<GetRevocRegDeltaResult>, IndyError> {
+    parse_get_reply(get_revoc_reg_delta_response, GET_REVOC_REG_DELTA)
+}
+
+pub fn parse_get_auth_rule_reply(get_auth_rule_response: &str) -> Result<GetReply<GetAuthRuleResult>, IndyError> {
+    parse_get_reply(get_auth_rule_response, GET_AUTH_RULE)
+}
+
+pub fn parse_get_txn_author_agreement_reply(get_txn_author_agreement_response: &str) -> Result<GetReply<GetTxnAuthorAgreementResult>, IndyError> {
+    parse_get_reply(get_txn_author_agreement_response, GET_TXN_AUTHR_AGRMT)
+}
+
+pub fn parse_get_acceptance_mechanisms_reply(get_acceptance_mechanisms_response: &str) -> Result<GetReply<GetAcceptanceMechanismsResult>, IndyError> {
+    parse_get_reply(get_acceptance_mechanisms_response, GET_TXN_AUTHR_AGRMT_AML)
+}
+
+/// Decodes the reply of the NODE transaction.
+pub fn parse_node_reply(node_response: &str) -> Result<WriteReply<NodeTxnData>, IndyError> {
+    let reply: WriteReply<NodeTxnData> = parse_reply(node_response)?;
+
+    if reply.txn.type_ != NODE {
+        return Err(_error(ErrorCode::CommonInvalidStructure,
+                          format!("Ledger reply of type {} has been passed instead of {}", reply.txn.type_, NODE)));
+    }
+
+    Ok(reply)
+}
+
+/// Decodes the replies of the nodes to VALIDATOR_INFO action (returned by `submit_action`).
+///
+/// The reply of the node which didn't answer in time is returned as `PoolLedgerTimeout` error.
+pub fn parse_validator_info_replies(validator_info_response: &str) -> Result<HashMap<String, Result<GetReply<ValidatorInfoResult>, IndyError>>, IndyError> {
+    let replies: HashMap<String, String> = serde_json::from_str(validator_info_response)
+        .map_err(|err| _error(ErrorCode::CommonInvalidStructure, format!("Cannot parse validator info replies: {}", err)))?;
+
+    Ok(replies
+        .into_iter()
+        .map(|(node, reply)| {
+            let reply = if reply == "timeout" {
+                Err(_error(ErrorCode::PoolLedgerTimeout, format!("Node {} hasn't replied in time", node)))
+            } else {
+                parse_get_reply(&reply, GET_VALIDATOR_INFO)
+            };
+            (node, reply)
+        })
+        .collect())
+}
+
+const NODE: &str = "0";
+const GET_NYM: &str = "105";
+const GET_ATTR: &str = "104";
+const GET_SCHEMA: &str = "107";
+const GET_CRED_DEF: &str = "108";
+const GET_REVOC_REG_DEF: &str = "115";
+const GET_REVOC_REG: &str = "116";
+const GET_REVOC_REG_DELTA: &str = "117";
+const GET_VALIDATOR_INFO: &str = "119";
+const GET_AUTH_RULE: &str = "121";
+const GET_TXN_AUTHR_AGRMT: &str = "6";
+const GET_TXN_AUTHR_AGRMT_AML: &str = "7";
+
+fn _parse_nested<T: DeserializeOwned>(data: Option<&String>) -> Result<Option<T>, IndyError> {
+    match data {
+        Some(data) => serde_json::from_str(data)
+            .map(Some)
+            .map_err(|err| _error(ErrorCode::CommonInvalidStructure, format!("Cannot parse data of ledger reply: {}", err))),
+        None => Ok(None)
+    }
+}
+
+fn _error(error_code: ErrorCode, message: String) -> IndyError {
+    IndyError {
+        error_code,
+        message,
+        indy_backtrace: None,
+    }
+}
@@ -19,7 +19,15 @@ use {CommandHandle, PoolHandle};
 /// * `config_name` - Name of the pool ledger configuration.
 /// * `config`  (required)- Pool configuration json. Example:
 /// {
-///     "genesis_txn": string (required), A path to genesis transaction file.
+///     "genesis_txn": string (optional), A path to genesis transaction file.
+///     "genesis_txns": array<object> (optional), Genesis transactions inlined into the config.
+///                    Either `genesis_txn` or `genesis_txns` must be specified.
+///     "genesis_hash": string (optional), Expected base58 encoded merkle root hash of genesis transactions.
+///     "genesis_signature": object (optional), Steward signature of the merkle root hash of genesis transactions:
+///         {
+///             "verkey": string, base58 encoded verkey of the signer,
+///             "signature": string, base58 encoded ed25519 signature
+///         }
 /// }
 pub fn create_pool_ledger_config(pool_name: &str, pool_config: Option<&str>) -> Box<dyn Future<Item=(), Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
@@ -58,6 +66,16 @@ fn _create_pool_ledger_config(command_handle: CommandHandle, pool_name: &str, po
 ///     "number_read_nodes": int (optional) - the number of nodes to send read requests (2 by default)
 ///         By default Libindy sends a read requests to 2 nodes in the pool.
 ///         If response isn't received or `state proof` is invalid Libindy sends the request again but to 2 (`number_read_nodes`) * 2 = 4 nodes and so far until completion.
+///     "read_consensus": string (optional) - consensus policy for read requests ("fast" by default)
+///         "fast" - first reply with verified state proof or f+1 matching replies
+///         "state_proof" - only reply with verified state proof is accepted
+///         "consensus" - f+1 matching replies are required
+///         "all" - request is sent to all nodes, divergent replies are returned as a diff (see indy_submit_request_with_options)
+///         Can be overridden for a single request by indy_submit_request_with_options.
+///     "transport": object (optional) - network transport used to communicate with the pool (ZMQ by default)
+///         {
+///             "type": string - "zmq"
+///         }
 /// }
 ///
 /// # Returns
@@ -79,6 +97,10 @@ fn _open_pool_ledger(command_handle: CommandHandle, pool_name: &str, config: Opt
 
 /// Refreshes a local copy of a pool ledger and updates pool nodes connections.
 ///
+/// Note: the pool ledger is also refreshed in background without interrupting requests in progress
+/// when libindy detects that the pool membership is outdated (replies are signed by unknown nodes
+/// or more than f nodes don't reply in time).
+///
 /// # Arguments
 /// * `handle` - pool handle returned by open_ledger
 pub fn refresh_pool_ledger(pool_handle: PoolHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
@@ -93,6 +115,64 @@ fn _refresh_pool_ledger(command_handle: CommandHandle, pool_handle: PoolHandle,
     ErrorCode::from(unsafe { pool::indy_refresh_pool_ledger(command_handle, pool_handle, cb) })
 }
 
+/// Returns health information about an opened pool ledger collected from node replies.
+///
+/// # Arguments
+/// * `handle` - pool handle returned by open_ledger
+///
+/// # Returns
+/// Pool status json:
+/// {
+///     "state": string - state of the pool worker ("active", "terminated", etc.)
+///     "nodes": [{
+///         "alias": string - node alias
+///         "last_seen": int or null - unix time (in seconds) of the last message received from the node
+///         "avg_latency_ms": int or null - average time between sending a request and receiving the node's reply
+///         "replies": int - count of REPLY messages received from the node
+///         "nacks": int - count of REQNACK and REJECT messages received from the node
+///         "timeouts": int - count of requests the node didn't reply in time
+///         "invalid_replies": int - count of messages received from the node which can't be parsed
+///         "ledger_size": int or null - size of the pool ledger reported by the node on the last catchup
+///     }]
+/// }
+pub fn get_pool_status(pool_handle: PoolHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_pool_status(command_handle, pool_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_pool_status(command_handle: CommandHandle, pool_handle: PoolHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { pool::indy_get_pool_status(command_handle, pool_handle, cb) })
+}
+
+/// Returns BLS keys of validator nodes of a pool ledger config.
+///
+/// The keys are read from the locally stored pool ledger, so the pool doesn't need to be opened.
+///
+/// # Arguments
+/// * `config_name` - Name of the pool ledger configuration.
+///
+/// # Returns
+/// BLS keys json:
+/// {
+///     <node alias>: <base58 BLS key> or null if node has no BLS key
+/// }
+pub fn get_pool_bls_keys(config_name: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_pool_bls_keys(command_handle, config_name, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_pool_bls_keys(command_handle: CommandHandle, config_name: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let config_name = c_str!(config_name);
+
+    ErrorCode::from(unsafe { pool::indy_get_pool_bls_keys(command_handle, config_name.as_ptr(), cb) })
+}
+
 /// Lists names of created pool ledgers
 pub fn list_pools() -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
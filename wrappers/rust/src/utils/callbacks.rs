@@ -10,7 +10,7 @@ use std::ffi::CStr;
 use std::sync::Mutex;
 
 use futures::*;
-use futures::sync::oneshot;
+use futures::sync::{mpsc, oneshot};
 
 lazy_static! {
     static ref CALLBACKS_EMPTY: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(), IndyError>>>> = Default::default();
@@ -27,6 +27,7 @@ lazy_static! {
     static ref CALLBACKS_STR_OPTSTR: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, Option<String>), IndyError>>>> = Default::default();
     static ref CALLBACKS_STR_STR_STR: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, String, String), IndyError>>>> = Default::default();
     static ref CALLBACKS_STR_OPTSTR_OPTSTR: Mutex<HashMap<CommandHandle, oneshot::Sender<Result<(String, Option<String>, Option<String>), IndyError>>>> = Default::default();
+    static ref CALLBACKS_STR_STREAM: Mutex<HashMap<CommandHandle, mpsc::UnboundedSender<Result<String, IndyError>>>> = Default::default();
 }
 
 macro_rules! cb_ec {
@@ -107,6 +108,37 @@ impl ClosureHandler {
            (rust_str!(str), rust_slice!(data, len).to_owned()));
 
     cb_ec!(cb_ec_bool(b: bool)->bool, CALLBACKS_BOOL, b);
+
+    /// Callback that can be called many times for the same command handle.
+    /// Results are sent to the stream until the receiver is dropped or `drop_stream` is called.
+    pub fn cb_ec_string_stream(command_handle: CommandHandle) -> (mpsc::UnboundedReceiver<Result<String, IndyError>>,
+                                                                   Option<extern fn(command_handle: CommandHandle, err: i32, str1: *const c_char)>) {
+        extern fn callback(command_handle: CommandHandle, err: i32, str1: *const c_char) {
+            let res = if err != 0 {
+                Err(IndyError::new(ErrorCode::from(err)))
+            } else {
+                Ok(rust_str!(str1))
+            };
+
+            let mut callbacks = CALLBACKS_STR_STREAM.lock().unwrap();
+
+            let closed = callbacks.get(&command_handle)
+                .map(|tx| tx.unbounded_send(res).is_err())
+                .unwrap_or(false);
+
+            if closed {
+                callbacks.remove(&command_handle);
+            }
+        }
+
+        let (tx, rx) = mpsc::unbounded();
+        CALLBACKS_STR_STREAM.lock().unwrap().insert(command_handle, tx);
+        (rx, Some(callback))
+    }
+
+    pub fn drop_stream(command_handle: CommandHandle) {
+        CALLBACKS_STR_STREAM.lock().unwrap().remove(&command_handle);
+    }
 }
 
 macro_rules! result_handler {
@@ -188,4 +220,18 @@ mod test {
         assert_eq!(str1, "This is a test".to_string());
         assert_eq!(str2, Some("The second string has something".to_string()));
     }
+
+    #[test]
+    fn ec_string_stream() {
+        let command_handle: CommandHandle = ::utils::sequence::SequenceUtils::get_next_id();
+        let (receiver, cb) = ClosureHandler::cb_ec_string_stream(command_handle);
+
+        let callback = cb.unwrap();
+        callback(command_handle, 0, CString::new("first").unwrap().as_ptr());
+        callback(command_handle, 0, CString::new("second").unwrap().as_ptr());
+        ClosureHandler::drop_stream(command_handle);
+
+        let results: Vec<String> = receiver.wait().map(|res| res.unwrap().unwrap()).collect();
+        assert_eq!(results, vec!["first".to_string(), "second".to_string()]);
+    }
 }
@@ -657,4 +657,280 @@ mod test_build_auth_rule_request {
                                                                          None,
                                                                          None).wait().unwrap();
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test_parse_replies {
+    use super::*;
+    use indy::ErrorCode;
+    use indy::ledger::response;
+
+    #[test]
+    pub fn parse_get_nym_reply_works() {
+        let reply = json!({
+            "op": "REPLY",
+            "result": {
+                "type": "105",
+                "identifier": "V4SGRU86Z58d6TV7PBUe6f",
+                "reqId": 1,
+                "seqNo": 10,
+                "txnTime": 1577836800,
+                "state_proof": {},
+                "dest": "VsKV7grR1BUE29mG2Fm2kX",
+                "data": json!({
+                    "dest": "VsKV7grR1BUE29mG2Fm2kX",
+                    "identifier": "V4SGRU86Z58d6TV7PBUe6f",
+                    "role": "101",
+                    "verkey": "~CoRER63DVYnWZtK8uAzNbx",
+                    "seqNo": 10,
+                    "txnTime": 1577836800
+                }).to_string()
+            }
+        }).to_string();
+
+        let reply = response::parse_get_nym_reply(&reply).unwrap();
+        assert!(reply.is_found());
+        assert_eq!(Some(1577836800), reply.txn_time);
+
+        let nym = reply.result.nym().unwrap().unwrap();
+        assert_eq!("VsKV7grR1BUE29mG2Fm2kX", nym.dest);
+        assert_eq!(Some("101".to_string()), nym.role);
+        assert_eq!(Some("~CoRER63DVYnWZtK8uAzNbx".to_string()), nym.verkey);
+    }
+
+    #[test]
+    pub fn parse_get_nym_reply_works_for_not_found() {
+        let reply = json!({
+            "op": "REPLY",
+            "result": {"type": "105", "reqId": 1, "seqNo": null, "txnTime": null, "dest": "VsKV7grR1BUE29mG2Fm2kX", "data": null}
+        }).to_string();
+
+        let reply = response::parse_get_nym_reply(&reply).unwrap();
+        assert!(!reply.is_found());
+        assert_eq!(None, reply.result.nym().unwrap());
+    }
+
+    #[test]
+    pub fn parse_get_nym_reply_works_for_reject() {
+        let reply = json!({"op": "REQNACK", "reqId": 1, "reason": "client request invalid"}).to_string();
+
+        let err = response::parse_get_nym_reply(&reply).unwrap_err();
+        assert_eq!(ErrorCode::LedgerInvalidTransaction, err.error_code);
+        assert_eq!("client request invalid", err.message);
+    }
+
+    #[test]
+    pub fn parse_get_nym_reply_works_for_other_type() {
+        let reply = json!({
+            "op": "REPLY",
+            "result": {"type": "104", "reqId": 1, "dest": "VsKV7grR1BUE29mG2Fm2kX", "raw": "endpoint", "data": null}
+        }).to_string();
+
+        let err = response::parse_get_nym_reply(&reply).unwrap_err();
+        assert_eq!(ErrorCode::CommonInvalidStructure, err.error_code);
+    }
+
+    #[test]
+    pub fn parse_get_attrib_reply_works() {
+        let reply = json!({
+            "op": "REPLY",
+            "result": {
+                "type": "104",
+                "reqId": 1,
+                "seqNo": 11,
+                "txnTime": 1577836800,
+                "dest": "VsKV7grR1BUE29mG2Fm2kX",
+                "raw": "endpoint",
+                "data": r#"{"endpoint":{"ha":"127.0.0.1:5555"}}"#
+            }
+        }).to_string();
+
+        let reply = response::parse_get_attrib_reply(&reply).unwrap();
+        assert_eq!(Some("endpoint".to_string()), reply.result.raw);
+        assert_eq!(json!({"endpoint": {"ha": "127.0.0.1:5555"}}), reply.result.raw_data().unwrap().unwrap());
+    }
+
+    #[test]
+    pub fn parse_get_schema_reply_works() {
+        let reply = json!({
+            "op": "REPLY",
+            "result": {
+                "type": "107",
+                "reqId": 1,
+                "seqNo": 12,
+                "txnTime": 1577836800,
+                "dest": "V4SGRU86Z58d6TV7PBUe6f",
+                "data": {"name": "gvt", "version": "1.0", "attr_names": ["name", "age"]}
+            }
+        }).to_string();
+
+        let reply = response::parse_get_schema_reply(&reply).unwrap();
+        assert_eq!("gvt", reply.result.data.name);
+        assert_eq!(Some(vec!["name".to_string(), "age".to_string()]), reply.result.data.attr_names);
+    }
+
+    #[test]
+    pub fn parse_get_cred_def_reply_works() {
+        let reply = json!({
+            "op": "REPLY",
+            "result": {
+                "type": "108",
+                "reqId": 1,
+                "seqNo": 13,
+                "txnTime": 1577836800,
+                "ref": 12,
+                "signature_type": "CL",
+                "origin": "V4SGRU86Z58d6TV7PBUe6f",
+                "tag": "TAG_1",
+                "data": {"primary": {"n": "1"}}
+            }
+        }).to_string();
+
+        let reply = response::parse_get_cred_def_reply(&reply).unwrap();
+        assert_eq!(12, reply.result.ref_);
+        assert_eq!(Some("TAG_1".to_string()), reply.result.tag);
+        assert_eq!(Some(json!({"primary": {"n": "1"}})), reply.result.data);
+    }
+
+    #[test]
+    pub fn parse_get_revoc_reg_delta_reply_works() {
+        let reply = json!({
+            "op": "REPLY",
+            "result": {
+                "type": "117",
+                "reqId": 1,
+                "seqNo": 15,
+                "txnTime": 1577836900,
+                "revocRegDefId": "V4SGRU86Z58d6TV7PBUe6f:4:V4SGRU86Z58d6TV7PBUe6f:3:CL:12:TAG_1:CL_ACCUM:TAG_1",
+                "from": null,
+                "to": 1577836900,
+                "data": {
+                    "revocDefType": "CL_ACCUM",
+                    "value": {
+                        "accum_to": {"value": {"accum": "1"}, "txnTime": 1577836900},
+                        "issued": [],
+                        "revoked": [1, 2]
+                    }
+                }
+            }
+        }).to_string();
+
+        let reply = response::parse_get_revoc_reg_delta_reply(&reply).unwrap();
+        let value = reply.result.data.unwrap().value;
+        assert_eq!(None, value.accum_from);
+        assert_eq!(1577836900, value.accum_to.txn_time);
+        assert_eq!(vec![1, 2], value.revoked);
+    }
+
+    #[test]
+    pub fn parse_get_auth_rule_reply_works() {
+        let reply = json!({
+            "op": "REPLY",
+            "result": {
+                "type": "121",
+                "reqId": 1,
+                "data": [{
+                    "auth_type": "1",
+                    "auth_action": "ADD",
+                    "field": "role",
+                    "old_value": null,
+                    "new_value": "101",
+                    "constraint": {
+                        "constraint_id": "OR",
+                        "auth_constraints": [
+                            {"constraint_id": "ROLE", "role": "0", "sig_count": 1, "need_to_be_owner": false, "metadata": {}},
+                            {"constraint_id": "FORBIDDEN"}
+                        ]
+                    }
+                }]
+            }
+        }).to_string();
+
+        let reply = response::parse_get_auth_rule_reply(&reply).unwrap();
+        let rule = &reply.result.data[0];
+        assert_eq!("role", rule.field);
+        match rule.constraint {
+            response::Constraint::OrConstraint(ref constraint) => {
+                assert_eq!(2, constraint.auth_constraints.len());
+                assert_eq!(response::Constraint::ForbiddenConstraint(response::ForbiddenConstraint {}), constraint.auth_constraints[1]);
+            }
+            _ => panic!("OR constraint expected")
+        }
+    }
+
+    #[test]
+    pub fn parse_get_txn_author_agreement_reply_works() {
+        let reply = json!({
+            "op": "REPLY",
+            "result": {
+                "type": "6",
+                "reqId": 1,
+                "seqNo": 3,
+                "txnTime": 1577836800,
+                "data": {"text": "some agreement", "version": "1.0", "digest": "abc", "ratification_ts": 1577836000}
+            }
+        }).to_string();
+
+        let reply = response::parse_get_txn_author_agreement_reply(&reply).unwrap();
+        let agreement = reply.result.data.unwrap();
+        assert_eq!(Some("some agreement".to_string()), agreement.text);
+        assert_eq!(Some(1577836000), agreement.ratification_ts);
+    }
+
+    #[test]
+    pub fn parse_get_acceptance_mechanisms_reply_works() {
+        let reply = json!({
+            "op": "REPLY",
+            "result": {
+                "type": "7",
+                "reqId": 1,
+                "seqNo": 2,
+                "txnTime": 1577836800,
+                "data": {"aml": {"click_agreement": "I agree"}, "version": "1.0", "amlContext": "some context"}
+            }
+        }).to_string();
+
+        let reply = response::parse_get_acceptance_mechanisms_reply(&reply).unwrap();
+        let aml = reply.result.data.unwrap();
+        assert!(aml.aml.contains_key("click_agreement"));
+        assert_eq!(Some("some context".to_string()), aml.aml_context);
+    }
+
+    #[test]
+    pub fn parse_node_reply_works() {
+        let reply = json!({
+            "op": "REPLY",
+            "result": {
+                "txn": {
+                    "type": "0",
+                    "data": {
+                        "dest": "Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv",
+                        "data": {"alias": "Node5", "node_ip": "10.0.0.5", "node_port": 9701, "services": ["VALIDATOR"]}
+                    },
+                    "metadata": {"from": "V4SGRU86Z58d6TV7PBUe6f", "reqId": 1},
+                    "protocolVersion": 2
+                },
+                "txnMetadata": {"seqNo": 5, "txnTime": 1577836800},
+                "ver": "1"
+            }
+        }).to_string();
+
+        let reply = response::parse_node_reply(&reply).unwrap();
+        assert_eq!("Node5", reply.txn.data.data.alias);
+        assert_eq!(Some(vec!["VALIDATOR".to_string()]), reply.txn.data.data.services);
+        assert_eq!(Some(5), reply.txn_metadata.seq_no);
+    }
+
+    #[test]
+    pub fn parse_validator_info_replies_works() {
+        let node_reply = json!({
+            "op": "REPLY",
+            "result": {"type": "119", "identifier": "V4SGRU86Z58d6TV7PBUe6f", "reqId": 1, "data": {"alias": "Node1"}}
+        }).to_string();
+        let replies = json!({"Node1": node_reply, "Node2": "timeout"}).to_string();
+
+        let replies = response::parse_validator_info_replies(&replies).unwrap();
+        assert_eq!(Some(json!({"alias": "Node1"})), replies["Node1"].as_ref().unwrap().result.data);
+        assert_eq!(ErrorCode::PoolLedgerTimeout, replies["Node2"].as_ref().unwrap_err().error_code);
+    }
+}